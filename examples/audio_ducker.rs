@@ -0,0 +1,87 @@
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use futures_util::{pin_mut, StreamExt};
+use obws::{
+    client::{CoalescedRequest, ConnectConfig},
+    events::Event,
+    requests::{
+        inputs::{InputId, Volume},
+        EventSubscription,
+    },
+    Client,
+};
+
+/// Peak level (in mul) of the microphone, above which it's considered to be speaking.
+const THRESHOLD: f32 = 0.05;
+/// Time to keep the music ducked, after the microphone went silent.
+const HOLD: Duration = Duration::from_millis(800);
+/// Music volume while ducked.
+const DUCKED_DB: f32 = -20.0;
+/// Music volume while not ducked.
+const NORMAL_DB: f32 = 0.0;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenvy::dotenv().ok();
+
+    env::set_var("RUST_LOG", "obws=debug");
+    tracing_subscriber::fmt::init();
+
+    let mic = env::var("OBS_MIC_INPUT").unwrap_or_else(|_| "Mic/Aux".to_owned());
+    let music = env::var("OBS_MUSIC_INPUT").unwrap_or_else(|_| "Music".to_owned());
+
+    // Volume meters are high volume events, that must be subscribed to explicitly.
+    let client = Client::connect_with_config(
+        ConnectConfig::builder("localhost", 4455)
+            .maybe_password(env::var("OBS_PASSWORD").ok())
+            .event_subscriptions(EventSubscription::ALL | EventSubscription::INPUT_VOLUME_METERS)
+            .build(),
+    )
+    .await?
+    .with_latest_wins([CoalescedRequest::InputVolume]);
+
+    let events = client.events()?;
+    pin_mut!(events);
+
+    let mut last_voice = None::<Instant>;
+    let mut ducked = false;
+
+    while let Some(event) = events.next().await {
+        let Event::InputVolumeMeters { inputs } = event else {
+            continue;
+        };
+
+        let peak = inputs
+            .iter()
+            .filter(|input| input.name == mic)
+            .flat_map(|input| &input.levels)
+            .map(|[_, peak, _]| *peak)
+            .fold(0.0, f32::max);
+
+        if peak > THRESHOLD {
+            last_voice = Some(Instant::now());
+        }
+
+        let speaking = last_voice.is_some_and(|last| last.elapsed() < HOLD);
+        if speaking == ducked {
+            continue;
+        }
+
+        ducked = speaking;
+        println!("{} music", if ducked { "ducking" } else { "restoring" });
+
+        client
+            .inputs()
+            .set_volume(
+                InputId::Name(&music),
+                Volume::Db(if ducked { DUCKED_DB } else { NORMAL_DB }),
+            )
+            .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use std::{
+    env,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use obws::{
+    requests::inputs::{InputId, SetSettings},
+    Client,
+};
+use serde_json::json;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenvy::dotenv().ok();
+
+    env::set_var("RUST_LOG", "obws=debug");
+    tracing_subscriber::fmt::init();
+
+    let source = env::var("OBS_TEXT_INPUT").unwrap_or_else(|_| "Countdown".to_owned());
+    let minutes = env::args()
+        .nth(1)
+        .map(|minutes| minutes.parse::<u64>())
+        .transpose()?
+        .unwrap_or(5);
+
+    let client = Client::connect("localhost", 4455, env::var("OBS_PASSWORD").ok()).await?;
+
+    let end = Instant::now() + Duration::from_secs(minutes * 60);
+    let mut interval = tokio::time::interval(Duration::from_secs(1));
+
+    loop {
+        interval.tick().await;
+
+        let left = end.saturating_duration_since(Instant::now()).as_secs();
+        let text = format!("{:02}:{:02}", left / 60, left % 60);
+
+        // Only the text is changed, all other settings of the text source stay as they are.
+        client
+            .inputs()
+            .set_settings(SetSettings {
+                input: InputId::Name(&source),
+                settings: &json!({ "text": text }),
+                overlay: Some(true),
+            })
+            .await?;
+
+        if left == 0 {
+            break;
+        }
+    }
+
+    Ok(())
+}
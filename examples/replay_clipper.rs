@@ -0,0 +1,46 @@
+use std::{env, path::Path, time::Duration};
+
+use anyhow::Result;
+use obws::Client;
+use tokio::fs;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenvy::dotenv().ok();
+
+    env::set_var("RUST_LOG", "obws=debug");
+    tracing_subscriber::fmt::init();
+
+    let every = env::args()
+        .nth(1)
+        .map(|seconds| seconds.parse::<u64>())
+        .transpose()?
+        .unwrap_or(60);
+
+    let client = Client::connect("localhost", 4455, env::var("OBS_PASSWORD").ok()).await?;
+    let replay_buffer = client.replay_buffer();
+
+    if !replay_buffer.status().await? {
+        replay_buffer.start().await?;
+    }
+
+    fs::create_dir_all("clips").await?;
+
+    let mut interval = tokio::time::interval(Duration::from_secs(every));
+    // The first tick completes immediately, but the buffer needs time to fill up.
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let path = replay_buffer.save_and_wait(Duration::from_secs(10)).await?;
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+
+        let target = Path::new("clips").join(name);
+        fs::copy(&path, &target).await?;
+
+        println!("saved clip to {}", target.display());
+    }
+}
@@ -0,0 +1,56 @@
+use std::env;
+
+use anyhow::Result;
+use futures_util::{pin_mut, StreamExt};
+use obws::{client::Priority, events::Event, Client};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenvy::dotenv().ok();
+
+    env::set_var("RUST_LOG", "obws=debug");
+    tracing_subscriber::fmt::init();
+
+    let mic = env::var("OBS_MIC_INPUT").unwrap_or_else(|_| "Mic/Aux".to_owned());
+    let brb = env::var("OBS_BRB_SCENE").unwrap_or_else(|_| "BRB".to_owned());
+
+    let client = Client::connect("localhost", 4455, env::var("OBS_PASSWORD").ok()).await?;
+
+    let events = client.events()?;
+    pin_mut!(events);
+
+    // Scene that was shown before switching to the BRB scene.
+    let mut previous = None;
+
+    while let Some(event) = events.next().await {
+        let Event::InputMuteStateChanged { id, muted } = event else {
+            continue;
+        };
+        if id.name != mic {
+            continue;
+        }
+
+        if muted {
+            let current = client.scenes().current_program_scene().await?.id.name;
+            if current != brb {
+                switch(&client, &brb).await?;
+                previous = Some(current);
+            }
+        } else if let Some(scene) = previous.take() {
+            switch(&client, &scene).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn switch(client: &Client, scene: &str) -> Result<()> {
+    println!("switching to scene `{scene}`");
+
+    // Scene switches are user facing, so send them ahead of any other queued requests.
+    Priority::Interactive
+        .scope(client.scenes().set_current_program_scene(scene))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+//! Smooth animations of scene items, that are played back by obs-websocket in sync with the
+//! rendered frames.
+//!
+//! An [`Animation`] describes the target transform of a scene item, and how long it takes to get
+//! there. It is played with [`crate::client::SceneItems::animate`], which calculates the
+//! intermediate transform for every frame and sends all of them as a single request batch. That
+//! way, the movement is not affected by network latency.
+
+use std::time::Duration;
+
+use crate::{
+    requests::{
+        scene_items::{Position, Scale, SceneItemTransform},
+        scenes::SceneId,
+    },
+    responses::scene_items::SceneItemTransform as CurrentTransform,
+};
+
+/// Description of a single scene item animation.
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct Animation<'a> {
+    /// Identifier of the scene the item is in.
+    pub scene: SceneId<'a>,
+    /// Numeric ID of the scene item.
+    pub item_id: i64,
+    /// The transform values at the end of the animation.
+    pub target: Target,
+    /// Total run time of the animation.
+    pub duration: Duration,
+    /// Timing function, that describes the speed of the animation over time.
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub easing: Easing,
+}
+
+/// Final transform values of an [`Animation`]. Any value that is left out stays unchanged.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct Target {
+    /// The x and y position of the scene item.
+    pub position: Option<(f32, f32)>,
+    /// The x and y scale factor of the scene item.
+    pub scale: Option<(f32, f32)>,
+    /// The clockwise rotation of the scene item in degrees.
+    pub rotation: Option<f32>,
+}
+
+/// Timing functions, that map the linear progress of an animation to the progress of the
+/// animated values.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Easing {
+    /// Constant speed from start to end.
+    #[default]
+    Linear,
+    /// Start slow and speed up towards the end.
+    EaseIn,
+    /// Start fast and slow down towards the end.
+    EaseOut,
+    /// Start slow, speed up in the middle and slow down towards the end.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Apply the easing to the linear progress `t`, which is clamped to the range `0.0..=1.0`.
+    #[must_use]
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+        }
+    }
+}
+
+/// Number of frames needed to play an animation of the given duration at the given frame rate.
+/// Always at least one frame, so the target is reached even for zero-length animations.
+pub(crate) fn frame_count(duration: Duration, fps_numerator: u32, fps_denominator: u32) -> u32 {
+    let fps = f64::from(fps_numerator) / f64::from(fps_denominator.max(1));
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let frames = (duration.as_secs_f64() * fps).ceil() as u32;
+
+    frames.max(1)
+}
+
+/// Calculate the transform for each frame of an animation, starting from the current transform.
+/// The last frame always matches the target exactly.
+pub(crate) fn frames(
+    current: &CurrentTransform,
+    target: Target,
+    easing: Easing,
+    count: u32,
+) -> Vec<SceneItemTransform> {
+    let lerp = |from: f32, to: f32, t: f32| from + (to - from) * t;
+
+    (1..=count)
+        .map(|frame| {
+            #[allow(clippy::cast_precision_loss)]
+            let t = easing.apply(frame as f32 / count as f32);
+
+            SceneItemTransform {
+                position: target.position.map(|(x, y)| Position {
+                    x: Some(lerp(current.position_x, x, t)),
+                    y: Some(lerp(current.position_y, y, t)),
+                }),
+                rotation: target
+                    .rotation
+                    .map(|rotation| lerp(current.rotation, rotation, t)),
+                scale: target.scale.map(|(x, y)| Scale {
+                    x: Some(lerp(current.scale_x, x, t)),
+                    y: Some(lerp(current.scale_y, y, t)),
+                }),
+                ..SceneItemTransform::default()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_bounds() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert!(easing.apply(0.0).abs() < f32::EPSILON);
+            assert!((easing.apply(1.0) - 1.0).abs() < f32::EPSILON);
+            assert!((easing.apply(2.0) - 1.0).abs() < f32::EPSILON);
+        }
+
+        assert!(Easing::EaseIn.apply(0.5) < 0.5);
+        assert!(Easing::EaseOut.apply(0.5) > 0.5);
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn frame_counts() {
+        assert_eq!(30, frame_count(Duration::from_secs(1), 30, 1));
+        assert_eq!(30, frame_count(Duration::from_secs(1), 30_000, 1001));
+        assert_eq!(15, frame_count(Duration::from_millis(250), 60, 1));
+        assert_eq!(1, frame_count(Duration::ZERO, 60, 1));
+    }
+
+    #[test]
+    fn linear_frames() {
+        let current = CurrentTransform {
+            position_x: 0.0,
+            position_y: 100.0,
+            scale_x: 1.0,
+            scale_y: 1.0,
+            ..CurrentTransform::default()
+        };
+        let target = Target {
+            position: Some((100.0, 0.0)),
+            ..Target::default()
+        };
+
+        let frames = frames(&current, target, Easing::Linear, 4);
+        assert_eq!(4, frames.len());
+
+        let positions = frames
+            .iter()
+            .map(|f| f.position.as_ref().map(|p| (p.x.unwrap(), p.y.unwrap())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Some((25.0, 75.0)),
+                Some((50.0, 50.0)),
+                Some((75.0, 25.0)),
+                Some((100.0, 0.0)),
+            ],
+            positions
+        );
+        assert!(frames
+            .iter()
+            .all(|f| f.scale.is_none() && f.rotation.is_none()));
+    }
+}
@@ -0,0 +1,285 @@
+//! Client-side parenting of scene items, as a lightweight alternative to OBS groups.
+//!
+//! An [`Attachment`] makes one or more child items follow the transform of a parent item in the
+//! same scene. The relative position, scale and rotation of each child is captured once the
+//! attachment starts running, and re-applied whenever the parent moves, is scaled or rotated.
+//!
+//! Changes of the parent are tracked through the high-volume
+//! [`SceneItemTransformChanged`](crate::events::Event::SceneItemTransformChanged) event, that
+//! has to be enabled through the
+//! [`EventSubscription::SCENE_ITEM_TRANSFORM_CHANGED`] subscription of the connection. Dragging
+//! an item around in the UI emits many of these events in a short time, so all changes within the
+//! duration of a single video frame are merged, and only the latest one is applied. The new
+//! transforms of all children are sent as a single request batch, that obs-websocket executes
+//! within the same frame, so the children never drift apart visibly.
+
+use std::time::Duration;
+
+use futures_util::{
+    future::{self, Either},
+    Stream, StreamExt,
+};
+
+use crate::{
+    client::check_status,
+    error::Result,
+    events::Event,
+    requests::{
+        scene_items::{Position, Request, Scale, SceneItemTransform, SetTransform},
+        scenes::SceneId,
+        EventSubscription, ExecutionType, RequestType,
+    },
+    responses::{config::VideoSettings, scene_items::SceneItemTransform as CurrentTransform},
+    Client,
+};
+
+/// Configuration of scene items, that follow the transform of a parent item.
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct Attachment<'a> {
+    /// Identifier of the scene, that contains the parent and all children.
+    pub scene: SceneId<'a>,
+    /// Numeric ID of the parent scene item.
+    pub parent: i64,
+    /// Numeric IDs of the scene items, that follow the parent.
+    pub children: Vec<i64>,
+}
+
+impl Attachment<'_> {
+    /// Capture the current transforms of the children relative to the parent, and keep them in
+    /// place whenever the parent changes, until the connection to obs-websocket is closed or
+    /// updating a child fails.
+    ///
+    /// This function doesn't return on its own while the connection is alive, so it's usually
+    /// run in a separate task.
+    pub async fn run(&self, client: &Client) -> Result<()> {
+        // Subscribe before loading the initial transforms, to not miss any changes in between.
+        let mut events =
+            Box::pin(client.events_for(EventSubscription::SCENE_ITEM_TRANSFORM_CHANGED)?);
+
+        let frame = frame_duration(&client.config().video_settings().await?);
+        let parent = client
+            .scene_items()
+            .transform(self.scene, self.parent)
+            .await?;
+
+        let mut links = Vec::with_capacity(self.children.len());
+        for &child in &self.children {
+            let transform = client.scene_items().transform(self.scene, child).await?;
+            links.push((child, Link::new(&parent, &transform)));
+        }
+
+        while let Some(mut latest) = self.next_change(&mut events).await {
+            // Only the latest change within a frame is visible, so skip over the ones before.
+            let mut deadline = client.clock().sleep(frame);
+            while let Either::Left((Some(transform), _)) =
+                future::select(Box::pin(self.next_change(&mut events)), &mut deadline).await
+            {
+                latest = transform;
+            }
+
+            self.follow(client, &links, &latest).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Wait for the next transform change of the parent, or [`None`] if the event stream ended.
+    async fn next_change(
+        &self,
+        events: &mut (impl Stream<Item = Event> + Unpin),
+    ) -> Option<CurrentTransform> {
+        while let Some(event) = events.next().await {
+            if let Event::SceneItemTransformChanged {
+                scene,
+                item_id,
+                transform,
+            } = event
+            {
+                if scene == self.scene && i64::try_from(item_id) == Ok(self.parent) {
+                    return Some(transform);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Move all children to their place relative to the given parent transform.
+    async fn follow(
+        &self,
+        client: &Client,
+        links: &[(i64, Link)],
+        parent: &CurrentTransform,
+    ) -> Result<()> {
+        let requests = links
+            .iter()
+            .map(|(item_id, link)| {
+                RequestType::from(Request::SetTransform(SetTransform {
+                    scene: self.scene,
+                    item_id: *item_id,
+                    transform: link.apply(parent),
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        client
+            .send_batch(&requests, true, ExecutionType::SerialFrame)
+            .await?
+            .into_iter()
+            .try_for_each(|result| check_status(result.status))
+    }
+}
+
+/// Duration of a single frame, at the configured frame rate.
+fn frame_duration(video: &VideoSettings) -> Duration {
+    Duration::from_secs_f64(
+        f64::from(video.fps_denominator) / f64::from(video.fps_numerator.max(1)),
+    )
+}
+
+/// Transform of a child, relative to the position, scale and rotation of its parent.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Link {
+    /// Offset from the parent's position, in the parent's unscaled and unrotated space.
+    offset: (f32, f32),
+    /// Scale factor, relative to the parent's scale.
+    scale: (f32, f32),
+    /// Rotation in degrees, relative to the parent's rotation.
+    rotation: f32,
+}
+
+impl Link {
+    fn new(parent: &CurrentTransform, child: &CurrentTransform) -> Self {
+        let (x, y) = rotate(
+            (
+                child.position_x - parent.position_x,
+                child.position_y - parent.position_y,
+            ),
+            -parent.rotation,
+        );
+        let (scale_x, scale_y) = (non_zero(parent.scale_x), non_zero(parent.scale_y));
+
+        Self {
+            offset: (x / scale_x, y / scale_y),
+            scale: (child.scale_x / scale_x, child.scale_y / scale_y),
+            rotation: child.rotation - parent.rotation,
+        }
+    }
+
+    fn apply(&self, parent: &CurrentTransform) -> SceneItemTransform {
+        let (x, y) = rotate(
+            (
+                self.offset.0 * parent.scale_x,
+                self.offset.1 * parent.scale_y,
+            ),
+            parent.rotation,
+        );
+
+        SceneItemTransform {
+            position: Some(Position {
+                x: Some(parent.position_x + x),
+                y: Some(parent.position_y + y),
+            }),
+            rotation: Some(parent.rotation + self.rotation),
+            scale: Some(Scale {
+                x: Some(parent.scale_x * self.scale.0),
+                y: Some(parent.scale_y * self.scale.1),
+            }),
+            ..SceneItemTransform::default()
+        }
+    }
+}
+
+/// Rotate a point clockwise around the origin, in screen coordinates where `y` points down.
+fn rotate((x, y): (f32, f32), degrees: f32) -> (f32, f32) {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    (x * cos - y * sin, x * sin + y * cos)
+}
+
+/// Replace a scale factor of zero with one, to keep the relative values finite.
+fn non_zero(scale: f32) -> f32 {
+    if scale.abs() < f32::EPSILON {
+        1.0
+    } else {
+        scale
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transform(x: f32, y: f32, scale: f32, rotation: f32) -> CurrentTransform {
+        CurrentTransform {
+            position_x: x,
+            position_y: y,
+            scale_x: scale,
+            scale_y: scale,
+            rotation,
+            ..CurrentTransform::default()
+        }
+    }
+
+    fn values(t: &SceneItemTransform) -> (f32, f32, f32, f32) {
+        let position = t.position.as_ref().unwrap();
+        (
+            position.x.unwrap(),
+            position.y.unwrap(),
+            t.scale.as_ref().unwrap().x.unwrap(),
+            t.rotation.unwrap(),
+        )
+    }
+
+    fn assert_close(expected: (f32, f32, f32, f32), actual: (f32, f32, f32, f32)) {
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-3;
+        assert!(
+            close(expected.0, actual.0)
+                && close(expected.1, actual.1)
+                && close(expected.2, actual.2)
+                && close(expected.3, actual.3),
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn follow_parent() {
+        let parent = transform(100.0, 100.0, 1.0, 0.0);
+        let link = Link::new(&parent, &transform(150.0, 120.0, 0.5, 10.0));
+
+        assert_close((150.0, 120.0, 0.5, 10.0), values(&link.apply(&parent)));
+        assert_close(
+            (250.0, 120.0, 0.5, 10.0),
+            values(&link.apply(&transform(200.0, 100.0, 1.0, 0.0))),
+        );
+        assert_close(
+            (300.0, 140.0, 1.0, 10.0),
+            values(&link.apply(&transform(200.0, 100.0, 2.0, 0.0))),
+        );
+        assert_close(
+            (80.0, 150.0, 0.5, 100.0),
+            values(&link.apply(&transform(100.0, 100.0, 1.0, 90.0))),
+        );
+    }
+
+    #[test]
+    fn frame_durations() {
+        let video = |fps_numerator, fps_denominator| VideoSettings {
+            fps_numerator,
+            fps_denominator,
+            base_width: 1920,
+            base_height: 1080,
+            output_width: 1920,
+            output_height: 1080,
+        };
+
+        assert_eq!(Duration::from_millis(50), frame_duration(&video(20, 1)));
+        assert_eq!(
+            Duration::from_secs_f64(1001.0 / 30_000.0),
+            frame_duration(&video(30_000, 1001))
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Summary of the audio mix on the program output, to find out which inputs can be heard on stream.
+//!
+//! An input is only audible in the program output, if all of the following conditions are met:
+//!
+//! - The input is active, meaning it's part of the program scene or a global audio device.
+//! - The input is not muted.
+//! - The volume of the input is above a threshold.
+//! - The input is routed to the audio track that is used for the output.
+//!
+//! [`program_mix`] checks all of these for every input with audio, and reports the conditions
+//! that prevent an input from being heard. This helps answering the common question of why there
+//! is no sound on stream.
+
+use crate::{
+    client::batch_response,
+    error::{Error, Result},
+    requests::{
+        inputs::{self, InputId as InputIdRequest},
+        sources, ExecutionType, RequestType,
+    },
+    responses::{self, inputs::InputId, StatusCode},
+    Client,
+};
+
+/// Options for [`program_mix`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct ProgramMixOptions {
+    /// Volume in dB, at or below which an input is considered silent.
+    #[cfg_attr(feature = "builder", builder(default = -60.0))]
+    pub threshold_db: f32,
+    /// Audio track in the range `1..=6`, that is used by the output.
+    #[cfg_attr(feature = "builder", builder(default = 1))]
+    pub track: u8,
+}
+
+impl Default for ProgramMixOptions {
+    fn default() -> Self {
+        Self {
+            threshold_db: -60.0,
+            track: 1,
+        }
+    }
+}
+
+/// Audio state of all inputs with audio, as returned by [`program_mix`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProgramMix {
+    /// All inputs that support audio, whether audible or not.
+    pub inputs: Vec<InputMix>,
+}
+
+impl ProgramMix {
+    /// Iterate over all inputs, that can currently be heard on the program output.
+    pub fn audible(&self) -> impl Iterator<Item = &InputMix> {
+        self.inputs.iter().filter(|input| input.is_audible())
+    }
+}
+
+/// Audio state of a single input, as part of [`ProgramMix`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InputMix {
+    /// Identifier of the input.
+    pub id: InputId,
+    /// Whether the input is active in the program output.
+    pub active: bool,
+    /// Whether the input is muted.
+    pub muted: bool,
+    /// Volume setting of the input in dB.
+    pub volume_db: f32,
+    /// Enable state of the audio tracks 1 to 6.
+    pub tracks: [bool; 6],
+    /// All reasons that prevent the input from being heard. Empty if the input is audible.
+    pub silenced_by: Vec<Silenced>,
+}
+
+impl InputMix {
+    /// Whether the input can be heard on the program output.
+    #[must_use]
+    pub fn is_audible(&self) -> bool {
+        self.silenced_by.is_empty()
+    }
+}
+
+/// Reason that prevents an input from being heard on the program output.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Silenced {
+    /// The input is not active in the program output.
+    Inactive,
+    /// The input is muted.
+    Muted,
+    /// The volume of the input is at or below the threshold.
+    LowVolume,
+    /// The input is not routed to the audio track of the output.
+    NotOnTrack,
+}
+
+/// Summarize the audio state of all inputs with audio, and which of them can be heard on the
+/// program output.
+///
+/// Inputs without audio support, like image sources, are left out. The state of all inputs is
+/// queried with a single request batch.
+pub async fn program_mix(client: &Client, options: ProgramMixOptions) -> Result<ProgramMix> {
+    let inputs = client.inputs().list(None).await?;
+    if inputs.is_empty() {
+        return Ok(ProgramMix::default());
+    }
+
+    let requests = inputs
+        .iter()
+        .flat_map(|input| {
+            let input = InputIdRequest::from(&input.id);
+            [
+                inputs::Request::Muted { input }.into(),
+                inputs::Request::Volume { input }.into(),
+                inputs::Request::AudioTracks { input }.into(),
+                sources::Request::Active {
+                    source: input.as_source(),
+                }
+                .into(),
+            ]
+        })
+        .collect::<Vec<RequestType<'_>>>();
+
+    let results = client
+        .send_batch(&requests, false, ExecutionType::Parallel)
+        .await?;
+
+    let mut mix = Vec::new();
+    let mut results = results.into_iter();
+
+    for input in inputs {
+        let (Some(muted), Some(volume), Some(tracks), Some(active)) = (
+            results.next(),
+            results.next(),
+            results.next(),
+            results.next(),
+        ) else {
+            return Err(Error::UnexpectedResponse);
+        };
+
+        let muted = match batch_response::<responses::inputs::InputMuted>(muted) {
+            Ok(muted) => muted.muted,
+            Err(Error::Api {
+                code: StatusCode::InvalidResourceState,
+                ..
+            }) => continue,
+            Err(e) => return Err(e),
+        };
+        let volume_db = batch_response::<responses::inputs::InputVolume>(volume)?.db;
+        let tracks = <[bool; 6]>::from(
+            batch_response::<responses::inputs::AudioTracks>(tracks)?.audio_tracks,
+        );
+        let active = batch_response::<responses::sources::SourceActive>(active)?.active;
+
+        let on_track = usize::from(options.track)
+            .checked_sub(1)
+            .and_then(|track| tracks.get(track))
+            .copied()
+            .unwrap_or_default();
+
+        let silenced_by = [
+            (!active, Silenced::Inactive),
+            (muted, Silenced::Muted),
+            (volume_db <= options.threshold_db, Silenced::LowVolume),
+            (!on_track, Silenced::NotOnTrack),
+        ]
+        .into_iter()
+        .filter_map(|(silenced, reason)| silenced.then_some(reason))
+        .collect();
+
+        mix.push(InputMix {
+            id: input.id,
+            active,
+            muted,
+            volume_db,
+            tracks,
+            silenced_by,
+        });
+    }
+
+    Ok(ProgramMix { inputs: mix })
+}
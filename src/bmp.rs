@@ -0,0 +1,120 @@
+//! Minimal decoding of the BMP screenshots, as used for the content checks of the
+//! [`crate::failover`] and [`crate::watchdog`] modules.
+
+use base64::engine::{general_purpose, Engine};
+
+/// Decode a Base64-encoded BMP image, as returned from
+/// [`crate::client::Sources::take_screenshot`], into the brightness of each pixel, in the range
+/// `0.0..=1.0`.
+///
+/// Images larger than `max_size` in either dimension are rejected, as they can't be the result of
+/// a screenshot request of that size. Returns [`None`] if the image is rejected or can't be
+/// decoded.
+pub(crate) fn luma(image: &str, max_size: u32) -> Option<Vec<f32>> {
+    let data = image.split_once(',').map_or(image, |(_, data)| data);
+    let bmp = general_purpose::STANDARD.decode(data).ok()?;
+
+    let read_u16 = |pos: usize| Some(u16::from_le_bytes(bmp.get(pos..pos + 2)?.try_into().ok()?));
+    let read_u32 = |pos: usize| Some(u32::from_le_bytes(bmp.get(pos..pos + 4)?.try_into().ok()?));
+
+    if bmp.get(..2)? != b"BM" {
+        return None;
+    }
+
+    let offset = usize::try_from(read_u32(10)?).ok()?;
+    let width = read_u32(18)?;
+    // negative heights describe top-down images, which doesn't matter for comparing pixels
+    let height = i32::from_le_bytes(read_u32(22)?.to_le_bytes()).unsigned_abs();
+    let bytes_per_pixel = match read_u16(28)? {
+        24 => 3,
+        32 => 4,
+        _ => return None,
+    };
+
+    if width > max_size || height > max_size {
+        return None;
+    }
+
+    let width = usize::try_from(width).ok()?;
+    let height = usize::try_from(height).ok()?;
+    let row_len = width.checked_mul(bytes_per_pixel)?;
+    let stride = row_len.checked_add(3)? / 4 * 4;
+    let end = stride.checked_mul(height)?.checked_add(offset)?;
+
+    if end > bmp.len() {
+        return None;
+    }
+
+    let mut luma = Vec::with_capacity(width * height);
+
+    for row in 0..height {
+        let start = offset + row * stride;
+        let pixels = bmp.get(start..start + row_len)?;
+
+        for pixel in pixels.chunks_exact(bytes_per_pixel) {
+            let (b, g, r) = (
+                f32::from(pixel[0]),
+                f32::from(pixel[1]),
+                f32::from(pixel[2]),
+            );
+            luma.push((0.2126 * r + 0.7152 * g + 0.0722 * b) / 255.0);
+        }
+    }
+
+    Some(luma)
+}
+
+/// Encode a 24-bit BMP image of the given size, filled with a single color, in the same format as
+/// obs-websocket returns screenshots.
+#[cfg(test)]
+pub(crate) fn encode(width: u32, height: u32, pixel: [u8; 3]) -> String {
+    let stride = (width * 3 + 3) / 4 * 4;
+    let mut data = Vec::new();
+    data.extend_from_slice(b"BM");
+    data.extend_from_slice(&(54 + stride * height).to_le_bytes());
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&54_u32.to_le_bytes());
+    data.extend_from_slice(&40_u32.to_le_bytes());
+    data.extend_from_slice(&width.to_le_bytes());
+    data.extend_from_slice(&height.to_le_bytes());
+    data.extend_from_slice(&1_u16.to_le_bytes());
+    data.extend_from_slice(&24_u16.to_le_bytes());
+    data.extend_from_slice(&[0; 24]);
+
+    for _ in 0..height {
+        for _ in 0..width {
+            data.extend_from_slice(&pixel);
+        }
+        data.resize(data.len() + (stride - width * 3) as usize, 0);
+    }
+
+    format!(
+        "data:image/bmp;base64,{}",
+        general_purpose::STANDARD.encode(data)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        assert_eq!(Some(vec![0.0; 6]), luma(&encode(3, 2, [0, 0, 0]), 32));
+        assert!(luma("data:image/png;base64,AAAA", 32).is_none());
+    }
+
+    #[test]
+    fn reject_invalid_sizes() {
+        assert!(luma(&encode(33, 2, [0, 0, 0]), 32).is_none());
+
+        // Header claiming a larger image than the data it contains.
+        let image = encode(2, 2, [0, 0, 0]);
+        let mut data = general_purpose::STANDARD
+            .decode(image.split_once(',').unwrap().1)
+            .unwrap();
+        data[22..26].copy_from_slice(&16_u32.to_le_bytes());
+        let image = general_purpose::STANDARD.encode(data);
+        assert!(luma(&image, 32).is_none());
+    }
+}
@@ -0,0 +1,223 @@
+//! Minimal C-compatible API, to embed this crate into applications written in other languages,
+//! like control boards written in C or C++.
+//!
+//! The crate is built as a regular Rust library by default. To get a library that can be linked
+//! from C, build it with an explicit crate type:
+//!
+//! ```sh
+//! cargo rustc --release --features capi --crate-type cdylib
+//! ```
+//!
+//! A client is created with [`obws_connect`] and must be released with [`obws_disconnect`].
+//! Functions returning a `c_int` report success with `0` and failure with `-1`. Details about
+//! failures are reported through the `tracing` logs.
+//!
+//! Events are queued in the background and can be fetched one by one as JSON strings with
+//! [`obws_poll_event`]. Each returned string must be released with [`obws_string_free`].
+
+use std::{
+    ffi::{c_char, c_int, CStr, CString},
+    future::Future,
+    ptr,
+    sync::mpsc,
+};
+
+use futures_util::StreamExt;
+use tokio::runtime::Runtime;
+use tracing::error;
+
+use crate::{
+    error::Result,
+    requests::{inputs::InputId, scenes::SceneId},
+    Client,
+};
+
+/// Opaque handle to a connected client, created with [`obws_connect`].
+pub struct ObwsClient {
+    client: Client,
+    events: mpsc::Receiver<CString>,
+    runtime: Runtime,
+}
+
+impl ObwsClient {
+    fn run(&self, name: &str, f: impl Future<Output = Result<()>>) -> c_int {
+        match self.runtime.block_on(f) {
+            Ok(()) => 0,
+            Err(e) => {
+                error!(error = ?e, "{name} failed");
+                -1
+            }
+        }
+    }
+}
+
+/// Connect to an obs-websocket instance.
+///
+/// The `password` may be null, if the instance doesn't require authentication. Returns null if
+/// the connection failed.
+///
+/// # Safety
+///
+/// `host` must be a valid, null-terminated string. `password` must either be null or a valid,
+/// null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn obws_connect(
+    host: *const c_char,
+    port: u16,
+    password: *const c_char,
+) -> *mut ObwsClient {
+    let Some(host) = to_str(host) else {
+        return ptr::null_mut();
+    };
+    let password = to_str(password);
+
+    let runtime = match tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            error!(error = ?e, "failed creating runtime");
+            return ptr::null_mut();
+        }
+    };
+
+    let client = match runtime.block_on(Client::connect(host, port, password)) {
+        Ok(client) => client,
+        Err(e) => {
+            error!(error = ?e, "failed connecting to obs-websocket");
+            return ptr::null_mut();
+        }
+    };
+
+    let (tx, events) = mpsc::channel();
+    if let Ok(stream) = client.events() {
+        runtime.spawn(async move {
+            let mut stream = Box::pin(stream);
+            while let Some(event) = stream.next().await {
+                let Some(json) = serde_json::to_string(&event)
+                    .ok()
+                    .and_then(|json| CString::new(json).ok())
+                else {
+                    continue;
+                };
+
+                if tx.send(json).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    Box::into_raw(Box::new(ObwsClient {
+        client,
+        events,
+        runtime,
+    }))
+}
+
+/// Disconnect from obs-websocket and release the client.
+///
+/// # Safety
+///
+/// `client` must be a pointer returned from [`obws_connect`] that wasn't released yet, or null.
+#[no_mangle]
+pub unsafe extern "C" fn obws_disconnect(client: *mut ObwsClient) {
+    if client.is_null() {
+        return;
+    }
+
+    let mut client = Box::from_raw(client);
+    let ObwsClient {
+        client: inner,
+        runtime,
+        ..
+    } = &mut *client;
+    runtime.block_on(inner.disconnect());
+}
+
+/// Switch the current program scene to the scene with the given name.
+///
+/// # Safety
+///
+/// `client` must be a valid pointer returned from [`obws_connect`], and `scene` a valid,
+/// null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn obws_set_current_program_scene(
+    client: *const ObwsClient,
+    scene: *const c_char,
+) -> c_int {
+    let (Some(client), Some(scene)) = (client.as_ref(), to_str(scene)) else {
+        return -1;
+    };
+
+    client.run(
+        "setting the current program scene",
+        client
+            .client
+            .scenes()
+            .set_current_program_scene(SceneId::Name(scene)),
+    )
+}
+
+/// Set the mute state of the input with the given name.
+///
+/// # Safety
+///
+/// `client` must be a valid pointer returned from [`obws_connect`], and `input` a valid,
+/// null-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn obws_set_input_muted(
+    client: *const ObwsClient,
+    input: *const c_char,
+    muted: bool,
+) -> c_int {
+    let (Some(client), Some(input)) = (client.as_ref(), to_str(input)) else {
+        return -1;
+    };
+
+    client.run(
+        "setting the input mute state",
+        client
+            .client
+            .inputs()
+            .set_muted(InputId::Name(input), muted),
+    )
+}
+
+/// Take the next queued event as JSON string, or null if no event is queued.
+///
+/// The returned string must be released with [`obws_string_free`].
+///
+/// # Safety
+///
+/// `client` must be a valid pointer returned from [`obws_connect`].
+#[no_mangle]
+pub unsafe extern "C" fn obws_poll_event(client: *const ObwsClient) -> *mut c_char {
+    client
+        .as_ref()
+        .and_then(|client| client.events.try_recv().ok())
+        .map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Release a string returned from any of the functions of this API.
+///
+/// # Safety
+///
+/// `value` must be a pointer returned from this API that wasn't released yet, or null.
+#[no_mangle]
+pub unsafe extern "C" fn obws_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        drop(CString::from_raw(value));
+    }
+}
+
+/// Convert a C string into a Rust string, returning [`None`] if it is null or not valid UTF-8.
+unsafe fn to_str<'a>(value: *const c_char) -> Option<&'a str> {
+    if value.is_null() {
+        None
+    } else {
+        CStr::from_ptr(value).to_str().ok()
+    }
+}
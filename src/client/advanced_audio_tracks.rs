@@ -0,0 +1,122 @@
+use super::Client;
+use crate::{
+    error::Result,
+    requests::custom::profile_parameters::{
+        AudioTrack, ADV_STREAM_TRACK, ADV_VOD_TRACK, ADV_VOD_TRACK_ENABLED,
+    },
+};
+
+/// API functions related to the audio tracks of the advanced output mode.
+///
+/// obs-websocket doesn't expose these settings directly, so they're read from and written to the
+/// current profile's parameters. OBS only picks up changes the next time an output starts.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond(
+/// #     "GetProfileParameter",
+/// #     json!({"parameterValue": "2", "defaultParameterValue": "1"}),
+/// # );
+/// # stub.respond("SetProfileParameter", json!(null));
+/// use obws::requests::custom::profile_parameters::AudioTrack;
+///
+/// let tracks = client.config().audio_tracks();
+/// assert_eq!(Some(AudioTrack::Track2), tracks.stream_track().await?);
+///
+/// tracks.set_bitrate(AudioTrack::Track2, 192).await?;
+/// # assert_eq!(
+/// #     Some(&(
+/// #         "SetProfileParameter".to_owned(),
+/// #         json!({
+/// #             "parameterCategory": "AdvOut",
+/// #             "parameterName": "Track2Bitrate",
+/// #             "parameterValue": "192",
+/// #         }),
+/// #     )),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
+pub struct AdvancedAudioTracks<'a> {
+    pub(super) client: &'a Client,
+}
+
+impl<'a> AdvancedAudioTracks<'a> {
+    /// Gets the audio track, that is used for streaming.
+    pub async fn stream_track(&self) -> Result<Option<AudioTrack>> {
+        self.client
+            .profiles()
+            .typed_parameter(ADV_STREAM_TRACK)
+            .await
+    }
+
+    /// Sets the audio track, that is used for streaming.
+    pub async fn set_stream_track(&self, track: AudioTrack) -> Result<()> {
+        self.client
+            .profiles()
+            .set_typed_parameter(ADV_STREAM_TRACK, Some(&track))
+            .await
+    }
+
+    /// Gets the audio track, that is sent as separate VOD (video on demand) track with the
+    /// stream, or [`None`] if no VOD track is sent.
+    pub async fn vod_track(&self) -> Result<Option<AudioTrack>> {
+        let profiles = self.client.profiles();
+
+        if !profiles
+            .typed_parameter(ADV_VOD_TRACK_ENABLED)
+            .await?
+            .unwrap_or_default()
+        {
+            return Ok(None);
+        }
+
+        profiles.typed_parameter(ADV_VOD_TRACK).await
+    }
+
+    /// Sets the audio track, that is sent as separate VOD (video on demand) track with the
+    /// stream. Use [`None`] to disable the VOD track.
+    pub async fn set_vod_track(&self, track: Option<AudioTrack>) -> Result<()> {
+        let profiles = self.client.profiles();
+
+        if let Some(track) = &track {
+            profiles
+                .set_typed_parameter(ADV_VOD_TRACK, Some(track))
+                .await?;
+        }
+
+        profiles
+            .set_typed_parameter(ADV_VOD_TRACK_ENABLED, Some(&track.is_some()))
+            .await
+    }
+
+    /// Gets the name of an audio track, or [`None`] if it has no custom name.
+    pub async fn name(&self, track: AudioTrack) -> Result<Option<String>> {
+        let name = self.client.profiles().typed_parameter(track.name()).await?;
+        Ok(name.filter(|name| !name.is_empty()))
+    }
+
+    /// Sets the name of an audio track. Use [`None`] to reset it to the default name.
+    pub async fn set_name(&self, track: AudioTrack, name: Option<&'a str>) -> Result<()> {
+        self.client
+            .profiles()
+            .set_typed_parameter(track.name(), name.map(str::to_owned).as_ref())
+            .await
+    }
+
+    /// Gets the audio bitrate of a track, in kbps.
+    pub async fn bitrate(&self, track: AudioTrack) -> Result<Option<u32>> {
+        self.client
+            .profiles()
+            .typed_parameter(track.bitrate())
+            .await
+    }
+
+    /// Sets the audio bitrate of a track, in kbps.
+    pub async fn set_bitrate(&self, track: AudioTrack, bitrate: u32) -> Result<()> {
+        self.client
+            .profiles()
+            .set_typed_parameter(track.bitrate(), Some(&bitrate))
+            .await
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use serde_json::value::RawValue;
+
+#[cfg(feature = "events")]
+use crate::events::Event;
+use crate::requests::RequestType;
+
+/// Read requests, whose responses can be cached with [`crate::Client::with_cache`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum CachedRequest {
+    /// The list of scenes, from [`crate::client::Scenes::list`].
+    SceneList,
+    /// The list of inputs, from [`crate::client::Inputs::list`].
+    InputList,
+    /// The list of monitors, from [`crate::client::Ui::list_monitors`].
+    MonitorList,
+}
+
+impl CachedRequest {
+    /// All known cacheable requests.
+    const ALL: [Self; 3] = [Self::SceneList, Self::InputList, Self::MonitorList];
+
+    /// The request type name, as used by obs-websocket.
+    const fn request_type(self) -> &'static str {
+        match self {
+            Self::SceneList => "GetSceneList",
+            Self::InputList => "GetInputList",
+            Self::MonitorList => "GetMonitorList",
+        }
+    }
+
+    /// Whether the event signals that a cached response of this request is outdated.
+    #[cfg(feature = "events")]
+    fn invalidated_by(self, event: &Event) -> bool {
+        match self {
+            Self::SceneList => matches!(
+                event,
+                Event::SceneCreated { .. }
+                    | Event::SceneRemoved { .. }
+                    | Event::SceneNameChanged { .. }
+                    | Event::SceneListChanged { .. }
+                    | Event::CurrentProgramSceneChanged { .. }
+                    | Event::CurrentPreviewSceneChanged { .. }
+                    | Event::CurrentSceneCollectionChanged { .. }
+            ),
+            Self::InputList => matches!(
+                event,
+                Event::InputCreated { .. }
+                    | Event::InputRemoved { .. }
+                    | Event::InputNameChanged { .. }
+                    | Event::CurrentSceneCollectionChanged { .. }
+            ),
+            Self::MonitorList => false,
+        }
+    }
+}
+
+/// Configuration of the response cache, enabled with [`crate::Client::with_cache`].
+///
+/// Only requests that got a time-to-live assigned are cached.
+#[derive(Clone, Debug, Default)]
+pub struct CacheConfig {
+    ttls: HashMap<CachedRequest, Duration>,
+}
+
+impl CacheConfig {
+    /// Create a new configuration, that doesn't cache any requests yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache responses of the given request for at most the given duration.
+    #[must_use]
+    pub fn ttl(mut self, request: CachedRequest, ttl: Duration) -> Self {
+        self.ttls.insert(request, ttl);
+        self
+    }
+}
+
+/// Storage of cached responses, shared between the client and the background task that receives
+/// messages, which drops outdated responses on events.
+#[derive(Default)]
+pub(super) struct ResponseCache {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    config: CacheConfig,
+    /// Cached responses, keyed by the full serialized request.
+    entries: HashMap<String, Entry>,
+}
+
+struct Entry {
+    #[cfg(feature = "events")]
+    request: CachedRequest,
+    expires: Instant,
+    value: Box<RawValue>,
+}
+
+/// Identifier of a cacheable request, created with [`ResponseCache::key`].
+pub(super) struct CacheKey {
+    #[cfg(feature = "events")]
+    request: CachedRequest,
+    ttl: Duration,
+    key: String,
+}
+
+impl ResponseCache {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Replace the configuration, dropping all cached responses.
+    pub fn configure(&self, config: CacheConfig) {
+        let mut state = self.lock();
+        state.config = config;
+        state.entries.clear();
+    }
+
+    /// Drop all cached responses.
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// Get the cache key for a request, or [`None`] if the request isn't cached.
+    pub fn key(&self, req: &RequestType<'_>) -> Option<CacheKey> {
+        if self.lock().config.ttls.is_empty() {
+            return None;
+        }
+
+        let request_type = req.name();
+        let request = CachedRequest::ALL
+            .into_iter()
+            .find(|request| request.request_type() == request_type)?;
+
+        let ttl = *self.lock().config.ttls.get(&request)?;
+        let value = serde_json::to_value(req).ok()?;
+
+        Some(CacheKey {
+            #[cfg(feature = "events")]
+            request,
+            ttl,
+            key: value.to_string(),
+        })
+    }
+
+    /// Get a cached response, if it's not expired yet.
+    pub fn get(&self, key: &CacheKey, now: Instant) -> Option<Box<RawValue>> {
+        self.lock()
+            .entries
+            .get(&key.key)
+            .filter(|entry| entry.expires > now)
+            .map(|entry| entry.value.clone())
+    }
+
+    /// Store a response in the cache.
+    pub fn insert(&self, key: CacheKey, value: Box<RawValue>, now: Instant) {
+        self.lock().entries.insert(
+            key.key,
+            Entry {
+                #[cfg(feature = "events")]
+                request: key.request,
+                expires: now + key.ttl,
+                value,
+            },
+        );
+    }
+
+    /// Drop all cached responses, that are outdated due to the event.
+    #[cfg(feature = "events")]
+    pub fn invalidate(&self, event: &Event) {
+        let mut state = self.lock();
+        if !state.entries.is_empty() {
+            state
+                .entries
+                .retain(|_, entry| !entry.request.invalidated_by(event));
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex as SyncMutex, MutexGuard, PoisonError,
+    },
+};
+
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+use crate::requests::RequestType;
+
+/// Write requests, that can be sent in "latest wins" mode with
+/// [`crate::Client::with_latest_wins`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum CoalescedRequest {
+    /// Volume changes, from [`crate::client::Inputs::set_volume`].
+    InputVolume,
+    /// Audio balance changes, from [`crate::client::Inputs::set_audio_balance`].
+    InputAudioBalance,
+    /// Transform changes, from [`crate::client::SceneItems::set_transform`].
+    ///
+    /// Only the latest transform is sent, so all updates to the same scene item should contain
+    /// the same set of properties. Otherwise, changes to properties that are only part of a
+    /// dropped update are lost.
+    SceneItemTransform,
+}
+
+impl CoalescedRequest {
+    /// All known coalescable requests.
+    const ALL: [Self; 3] = [
+        Self::InputVolume,
+        Self::InputAudioBalance,
+        Self::SceneItemTransform,
+    ];
+
+    /// The request type name, as used by obs-websocket.
+    const fn request_type(self) -> &'static str {
+        match self {
+            Self::InputVolume => "SetInputVolume",
+            Self::InputAudioBalance => "SetInputAudioBalance",
+            Self::SceneItemTransform => "SetSceneItemTransform",
+        }
+    }
+
+    /// Fields of the request data, that carry the new value instead of identifying the target.
+    const fn value_fields(self) -> &'static [&'static str] {
+        match self {
+            Self::InputVolume => &["inputVolumeMul", "inputVolumeDb"],
+            Self::InputAudioBalance => &["inputAudioBalance"],
+            Self::SceneItemTransform => &["sceneItemTransform"],
+        }
+    }
+}
+
+/// Tracker of in-flight write requests, that drops queued writes once a newer write to the same
+/// target arrives.
+///
+/// Every write gets a sequence number for its target and then waits for its turn. Once it's up,
+/// it's only sent if no newer write to the same target was queued in the meantime. That way, at
+/// most one write per target is in flight, and OBS always receives the freshest value next.
+#[derive(Default)]
+pub(super) struct Coalescer {
+    state: SyncMutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    enabled: HashSet<CoalescedRequest>,
+    /// Write slots, keyed by the request type and target of the request. A slot is removed again,
+    /// once no write to its target is queued or in flight anymore.
+    slots: HashMap<String, Arc<Slot>>,
+}
+
+#[derive(Default)]
+struct Slot {
+    /// Sequence number of the latest queued write.
+    latest: AtomicU64,
+    /// Lock that is held while a write is in flight.
+    lock: Arc<Mutex<()>>,
+}
+
+/// Permit to send a write request, acquired through [`Coalescer::acquire`]. The next write to the
+/// same target is held back until the permit is dropped.
+///
+/// The fields are dropped in order, so the next write can go ahead before the slot is released.
+pub(super) struct Permit<'a> {
+    _guard: Option<OwnedMutexGuard<()>>,
+    _slot: Option<SlotRef<'a>>,
+}
+
+/// Reference to a write slot, that removes the slot from the [`Coalescer`] when it's the last
+/// one.
+struct SlotRef<'a> {
+    coalescer: &'a Coalescer,
+    key: String,
+    slot: Arc<Slot>,
+}
+
+impl Drop for SlotRef<'_> {
+    fn drop(&mut self) {
+        let mut state = self.coalescer.lock();
+        // Other writes to the same target only get a reference while the state is locked, so
+        // if there is none besides the map's and this one, no write is queued or in flight.
+        if Arc::strong_count(&self.slot) == 2 {
+            state.slots.remove(&self.key);
+        }
+    }
+}
+
+impl Coalescer {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Replace the set of requests, that are sent in "latest wins" mode.
+    pub fn configure(&self, requests: HashSet<CoalescedRequest>) {
+        self.lock().enabled = requests;
+    }
+
+    /// Wait for the turn to send a request.
+    ///
+    /// Returns [`None`] if a newer write to the same target was queued while waiting, meaning
+    /// this request must be dropped. Requests that aren't coalesced get a permit immediately.
+    pub async fn acquire(&self, req: &RequestType<'_>) -> Option<Permit<'_>> {
+        let Some(key) = self.key(req) else {
+            return Some(Permit {
+                _guard: None,
+                _slot: None,
+            });
+        };
+
+        let (slot, sequence) = {
+            let mut state = self.lock();
+            let slot = Arc::clone(state.slots.entry(key.clone()).or_default());
+            let sequence = slot.latest.fetch_add(1, Ordering::SeqCst) + 1;
+            (slot, sequence)
+        };
+
+        let slot = SlotRef {
+            coalescer: self,
+            key,
+            slot,
+        };
+        let guard = Arc::clone(&slot.slot.lock).lock_owned().await;
+
+        (slot.slot.latest.load(Ordering::SeqCst) == sequence).then_some(Permit {
+            _guard: Some(guard),
+            _slot: Some(slot),
+        })
+    }
+
+    /// Get the key of the target that a request writes to, or [`None`] if the request isn't
+    /// coalesced.
+    fn key(&self, req: &RequestType<'_>) -> Option<String> {
+        if self.lock().enabled.is_empty() {
+            return None;
+        }
+
+        let request_type = req.name();
+        let request = CoalescedRequest::ALL
+            .into_iter()
+            .find(|request| request.request_type() == request_type)?;
+
+        if !self.lock().enabled.contains(&request) {
+            return None;
+        }
+
+        let mut value = serde_json::to_value(req).ok()?;
+        let data = value.get_mut("requestData")?.as_object_mut()?;
+        for field in request.value_fields() {
+            data.remove(*field);
+        }
+
+        Some(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+
+    use super::*;
+    use crate::requests::inputs::{InputId, Request, Volume};
+
+    fn volume(db: f32) -> RequestType<'static> {
+        Request::SetVolume {
+            input: InputId::Name("Music"),
+            volume: Volume::Db(db),
+        }
+        .into()
+    }
+
+    #[test]
+    fn slots_are_released() {
+        let coalescer = Coalescer::default();
+        coalescer.configure(HashSet::from([CoalescedRequest::InputVolume]));
+
+        let first = coalescer.acquire(&volume(-10.0)).now_or_never().flatten();
+        assert!(first.is_some());
+
+        // queued behind the first write, and superseded by the third one
+        let (second_req, third_req) = (volume(-12.0), volume(-14.0));
+        let mut second = Box::pin(coalescer.acquire(&second_req));
+        assert!((&mut second).now_or_never().is_none());
+        let mut third = Box::pin(coalescer.acquire(&third_req));
+        assert!((&mut third).now_or_never().is_none());
+        assert_eq!(1, coalescer.lock().slots.len());
+
+        drop(first);
+        assert!(second.now_or_never().flatten().is_none());
+        let third = third.now_or_never().flatten();
+        assert!(third.is_some());
+        assert_eq!(1, coalescer.lock().slots.len());
+
+        drop(third);
+        assert!(coalescer.lock().slots.is_empty());
+    }
+}
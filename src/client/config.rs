@@ -1,18 +1,54 @@
 use serde::{de::DeserializeOwned, Serialize};
 
-use super::Client;
+use super::{AdvancedAudioTracks, Client};
 use crate::{
     error::Result,
-    requests::config::{Realm, Request, SetPersistentData, SetVideoSettings},
+    requests::{
+        config::{
+            Realm, Request, Resolution, SetPersistentData, SetVideoColorSettings, SetVideoSettings,
+        },
+        custom::profile_parameters::{COLOR_FORMAT, COLOR_RANGE, COLOR_SPACE},
+    },
     responses::config as responses,
 };
 
 /// API functions related to OBS configuration.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond(
+/// #     "GetVideoSettings",
+/// #     json!({
+/// #         "fpsNumerator": 60,
+/// #         "fpsDenominator": 1,
+/// #         "baseWidth": 1920,
+/// #         "baseHeight": 1080,
+/// #         "outputWidth": 1280,
+/// #         "outputHeight": 720,
+/// #     }),
+/// # );
+/// let settings = client.config().video_settings().await?;
+/// assert_eq!((1920, 1080), (settings.base_width, settings.base_height));
+/// # assert_eq!(
+/// #     Some(&("GetVideoSettings".to_owned(), json!(null))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Config<'a> {
     pub(super) client: &'a Client,
 }
 
 impl<'a> Config<'a> {
+    /// Access API functions related to the audio tracks of the advanced output mode, like the
+    /// track names, bitrates and the VOD track.
+    #[must_use]
+    pub fn audio_tracks(&self) -> AdvancedAudioTracks<'a> {
+        AdvancedAudioTracks {
+            client: self.client,
+        }
+    }
+
     /// Gets the value of a "slot" from the selected persistent data realm.
     #[doc(alias = "GetPersistentData")]
     pub async fn get_persistent_data(
@@ -54,6 +90,61 @@ impl<'a> Config<'a> {
             .await
     }
 
+    /// Sets the base (canvas) resolution, while keeping the frame rate and output resolution.
+    ///
+    /// obs-websocket only accepts resolutions in pairs, so the current video settings are read
+    /// first and sent back together with the new resolution.
+    pub async fn set_canvas_resolution(&self, resolution: Resolution) -> Result<()> {
+        let mut settings = SetVideoSettings::from(self.video_settings().await?);
+        settings.base_width = Some(resolution.width());
+        settings.base_height = Some(resolution.height());
+
+        self.set_video_settings(settings).await
+    }
+
+    /// Gets the current color settings of the video output, which are part of the advanced video
+    /// settings.
+    ///
+    /// obs-websocket doesn't expose these settings directly, so they're read from the current
+    /// profile's parameters.
+    pub async fn video_color_settings(&self) -> Result<responses::VideoColorSettings> {
+        let profiles = self.client.profiles();
+
+        Ok(responses::VideoColorSettings {
+            format: profiles.typed_parameter(COLOR_FORMAT).await?,
+            space: profiles.typed_parameter(COLOR_SPACE).await?,
+            range: profiles.typed_parameter(COLOR_RANGE).await?,
+        })
+    }
+
+    /// Sets the color settings of the video output, which are part of the advanced video
+    /// settings.
+    ///
+    /// The settings are written to the current profile's parameters. OBS only applies them on the
+    /// next reset of the video pipeline, for example through [`Self::set_video_settings`], which
+    /// is not possible while any output is active.
+    pub async fn set_video_color_settings(&self, settings: SetVideoColorSettings) -> Result<()> {
+        let profiles = self.client.profiles();
+
+        if let Some(format) = &settings.format {
+            profiles
+                .set_typed_parameter(COLOR_FORMAT, Some(format))
+                .await?;
+        }
+        if let Some(space) = &settings.space {
+            profiles
+                .set_typed_parameter(COLOR_SPACE, Some(space))
+                .await?;
+        }
+        if let Some(range) = &settings.range {
+            profiles
+                .set_typed_parameter(COLOR_RANGE, Some(range))
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// Gets the current stream service settings (stream destination).
     #[doc(alias = "GetStreamServiceSettings")]
     pub async fn stream_service_settings<T>(&self) -> Result<responses::StreamServiceSettings<T>>
@@ -84,6 +175,17 @@ impl<'a> Config<'a> {
     }
 
     /// Gets the current directory that the record output is set to.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetRecordDirectory",
+    /// #     json!({"recordDirectory": "/home/obs/Videos"}),
+    /// # );
+    /// let directory = client.config().record_directory().await?;
+    /// assert_eq!("/home/obs/Videos", directory);
+    /// # });
+    /// ```
     #[doc(alias = "GetRecordDirectory")]
     pub async fn record_directory(&self) -> Result<String> {
         self.client
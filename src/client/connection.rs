@@ -1,37 +1,313 @@
-use std::collections::{HashMap, VecDeque};
+#[cfg(feature = "events")]
+use std::sync::{atomic::AtomicUsize, Arc, Weak};
+use std::{
+    any::Any,
+    collections::{HashMap, VecDeque},
+    sync::atomic::{AtomicU64, Ordering},
+};
 
 use futures_util::{Sink, SinkExt, Stream, StreamExt};
+#[cfg(feature = "events")]
+use tokio::sync::broadcast;
 use tokio::{
-    sync::{oneshot, Mutex},
+    sync::{oneshot, Mutex, Notify},
     time::{self, Duration},
 };
 pub use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
-use tokio_tungstenite::tungstenite::Message;
-use tracing::debug;
+use tokio_tungstenite::tungstenite::{Message, Utf8Bytes};
+use tracing::{debug, warn};
 
 use super::InnerError;
+#[cfg(feature = "events")]
+use crate::events::{Event, EventOrLag};
 use crate::{
+    error::{AuthenticationFailure, Error},
     requests::{ClientRequest, EventSubscription, Identify},
-    responses::{Hello, Identified, RequestResponse, ServerMessage, Status},
+    responses::{
+        Hello, Identified, RequestBatchResponse, RequestBatchResult, RequestResponse,
+        ServerMessage, Status, WebSocketCloseCode,
+    },
 };
 
+/// Response to a single request or a request batch, that is handed to the waiting receiver.
+pub(super) enum Response {
+    /// Response to a single request, consisting of the status and raw response data, that is
+    /// only deserialized by the waiting caller.
+    Single(Status, Box<serde_json::value::RawValue>),
+    /// Results of all requests that were part of a request batch.
+    Batch(Vec<RequestBatchResult>),
+}
+
+/// Number of recently answered request IDs, that are remembered to detect duplicate responses.
+const ANSWERED_HISTORY: usize = 64;
+
+/// What to do with responses, that don't belong to any waiting request.
+///
+/// These are either duplicates of already answered requests, or orphans that no request is
+/// waiting for, like responses arriving after their request timed out. Both are counted in the
+/// [`ConnectionStats`], regardless of the policy.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum UnexpectedResponsePolicy {
+    /// Silently drop the response.
+    Ignore,
+    /// Log a warning and drop the response.
+    #[default]
+    Log,
+    /// Treat the response as protocol violation, shutting down the client and reporting
+    /// [`CloseReason::UnexpectedResponse`] through [`Client::closed`](crate::Client::closed).
+    Error,
+}
+
+/// Kind of a response, that doesn't belong to any waiting request.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum UnexpectedResponse {
+    /// The request with the same ID was already answered before.
+    Duplicate,
+    /// No request with the ID is waiting for a response.
+    Orphan,
+}
+
+/// What to do when an event listener lags behind, and events are dropped because its broadcast
+/// channel is full.
+///
+/// Lagging listeners are always counted in the [`EventLagStats`], regardless of the policy.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BroadcastGrowth {
+    /// Log a warning, that includes a suggested capacity, but keep the channel as is.
+    #[default]
+    Warn,
+    /// Replace the channel with a larger one, that can hold at least the dropped events in
+    /// addition. Existing listeners move over to the new channel without losing further events.
+    Grow {
+        /// Capacity that the channel never grows beyond. Once reached, a warning is logged
+        /// instead.
+        max_capacity: usize,
+    },
+}
+
+/// Statistics about event listeners, that lagged behind, as gathered by
+/// [`Client::event_lag_stats`](crate::Client::event_lag_stats).
+#[cfg(feature = "events")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct EventLagStats {
+    /// Number of times any listener lagged behind.
+    pub lagged: u64,
+    /// Total number of events, that listeners missed because they lagged behind.
+    pub dropped_events: u64,
+    /// Capacity of new broadcast channels, which is the largest capacity that any channel grew
+    /// to, or the configured capacity if none grew yet.
+    pub capacity: usize,
+}
+
+/// Statistics about anomalies of the connection, as gathered by
+/// [`Client::connection_stats`](crate::Client::connection_stats).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub struct ConnectionStats {
+    /// Number of responses for requests, that were already answered before.
+    pub duplicate_responses: u64,
+    /// Number of responses with an ID, that no request was waiting for.
+    pub orphan_responses: u64,
+    /// Number of requests that are currently waiting for their response.
+    ///
+    /// Requests that are canceled, by dropping their future before the response arrived, are
+    /// removed right away and don't count towards this number.
+    pub pending_requests: usize,
+}
+
+/// Encoding of the messages, that are exchanged with obs-websocket.
+///
+/// The encoding is negotiated through the web-socket subprotocol while connecting. If
+/// obs-websocket doesn't accept the requested encoding, the connection attempt fails with an
+/// [`Error::Connect`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Codec {
+    /// JSON text messages, which is the default encoding of obs-websocket.
+    #[default]
+    Json,
+    /// Binary `MsgPack` messages, which are more compact than JSON and reduce the bandwidth,
+    /// especially for event-heavy workloads. Only available with the `msgpack` feature.
+    ///
+    /// Messages are transcoded from and to JSON on the fly, so all requests, responses and events
+    /// behave the same as with the default encoding.
+    #[cfg(feature = "msgpack")]
+    MsgPack,
+}
+
+/// Failure to decode a received message into its JSON representation.
+pub(super) enum DecodeError {
+    IntoText(Box<tokio_tungstenite::tungstenite::Error>),
+    #[cfg_attr(not(feature = "msgpack"), allow(dead_code))]
+    Deserialize(serde_json::Error),
+}
+
+impl Codec {
+    /// Subprotocol to request during the web-socket upgrade, or [`None`] for the server's default.
+    pub(super) fn subprotocol(self) -> Option<&'static str> {
+        match self {
+            Self::Json => None,
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack => Some("obswebsocket.msgpack"),
+        }
+    }
+
+    /// Turn a serialized JSON message into a web-socket message of this encoding.
+    #[cfg_attr(not(feature = "msgpack"), allow(clippy::unnecessary_wraps))]
+    pub(super) fn encode(
+        self,
+        json: String,
+    ) -> Result<Message, crate::error::SerializeMessageError> {
+        match self {
+            Self::Json => Ok(Message::text(json)),
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack => {
+                let mut buf = Vec::with_capacity(json.len());
+                serde_transcode::transcode(
+                    &mut serde_json::Deserializer::from_str(&json),
+                    &mut rmp_serde::Serializer::new(&mut buf),
+                )
+                .map_err(serde::ser::Error::custom)
+                .map_err(crate::error::SerializeMessageError)?;
+
+                Ok(Message::binary(buf))
+            }
+        }
+    }
+
+    /// Turn a received web-socket message of this encoding into its JSON representation.
+    pub(super) fn decode(self, message: Message) -> Result<Utf8Bytes, DecodeError> {
+        match self {
+            Self::Json => message
+                .into_text()
+                .map_err(|e| DecodeError::IntoText(Box::new(e))),
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack => {
+                let data = message.into_data();
+                let mut buf = Vec::with_capacity(data.len() * 2);
+                serde_transcode::transcode(
+                    &mut rmp_serde::Deserializer::new(&*data),
+                    &mut serde_json::Serializer::new(&mut buf),
+                )
+                .map_err(DecodeError::Deserialize)?;
+
+                // The JSON serializer only ever produces valid UTF-8.
+                String::from_utf8(buf)
+                    .map(Into::into)
+                    .map_err(|e| DecodeError::Deserialize(serde::de::Error::custom(e)))
+            }
+        }
+    }
+}
+
+impl From<DecodeError> for InnerError {
+    fn from(value: DecodeError) -> Self {
+        match value {
+            DecodeError::IntoText(e) => Self::IntoText(e),
+            DecodeError::Deserialize(e) => Self::DeserializeMessage(e),
+        }
+    }
+}
+
+impl From<DecodeError> for HandshakeError {
+    fn from(value: DecodeError) -> Self {
+        match value {
+            DecodeError::IntoText(e) => IntoTextError(e).into(),
+            DecodeError::Deserialize(e) => crate::error::DeserializeResponseError(e).into(),
+        }
+    }
+}
+
 /// Wrapper for the list of ongoing requests that wait for response.
+///
+/// The list is guarded by a blocking mutex, that is never held across an `.await` point. That
+/// way, entries can be removed synchronously from the [`Drop`] implementation of
+/// [`PendingReceiver`], once a request future is canceled.
 #[derive(Default)]
-pub(super) struct ReceiverList(Mutex<HashMap<u64, oneshot::Sender<(Status, serde_json::Value)>>>);
+pub(super) struct ReceiverList {
+    receivers: std::sync::Mutex<HashMap<u64, oneshot::Sender<Response>>>,
+    /// Notified whenever the last outstanding receiver was removed from the list.
+    empty: Notify,
+    /// IDs of the most recently answered requests, to tell duplicates apart from orphans.
+    answered: Mutex<VecDeque<u64>>,
+    policy: UnexpectedResponsePolicy,
+    duplicate_responses: AtomicU64,
+    orphan_responses: AtomicU64,
+}
+
+/// Guard for a receiver in the [`ReceiverList`], that removes the receiver from the list once
+/// dropped.
+///
+/// If the response already arrived, the receiver was taken out of the list before and dropping
+/// the guard does nothing.
+pub(super) struct PendingReceiver<'a> {
+    list: &'a ReceiverList,
+    id: u64,
+}
+
+impl Drop for PendingReceiver<'_> {
+    fn drop(&mut self) {
+        self.list.take(self.id);
+    }
+}
 
 impl ReceiverList {
+    pub fn new(policy: UnexpectedResponsePolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
     /// Add a new receiver to the wait list, that will be notified once a request with the given
     /// ID is received.
-    pub async fn add(&self, id: u64) -> oneshot::Receiver<(Status, serde_json::Value)> {
+    ///
+    /// The receiver stays in the list until either the response arrived, or the returned guard is
+    /// dropped.
+    pub fn add(&self, id: u64) -> (oneshot::Receiver<Response>, PendingReceiver<'_>) {
         let (tx, rx) = oneshot::channel();
-        self.0.lock().await.insert(id, tx);
-        rx
+        self.lock_receivers().insert(id, tx);
+        (rx, PendingReceiver { list: self, id })
+    }
+
+    /// Number of receivers that are still waiting for their response.
+    pub fn len(&self) -> usize {
+        self.lock_receivers().len()
     }
 
-    /// Remove a previously added receiver. Used to free up resources, in case sending the request
-    /// failed.
-    pub async fn remove(&self, id: u64) {
-        self.0.lock().await.remove(&id);
+    /// Wait until there are no more receivers waiting for a response.
+    pub async fn wait_empty(&self) {
+        loop {
+            let notified = self.empty.notified();
+            if self.lock_receivers().is_empty() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Take the receiver with the given ID out of the list, notifying any listeners that wait for
+    /// the list to become empty.
+    fn take(&self, id: u64) -> Option<oneshot::Sender<Response>> {
+        let mut receivers = self.lock_receivers();
+        let tx = receivers.remove(&id);
+
+        if receivers.is_empty() {
+            self.empty.notify_waiters();
+        }
+
+        tx
+    }
+
+    fn lock_receivers(&self) -> std::sync::MutexGuard<'_, HashMap<u64, oneshot::Sender<Response>>> {
+        match self.receivers.lock() {
+            Ok(receivers) => receivers,
+            Err(poisoned) => poisoned.into_inner(),
+        }
     }
 
     /// Notify a waiting receiver with the response to a request.
@@ -47,16 +323,76 @@ impl ReceiverList {
             .parse()
             .map_err(|e| InnerError::InvalidRequestId(e, id))?;
 
-        if let Some(tx) = self.0.lock().await.remove(&id) {
-            tx.send((status, data)).ok();
+        match self.take(id) {
+            Some(tx) => {
+                self.answer(id).await;
+                tx.send(Response::Single(status, data)).ok();
+                Ok(())
+            }
+            None => self.unexpected(id).await,
+        }
+    }
+
+    /// Notify a waiting receiver with the response to a request batch.
+    pub async fn notify_batch(&self, response: RequestBatchResponse) -> Result<(), InnerError> {
+        let RequestBatchResponse { id, results } = response;
+
+        let id = id
+            .parse()
+            .map_err(|e| InnerError::InvalidRequestId(e, id))?;
+
+        match self.take(id) {
+            Some(tx) => {
+                self.answer(id).await;
+                tx.send(Response::Batch(results)).ok();
+                Ok(())
+            }
+            None => self.unexpected(id).await,
+        }
+    }
+
+    /// Remember the ID of an answered request, to detect duplicate responses for it.
+    async fn answer(&self, id: u64) {
+        let mut answered = self.answered.lock().await;
+        if answered.len() == ANSWERED_HISTORY {
+            answered.pop_front();
+        }
+        answered.push_back(id);
+    }
+
+    /// Count a response without waiting receiver, and handle it according to the policy.
+    async fn unexpected(&self, id: u64) -> Result<(), InnerError> {
+        let kind = if self.answered.lock().await.contains(&id) {
+            self.duplicate_responses.fetch_add(1, Ordering::Relaxed);
+            UnexpectedResponse::Duplicate
+        } else {
+            self.orphan_responses.fetch_add(1, Ordering::Relaxed);
+            UnexpectedResponse::Orphan
+        };
+
+        match self.policy {
+            UnexpectedResponsePolicy::Ignore => Ok(()),
+            UnexpectedResponsePolicy::Log => {
+                warn!(id, ?kind, "received response without waiting request");
+                Ok(())
+            }
+            UnexpectedResponsePolicy::Error => Err(InnerError::UnexpectedResponse(kind, id)),
         }
+    }
 
-        Ok(())
+    /// Current counters of unexpected responses.
+    pub fn stats(&self) -> ConnectionStats {
+        ConnectionStats {
+            duplicate_responses: self.duplicate_responses.load(Ordering::Relaxed),
+            orphan_responses: self.orphan_responses.load(Ordering::Relaxed),
+            pending_requests: self.len(),
+        }
     }
 
     /// Reset the list, canceling any outstanding receivers.
-    pub async fn reset(&self) {
-        self.0.lock().await.clear();
+    pub fn reset(&self) {
+        self.lock_receivers().clear();
+        self.empty.notify_waiters();
     }
 }
 
@@ -85,6 +421,269 @@ impl ReidentifyReceiverList {
     }
 }
 
+/// Distributes received events to all listeners.
+///
+/// Listeners of [`super::Client::events`] share a single channel with all events. Each listener of
+/// [`super::Client::events_for`] gets its own channel instead, that only receives events of the
+/// requested categories. That way, a slow listener can't be starved by high-volume events it isn't
+/// interested in.
+///
+/// Whenever a listener lags behind, the [`BroadcastGrowth`] policy decides whether its channel is
+/// replaced by a larger one. The listeners of the replaced channel first receive the remaining
+/// events of it, and then continue with a receiver of the new channel, that was created at the
+/// time of the replacement. That way, no events are lost or duplicated during the move.
+#[cfg(feature = "events")]
+pub(super) struct EventRouter {
+    all: Arc<std::sync::Mutex<EventChannel>>,
+    routes: std::sync::Mutex<Vec<(EventSubscription, Arc<std::sync::Mutex<EventChannel>>)>>,
+    /// Capacity for new channels, which is the largest capacity any channel grew to.
+    capacity: AtomicUsize,
+    growth: BroadcastGrowth,
+    lagged: AtomicU64,
+    dropped_events: AtomicU64,
+}
+
+/// A single broadcast channel of the [`EventRouter`], that can be replaced by a larger one.
+#[cfg(feature = "events")]
+struct EventChannel {
+    tx: broadcast::Sender<Arc<Event>>,
+    capacity: usize,
+    /// Increased each time the channel is replaced.
+    generation: u64,
+    /// Receivers of replacement channels, created for the listeners of the previous generation,
+    /// that didn't move over yet.
+    handoff: Vec<(u64, broadcast::Receiver<Arc<Event>>)>,
+}
+
+/// Listener of a channel of the [`EventRouter`], that follows the channel when it's replaced by
+/// a larger one.
+#[cfg(feature = "events")]
+pub(super) struct EventReceiver {
+    rx: broadcast::Receiver<Arc<Event>>,
+    generation: u64,
+    channel: Weak<std::sync::Mutex<EventChannel>>,
+    router: Weak<EventRouter>,
+}
+
+#[cfg(feature = "events")]
+impl EventReceiver {
+    /// Receive the next event, or the number of dropped events if the listener lagged behind.
+    /// Returns [`None`] once the connection is closed.
+    pub async fn recv(&mut self) -> Option<EventOrLag> {
+        Some(match self.recv_shared().await? {
+            Ok(event) => EventOrLag::Event(
+                // The last listener to receive the event can take it without cloning.
+                Arc::try_unwrap(event).unwrap_or_else(|event| (*event).clone()),
+            ),
+            Err(count) => EventOrLag::Lagged(count),
+        })
+    }
+
+    /// Receive the next event, shared with all other listeners, or the number of dropped events
+    /// as error if the listener lagged behind. Returns [`None`] once the connection is closed.
+    pub async fn recv_shared(&mut self) -> Option<Result<Arc<Event>, u64>> {
+        loop {
+            match self.rx.recv().await {
+                Ok(event) => return Some(Ok(event)),
+                Err(broadcast::error::RecvError::Lagged(count)) => {
+                    if let (Some(router), Some(channel)) =
+                        (self.router.upgrade(), self.channel.upgrade())
+                    {
+                        router.lagged(&channel, self.generation, count);
+                    }
+                    return Some(Err(count));
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    // The channel is only closed without the router going away, if it was
+                    // replaced by a larger one.
+                    let channel = self.channel.upgrade()?;
+                    let mut channel = lock(&channel);
+                    let next = self.generation + 1;
+                    let index = channel.handoff.iter().position(|(g, _)| *g == next)?;
+
+                    self.rx = channel.handoff.swap_remove(index).1;
+                    self.generation = next;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "events")]
+impl Drop for EventReceiver {
+    fn drop(&mut self) {
+        // Release the receivers, that were prepared in the handoff for this listener to move over
+        // to newer generations of the channel, as they'd never be picked up otherwise.
+        let Some(channel) = self.channel.upgrade() else {
+            return;
+        };
+        let mut channel = lock(&channel);
+
+        for generation in self.generation + 1..=channel.generation {
+            if let Some(index) = channel.handoff.iter().position(|(g, _)| *g == generation) {
+                channel.handoff.swap_remove(index);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "events")]
+impl EventRouter {
+    pub fn new(capacity: usize, growth: BroadcastGrowth) -> Arc<Self> {
+        Arc::new(Self {
+            all: Arc::new(std::sync::Mutex::new(EventChannel::new(capacity))),
+            routes: std::sync::Mutex::default(),
+            capacity: AtomicUsize::new(capacity),
+            growth,
+            lagged: AtomicU64::new(0),
+            dropped_events: AtomicU64::new(0),
+        })
+    }
+
+    /// Create a new listener for all events.
+    pub fn subscribe(self: &Arc<Self>) -> EventReceiver {
+        self.receiver(&self.all)
+    }
+
+    /// Create a new listener with its own channel, that only receives events of the given
+    /// categories, and events that don't belong to any category, like
+    /// [`Event::ServerStopped`].
+    pub fn subscribe_to(self: &Arc<Self>, subscription: EventSubscription) -> EventReceiver {
+        let channel = Arc::new(std::sync::Mutex::new(EventChannel::new(
+            self.capacity.load(Ordering::Relaxed),
+        )));
+        let receiver = self.receiver(&channel);
+        lock(&self.routes).push((subscription, channel));
+        receiver
+    }
+
+    fn receiver(self: &Arc<Self>, channel: &Arc<std::sync::Mutex<EventChannel>>) -> EventReceiver {
+        let locked = lock(channel);
+        EventReceiver {
+            rx: locked.tx.subscribe(),
+            generation: locked.generation,
+            channel: Arc::downgrade(channel),
+            router: Arc::downgrade(self),
+        }
+    }
+
+    /// Send an event to all interested listeners. Events are dropped if nobody listens.
+    pub fn send(&self, event: Event) {
+        let category = event.subscription();
+        // Listeners share the event, instead of each receiving its own copy.
+        let event = Arc::new(event);
+
+        {
+            let mut routes = lock(&self.routes);
+            routes.retain(|(_, channel)| Arc::weak_count(channel) > 0);
+
+            for (subscription, channel) in routes.iter() {
+                if category.is_empty() || subscription.intersects(category) {
+                    lock(channel).tx.send(Arc::clone(&event)).ok();
+                }
+            }
+        }
+
+        lock(&self.all).tx.send(event).ok();
+    }
+
+    /// Get the statistics about lagging listeners.
+    pub fn stats(&self) -> EventLagStats {
+        EventLagStats {
+            lagged: self.lagged.load(Ordering::Relaxed),
+            dropped_events: self.dropped_events.load(Ordering::Relaxed),
+            capacity: self.capacity.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Record that a listener of the given channel generation lagged behind, and grow the
+    /// channel if the policy allows it.
+    fn lagged(&self, channel: &std::sync::Mutex<EventChannel>, generation: u64, count: u64) {
+        self.lagged.fetch_add(1, Ordering::Relaxed);
+        self.dropped_events.fetch_add(count, Ordering::Relaxed);
+
+        let mut channel = lock(channel);
+        if channel.generation != generation {
+            // Already replaced, after another listener of the same generation lagged behind.
+            return;
+        }
+
+        let suggested = channel
+            .capacity
+            .saturating_add(usize::try_from(count).unwrap_or(usize::MAX))
+            .checked_next_power_of_two()
+            .unwrap_or(usize::MAX);
+
+        let BroadcastGrowth::Grow { max_capacity } = self.growth else {
+            warn!(
+                count,
+                capacity = channel.capacity,
+                suggested_capacity = suggested,
+                "event listener lagged behind and events were dropped, consider increasing the \
+                 broadcast capacity",
+            );
+            return;
+        };
+
+        let capacity = suggested.min(max_capacity);
+        if capacity <= channel.capacity {
+            warn!(
+                count,
+                capacity = channel.capacity,
+                suggested_capacity = suggested,
+                "event listener lagged behind and events were dropped, but the broadcast capacity \
+                 is already at its maximum",
+            );
+            return;
+        }
+
+        debug!(
+            count,
+            from = channel.capacity,
+            to = capacity,
+            "growing broadcast channel for events",
+        );
+        channel.grow(capacity);
+        self.capacity.fetch_max(capacity, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "events")]
+impl EventChannel {
+    fn new(capacity: usize) -> Self {
+        Self {
+            tx: broadcast::channel(capacity).0,
+            capacity,
+            generation: 0,
+            handoff: Vec::new(),
+        }
+    }
+
+    /// Replace the channel with a larger one, preparing a receiver of the new channel for each
+    /// listener of the current one.
+    fn grow(&mut self, capacity: usize) {
+        let (tx, _) = broadcast::channel(capacity);
+        let generation = self.generation + 1;
+
+        // Each receiver of the current channel belongs to a listener, that moves over eventually.
+        // That includes receivers in the handoff, waiting for listeners of older generations.
+        let listeners = self.tx.receiver_count();
+        self.handoff
+            .extend((0..listeners).map(|_| (generation, tx.subscribe())));
+
+        self.tx = tx;
+        self.capacity = capacity;
+        self.generation = generation;
+    }
+}
+
+#[cfg(feature = "events")]
+fn lock<T>(mutex: &std::sync::Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
 /// Errors that can occur while performing the initial handshake with obs-websocket.
 #[derive(Debug, thiserror::Error)]
 #[non_exhaustive]
@@ -118,18 +717,40 @@ pub enum HandshakeError {
     NoIdentified,
 }
 
+impl HandshakeError {
+    /// Convert into the crate's error type, turning a rejected authentication into the dedicated
+    /// [`Error::AuthenticationFailed`].
+    pub(super) fn into_error(self, password_given: bool) -> Error {
+        match self {
+            Self::ConnectionClosed(Some(details))
+                if u16::from(details.code) == WebSocketCloseCode::AuthenticationFailed as u16 =>
+            {
+                Error::AuthenticationFailed {
+                    kind: if password_given {
+                        AuthenticationFailure::PasswordRejected
+                    } else {
+                        AuthenticationFailure::MissingPassword
+                    },
+                    details,
+                }
+            }
+            other => Error::Handshake(other),
+        }
+    }
+}
+
 /// Receiving a message did not succeed.
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct ReceiveError(tokio_tungstenite::tungstenite::Error);
+pub struct ReceiveError(Box<tokio_tungstenite::tungstenite::Error>);
 
 /// The web-socket message was not convertible to text.
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct IntoTextError(tokio_tungstenite::tungstenite::Error);
+pub struct IntoTextError(Box<tokio_tungstenite::tungstenite::Error>);
 
 /// Description about the reason of why the web-socket connection was closed.
-#[derive(Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CloseDetails {
     /// Close code to precisely identify the reason.
     ///
@@ -141,67 +762,72 @@ pub struct CloseDetails {
     pub reason: String,
 }
 
+/// Reason of why the connection to obs-websocket ended, as reported by
+/// [`Client::closed`](crate::Client::closed).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CloseReason {
+    /// obs-websocket closed the connection, with optional details about the reason.
+    Server(Option<CloseDetails>),
+    /// The connection was interrupted or closed by the client.
+    Disconnected,
+    /// Handling a received message panicked inside the background task of the client. Contains
+    /// the panic message, if available.
+    ///
+    /// This is always a bug in this crate and should be reported.
+    InternalPanic(String),
+    /// obs-websocket sent a response that no request was waiting for, and the client was
+    /// configured to treat that as error with [`UnexpectedResponsePolicy::Error`].
+    UnexpectedResponse(UnexpectedResponse),
+}
+
+impl CloseReason {
+    /// Create the reason from the payload of a caught panic.
+    pub(super) fn from_panic(payload: &(dyn Any + Send)) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_owned())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+
+        Self::InternalPanic(message)
+    }
+}
+
 pub(super) async fn handshake(
     write: &mut (impl Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin),
     read: &mut (impl Stream<Item = tokio_tungstenite::tungstenite::Result<Message>> + Unpin),
     password: Option<&str>,
     event_subscriptions: Option<EventSubscription>,
+    codec: Codec,
 ) -> Result<(), HandshakeError> {
-    async fn read_message(
-        read: &mut (impl Stream<Item = tokio_tungstenite::tungstenite::Result<Message>> + Unpin),
-    ) -> Result<ServerMessage, HandshakeError> {
-        let mut message = read
-            .next()
-            .await
-            .ok_or(HandshakeError::ConnectionClosed(None))?
-            .map_err(ReceiveError)?;
-
-        if let Message::Close(info) = &mut message {
-            return Err(HandshakeError::ConnectionClosed(info.take().map(|i| {
-                CloseDetails {
-                    code: i.code,
-                    reason: i.reason.as_str().to_owned(),
-                }
-            })));
-        }
-
-        let message = message.into_text().map_err(IntoTextError)?;
-
-        serde_json::from_str::<ServerMessage>(&message)
-            .map_err(crate::error::DeserializeResponseError)
-            .map_err(Into::into)
-    }
-
-    let server_message = time::timeout(Duration::from_secs(5), read_message(read))
+    let server_message = time::timeout(Duration::from_secs(5), read_hello(read, codec))
         .await
         .map_err(|_| HandshakeError::NoHello)?;
 
-    match server_message? {
-        ServerMessage::Hello(Hello {
-            obs_web_socket_version: _,
-            rpc_version,
-            authentication,
-        }) => {
-            let authentication = authentication.zip(password).map(|(auth, password)| {
-                create_auth_response(&auth.challenge, &auth.salt, password)
-            });
-
-            let req = serde_json::to_string(&ClientRequest::Identify(Identify {
-                rpc_version,
-                authentication,
-                event_subscriptions,
-            }))
-            .map_err(crate::error::SerializeMessageError)?;
-
-            write
-                .send(Message::text(req))
-                .await
-                .map_err(crate::error::SendError)?;
-        }
-        _ => return Err(HandshakeError::NoHello),
-    }
+    let Hello {
+        obs_web_socket_version: _,
+        rpc_version,
+        authentication,
+    } = server_message?;
+
+    let authentication = authentication
+        .zip(password)
+        .map(|(auth, password)| create_auth_response(&auth.challenge, &auth.salt, password));
+
+    let req = serde_json::to_string(&ClientRequest::Identify(Identify {
+        rpc_version,
+        authentication,
+        event_subscriptions,
+    }))
+    .map_err(crate::error::SerializeMessageError)?;
+
+    write
+        .send(codec.encode(req)?)
+        .await
+        .map_err(|e| crate::error::SendError(Box::new(e)))?;
 
-    match read_message(read).await? {
+    match read_message(read, codec).await? {
         ServerMessage::Identified(Identified {
             negotiated_rpc_version,
         }) => {
@@ -213,6 +839,43 @@ pub(super) async fn handshake(
     Ok(())
 }
 
+async fn read_message(
+    read: &mut (impl Stream<Item = tokio_tungstenite::tungstenite::Result<Message>> + Unpin),
+    codec: Codec,
+) -> Result<ServerMessage, HandshakeError> {
+    let mut message = read
+        .next()
+        .await
+        .ok_or(HandshakeError::ConnectionClosed(None))?
+        .map_err(|e| ReceiveError(Box::new(e)))?;
+
+    if let Message::Close(info) = &mut message {
+        return Err(HandshakeError::ConnectionClosed(info.take().map(|i| {
+            CloseDetails {
+                code: i.code,
+                reason: i.reason.as_str().to_owned(),
+            }
+        })));
+    }
+
+    let message = codec.decode(message)?;
+
+    serde_json::from_str::<ServerMessage>(&message)
+        .map_err(crate::error::DeserializeResponseError)
+        .map_err(Into::into)
+}
+
+/// Read the initial `Hello` message, that obs-websocket sends right after connecting.
+pub(super) async fn read_hello(
+    read: &mut (impl Stream<Item = tokio_tungstenite::tungstenite::Result<Message>> + Unpin),
+    codec: Codec,
+) -> Result<Hello, HandshakeError> {
+    match read_message(read, codec).await? {
+        ServerMessage::Hello(hello) => Ok(hello),
+        _ => Err(HandshakeError::NoHello),
+    }
+}
+
 fn create_auth_response(challenge: &str, salt: &str, password: &str) -> String {
     use base64::engine::{general_purpose, Engine};
     use sha2::{Digest, Sha256};
@@ -233,3 +896,38 @@ fn create_auth_response(challenge: &str, salt: &str, password: &str) -> String {
 
     auth
 }
+
+#[cfg(all(test, feature = "events"))]
+mod tests {
+    use futures_util::FutureExt;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn dropped_listeners_release_handoff() {
+        let router = EventRouter::new(1, BroadcastGrowth::Grow { max_capacity: 16 });
+        let mut first = router.subscribe();
+        let second = router.subscribe();
+
+        router.send(Event::CustomEvent(json!(1)));
+        router.send(Event::CustomEvent(json!(2)));
+        assert!(matches!(
+            first.recv().now_or_never(),
+            Some(Some(EventOrLag::Lagged(1)))
+        ));
+        assert_eq!(2, lock(&router.all).handoff.len());
+
+        drop(second);
+        assert_eq!(1, lock(&router.all).handoff.len());
+
+        router.send(Event::CustomEvent(json!(3)));
+        for expected in [2, 3] {
+            assert!(matches!(
+                first.recv().now_or_never(),
+                Some(Some(EventOrLag::Event(Event::CustomEvent(value)))) if value == expected
+            ));
+        }
+        assert!(lock(&router.all).handoff.is_empty());
+    }
+}
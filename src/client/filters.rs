@@ -1,6 +1,8 @@
 use serde::{de::DeserializeOwned, Serialize};
 
 use super::Client;
+#[cfg(feature = "presets")]
+use crate::requests::custom::filter_presets::FilterPreset;
 use crate::{
     error::Result,
     requests::{
@@ -14,11 +16,35 @@ use crate::{
 };
 
 /// API functions related to filters.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond(
+/// #     "GetSourceFilterKindList",
+/// #     json!({"sourceFilterKinds": ["color_filter", "mask_filter"]}),
+/// # );
+/// # stub.respond("RemoveSourceFilter", json!(null));
+/// let kinds = client.filters().list_kinds().await?;
+/// assert_eq!(vec!["color_filter", "mask_filter"], kinds);
+///
+/// client
+///     .filters()
+///     .remove("Camera", "Color Correction")
+///     .await?;
+/// # assert_eq!(
+/// #     Some(&(
+/// #         "RemoveSourceFilter".to_owned(),
+/// #         json!({"sourceName": "Camera", "filterName": "Color Correction"}),
+/// #     )),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Filters<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Filters<'a> {
+impl Filters<'_> {
     /// Gets an array of all available source filter kinds.
     #[doc(alias = "GetSourceFilterKindList")]
     pub async fn list_kinds(&self) -> Result<Vec<String>> {
@@ -30,7 +56,11 @@ impl<'a> Filters<'a> {
 
     /// Gets an array of all of a source's filters.
     #[doc(alias = "GetSourceFilterList")]
-    pub async fn list(&self, source: SourceId<'_>) -> Result<Vec<responses::SourceFilter>> {
+    pub async fn list(
+        &self,
+        source: impl Into<SourceId<'_>>,
+    ) -> Result<Vec<responses::SourceFilter>> {
+        let source = source.into();
         self.client
             .send_message::<_, responses::Filters>(Request::List { source })
             .await
@@ -73,7 +103,8 @@ impl<'a> Filters<'a> {
 
     /// Removes a filter from a source.
     #[doc(alias = "RemoveSourceFilter")]
-    pub async fn remove(&self, source: SourceId<'_>, filter: &str) -> Result<()> {
+    pub async fn remove(&self, source: impl Into<SourceId<'_>>, filter: &str) -> Result<()> {
+        let source = source.into();
         self.client
             .send_message(Request::Remove { source, filter })
             .await
@@ -87,7 +118,12 @@ impl<'a> Filters<'a> {
 
     /// Gets the info for a specific source filter.
     #[doc(alias = "GetSourceFilter")]
-    pub async fn get(&self, source: SourceId<'_>, filter: &str) -> Result<responses::SourceFilter> {
+    pub async fn get(
+        &self,
+        source: impl Into<SourceId<'_>>,
+        filter: &str,
+    ) -> Result<responses::SourceFilter> {
+        let source = source.into();
         self.client
             .send_message(Request::Get { source, filter })
             .await
@@ -117,8 +153,79 @@ impl<'a> Filters<'a> {
     }
 
     /// Sets the enable state of a source filter.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond("SetSourceFilterEnabled", json!(null));
+    /// use obws::requests::filters::SetEnabled;
+    ///
+    /// client
+    ///     .filters()
+    ///     .set_enabled(SetEnabled {
+    ///         source: "Camera".into(),
+    ///         filter: "Color Correction",
+    ///         enabled: false,
+    ///     })
+    ///     .await?;
+    /// # assert_eq!(
+    /// #     Some(&(
+    /// #         "SetSourceFilterEnabled".to_owned(),
+    /// #         json!({
+    /// #             "sourceName": "Camera",
+    /// #             "filterName": "Color Correction",
+    /// #             "filterEnabled": false,
+    /// #         }),
+    /// #     )),
+    /// #     stub.received().last(),
+    /// # );
+    /// # });
+    /// ```
     #[doc(alias = "SetSourceFilterEnabled")]
     pub async fn set_enabled(&self, enabled: SetEnabled<'_>) -> Result<()> {
         self.client.send_message(Request::SetEnabled(enabled)).await
     }
+
+    /// Creates all filters of a preset on the given source, in the order they're defined in the
+    /// preset.
+    ///
+    /// Placeholders in the preset are replaced before creating the filters. The special
+    /// `{{source}}` placeholder resolves to the name or UUID of the given source.
+    #[cfg(feature = "presets")]
+    pub async fn apply_preset(
+        &self,
+        source: impl Into<SourceId<'_>>,
+        preset: &FilterPreset,
+    ) -> Result<()> {
+        let source = source.into();
+        for filter in preset.render(&source.to_string()) {
+            self.create(Create {
+                source,
+                filter: &filter.name,
+                kind: &filter.kind,
+                settings: Some(filter.settings),
+            })
+            .await?;
+
+            if !filter.enabled {
+                self.set_enabled(SetEnabled {
+                    source,
+                    filter: &filter.name,
+                    enabled: false,
+                })
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Exports the current filter chain of a source as preset, which can then be stored with
+    /// [`FilterPreset::to_toml`].
+    #[cfg(feature = "presets")]
+    pub async fn export_preset(&self, source: impl Into<SourceId<'_>>) -> Result<FilterPreset> {
+        let source = source.into();
+        self.list(source)
+            .await
+            .map(|filters| FilterPreset::from_filters(&filters))
+    }
 }
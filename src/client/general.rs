@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use serde::{de::DeserializeOwned, Serialize};
 
-use super::Client;
+use super::{Client, StatsHistory};
 use crate::{
     error::{Error, Result},
     requests::general::{CallVendorRequest, CallVendorRequestInternal, Request},
@@ -8,6 +10,23 @@ use crate::{
 };
 
 /// General functions of the API.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("BroadcastCustomEvent", json!(null));
+/// let version = client.general().version().await?;
+/// assert_eq!(1, version.rpc_version);
+///
+/// client
+///     .general()
+///     .broadcast_custom_event(&serde_json::json!({"greeting": "hello"}))
+///     .await?;
+/// # assert_eq!(
+/// #     Some(&("BroadcastCustomEvent".to_owned(), json!({"eventData": {"greeting": "hello"}}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct General<'a> {
     pub(super) client: &'a Client,
 }
@@ -25,6 +44,16 @@ impl<'a> General<'a> {
         self.client.send_message(Request::Stats).await
     }
 
+    /// Create a collector, that samples [`Self::stats`] in the given interval and keeps all
+    /// samples within the given time window, to calculate aggregates like the average render time
+    /// or skipped frames per minute.
+    ///
+    /// No samples are taken until the collector is run, see [`StatsHistory::run`].
+    #[must_use]
+    pub fn stats_history(&self, interval: Duration, window: Duration) -> StatsHistory<'a> {
+        StatsHistory::new(self.client, interval, window)
+    }
+
     /// Broadcasts a custom event to all web-socket clients. Receivers are clients which are
     /// identified and subscribed.
     #[doc(alias = "BroadcastCustomEvent")]
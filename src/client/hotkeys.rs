@@ -1,16 +1,39 @@
+use std::collections::BTreeMap;
+
 use super::Client;
 use crate::{
     error::Result,
-    requests::hotkeys::{KeyModifiers, Request},
+    requests::hotkeys::{sequence::KeySequence, KeyModifiers, Request},
     responses::hotkeys as responses,
 };
 
 /// API functions related to hotkeys.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("GetHotkeyList", json!({"hotkeys": ["OBSBasic.StartRecording"]}));
+/// # stub.respond("TriggerHotkeyByName", json!(null));
+/// let hotkeys = client.hotkeys().list().await?;
+/// assert_eq!(vec!["OBSBasic.StartRecording"], hotkeys);
+///
+/// client
+///     .hotkeys()
+///     .trigger_by_name("OBSBasic.StartRecording", None)
+///     .await?;
+/// # assert_eq!(
+/// #     Some(&(
+/// #         "TriggerHotkeyByName".to_owned(),
+/// #         json!({"hotkeyName": "OBSBasic.StartRecording", "contextName": null}),
+/// #     )),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Hotkeys<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Hotkeys<'a> {
+impl Hotkeys<'_> {
     /// Gets an array of all hotkey names in OBS.
     #[doc(alias = "GetHotkeyList")]
     pub async fn list(&self) -> Result<Vec<String>> {
@@ -20,6 +43,26 @@ impl<'a> Hotkeys<'a> {
             .map(|h| h.hotkeys)
     }
 
+    /// Gets all hotkeys in OBS like [`Self::list`], with additional information about each one,
+    /// grouped by their category.
+    ///
+    /// The hotkeys within each category keep the order, in which they were reported by OBS.
+    pub async fn list_detailed(
+        &self,
+    ) -> Result<BTreeMap<responses::HotkeyCategory, Vec<responses::HotkeyInfo>>> {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for info in self
+            .list()
+            .await?
+            .into_iter()
+            .map(responses::HotkeyInfo::from)
+        {
+            groups.entry(info.category).or_default().push(info);
+        }
+
+        Ok(groups)
+    }
+
     /// Triggers a hotkey using its name. See [`Self::list`].
     #[doc(alias = "TriggerHotkeyByName")]
     pub async fn trigger_by_name(&self, name: &str, context: Option<&str>) -> Result<()> {
@@ -35,4 +78,12 @@ impl<'a> Hotkeys<'a> {
             .send_message(Request::TriggerBySequence { id, modifiers })
             .await
     }
+
+    /// Triggers a hotkey using a typed key sequence, like one parsed from `"Ctrl+Shift+S"`.
+    #[doc(alias = "TriggerHotkeyByKeySequence")]
+    pub async fn trigger_by_key_sequence(&self, sequence: &KeySequence) -> Result<()> {
+        self.client
+            .send_message(Request::TriggerByKeySequence(sequence))
+            .await
+    }
 }
@@ -1,22 +1,45 @@
+#[cfg(feature = "events")]
+use futures_util::{Stream, StreamExt};
 use serde::{de::DeserializeOwned, Serialize};
 use time::Duration;
 
 use super::Client;
+#[cfg(feature = "events")]
+use crate::events::Event;
 use crate::{
-    common::MonitorType,
+    common::{AudioTracks, MonitorType, ObwsDuration},
     error::Result,
-    requests::inputs::{
-        Create, CreateInputInternal, InputId, Request, SetSettings, SetSettingsInternal, Volume,
+    requests::{
+        batch::{BatchExecution, BatchRequest},
+        inputs::{
+            Create, CreateInputInternal, InputId, Request, SetSettings, SetSettingsInternal, Volume,
+        },
+        scene_items::SceneItemTransform,
+        scenes::SceneId,
     },
-    responses::inputs as responses,
+    responses::{inputs as responses, lenient::LenientList},
 };
 
 /// API functions related to inputs.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("GetInputMute", json!({"inputMuted": false}));
+/// # stub.respond("SetInputMute", json!(null));
+/// assert!(!client.inputs().muted("Mic/Aux").await?);
+///
+/// client.inputs().set_muted("Mic/Aux", true).await?;
+/// # assert_eq!(
+/// #     Some(&("SetInputMute".to_owned(), json!({"inputName": "Mic/Aux", "inputMuted": true}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Inputs<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Inputs<'a> {
+impl Inputs<'_> {
     /// Gets an array of all inputs in OBS.
     #[doc(alias = "GetInputList")]
     pub async fn list(&self, kind: Option<&str>) -> Result<Vec<responses::Input>> {
@@ -26,6 +49,16 @@ impl<'a> Inputs<'a> {
             .map(|i| i.inputs)
     }
 
+    /// Gets an array of all inputs in OBS, like [`Self::list`], but skips any inputs that failed
+    /// to deserialize instead of failing the whole request.
+    #[doc(alias = "GetInputList")]
+    pub async fn list_lenient(&self, kind: Option<&str>) -> Result<LenientList<responses::Input>> {
+        self.client
+            .send_message::<_, responses::Inputs<_>>(Request::List { kind })
+            .await
+            .map(|i| i.inputs)
+    }
+
     /// Gets an array of all available input kinds in OBS.
     #[doc(alias = "GetInputKindList")]
     pub async fn list_kinds(&self, unversioned: bool) -> Result<Vec<String>> {
@@ -35,6 +68,33 @@ impl<'a> Inputs<'a> {
             .map(|ik| ik.input_kinds)
     }
 
+    /// Gets all available input kinds in OBS, pairing each versioned kind with its unversioned
+    /// name.
+    ///
+    /// This allows to match configurations written against unversioned kinds, to the concrete
+    /// versioned kind present on the host.
+    #[doc(alias = "GetInputKindList")]
+    pub async fn list_kinds_detailed(&self) -> Result<Vec<responses::InputKindInfo>> {
+        let unversioned = self.list_kinds(true).await?;
+        let versioned = self.list_kinds(false).await?;
+
+        Ok(versioned
+            .into_iter()
+            .map(|versioned| {
+                let unversioned = unversioned
+                    .iter()
+                    .find(|kind| is_version_of(&versioned, kind))
+                    .cloned()
+                    .unwrap_or_else(|| versioned.clone());
+
+                responses::InputKindInfo {
+                    unversioned,
+                    versioned,
+                }
+            })
+            .collect())
+    }
+
     /// Gets the names of all special inputs.
     #[doc(alias = "GetSpecialInputs")]
     pub async fn specials(&self) -> Result<responses::SpecialInputs> {
@@ -60,10 +120,14 @@ impl<'a> Inputs<'a> {
     /// **Note:** Does not include defaults. To create the entire settings object, overlay input
     /// settings over the default input settings provided by [`Inputs::default_settings`].
     #[doc(alias = "GetInputSettings")]
-    pub async fn settings<T>(&self, input: InputId<'_>) -> Result<responses::InputSettings<T>>
+    pub async fn settings<T>(
+        &self,
+        input: impl Into<InputId<'_>>,
+    ) -> Result<responses::InputSettings<T>>
     where
         T: DeserializeOwned,
     {
+        let input = input.into();
         self.client.send_message(Request::Settings { input }).await
     }
 
@@ -85,24 +149,92 @@ impl<'a> Inputs<'a> {
 
     /// Gets the audio mute state of an input.
     #[doc(alias = "GetInputMute")]
-    pub async fn muted(&self, input: InputId<'_>) -> Result<bool> {
+    pub async fn muted(&self, input: impl Into<InputId<'_>>) -> Result<bool> {
+        let input = input.into();
         self.client
             .send_message::<_, responses::InputMuted>(Request::Muted { input })
             .await
             .map(|im| im.muted)
     }
 
+    /// Watch the audio mute state of an input.
+    ///
+    /// The returned stream yields the current mute state first, followed by every change of it.
+    /// The input is tracked by its UUID, so the stream keeps working if the input is renamed, and
+    /// ends once the input is removed.
+    ///
+    /// The event subscription is set up before querying the current state, so no changes are
+    /// missed in between.
+    #[cfg(feature = "events")]
+    pub async fn watch_muted(
+        &self,
+        input: impl Into<InputId<'_>>,
+    ) -> Result<impl Stream<Item = bool>> {
+        let input = input.into();
+        let mut events = Box::pin(self.client.events()?);
+        let muted = self.muted(input).await?;
+
+        let uuid = match input {
+            InputId::Uuid(uuid) => Some(uuid),
+            InputId::Name(name) => self
+                .list(None)
+                .await?
+                .into_iter()
+                .find(|i| i.id.name == name)
+                .map(|i| i.id.uuid),
+        };
+        let mut name = match input {
+            InputId::Name(name) => name.to_owned(),
+            InputId::Uuid(_) => String::new(),
+        };
+
+        Ok(async_stream::stream! {
+            yield muted;
+
+            while let Some(event) = events.next().await {
+                match event {
+                    Event::InputMuteStateChanged { id, muted }
+                        if uuid.map_or(id.name == name, |uuid| id.uuid == uuid) =>
+                    {
+                        yield muted;
+                    }
+                    Event::InputNameChanged { uuid: changed, old_name, new_name }
+                        if uuid.map_or(old_name == name, |uuid| changed == uuid) =>
+                    {
+                        name = new_name;
+                    }
+                    Event::InputRemoved { id }
+                        if uuid.map_or(id.name == name, |uuid| id.uuid == uuid) =>
+                    {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        })
+    }
+
     /// Sets the audio mute state of an input.
     #[doc(alias = "SetInputMute")]
-    pub async fn set_muted(&self, input: InputId<'_>, muted: bool) -> Result<()> {
+    pub async fn set_muted(&self, input: impl Into<InputId<'_>>, muted: bool) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::SetMuted { input, muted })
             .await
     }
 
     /// Toggles the audio mute state of an input.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond("ToggleInputMute", json!({"inputMuted": true}));
+    /// let muted = client.inputs().toggle_mute("Mic/Aux").await?;
+    /// assert!(muted);
+    /// # });
+    /// ```
     #[doc(alias = "ToggleInputMute")]
-    pub async fn toggle_mute(&self, input: InputId<'_>) -> Result<bool> {
+    pub async fn toggle_mute(&self, input: impl Into<InputId<'_>>) -> Result<bool> {
+        let input = input.into();
         self.client
             .send_message::<_, responses::InputMuted>(Request::ToggleMute { input })
             .await
@@ -111,13 +243,40 @@ impl<'a> Inputs<'a> {
 
     /// Gets the current volume setting of an input.
     #[doc(alias = "GetInputVolume")]
-    pub async fn volume(&self, input: InputId<'_>) -> Result<responses::InputVolume> {
+    pub async fn volume(&self, input: impl Into<InputId<'_>>) -> Result<responses::InputVolume> {
+        let input = input.into();
         self.client.send_message(Request::Volume { input }).await
     }
 
     /// Sets the volume setting of an input.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetInputVolume",
+    /// #     json!({"inputVolumeMul": 0.5, "inputVolumeDb": -6.0}),
+    /// # );
+    /// # stub.respond("SetInputVolume", json!(null));
+    /// use obws::requests::inputs::Volume;
+    ///
+    /// // Lower the volume of the music by another 6 dB.
+    /// let volume = client.inputs().volume("Music").await?;
+    /// client
+    ///     .inputs()
+    ///     .set_volume("Music", Volume::Db(volume.db - 6.0))
+    ///     .await?;
+    /// # assert_eq!(
+    /// #     Some(&(
+    /// #         "SetInputVolume".to_owned(),
+    /// #         json!({"inputName": "Music", "inputVolumeDb": -12.0}),
+    /// #     )),
+    /// #     stub.received().last(),
+    /// # );
+    /// # });
+    /// ```
     #[doc(alias = "SetInputVolume")]
-    pub async fn set_volume(&self, input: InputId<'_>, volume: Volume) -> Result<()> {
+    pub async fn set_volume(&self, input: impl Into<InputId<'_>>, volume: Volume) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::SetVolume { input, volume })
             .await
@@ -125,7 +284,8 @@ impl<'a> Inputs<'a> {
 
     /// Sets the name of an input (rename).
     #[doc(alias = "SetInputName")]
-    pub async fn set_name(&self, input: InputId<'_>, new: &str) -> Result<()> {
+    pub async fn set_name(&self, input: impl Into<InputId<'_>>, new: &str) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::SetName { input, new })
             .await
@@ -138,33 +298,73 @@ impl<'a> Inputs<'a> {
         T: Serialize,
     {
         self.client
-            .send_message(Request::Create(CreateInputInternal {
-                scene: input.scene,
-                input: input.input,
-                kind: input.kind,
-                settings: input
-                    .settings
-                    .map(|settings| {
-                        serde_json::to_value(&settings)
-                            .map_err(crate::error::SerializeCustomDataError)
-                    })
-                    .transpose()?,
-                enabled: input.enabled,
-            }))
+            .send_message(Request::Create(create_internal(input)?))
             .await
     }
 
+    /// Creates a new input like [`Self::create`], and places the new scene item with the given
+    /// transform right away.
+    ///
+    /// Both requests are sent in a single request batch, that passes the new scene item's ID from
+    /// one request to the next. The batch stops if the input can't be created, but an input that
+    /// was created stays in place, even if the transform fails.
+    #[doc(alias = "CreateInput")]
+    #[doc(alias = "SetSceneItemTransform")]
+    pub async fn create_placed<T>(
+        &self,
+        input: Create<'_, T>,
+        transform: SceneItemTransform,
+    ) -> Result<responses::SceneItemId>
+    where
+        T: Serialize,
+    {
+        #[derive(Serialize)]
+        struct Placement<'a> {
+            #[serde(flatten)]
+            scene: SceneId<'a>,
+            #[serde(rename = "sceneItemTransform")]
+            transform: SceneItemTransform,
+        }
+
+        let scene = input.scene;
+        let create = serde_json::to_value(create_internal(input)?)
+            .map_err(crate::error::SerializeCustomDataError)?;
+        let placement = serde_json::to_value(Placement { scene, transform })
+            .map_err(crate::error::SerializeCustomDataError)?;
+
+        let results = self
+            .client
+            .batch(
+                [
+                    BatchRequest::new("CreateInput")
+                        .data(create)
+                        .output("sceneItemId", "sceneItemId"),
+                    BatchRequest::new("SetSceneItemTransform")
+                        .data(placement)
+                        .input("sceneItemId", "sceneItemId"),
+                ],
+                BatchExecution::SerialRealtime,
+            )
+            .await?;
+
+        serde_json::from_value(results.into_iter().next().unwrap_or_default())
+            .map_err(crate::error::DeserializeResponseError)
+            .map_err(Into::into)
+    }
+
     /// Removes an existing input.
     ///
     /// **Note:** Will immediately remove all associated scene items.
     #[doc(alias = "RemoveInput")]
-    pub async fn remove(&self, input: InputId<'_>) -> Result<()> {
+    pub async fn remove(&self, input: impl Into<InputId<'_>>) -> Result<()> {
+        let input = input.into();
         self.client.send_message(Request::Remove { input }).await
     }
 
     /// Gets the audio balance of an input.
     #[doc(alias = "GetInputAudioBalance")]
-    pub async fn audio_balance(&self, input: InputId<'_>) -> Result<f32> {
+    pub async fn audio_balance(&self, input: impl Into<InputId<'_>>) -> Result<f32> {
+        let input = input.into();
         self.client
             .send_message::<_, responses::AudioBalance>(Request::AudioBalance { input })
             .await
@@ -173,7 +373,12 @@ impl<'a> Inputs<'a> {
 
     /// Sets the audio balance of an input.
     #[doc(alias = "SetInputAudioBalance")]
-    pub async fn set_audio_balance(&self, input: InputId<'_>, balance: f32) -> Result<()> {
+    pub async fn set_audio_balance(
+        &self,
+        input: impl Into<InputId<'_>>,
+        balance: f32,
+    ) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::SetAudioBalance { input, balance })
             .await
@@ -183,7 +388,8 @@ impl<'a> Inputs<'a> {
     ///
     /// **Note:** The audio sync offset can be negative too!
     #[doc(alias = "GetInputAudioSyncOffset")]
-    pub async fn audio_sync_offset(&self, input: InputId<'_>) -> Result<Duration> {
+    pub async fn audio_sync_offset(&self, input: impl Into<InputId<'_>>) -> Result<Duration> {
+        let input = input.into();
         self.client
             .send_message::<_, responses::AudioSyncOffset>(Request::AudioSyncOffset { input })
             .await
@@ -192,7 +398,13 @@ impl<'a> Inputs<'a> {
 
     /// Sets the audio sync offset of an input.
     #[doc(alias = "SetInputAudioSyncOffset")]
-    pub async fn set_audio_sync_offset(&self, input: InputId<'_>, offset: Duration) -> Result<()> {
+    pub async fn set_audio_sync_offset(
+        &self,
+        input: impl Into<InputId<'_>>,
+        offset: impl Into<ObwsDuration>,
+    ) -> Result<()> {
+        let input = input.into();
+        let offset = Duration::from(offset.into());
         self.client
             .send_message(Request::SetAudioSyncOffset { input, offset })
             .await
@@ -200,7 +412,8 @@ impl<'a> Inputs<'a> {
 
     /// Gets the audio monitor type of input.
     #[doc(alias = "GetInputAudioMonitorType")]
-    pub async fn audio_monitor_type(&self, input: InputId<'_>) -> Result<MonitorType> {
+    pub async fn audio_monitor_type(&self, input: impl Into<InputId<'_>>) -> Result<MonitorType> {
+        let input = input.into();
         self.client
             .send_message::<_, responses::AudioMonitorType>(Request::AudioMonitorType { input })
             .await
@@ -211,9 +424,10 @@ impl<'a> Inputs<'a> {
     #[doc(alias = "SetInputAudioMonitorType")]
     pub async fn set_audio_monitor_type(
         &self,
-        input: InputId<'_>,
+        input: impl Into<InputId<'_>>,
         monitor_type: MonitorType,
     ) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::SetAudioMonitorType {
                 input,
@@ -224,22 +438,28 @@ impl<'a> Inputs<'a> {
 
     /// Gets the enable state of all audio tracks of an input.
     #[doc(alias = "GetInputAudioTracks")]
-    pub async fn audio_tracks(&self, input: InputId<'_>) -> Result<[bool; 6]> {
+    pub async fn audio_tracks(&self, input: impl Into<InputId<'_>>) -> Result<AudioTracks> {
+        let input = input.into();
         self.client
             .send_message::<_, responses::AudioTracks>(Request::AudioTracks { input })
             .await
             .map(|at| at.audio_tracks)
     }
 
-    /// Sets the enable state of audio tracks of an input.
+    /// Sets the enable state of audio tracks of an input. Tracks that are not set keep their
+    /// current state.
     #[doc(alias = "SetInputAudioTracks")]
     pub async fn set_audio_tracks(
         &self,
-        input: InputId<'_>,
-        tracks: [Option<bool>; 6],
+        input: impl Into<InputId<'_>>,
+        tracks: impl Into<AudioTracks>,
     ) -> Result<()> {
+        let input = input.into();
         self.client
-            .send_message(Request::SetAudioTracks { input, tracks })
+            .send_message(Request::SetAudioTracks {
+                input,
+                tracks: tracks.into(),
+            })
             .await
     }
 
@@ -250,9 +470,10 @@ impl<'a> Inputs<'a> {
     #[doc(alias = "GetInputPropertiesListPropertyItems")]
     pub async fn properties_list_property_items(
         &self,
-        input: InputId<'_>,
+        input: impl Into<InputId<'_>>,
         property: &str,
     ) -> Result<Vec<responses::ListPropertyItem>> {
+        let input = input.into();
         self.client
             .send_message::<_, responses::ListPropertyItems>(Request::PropertiesListPropertyItems {
                 input,
@@ -262,15 +483,69 @@ impl<'a> Inputs<'a> {
             .map(|lpi| lpi.property_items)
     }
 
+    /// Basically [`Self::properties_list_property_items`], but decodes the value of each item
+    /// into a custom type.
+    ///
+    /// This is helpful for device pickers, where the values are known to be of a specific type,
+    /// like display indices or device identifiers.
+    pub async fn properties_list_enum<T>(
+        &self,
+        input: impl Into<InputId<'_>>,
+        property: &str,
+    ) -> Result<Vec<responses::ListPropertyItem<T>>>
+    where
+        T: DeserializeOwned,
+    {
+        let input = input.into();
+        self.client
+            .send_message::<_, responses::ListPropertyItems<T>>(
+                Request::PropertiesListPropertyItems { input, property },
+            )
+            .await
+            .map(|lpi| lpi.property_items)
+    }
+
     /// Presses a button in the properties of an input.
     ///
     /// **Note:** Use this in cases where there is a button in the properties of an input that
     /// cannot be accessed in any other way. For example, browser sources, where there is a refresh
     /// button.
     #[doc(alias = "PressInputPropertiesButton")]
-    pub async fn press_properties_button(&self, input: InputId<'_>, property: &str) -> Result<()> {
+    pub async fn press_properties_button(
+        &self,
+        input: impl Into<InputId<'_>>,
+        property: &str,
+    ) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::PressPropertiesButton { input, property })
             .await
     }
 }
+
+/// Check whether the `versioned` input kind is the `unversioned` kind, with an optional version
+/// suffix in the form of `_v<number>`.
+fn is_version_of(versioned: &str, unversioned: &str) -> bool {
+    versioned.strip_prefix(unversioned).is_some_and(|suffix| {
+        suffix.is_empty()
+            || suffix.strip_prefix("_v").is_some_and(|version| {
+                !version.is_empty() && version.bytes().all(|b| b.is_ascii_digit())
+            })
+    })
+}
+
+/// Convert the typed input creation settings into the request, with generic settings.
+fn create_internal<T: Serialize>(input: Create<'_, T>) -> Result<CreateInputInternal<'_>> {
+    Ok(CreateInputInternal {
+        scene: input.scene,
+        input: input.input,
+        kind: input.kind,
+        settings: input
+            .settings
+            .map(|settings| {
+                serde_json::to_value(&settings).map_err(crate::error::SerializeCustomDataError)
+            })
+            .transpose()?,
+        enabled: input.enabled,
+    })
+}
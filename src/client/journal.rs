@@ -0,0 +1,280 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex, MutexGuard, PoisonError,
+    },
+    time::SystemTime,
+};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::requests::RequestType;
+
+/// A single state-changing request, that was sent by the client and succeeded, as recorded by
+/// the session journal enabled through [`crate::Client::with_session_journal`].
+///
+/// Changes serialize into JSON objects like the following, to export them into log files or
+/// reports:
+///
+/// ```json
+/// {
+///   "time": {"secs_since_epoch": 1735689600, "nanos_since_epoch": 0},
+///   "requestType": "SetInputVolume",
+///   "target": {"inputName": "Mic/Aux"},
+///   "old": {"inputVolumeDb": -10.0},
+///   "new": {"inputVolumeDb": -6.0}
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct SessionChange {
+    /// Point in time when the request succeeded.
+    pub time: SystemTime,
+    /// Name of the request, like `SetInputVolume`.
+    #[serde(rename = "requestType")]
+    pub request_type: String,
+    /// Fields of the request that identify what was changed, like the input name.
+    pub target: Map<String, Value>,
+    /// Previous value of the target, if it was changed by the same request type before during this
+    /// session.
+    pub old: Option<Map<String, Value>>,
+    /// Fields of the request that describe the change, like the new volume.
+    pub new: Map<String, Value>,
+}
+
+/// Maximum amount of recorded changes. Once reached, the oldest changes are dropped.
+const CAPACITY: usize = 1000;
+
+/// Requests that change the state of OBS, and are recorded once they succeeded. Sorted, to be
+/// searched with a binary search.
+///
+/// Requests that only trigger an action, like hotkeys, custom events or vendor requests, are left
+/// out, as their effect on the state is unknown.
+const STATE_CHANGING: &[&str] = &[
+    "CreateInput",
+    "CreateProfile",
+    "CreateScene",
+    "CreateSceneCollection",
+    "CreateSceneItem",
+    "CreateSourceFilter",
+    "DuplicateSceneItem",
+    "OffsetMediaInputCursor",
+    "PauseRecord",
+    "RemoveInput",
+    "RemoveProfile",
+    "RemoveScene",
+    "RemoveSceneItem",
+    "RemoveSourceFilter",
+    "ResumeRecord",
+    "SetCurrentPreviewScene",
+    "SetCurrentProfile",
+    "SetCurrentProgramScene",
+    "SetCurrentSceneCollection",
+    "SetCurrentSceneTransition",
+    "SetCurrentSceneTransitionDuration",
+    "SetCurrentSceneTransitionSettings",
+    "SetInputAudioBalance",
+    "SetInputAudioMonitorType",
+    "SetInputAudioSyncOffset",
+    "SetInputAudioTracks",
+    "SetInputMute",
+    "SetInputName",
+    "SetInputSettings",
+    "SetInputVolume",
+    "SetMediaInputCursor",
+    "SetOutputSettings",
+    "SetPersistentData",
+    "SetProfileParameter",
+    "SetRecordDirectory",
+    "SetSceneItemBlendMode",
+    "SetSceneItemEnabled",
+    "SetSceneItemIndex",
+    "SetSceneItemLocked",
+    "SetSceneItemPrivateSettings",
+    "SetSceneItemTransform",
+    "SetSceneName",
+    "SetSceneSceneTransitionOverride",
+    "SetSourceFilterEnabled",
+    "SetSourceFilterIndex",
+    "SetSourceFilterName",
+    "SetSourceFilterSettings",
+    "SetStreamServiceSettings",
+    "SetStudioModeEnabled",
+    "SetTBarPosition",
+    "SetVideoSettings",
+    "StartOutput",
+    "StartRecord",
+    "StartReplayBuffer",
+    "StartStream",
+    "StartVirtualCam",
+    "StopOutput",
+    "StopRecord",
+    "StopReplayBuffer",
+    "StopStream",
+    "StopVirtualCam",
+    "ToggleInputMute",
+    "ToggleOutput",
+    "ToggleRecord",
+    "ToggleRecordPause",
+    "ToggleReplayBuffer",
+    "ToggleStream",
+    "ToggleVirtualCam",
+    "TriggerMediaInputAction",
+    "TriggerStudioModeTransition",
+];
+
+/// Recorded changes, that are only tracked once enabled.
+#[derive(Default)]
+pub(super) struct Journal {
+    enabled: AtomicBool,
+    changes: Mutex<VecDeque<SessionChange>>,
+}
+
+/// Change of a request, that is only recorded once the request succeeded.
+pub(super) struct PendingChange {
+    request_type: String,
+    target: Map<String, Value>,
+    new: Map<String, Value>,
+}
+
+impl Journal {
+    fn lock(&self) -> MutexGuard<'_, VecDeque<SessionChange>> {
+        self.changes.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn enable(&self) {
+        self.enabled.store(true, Ordering::Relaxed);
+    }
+
+    /// Prepare the change of a request, or [`None`] if the journal is disabled or the request
+    /// doesn't change any state.
+    pub fn prepare(&self, req: &RequestType<'_>) -> Option<PendingChange> {
+        if !self.enabled.load(Ordering::Relaxed) {
+            return None;
+        }
+
+        let request_type = req.name();
+        if STATE_CHANGING.binary_search(&request_type).is_err() {
+            return None;
+        }
+
+        let mut value = serde_json::to_value(req).ok()?;
+
+        let data = match value.get_mut("requestData").map(Value::take) {
+            Some(Value::Object(data)) => data,
+            _ => Map::new(),
+        };
+        let (target, new) = data
+            .into_iter()
+            .partition(|(key, _)| is_target(request_type, key));
+
+        Some(PendingChange {
+            request_type: request_type.to_owned(),
+            target,
+            new,
+        })
+    }
+
+    /// Record a prepared change, after its request succeeded.
+    pub fn commit(&self, change: PendingChange) {
+        let mut changes = self.lock();
+        let old = changes
+            .iter()
+            .rev()
+            .find(|c| c.request_type == change.request_type && c.target == change.target)
+            .map(|c| c.new.clone());
+
+        if changes.len() == CAPACITY {
+            changes.pop_front();
+        }
+        changes.push_back(SessionChange {
+            time: SystemTime::now(),
+            request_type: change.request_type,
+            target: change.target,
+            old,
+            new: change.new,
+        });
+    }
+
+    pub fn changes(&self) -> Vec<SessionChange> {
+        self.lock().iter().cloned().collect()
+    }
+}
+
+/// Whether a request field identifies the target of a request, like `inputName` or `sceneUuid`,
+/// rather than being part of the change, like `newInputName`.
+///
+/// Requests that switch the current item, like `SetCurrentProgramScene`, have no target, as the
+/// name of the item is the new value.
+fn is_target(request_type: &str, key: &str) -> bool {
+    !request_type.starts_with("SetCurrent")
+        && !key.starts_with("new")
+        && (key.ends_with("Name") || key.ends_with("Uuid") || key.ends_with("Id"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::requests::{
+        batch::BatchRequest,
+        inputs::{InputId, Request, Volume},
+    };
+
+    #[test]
+    fn journal() {
+        let journal = Journal::default();
+        let volume = |db| {
+            RequestType::from(Request::SetVolume {
+                input: InputId::Name("mic"),
+                volume: Volume::Db(db),
+            })
+        };
+
+        assert!(journal.prepare(&volume(-10.0)).is_none());
+        journal.enable();
+        assert!(journal
+            .prepare(
+                &Request::Volume {
+                    input: InputId::Name("mic")
+                }
+                .into()
+            )
+            .is_none());
+        assert!(journal
+            .prepare(&BatchRequest::new("TriggerHotkeyByName").into())
+            .is_none());
+
+        journal.commit(journal.prepare(&volume(-10.0)).unwrap());
+        journal.commit(journal.prepare(&volume(-6.0)).unwrap());
+
+        let changes = journal.changes();
+        assert_eq!(2, changes.len());
+        assert_eq!("SetInputVolume", changes[1].request_type);
+        assert_eq!(
+            Some(&Value::from("mic")),
+            changes[1].target.get("inputName")
+        );
+        assert_eq!(
+            Some(&Value::from(-10.0)),
+            changes[1].old.as_ref().unwrap().get("inputVolumeDb")
+        );
+        assert_eq!(
+            Some(&Value::from(-6.0)),
+            changes[1].new.get("inputVolumeDb")
+        );
+
+        for db in 0..1000_u16 {
+            journal.commit(journal.prepare(&volume(f32::from(db))).unwrap());
+        }
+        let changes = journal.changes();
+        assert_eq!(CAPACITY, changes.len());
+        assert_eq!(Some(&Value::from(0.0)), changes[0].new.get("inputVolumeDb"));
+    }
+
+    #[test]
+    fn state_changing_sorted() {
+        assert!(STATE_CHANGING.windows(2).all(|w| w[0] < w[1]));
+    }
+}
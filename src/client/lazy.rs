@@ -0,0 +1,108 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use tokio::sync::{Mutex, OnceCell};
+
+use super::{Client, ConnectConfig};
+use crate::error::{Error, Result};
+
+/// A client, that only connects to obs-websocket once it's first used, as created by
+/// [`Client::connect_lazy`].
+///
+/// The connection is established by the first call to [`Self::client`]. If several tasks call it
+/// concurrently before the connection is ready, only a single connection attempt is made, and all
+/// callers wait for its result. In case the attempt fails, the error is returned to all waiting
+/// callers as [`Error::LazyConnect`], and the next call starts a new attempt.
+///
+/// ```no_run
+/// # async fn run(config: obws::client::ConnectConfig<&str, &str>) -> obws::error::Result<()> {
+/// use obws::Client;
+///
+/// let client = Client::connect_lazy(config);
+///
+/// // No connection was made so far, which only happens with the first request.
+/// let version = client.client().await?.general().version().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct LazyClient<H, P>
+where
+    H: AsRef<str>,
+    P: AsRef<str>,
+{
+    config: ConnectConfig<H, P>,
+    client: OnceCell<Client>,
+    /// Guards the connection attempts, and holds the error of the last failed one.
+    failure: Mutex<Option<Arc<Error>>>,
+    /// Number of failed connection attempts so far.
+    failures: AtomicU64,
+}
+
+impl<H, P> LazyClient<H, P>
+where
+    H: AsRef<str>,
+    P: AsRef<str>,
+{
+    pub(super) fn new(config: ConnectConfig<H, P>) -> Self {
+        Self {
+            config,
+            client: OnceCell::new(),
+            failure: Mutex::new(None),
+            failures: AtomicU64::new(0),
+        }
+    }
+
+    /// Get the connected client, establishing the connection first if this didn't happen yet.
+    ///
+    /// # Errors
+    ///
+    /// Fails with an [`Error::LazyConnect`], that wraps the same errors as
+    /// [`Client::connect_with_config`], if the connection can't be established.
+    pub async fn client(&self) -> Result<&Client> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+
+        let failures = self.failures.load(Ordering::Acquire);
+        let mut failure = self.failure.lock().await;
+
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+
+        // Another caller finished an attempt while we were waiting, so share its outcome.
+        if self.failures.load(Ordering::Acquire) != failures {
+            if let Some(error) = &*failure {
+                return Err(Error::LazyConnect(Arc::clone(error)));
+            }
+        }
+
+        match Client::establish(&self.config).await {
+            Ok(client) => {
+                *failure = None;
+                Ok(self.client.get_or_init(|| async { client }).await)
+            }
+            Err(error) => {
+                let error = Arc::new(error);
+                *failure = Some(Arc::clone(&error));
+                self.failures.fetch_add(1, Ordering::Release);
+                Err(Error::LazyConnect(error))
+            }
+        }
+    }
+
+    /// Get the client, if the connection was already established, without trying to connect
+    /// otherwise.
+    #[must_use]
+    pub fn get(&self) -> Option<&Client> {
+        self.client.get()
+    }
+
+    /// Take out the client, if the connection was already established.
+    #[must_use]
+    pub fn into_inner(self) -> Option<Client> {
+        self.client.into_inner()
+    }
+}
@@ -1,22 +1,66 @@
+#[cfg(feature = "events")]
+use futures_util::{
+    future::{self, Either},
+    Stream, StreamExt,
+};
 use time::Duration;
 
 use super::Client;
 use crate::{
-    common::MediaAction,
+    common::{MediaAction, ObwsDuration},
     error::Result,
     requests::{inputs::InputId, media_inputs::Request},
     responses::media_inputs as responses,
 };
+#[cfg(feature = "events")]
+use crate::{events::Event, responses::media_inputs::MediaState};
 
 /// API functions related to media inputs.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("SetMediaInputCursor", json!(null));
+/// use std::time::Duration;
+///
+/// client
+///     .media_inputs()
+///     .set_cursor("Intro Video", Duration::from_secs(5))
+///     .await?;
+/// # assert_eq!(
+/// #     Some(&(
+/// #         "SetMediaInputCursor".to_owned(),
+/// #         json!({"inputName": "Intro Video", "mediaCursor": 5000}),
+/// #     )),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct MediaInputs<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> MediaInputs<'a> {
+impl MediaInputs<'_> {
     /// Gets the status of a media input.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetMediaInputStatus",
+    /// #     json!({
+    /// #         "mediaState": "OBS_MEDIA_STATE_PLAYING",
+    /// #         "mediaDuration": 60000,
+    /// #         "mediaCursor": 15000,
+    /// #     }),
+    /// # );
+    /// use obws::responses::media_inputs::MediaState;
+    ///
+    /// let status = client.media_inputs().status("Intro Video").await?;
+    /// assert_eq!(MediaState::Playing, status.state);
+    /// # });
+    /// ```
     #[doc(alias = "GetMediaInputStatus")]
-    pub async fn status(&self, input: InputId<'_>) -> Result<responses::MediaStatus> {
+    pub async fn status(&self, input: impl Into<InputId<'_>>) -> Result<responses::MediaStatus> {
+        let input = input.into();
         self.client.send_message(Request::Status { input }).await
     }
 
@@ -24,7 +68,13 @@ impl<'a> MediaInputs<'a> {
     ///
     /// This request does not perform bounds checking of the cursor position.
     #[doc(alias = "SetMediaInputCursor")]
-    pub async fn set_cursor(&self, input: InputId<'_>, cursor: Duration) -> Result<()> {
+    pub async fn set_cursor(
+        &self,
+        input: impl Into<InputId<'_>>,
+        cursor: impl Into<ObwsDuration>,
+    ) -> Result<()> {
+        let input = input.into();
+        let cursor = Duration::from(cursor.into());
         self.client
             .send_message(Request::SetCursor { input, cursor })
             .await
@@ -34,7 +84,13 @@ impl<'a> MediaInputs<'a> {
     ///
     /// This request does not perform bounds checking of the cursor position.
     #[doc(alias = "OffsetMediaInputCursor")]
-    pub async fn offset_cursor(&self, input: InputId<'_>, offset: Duration) -> Result<()> {
+    pub async fn offset_cursor(
+        &self,
+        input: impl Into<InputId<'_>>,
+        offset: impl Into<ObwsDuration>,
+    ) -> Result<()> {
+        let input = input.into();
+        let offset = Duration::from(offset.into());
         self.client
             .send_message(Request::OffsetCursor { input, offset })
             .await
@@ -42,9 +98,114 @@ impl<'a> MediaInputs<'a> {
 
     /// Triggers an action on a media input.
     #[doc(alias = "TriggerMediaInputAction")]
-    pub async fn trigger_action(&self, input: InputId<'_>, action: MediaAction) -> Result<()> {
+    pub async fn trigger_action(
+        &self,
+        input: impl Into<InputId<'_>>,
+        action: MediaAction,
+    ) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::TriggerAction { input, action })
             .await
     }
 }
+
+#[cfg(feature = "events")]
+impl<'a> MediaInputs<'a> {
+    /// Watch the playback progress of a media input, for example to drive a countdown overlay.
+    ///
+    /// The returned stream yields the current status first. While the media is playing, the status
+    /// is polled in the given interval, to keep the cursor position up to date. Changes of the
+    /// playback, like starting, pausing or ending, are picked up through events and yield the new
+    /// status right away.
+    ///
+    /// The stream ends once the input is removed. If querying the status fails, the error is
+    /// yielded as last item.
+    pub async fn progress(
+        &self,
+        input: impl Into<InputId<'_>>,
+        interval: std::time::Duration,
+    ) -> Result<impl Stream<Item = Result<responses::MediaStatus>> + 'a> {
+        let input = input.into();
+        let client = self.client;
+        let mut events = Box::pin(client.events()?);
+        let status = self.status(input).await?;
+
+        let uuid = match input {
+            InputId::Uuid(uuid) => Some(uuid),
+            InputId::Name(name) => client
+                .inputs()
+                .list(None)
+                .await?
+                .into_iter()
+                .find(|i| i.id.name == name)
+                .map(|i| i.id.uuid),
+        };
+        let mut name = match input {
+            InputId::Name(name) => name.to_owned(),
+            InputId::Uuid(_) => String::new(),
+        };
+
+        Ok(async_stream::stream! {
+            let mut playing = is_playing(&status);
+            yield Ok(status);
+
+            loop {
+                let event = if playing {
+                    match future::select(events.next(), client.clock().sleep(interval)).await {
+                        Either::Left((Some(event), _)) => Some(event),
+                        Either::Left((None, _)) => break,
+                        Either::Right(((), _)) => None,
+                    }
+                } else {
+                    match events.next().await {
+                        Some(event) => Some(event),
+                        None => break,
+                    }
+                };
+
+                match event {
+                    None => {}
+                    Some(
+                        Event::MediaInputPlaybackStarted { id }
+                        | Event::MediaInputPlaybackEnded { id }
+                        | Event::MediaInputActionTriggered { id, .. },
+                    ) if uuid.map_or(id.name == name, |uuid| id.uuid == uuid) => {}
+                    Some(Event::InputNameChanged { uuid: changed, old_name, new_name })
+                        if uuid.map_or(old_name == name, |uuid| changed == uuid) =>
+                    {
+                        name = new_name;
+                        continue;
+                    }
+                    Some(Event::InputRemoved { id })
+                        if uuid.map_or(id.name == name, |uuid| id.uuid == uuid) =>
+                    {
+                        break;
+                    }
+                    Some(_) => continue,
+                }
+
+                let input = uuid.map_or(InputId::Name(&name), InputId::Uuid);
+                match client.media_inputs().status(input).await {
+                    Ok(status) => {
+                        playing = is_playing(&status);
+                        yield Ok(status);
+                    }
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Whether the media is playing or about to play, meaning its cursor keeps moving.
+#[cfg(feature = "events")]
+fn is_playing(status: &responses::MediaStatus) -> bool {
+    matches!(
+        status.state,
+        MediaState::Playing | MediaState::Opening | MediaState::Buffering
+    )
+}
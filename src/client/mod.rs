@@ -3,75 +3,134 @@
 #[cfg(feature = "events")]
 use std::sync::Weak;
 use std::{
+    collections::{HashMap, HashSet},
     future::Future,
+    panic::AssertUnwindSafe,
+    pin::Pin,
     sync::{
-        atomic::{AtomicU64, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, OnceLock,
     },
     time::Duration,
 };
 
 use futures_util::{
-    sink::SinkExt,
-    stream::{SplitSink, Stream, StreamExt},
+    future::FutureExt,
+    sink::Sink,
+    stream::{Stream, StreamExt},
 };
 use semver::{Comparator, Op, Prerelease};
-use serde::de::DeserializeOwned;
-#[cfg(feature = "events")]
-use tokio::sync::broadcast;
-use tokio::{net::TcpStream, sync::Mutex, task::JoinHandle};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::value::RawValue;
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    net::TcpStream,
+    sync::{watch, Mutex},
+    task::JoinHandle,
+};
 use tokio_tungstenite::{
-    tungstenite::{self, protocol::CloseFrame, Message},
+    tungstenite::{
+        self,
+        client::IntoClientRequest,
+        http::{header::SEC_WEBSOCKET_PROTOCOL, HeaderValue},
+        protocol::CloseFrame,
+        Message,
+    },
     MaybeTlsStream, WebSocketStream,
 };
 use tracing::{debug, error, info, trace, warn};
 
-use self::connection::{ReceiverList, ReidentifyReceiverList};
+#[cfg(feature = "events")]
+pub use self::connection::EventLagStats;
+#[cfg(feature = "events")]
+use self::connection::{EventReceiver, EventRouter};
 pub use self::{
+    advanced_audio_tracks::AdvancedAudioTracks,
+    cache::{CacheConfig, CachedRequest},
+    coalesce::CoalescedRequest,
     config::Config,
-    connection::{HandshakeError, IntoTextError, ReceiveError},
+    connection::{
+        BroadcastGrowth, CloseCode, CloseDetails, CloseReason, Codec, ConnectionStats,
+        HandshakeError, IntoTextError, ReceiveError, UnexpectedResponse, UnexpectedResponsePolicy,
+    },
     filters::Filters,
     general::General,
     hotkeys::Hotkeys,
     inputs::Inputs,
+    journal::SessionChange,
+    lazy::LazyClient,
     media_inputs::MediaInputs,
     outputs::Outputs,
+    priority::Priority,
     profiles::Profiles,
     recording::Recording,
     replay_buffer::ReplayBuffer,
     scene_collections::SceneCollections,
     scene_items::SceneItems,
     scenes::Scenes,
+    server_profile::ServerProfile,
     sources::Sources,
+    stats_history::{StatsHistory, StatsSample, StatsSummary},
     streaming::Streaming,
+    timeout::with_timeout,
     transitions::Transitions,
     ui::Ui,
     virtual_cam::VirtualCam,
 };
-#[cfg(feature = "events")]
-use crate::events::Event;
+use self::{
+    cache::ResponseCache,
+    coalesce::Coalescer,
+    connection::{ReceiverList, ReidentifyReceiverList, Response},
+    journal::Journal,
+    priority::SendQueue,
+};
+#[cfg(feature = "testing")]
+use crate::testing::Recorder;
 use crate::{
+    clock::{Clock, TokioClock},
     error::{Error, Result},
-    requests::{ClientRequest, EventSubscription, Reidentify, Request, RequestType},
-    responses::ServerMessage,
+    requests::{
+        batch::{BatchExecution, BatchRequest},
+        ClientRequest, EventSubscription, ExecutionType, Reidentify, Request, RequestBatch,
+        RequestType,
+    },
+    responses::{
+        general::{CoverageReport, Health},
+        ui::Monitor,
+        RequestBatchResult, ServerMessage, Status,
+    },
+};
+#[cfg(feature = "events")]
+use crate::{
+    dispatch::{EventHandler, EventKind, Registration},
+    events::{Event, EventOrLag},
 };
 
+mod advanced_audio_tracks;
+mod cache;
+mod coalesce;
 mod config;
 mod connection;
 mod filters;
 mod general;
 mod hotkeys;
 mod inputs;
+mod journal;
+mod lazy;
 mod media_inputs;
 mod outputs;
+mod priority;
 mod profiles;
 mod recording;
 mod replay_buffer;
 mod scene_collections;
 mod scene_items;
 mod scenes;
+mod server_profile;
 mod sources;
+mod stats_history;
 mod streaming;
+mod timeout;
 mod transitions;
 mod ui;
 mod virtual_cam;
@@ -79,21 +138,30 @@ mod virtual_cam;
 #[derive(Debug, thiserror::Error)]
 enum InnerError {
     #[error("websocket message not convertible to text")]
-    IntoText(#[source] tokio_tungstenite::tungstenite::Error),
+    IntoText(#[source] Box<tokio_tungstenite::tungstenite::Error>),
     #[error("failed deserializing message")]
     DeserializeMessage(#[source] serde_json::Error),
     #[error("the request ID `{0}` is not an integer")]
     InvalidRequestId(#[source] std::num::ParseIntError, String),
     #[error("received unexpected server message: {0:?}")]
     UnexpectedMessage(ServerMessage),
+    #[error("received {0:?} response for request ID `{1}`")]
+    UnexpectedResponse(UnexpectedResponse, u64),
 }
 
 /// The client is the main entry point to access the obs-websocket API. It allows to call various
 /// functions to remote control an OBS instance as well as to listen to events caused by the user
 /// by interacting with OBS.
+///
+/// # Cancellation safety
+///
+/// All request futures are cancellation safe. They can be dropped at any point, for example when
+/// losing a race in `tokio::select!`, without affecting the connection or other requests. A
+/// canceled request might still be executed by OBS, if it was already queued for sending, but its
+/// response is discarded and the client stops waiting for it right away.
 pub struct Client {
-    /// The writer handle to the web-socket stream.
-    write: Mutex<MessageWriter>,
+    /// Queue of outgoing messages, that are written to the web-socket stream by a dedicated task.
+    write: SendQueue,
     /// Global counter for requests that help to find out what response belongs to what previously
     /// sent request.
     id_counter: AtomicU64,
@@ -105,19 +173,54 @@ pub struct Client {
     /// these requests don't carry any kind of ID, they're handled sequentially and must be tracked
     /// separate from normal requests.
     reidentify_receivers: Arc<ReidentifyReceiverList>,
-    /// Broadcast sender that distributes received events to all current listeners. Events are
-    /// dropped if nobody listens.
+    /// Router that distributes received events to all current listeners. Events are dropped if
+    /// nobody listens.
     #[cfg(feature = "events")]
-    event_sender: Weak<broadcast::Sender<Event>>,
+    event_sender: Weak<EventRouter>,
     /// Handle to the background task that receives messages and distributes them to waiting
     /// receivers and event listeners. It allows to shut down all the machinery once the client is
     /// no longer needed.
-    handle: Option<JoinHandle<()>>,
+    handle: Mutex<Option<JoinHandle<()>>>,
+    /// Set once a graceful [`Self::shutdown`] started, to stop accepting new requests.
+    closing: AtomicBool,
     dangerous: DangerousConnectConfig,
+    /// Optional soft limit for the size of outgoing messages.
+    request_size_limit: Option<RequestSizeLimit>,
+    /// Optional maximum time to wait for responses.
+    request_timeout: Option<Duration>,
+    /// Last known monitors that source projectors were opened on through
+    /// [`Ui::ensure_projector`]. The key is the string version of the source identifier.
+    projectors: Mutex<HashMap<String, Monitor>>,
+    /// Last sample of the recording timecode, taken by [`Recording::timecode_of`].
+    record_timecode: Mutex<Option<recording::TimecodeSample>>,
+    /// Source of time for time-based utilities.
+    clock: Arc<dyn Clock>,
+    /// Cached responses of read requests, enabled through [`Self::with_cache`].
+    cache: Arc<ResponseCache>,
+    /// Write requests in flight, for requests sent in "latest wins" mode, enabled through
+    /// [`Self::with_latest_wins`].
+    coalescer: Coalescer,
+    /// State-changing requests sent during this session, enabled through
+    /// [`Self::with_session_journal`].
+    journal: Journal,
+    /// Kind of the connected server, resolved while connecting.
+    server_profile: OnceLock<ServerProfile>,
+    /// Requests known to the server, if any other requests must be refused, as decided by the
+    /// [`ServerProfile`].
+    available_requests: OnceLock<HashSet<String>>,
+    /// Image formats for screenshots, as reported by obs-websocket while connecting.
+    supported_image_formats: OnceLock<Vec<String>>,
+    /// Reason of why the connection ended, set by the background task once it stops.
+    close_reason: watch::Receiver<Option<CloseReason>>,
+    /// Encoding of the messages exchanged with obs-websocket.
+    codec: Codec,
 }
 
 /// Shorthand for the writer side of a web-socket stream that has been split into reader and writer.
-type MessageWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
+///
+/// The sink is boxed to erase the type of the underlying stream, as it can either be a TCP
+/// connection or any user provided stream.
+type MessageWriter = Pin<Box<dyn Sink<Message, Error = tungstenite::Error> + Send>>;
 
 /// Default broadcast capacity used when not overwritten by the user.
 pub const DEFAULT_BROADCAST_CAPACITY: usize = 100;
@@ -140,6 +243,11 @@ where
     /// Dangerous configuration options that are not given any support for.
     #[cfg_attr(feature = "builder", builder(field))]
     pub dangerous: Option<DangerousConnectConfig>,
+    /// Custom URL to connect to, like `wss://example.com/obs`, instead of the one built from the
+    /// host, port and TLS setting. This is helpful when OBS is located behind a reverse proxy,
+    /// that exposes obs-websocket under a custom path.
+    #[cfg_attr(feature = "builder", builder(into))]
+    pub url: Option<String>,
     /// The host name, usually `localhost` unless the OBS instance is on a remote machine.
     /// Optional password to authenticate against `obs-websocket`.
     pub password: Option<P>,
@@ -147,9 +255,15 @@ where
     /// events are listened to, with the exception of high volume events.
     pub event_subscriptions: Option<EventSubscription>,
     /// Whether to use TLS when connecting. Only useful when OBS runs on a remote machine.
-    #[cfg(feature = "tls")]
+    #[cfg(any(feature = "tls-rustls", feature = "tls-native"))]
     #[cfg_attr(feature = "builder", builder(default))]
     pub tls: bool,
+    /// Custom configuration of the rustls TLS stack, for example to trust a company internal
+    /// certificate authority. By default, the root certificates of the Mozilla CA store are used.
+    ///
+    /// Setting this always uses rustls, even if the `tls-native` feature is enabled as well.
+    #[cfg(feature = "tls-rustls")]
+    pub tls_config: Option<Arc<rustls::ClientConfig>>,
     /// Capacity of the broadcast channel for events. The default is [`DEFAULT_BROADCAST_CAPACITY`]
     /// which should suffice.
     ///
@@ -159,6 +273,11 @@ where
     #[cfg_attr(not(feature = "events"), allow(dead_code))]
     #[cfg_attr(feature = "builder", builder(default = DEFAULT_BROADCAST_CAPACITY))]
     pub broadcast_capacity: usize,
+    /// What to do when event listeners lag behind and the broadcast channel drops events. By
+    /// default, a warning with a suggested capacity is logged.
+    #[cfg_attr(not(feature = "events"), allow(dead_code))]
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub broadcast_growth: BroadcastGrowth,
     /// Maximum wait time to establish a connection with the OBS instance. The default is
     /// [`DEFAULT_CONNECT_TIMEOUT`].
     ///
@@ -166,6 +285,44 @@ where
     /// cancel the attempt and return an [`Error::Timeout`].
     #[cfg_attr(feature = "builder", builder(default = DEFAULT_CONNECT_TIMEOUT))]
     pub connect_timeout: Duration,
+    /// Optional soft limit for the serialized size of outgoing requests and request batches.
+    ///
+    /// This helps to detect unexpectedly large payloads, like whole files embedded into input
+    /// settings. No limit is enforced by default.
+    pub request_size_limit: Option<RequestSizeLimit>,
+    /// Whether to continue receiving messages, if handling a single message panicked.
+    ///
+    /// By default, the client shuts down in this case and reports
+    /// [`CloseReason::InternalPanic`] through [`Client::closed`]. When enabled, the panic is only
+    /// logged and the client keeps running, as long as the connection itself is still healthy.
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub restart_on_panic: bool,
+    /// Maximum time to wait for the response of a single request.
+    ///
+    /// If exceeded, the request fails with an [`Error::Timeout`]. Requests wait indefinitely by
+    /// default. The limit can be overridden for individual requests with [`with_timeout`].
+    pub request_timeout: Option<Duration>,
+    /// How to handle responses, that no request is waiting for, like duplicate responses sent by
+    /// a misbehaving proxy. By default, they're logged and dropped.
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub unexpected_responses: UnexpectedResponsePolicy,
+    /// Kind of server that is connected to, to work around known quirks of servers that only
+    /// emulate obs-websocket. By default, the regular obs-websocket plugin is expected.
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub server_profile: ServerProfile,
+    /// Encoding of the messages exchanged with obs-websocket. By default, JSON is used.
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub codec: Codec,
+}
+
+/// Limit for the serialized size of outgoing messages, and what to do when it's exceeded.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RequestSizeLimit {
+    /// Log a warning for messages larger than the given amount of bytes, but still send them.
+    Warn(usize),
+    /// Refuse to send messages larger than the given amount of bytes, and fail with an
+    /// [`Error::RequestTooLarge`] instead.
+    Reject(usize),
 }
 
 #[cfg(feature = "builder")]
@@ -186,7 +343,7 @@ where
 }
 
 /// Dangerous configuration options that are not given any support for.
-#[derive(Default)]
+#[derive(Clone, Copy, Default)]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct DangerousConnectConfig {
     /// Skip validation of the minimum OBS Studio version.
@@ -220,16 +377,146 @@ where
     H: AsRef<str>,
     P: AsRef<str>,
 {
-    #[cfg(feature = "tls")]
+    #[cfg(any(feature = "tls-rustls", feature = "tls-native"))]
     fn tls(&self) -> bool {
         self.tls
     }
 
-    #[cfg(not(feature = "tls"))]
+    #[cfg(not(any(feature = "tls-rustls", feature = "tls-native")))]
     #[expect(clippy::unused_self)]
     fn tls(&self) -> bool {
         false
     }
+
+    /// Establish the web-socket connection, using the custom TLS configuration if one is set.
+    async fn connect_async(
+        &self,
+    ) -> tungstenite::Result<(
+        WebSocketStream<MaybeTlsStream<TcpStream>>,
+        tungstenite::handshake::client::Response,
+    )> {
+        #[cfg(feature = "tls-rustls")]
+        if let Some(tls_config) = &self.tls_config {
+            return tokio_tungstenite::connect_async_tls_with_config(
+                self.request()?,
+                None,
+                false,
+                Some(tokio_tungstenite::Connector::Rustls(Arc::clone(tls_config))),
+            )
+            .await;
+        }
+
+        tokio_tungstenite::connect_async(self.request()?).await
+    }
+
+    /// Build the request for the web-socket upgrade, that asks for the configured codec.
+    // The error of tungstenite is passed on as is, and only boxed once wrapped in our own errors.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn request(&self) -> tungstenite::Result<tungstenite::handshake::client::Request> {
+        let mut request = self.url().into_client_request()?;
+        if let Some(protocol) = self.codec.subprotocol() {
+            request
+                .headers_mut()
+                .insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_static(protocol));
+        }
+
+        Ok(request)
+    }
+
+    pub(crate) fn url(&self) -> String {
+        if let Some(url) = &self.url {
+            return url.clone();
+        }
+
+        format!(
+            "{}://{}:{}",
+            if self.tls() { "wss" } else { "ws" },
+            self.host.as_ref(),
+            self.port
+        )
+    }
+}
+
+/// Information about an obs-websocket instance, gathered by [`probe`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProbeInfo {
+    /// Version of the obs-websocket plugin.
+    pub obs_web_socket_version: semver::Version,
+    /// The RPC version that the server would like to use.
+    pub rpc_version: u32,
+    /// Whether the server requires a password for authentication.
+    pub authentication_required: bool,
+}
+
+/// Check the connectivity to an obs-websocket instance, without fully connecting to it.
+///
+/// This only performs the web-socket upgrade and reads the initial `Hello` message, but doesn't
+/// identify against obs-websocket. It allows to validate the connection settings, and detect
+/// whether a password is needed, before asking the user for one.
+///
+/// The connection is made the same way as [`Client::connect_with_config`] would, respecting the
+/// custom URL, TLS and codec settings of the configuration. The password isn't used.
+///
+/// # Errors
+///
+/// Will return an [`Error::Timeout`] if the `Hello` message couldn't be received within the
+/// configured [`ConnectConfig::connect_timeout`].
+pub async fn probe<H, P>(config: ConnectConfig<H, P>) -> Result<ProbeInfo>
+where
+    H: AsRef<str>,
+    P: AsRef<str>,
+{
+    let probe = async {
+        let (mut socket, _) = config
+            .connect_async()
+            .await
+            .map_err(|e| crate::error::ConnectError(Box::new(e)))?;
+
+        let hello = self::connection::read_hello(&mut socket, config.codec).await?;
+        socket.close(None).await.ok();
+
+        Ok(ProbeInfo {
+            obs_web_socket_version: hello.obs_web_socket_version,
+            rpc_version: hello.rpc_version,
+            authentication_required: hello.authentication.is_some(),
+        })
+    };
+
+    tokio::time::timeout(config.connect_timeout, probe)
+        .await
+        .map_err(|_| Error::Timeout)?
+}
+
+/// Turn the status of a request response into an error, if the request failed.
+pub(crate) fn check_status(status: Status) -> Result<()> {
+    if status.result {
+        Ok(())
+    } else {
+        Err(Error::from_status(status.code, status.comment))
+    }
+}
+
+/// Check the status of each request batch result and deserialize its response data.
+fn batch_responses<T: DeserializeOwned>(results: Vec<RequestBatchResult>) -> Result<Vec<T>> {
+    results.into_iter().map(batch_response).collect()
+}
+
+/// Check the status of a single request batch result and deserialize its response data.
+pub(crate) fn batch_response<T: DeserializeOwned>(result: RequestBatchResult) -> Result<T> {
+    check_status(result.status)?;
+    serde_json::from_str(result.data.get())
+        .map_err(crate::error::DeserializeResponseError)
+        .map_err(Into::into)
+}
+
+/// Event subscriptions used when not overwritten by the user. All events are subscribed to if the
+/// `events` feature is enabled, as no events could be received otherwise.
+fn default_event_subscriptions() -> Option<EventSubscription> {
+    if cfg!(feature = "events") {
+        None
+    } else {
+        Some(EventSubscription::NONE)
+    }
 }
 
 impl Client {
@@ -247,17 +534,23 @@ impl Client {
         Self::connect_with_config(ConnectConfig {
             host,
             port,
+            url: None,
             password,
-            event_subscriptions: if cfg!(feature = "events") {
-                None
-            } else {
-                Some(EventSubscription::NONE)
-            },
-            #[cfg(feature = "tls")]
+            event_subscriptions: default_event_subscriptions(),
+            #[cfg(any(feature = "tls-rustls", feature = "tls-native"))]
             tls: false,
+            #[cfg(feature = "tls-rustls")]
+            tls_config: None,
             broadcast_capacity: DEFAULT_BROADCAST_CAPACITY,
+            broadcast_growth: BroadcastGrowth::default(),
             connect_timeout: DEFAULT_CONNECT_TIMEOUT,
             dangerous: None,
+            request_size_limit: None,
+            restart_on_panic: false,
+            request_timeout: None,
+            unexpected_responses: UnexpectedResponsePolicy::default(),
+            server_profile: ServerProfile::default(),
+            codec: Codec::default(),
         })
         .await
     }
@@ -267,6 +560,112 @@ impl Client {
     where
         H: AsRef<str>,
         P: AsRef<str>,
+    {
+        Self::establish(&config).await
+    }
+
+    /// Create a client with the given configuration, that only connects to the obs-websocket
+    /// instance once it's first used.
+    ///
+    /// This is helpful for long-running applications, that might never interact with OBS, and
+    /// shouldn't fail or wait on startup in that case. See [`LazyClient`] for details.
+    pub fn connect_lazy<H, P>(config: ConnectConfig<H, P>) -> LazyClient<H, P>
+    where
+        H: AsRef<str>,
+        P: AsRef<str>,
+    {
+        LazyClient::new(config)
+    }
+
+    /// Establish a connection with the given configuration, without taking ownership of it, so
+    /// it can be re-used for further attempts.
+    async fn establish<H, P>(config: &ConnectConfig<H, P>) -> Result<Self>
+    where
+        H: AsRef<str>,
+        P: AsRef<str>,
+    {
+        let (socket, _) = tokio::time::timeout(config.connect_timeout, config.connect_async())
+            .await
+            .map_err(|_| Error::Timeout)?
+            .map_err(|e| crate::error::ConnectError(Box::new(e)))?;
+
+        Self::connect_with_socket(
+            socket,
+            config,
+            #[cfg(feature = "testing")]
+            None,
+        )
+        .await
+    }
+
+    /// Connect to a obs-websocket instance at the given URL, for example
+    /// `wss://example.com/obs`, with the given configuration.
+    ///
+    /// This is helpful when OBS is located behind a reverse proxy, that exposes obs-websocket
+    /// under a custom path. It's a shorthand for setting
+    /// [`ConnectConfig::url`](ConnectConfig#structfield.url), so the host and port of the
+    /// configuration are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`Error::Timeout`] if the connection couldn't be established within the
+    /// configured [`ConnectConfig::connect_timeout`].
+    pub async fn connect_url<H, P>(
+        url: impl Into<String>,
+        config: ConnectConfig<H, P>,
+    ) -> Result<Self>
+    where
+        H: AsRef<str>,
+        P: AsRef<str>,
+    {
+        Self::connect_with_config(ConnectConfig {
+            url: Some(url.into()),
+            ..config
+        })
+        .await
+    }
+
+    /// Connect to a obs-websocket instance over an already established stream, like a SSH tunnel,
+    /// a unix socket or a custom proxy connection.
+    ///
+    /// Only the web-socket upgrade and all following communication are performed over the given
+    /// stream. The `url`, or the `host`, `port` and `tls` settings of the configuration are solely
+    /// used to build the request URL of the web-socket upgrade. No encryption is applied to the
+    /// stream, so it must be wrapped in a TLS stream beforehand, if needed.
+    pub async fn connect_with_stream<S, H, P>(
+        stream: S,
+        config: ConnectConfig<H, P>,
+    ) -> Result<Self>
+    where
+        S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+        H: AsRef<str>,
+        P: AsRef<str>,
+    {
+        let (socket, _) = tokio::time::timeout(config.connect_timeout, async {
+            tokio_tungstenite::client_async(config.request()?, stream).await
+        })
+        .await
+        .map_err(|_| Error::Timeout)?
+        .map_err(|e| crate::error::ConnectError(Box::new(e)))?;
+
+        Self::connect_with_socket(
+            socket,
+            &config,
+            #[cfg(feature = "testing")]
+            None,
+        )
+        .await
+    }
+
+    pub(crate) async fn connect_with_socket<S, H, P>(
+        socket: WebSocketStream<S>,
+        config: &ConnectConfig<H, P>,
+        #[cfg(feature = "testing")] recorder: Option<Recorder>,
+    ) -> Result<Self>
+    where
+        S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+        H: AsRef<str>,
+        P: AsRef<str>,
     {
         if config.dangerous.is_some() {
             warn!(
@@ -275,28 +674,16 @@ impl Client {
             );
         }
 
-        let (socket, _) = tokio::time::timeout(
-            config.connect_timeout,
-            tokio_tungstenite::connect_async(format!(
-                "{}://{}:{}",
-                if config.tls() { "wss" } else { "ws" },
-                config.host.as_ref(),
-                config.port
-            )),
-        )
-        .await
-        .map_err(|_| Error::Timeout)?
-        .map_err(crate::error::ConnectError)?;
-
-        let (mut write, mut read) = socket.split();
+        let (write, mut read) = socket.split();
+        let mut write: MessageWriter = Box::pin(write);
 
-        let receivers = Arc::new(ReceiverList::default());
+        let receivers = Arc::new(ReceiverList::new(config.unexpected_responses));
         let reidentify_receivers = Arc::new(ReidentifyReceiverList::default());
+        let cache = Arc::new(ResponseCache::default());
+        let (close_tx, close_reason) = watch::channel(None);
 
         #[cfg(feature = "events")]
-        let (event_sender, _) = broadcast::channel(config.broadcast_capacity);
-        #[cfg(feature = "events")]
-        let event_sender = Arc::new(event_sender);
+        let event_sender = EventRouter::new(config.broadcast_capacity, config.broadcast_growth);
         #[cfg(feature = "events")]
         let events_tx = Arc::clone(&event_sender);
 
@@ -305,8 +692,10 @@ impl Client {
             &mut read,
             config.password.as_ref().map(AsRef::as_ref),
             config.event_subscriptions,
+            config.codec,
         )
-        .await?;
+        .await
+        .map_err(|e| e.into_error(config.password.is_some()))?;
 
         let handle = tokio::spawn(recv_loop(
             read,
@@ -314,9 +703,18 @@ impl Client {
             events_tx,
             Arc::clone(&receivers),
             Arc::clone(&reidentify_receivers),
+            #[cfg(feature = "events")]
+            Arc::clone(&cache),
+            Watchdog {
+                close_tx,
+                restart_on_panic: config.restart_on_panic,
+                codec: config.codec,
+            },
+            #[cfg(feature = "testing")]
+            recorder,
         ));
 
-        let write = Mutex::new(write);
+        let write = SendQueue::new(write);
         let id_counter = AtomicU64::new(1);
 
         let client = Self {
@@ -326,19 +724,34 @@ impl Client {
             reidentify_receivers,
             #[cfg(feature = "events")]
             event_sender: Arc::downgrade(&event_sender),
-            handle: Some(handle),
+            handle: Mutex::new(Some(handle)),
+            closing: AtomicBool::new(false),
             dangerous: config.dangerous.unwrap_or_default(),
+            request_size_limit: config.request_size_limit,
+            request_timeout: config.request_timeout,
+            projectors: Mutex::default(),
+            record_timecode: Mutex::default(),
+            clock: Arc::new(TokioClock),
+            cache,
+            coalescer: Coalescer::default(),
+            journal: Journal::default(),
+            server_profile: OnceLock::new(),
+            available_requests: OnceLock::new(),
+            supported_image_formats: OnceLock::new(),
+            close_reason,
+            codec: config.codec,
         };
 
-        client.verify_versions().await?;
+        client.verify_versions(config.server_profile).await?;
 
         Ok(client)
     }
 
-    async fn verify_versions(&self) -> Result<()> {
+    async fn verify_versions(&self, server_profile: ServerProfile) -> Result<()> {
         let version = self.general().version().await?;
 
-        if !self.dangerous.skip_studio_version_check
+        if server_profile == ServerProfile::Standard
+            && !self.dangerous.skip_studio_version_check
             && !OBS_STUDIO_VERSION.matches(&version.obs_version)
         {
             return Err(Error::ObsStudioVersion(
@@ -363,58 +776,163 @@ impl Client {
             });
         }
 
+        if let Some(available) = server_profile.available_requests(&version) {
+            self.available_requests.set(available).ok();
+        }
+        self.server_profile.set(server_profile).ok();
+        self.supported_image_formats
+            .set(version.supported_image_formats)
+            .ok();
+
         Ok(())
     }
 
+    /// Refuse requests that the server doesn't know about, if its [`ServerProfile`] requires it.
+    fn check_available(&self, req: &RequestType<'_>) -> Result<()> {
+        let Some(available) = self.available_requests.get() else {
+            return Ok(());
+        };
+
+        let request_type = req.name();
+        if available.contains(request_type) {
+            Ok(())
+        } else {
+            Err(Error::UnsupportedRequest(request_type.to_owned()))
+        }
+    }
+
     async fn send_message<'a, R, T>(&self, req: R) -> Result<T>
     where
         R: Into<RequestType<'a>>,
         T: DeserializeOwned,
     {
-        async fn send<'a>(
-            id_counter: &AtomicU64,
-            receivers: &Arc<ReceiverList>,
-            write: &Mutex<MessageWriter>,
-            req: RequestType<'a>,
-        ) -> Result<serde_json::Value> {
-            let id = id_counter.fetch_add(1, Ordering::SeqCst);
+        async fn send(client: &Client, req: RequestType<'_>) -> Result<Box<RawValue>> {
+            let id = client.id_counter.fetch_add(1, Ordering::SeqCst);
             let id_str = id.to_string();
             let req = ClientRequest::Request(Request {
                 request_id: &id_str,
                 ty: req,
             });
-            let json = serde_json::to_string(&req).map_err(crate::error::SerializeMessageError)?;
-
-            let rx = receivers.add(id).await;
 
-            trace!(%json, "sending message");
-            let write_result = write
-                .lock()
-                .await
-                .send(Message::text(json))
-                .await
-                .map_err(crate::error::SendError);
+            match client.send_and_wait(id, &req).await? {
+                Response::Single(status, resp) => check_status(status).map(|()| resp),
+                Response::Batch(_) => Err(Error::UnexpectedResponse),
+            }
+        }
 
-            if let Err(e) = write_result {
-                receivers.remove(id).await;
-                return Err(e.into());
+        let req = req.into();
+        self.check_available(&req)?;
+        let cache_key = self.cache.key(&req);
+
+        let cached = cache_key
+            .as_ref()
+            .and_then(|key| self.cache.get(key, self.clock.now()));
+
+        let resp = if let Some(resp) = cached {
+            trace!("using cached response");
+            resp
+        } else if let Some(_permit) = self.coalescer.acquire(&req).await {
+            let change = self.journal.prepare(&req);
+            let resp = send(self, req).await?;
+            if let Some(change) = change {
+                self.journal.commit(change);
             }
+            if let Some(key) = cache_key {
+                self.cache.insert(key, resp.clone(), self.clock.now());
+            }
+            resp
+        } else {
+            trace!("dropping write, superseded by a newer one");
+            RawValue::NULL.to_owned()
+        };
 
-            let (status, resp) = rx.await.map_err(crate::error::ReceiveMessageError)?;
-            if !status.result {
-                return Err(Error::Api {
-                    code: status.code,
-                    message: status.comment,
-                });
+        serde_json::from_str(resp.get())
+            .map_err(crate::error::DeserializeResponseError)
+            .map_err(Into::into)
+    }
+
+    /// Send a batch of requests, that is processed by obs-websocket in one go.
+    ///
+    /// The results are returned in the same order as the requests, but may be shorter than the
+    /// list of requests, if `halt_on_failure` is set and one of the requests failed.
+    pub(crate) async fn send_batch(
+        &self,
+        requests: &[RequestType<'_>],
+        halt_on_failure: bool,
+        execution_type: ExecutionType,
+    ) -> Result<Vec<RequestBatchResult>> {
+        for req in requests {
+            self.check_available(req)?;
+        }
+
+        let id = self.id_counter.fetch_add(1, Ordering::SeqCst);
+        let id_str = id.to_string();
+        let req = ClientRequest::RequestBatch(RequestBatch {
+            request_id: &id_str,
+            halt_on_failure: Some(halt_on_failure),
+            requests,
+            execution_type: Some(execution_type),
+        });
+
+        let changes = requests
+            .iter()
+            .map(|req| self.journal.prepare(req))
+            .collect::<Vec<_>>();
+
+        let results = match self.send_and_wait(id, &req).await? {
+            Response::Batch(results) => results,
+            Response::Single(..) => return Err(Error::UnexpectedResponse),
+        };
+
+        for (change, result) in changes.into_iter().zip(&results) {
+            if let Some(change) = change.filter(|_| result.status.result) {
+                self.journal.commit(change);
             }
+        }
 
-            Ok(resp)
+        Ok(results)
+    }
+
+    /// Send a request or request batch with the given ID, and wait for the response to arrive.
+    async fn send_and_wait(&self, id: u64, req: &ClientRequest<'_>) -> Result<Response> {
+        if self.closing.load(Ordering::SeqCst) {
+            return Err(Error::Disconnected);
         }
 
-        let resp = send(&self.id_counter, &self.receivers, &self.write, req.into()).await?;
-        serde_json::from_value(resp)
-            .map_err(crate::error::DeserializeResponseError)
-            .map_err(Into::into)
+        let json = serde_json::to_string(req).map_err(crate::error::SerializeMessageError)?;
+        let size = json.len();
+
+        match self.request_size_limit {
+            Some(RequestSizeLimit::Warn(limit)) if size > limit => {
+                warn!(id, size, limit, "message exceeds the configured size limit");
+            }
+            Some(RequestSizeLimit::Reject(limit)) if size > limit => {
+                return Err(Error::RequestTooLarge { size, limit });
+            }
+            _ => {}
+        }
+
+        trace!(%json, size, "sending message");
+        let message = self.codec.encode(json)?;
+
+        // Removes the receiver again, if this future is dropped before the response arrived.
+        let (rx, _pending) = self.receivers.add(id);
+
+        self.write.send(message).await?;
+
+        let Some(timeout) = self::timeout::current().or(self.request_timeout) else {
+            return rx
+                .await
+                .map_err(crate::error::ReceiveMessageError)
+                .map_err(Into::into);
+        };
+
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(resp) => resp
+                .map_err(crate::error::ReceiveMessageError)
+                .map_err(Into::into),
+            Err(_) => Err(Error::Timeout),
+        }
     }
 
     /// Disconnect from obs-websocket and shut down all machinery.
@@ -423,7 +941,7 @@ impl Client {
     /// tasks to complete. Therefore, it is recommended to call this manually once the client is
     /// no longer needed.
     pub fn disconnect(&mut self) -> impl Future {
-        let handle = self.handle.take().map(|h| {
+        let handle = self.handle.get_mut().take().map(|h| {
             h.abort();
             h
         });
@@ -435,25 +953,111 @@ impl Client {
         }
     }
 
+    /// Gracefully disconnect from obs-websocket.
+    ///
+    /// In contrast to [`Self::disconnect`], this stops accepting new requests first, and waits for
+    /// all outstanding requests to receive their response. Afterwards, the connection is properly
+    /// closed and the background machinery shut down.
+    ///
+    /// The timeout limits the total time for the shutdown. If it is reached, any requests that
+    /// still wait for their response are canceled and the background task is aborted.
+    pub async fn shutdown(&self, timeout: Duration) -> Result<()> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        self.closing.store(true, Ordering::SeqCst);
+
+        if tokio::time::timeout_at(deadline, self.receivers.wait_empty())
+            .await
+            .is_err()
+        {
+            warn!("timed out waiting for outstanding requests");
+        }
+
+        let close_result = self.write.close().await;
+
+        if let Some(mut handle) = self.handle.lock().await.take() {
+            if tokio::time::timeout_at(deadline, &mut handle)
+                .await
+                .is_err()
+            {
+                warn!("timed out waiting for the connection to close");
+                handle.abort();
+                handle.await.ok();
+            }
+        }
+
+        close_result
+    }
+
+    /// Wait until OBS finished starting up and is ready to handle requests.
+    ///
+    /// Right after OBS launched, obs-websocket already accepts connections while the scene
+    /// collection is still loading, and many requests fail with [`Error::NotReady`]. This
+    /// function polls a cheap request until it succeeds. With the `events` feature, it
+    /// additionally waits for a scene collection change, that is in progress, to finish.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::Timeout`] if OBS isn't ready before the timeout passed, or with the
+    /// error of the polled request, if it failed for any other reason than OBS not being ready.
+    pub async fn wait_until_ready(&self, timeout: Duration) -> Result<()> {
+        const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+        let deadline = self.clock.now() + timeout;
+
+        #[cfg(feature = "events")]
+        let events = self.events()?;
+        #[cfg(feature = "events")]
+        futures_util::pin_mut!(events);
+        #[cfg(feature = "events")]
+        let mut collection_changing = false;
+
+        loop {
+            #[cfg(feature = "events")]
+            while let Some(Some(event)) = futures_util::FutureExt::now_or_never(events.next()) {
+                match event {
+                    Event::CurrentSceneCollectionChanging { .. } => collection_changing = true,
+                    Event::CurrentSceneCollectionChanged { .. } => collection_changing = false,
+                    _ => {}
+                }
+            }
+
+            match self.scenes().list().await {
+                #[cfg(feature = "events")]
+                Ok(_) if collection_changing => {}
+                Ok(_) => return Ok(()),
+                Err(e) if e.is_retryable() => {}
+                Err(e) => return Err(e),
+            }
+
+            let now = self.clock.now();
+            if now >= deadline {
+                return Err(Error::Timeout);
+            }
+
+            debug!("OBS not ready yet, retrying");
+            self.clock.sleep(POLL_INTERVAL.min(deadline - now)).await;
+        }
+    }
+
     /// Adjust settings of the currently active connection by re-identifying against
     /// `obs-websocket`.
     ///
     /// This currently allows to change the events to listen for, without the need of a full
     /// disconnect and new connection.
     pub async fn reidentify(&self, event_subscriptions: EventSubscription) -> Result<()> {
+        if self.closing.load(Ordering::SeqCst) {
+            return Err(Error::Disconnected);
+        }
+
         let json = serde_json::to_string(&ClientRequest::Reidentify(Reidentify {
             event_subscriptions: Some(event_subscriptions),
         }))
         .map_err(crate::error::SerializeMessageError)?;
 
+        let message = self.codec.encode(json)?;
         let rx = self.reidentify_receivers.add().await;
 
-        self.write
-            .lock()
-            .await
-            .send(Message::text(json))
-            .await
-            .map_err(crate::error::SendError)?;
+        self.write.send(message).await?;
 
         let resp = rx.await.map_err(crate::error::ReceiveMessageError)?;
         debug!(
@@ -464,6 +1068,137 @@ impl Client {
         Ok(())
     }
 
+    /// Compare the requests available in the connected obs-websocket instance against the
+    /// requests implemented by this crate.
+    ///
+    /// This is helpful when running against newer or older versions of obs-websocket, to find
+    /// out which functions can't be used. Both lists in the report are sorted by name.
+    pub async fn coverage_report(&self) -> Result<CoverageReport> {
+        let available = self.general().version().await?.available_requests;
+
+        let mut missing_in_obws = available
+            .iter()
+            .filter(|name| !crate::requests::REQUEST_TYPES.contains(&name.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        missing_in_obws.sort_unstable();
+
+        let mut missing_in_server = crate::requests::REQUEST_TYPES
+            .iter()
+            .copied()
+            .filter(|name| !available.iter().any(|available| available == name))
+            .collect::<Vec<_>>();
+        missing_in_server.sort_unstable();
+
+        Ok(CoverageReport {
+            missing_in_obws,
+            missing_in_server,
+        })
+    }
+
+    /// Send a custom batch of requests, that can pass fields of a response on to later requests
+    /// through variables. See [`crate::requests::batch`] for an example.
+    ///
+    /// The batch halts on the first failed request, as later requests likely depend on its
+    /// variables. The response data of all requests is returned in order, unless any of them
+    /// failed, in which case the error of the failed request is returned.
+    ///
+    /// # Errors
+    ///
+    /// Fails with an [`Error::InvalidBatchSleep`] without sending the batch, if it contains a
+    /// sleep request that doesn't match the execution type, like
+    /// [`BatchRequest::sleep_frames`] in a [`BatchExecution::SerialRealtime`] batch.
+    pub async fn batch<'a>(
+        &self,
+        requests: impl IntoIterator<Item = BatchRequest<'a>>,
+        execution: BatchExecution,
+    ) -> Result<Vec<serde_json::Value>> {
+        let requests = requests
+            .into_iter()
+            .map(|request| {
+                if request.invalid_sleep(execution) {
+                    Err(Error::InvalidBatchSleep(execution))
+                } else {
+                    Ok(RequestType::from(request))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.send_batch(&requests, true, execution.into())
+            .await?
+            .into_iter()
+            .map(batch_response)
+            .collect()
+    }
+
+    /// Send a request, that isn't wrapped by this crate yet, like a request added in a newer
+    /// version of obs-websocket.
+    ///
+    /// The request is identified by its raw name, like `GetVersion`, and the data must serialize
+    /// into a JSON object, or into nothing (`()` or [`None`]) for requests without data. The
+    /// response data is deserialized into `T`, which can be [`serde_json::Value`] if its
+    /// structure isn't known upfront.
+    ///
+    /// # Errors
+    ///
+    /// Fails with an [`Error::InvalidCustomData`] if the data doesn't serialize into an object,
+    /// in addition to the usual errors of a request.
+    pub async fn send_custom<T>(&self, request_type: &str, data: impl Serialize) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let data = serde_json::to_value(data).map_err(crate::error::SerializeCustomDataError)?;
+        let request = match data {
+            serde_json::Value::Null => BatchRequest::new(request_type),
+            serde_json::Value::Object(_) => BatchRequest::new(request_type).data(data),
+            _ => return Err(Error::InvalidCustomData),
+        };
+
+        self.send_message(request).await
+    }
+
+    /// Get a combined snapshot of the most important states of OBS, like whether the stream is
+    /// running, and general statistics.
+    ///
+    /// All states are queried in a single request batch, that obs-websocket processes in
+    /// parallel, which makes this well suited for status bars, that update frequently.
+    pub async fn health(&self) -> Result<Health> {
+        let requests = [
+            crate::requests::streaming::Request::GetStreamStatus.into(),
+            crate::requests::recording::Request::Status.into(),
+            crate::requests::virtual_cam::Request::Status.into(),
+            crate::requests::replay_buffer::Request::Status.into(),
+            crate::requests::scenes::Request::CurrentProgramScene.into(),
+            crate::requests::general::Request::Stats.into(),
+        ];
+
+        let results = self
+            .send_batch(&requests, false, ExecutionType::Parallel)
+            .await?;
+        let Ok([stream, record, virtual_cam, replay_buffer, scene, stats]) =
+            <[RequestBatchResult; 6]>::try_from(results)
+        else {
+            return Err(Error::UnexpectedResponse);
+        };
+
+        Ok(Health {
+            stream: batch_response(stream)?,
+            record: batch_response(record)?,
+            virtual_cam: batch_response::<crate::responses::virtual_cam::OutputActive>(
+                virtual_cam,
+            )?
+            .active,
+            replay_buffer: batch_response::<crate::responses::replay_buffer::OutputActive>(
+                replay_buffer,
+            )
+            .ok()
+            .map(|status| status.active),
+            program_scene: batch_response::<crate::responses::scenes::CurrentProgramScene>(scene)?
+                .id,
+            stats: batch_response(stats)?,
+        })
+    }
+
     /// Get a stream of events. Each call to this function creates a new listener, therefore it's
     /// recommended to keep the stream around and iterate over it.
     ///
@@ -478,18 +1213,233 @@ impl Client {
     #[cfg(feature = "events")]
     pub fn events(&self) -> Result<impl Stream<Item = Event>> {
         if let Some(sender) = &self.event_sender.upgrade() {
-            let mut receiver = sender.subscribe();
+            Ok(event_stream(sender.subscribe()))
+        } else {
+            Err(crate::error::Error::Disconnected)
+        }
+    }
 
-            Ok(async_stream::stream! {
-                while let Ok(event) = receiver.recv().await {
-                    yield event;
-                }
-            })
+    /// Get a stream of events, that only contains events of the given categories, like
+    /// [`EventSubscription::SCENES`]. Events created by this crate, like [`Event::ServerStopped`],
+    /// are always part of the stream.
+    ///
+    /// In contrast to [`Self::events`], each stream has its own buffer of the configured
+    /// broadcast capacity. Therefore, high-volume events like [`Event::InputVolumeMeters`] don't
+    /// cause a listener to lag behind, unless it subscribed to them.
+    ///
+    /// **Note**: The events still have to be enabled through the event subscriptions of the
+    /// connection, to be sent by obs-websocket in the first place.
+    ///
+    /// # Errors
+    ///
+    /// Getting a new stream of events fails with [`Error::Disconnected`] if the client is
+    /// disconnected from obs-websocket.
+    #[cfg(feature = "events")]
+    pub fn events_for(&self, subscription: EventSubscription) -> Result<impl Stream<Item = Event>> {
+        if let Some(sender) = &self.event_sender.upgrade() {
+            Ok(event_stream(sender.subscribe_to(subscription)))
+        } else {
+            Err(crate::error::Error::Disconnected)
+        }
+    }
+
+    /// Get a stream of events like [`Self::events`], that yields events shared with all other
+    /// listeners, instead of a copy for each listener.
+    ///
+    /// This avoids cloning each event for each stream, which reduces allocations for listeners of
+    /// high-volume events, like [`Event::InputVolumeMeters`] or
+    /// [`Event::SceneItemTransformChanged`].
+    ///
+    /// # Errors
+    ///
+    /// Getting a new stream of events fails with [`Error::Disconnected`] if the client is
+    /// disconnected from obs-websocket.
+    #[cfg(feature = "events")]
+    pub fn events_shared(&self) -> Result<impl Stream<Item = Arc<Event>>> {
+        if let Some(sender) = &self.event_sender.upgrade() {
+            Ok(shared_event_stream(sender.subscribe()))
+        } else {
+            Err(crate::error::Error::Disconnected)
+        }
+    }
+
+    /// Get a stream of events for the given categories like [`Self::events_for`], that yields
+    /// events shared with all other listeners, like [`Self::events_shared`].
+    ///
+    /// # Errors
+    ///
+    /// Getting a new stream of events fails with [`Error::Disconnected`] if the client is
+    /// disconnected from obs-websocket.
+    #[cfg(feature = "events")]
+    pub fn events_for_shared(
+        &self,
+        subscription: EventSubscription,
+    ) -> Result<impl Stream<Item = Arc<Event>>> {
+        if let Some(sender) = &self.event_sender.upgrade() {
+            Ok(shared_event_stream(sender.subscribe_to(subscription)))
+        } else {
+            Err(crate::error::Error::Disconnected)
+        }
+    }
+
+    /// Register an async callback for a single kind of event, like
+    /// [`SceneCreated`](crate::dispatch::kinds::SceneCreated).
+    ///
+    /// The callback runs in its own task, and is called for each matching event in order. It's
+    /// called for the next event only after the previous call finished, so long running work
+    /// should be spawned into a separate task. Have a look at the [`dispatch`](crate::dispatch)
+    /// module for details.
+    ///
+    /// # Errors
+    ///
+    /// Registering a callback fails with [`Error::Disconnected`] if the client is disconnected
+    /// from obs-websocket.
+    #[cfg(feature = "events")]
+    pub fn on<K: EventKind>(&self, handler: impl EventHandler) -> Result<Registration> {
+        self.events_for_shared(K::SUBSCRIPTION)
+            .map(|events| crate::dispatch::spawn::<K>(events, handler))
+    }
+
+    /// Get a stream of events like [`Self::events`], that additionally reports when events were
+    /// dropped, because the listener couldn't keep up.
+    ///
+    /// Regular event streams skip over dropped events without notice. This stream yields an
+    /// [`EventOrLag::Lagged`] item with the number of dropped events instead, so that the
+    /// consumer can resynchronize its state.
+    ///
+    /// # Errors
+    ///
+    /// Getting a new stream of events fails with [`Error::Disconnected`] if the client is
+    /// disconnected from obs-websocket.
+    #[cfg(feature = "events")]
+    pub fn events_with_lag(&self) -> Result<impl Stream<Item = EventOrLag>> {
+        if let Some(sender) = &self.event_sender.upgrade() {
+            Ok(lag_aware_event_stream(sender.subscribe()))
         } else {
             Err(crate::error::Error::Disconnected)
         }
     }
 
+    /// Get statistics about event listeners, that lagged behind and missed events.
+    ///
+    /// This helps to find a suitable [`ConnectConfig::broadcast_capacity`], before events are
+    /// silently lost in production.
+    ///
+    /// # Errors
+    ///
+    /// Fails with [`Error::Disconnected`] if the client is disconnected from obs-websocket.
+    #[cfg(feature = "events")]
+    pub fn event_lag_stats(&self) -> Result<EventLagStats> {
+        if let Some(sender) = &self.event_sender.upgrade() {
+            Ok(sender.stats())
+        } else {
+            Err(crate::error::Error::Disconnected)
+        }
+    }
+
+    /// Replace the clock, that is used by the time-based utilities of the client.
+    ///
+    /// This is mostly useful for tests, that need to control the passing of time. By default, the
+    /// [`TokioClock`] is used. Timeouts of the protocol itself, like the
+    /// [`ConnectConfig::request_timeout`], always use the timer of the tokio runtime instead.
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Wait until the connection to obs-websocket ended, and get the reason for it.
+    ///
+    /// This includes failures of the client itself, like a panic while handling a received
+    /// message, which would otherwise leave the client unusable without notice.
+    pub async fn closed(&self) -> CloseReason {
+        let mut close_reason = self.close_reason.clone();
+        let reason = match close_reason.wait_for(Option::is_some).await {
+            Ok(reason) => reason.clone(),
+            Err(_) => None,
+        };
+
+        reason.unwrap_or(CloseReason::Disconnected)
+    }
+
+    /// Get the reason of why the connection to obs-websocket ended, or [`None`] if it's still
+    /// alive.
+    #[must_use]
+    pub fn close_reason(&self) -> Option<CloseReason> {
+        self.close_reason.borrow().clone()
+    }
+
+    /// Get statistics about anomalies of the connection, like responses that no request was
+    /// waiting for.
+    #[must_use]
+    pub fn connection_stats(&self) -> ConnectionStats {
+        self.receivers.stats()
+    }
+
+    /// Get the kind of server that is connected to, as configured while connecting.
+    #[must_use]
+    pub fn server_profile(&self) -> ServerProfile {
+        self.server_profile.get().copied().unwrap_or_default()
+    }
+
+    /// Enable caching of responses for read requests, like the list of scenes.
+    ///
+    /// Cached responses are reused until their time-to-live passes. With the `events` feature,
+    /// they're dropped early when a corresponding event signals a change, like a newly created
+    /// scene. This only works if the client is subscribed to these events, otherwise responses are
+    /// only dropped after their time-to-live.
+    #[must_use]
+    pub fn with_cache(self, config: CacheConfig) -> Self {
+        self.cache.configure(config);
+        self
+    }
+
+    /// Drop all cached responses, enabled through [`Self::with_cache`].
+    pub fn clear_cache(&self) {
+        self.cache.clear();
+    }
+
+    /// Send the given write requests in "latest wins" mode, meant for real-time controllers like
+    /// fader boards, that send many updates in quick succession.
+    ///
+    /// Only one write per target, like the volume of a single input, is in flight at any time.
+    /// Further writes to the same target are queued, and once the in-flight write completes, all
+    /// queued ones except for the newest are dropped. That way, OBS always receives the freshest
+    /// value next, instead of working through a backlog of outdated ones.
+    ///
+    /// Dropped writes return successfully right away, without being sent to obs-websocket.
+    #[must_use]
+    pub fn with_latest_wins(self, requests: impl IntoIterator<Item = CoalescedRequest>) -> Self {
+        self.coalescer.configure(requests.into_iter().collect());
+        self
+    }
+
+    /// Record all state-changing requests, that are sent by this client and succeed, like
+    /// switching scenes or changing the volume of an input. The recorded changes are available
+    /// through [`Self::session_changes`].
+    ///
+    /// This helps to answer what an automated client changed during a session. Requests that only
+    /// read state or trigger actions, like hotkeys and vendor requests, aren't recorded. Only the
+    /// latest 1000 changes are kept.
+    #[must_use]
+    pub fn with_session_journal(self) -> Self {
+        self.journal.enable();
+        self
+    }
+
+    /// Get all changes, that were recorded since the session journal was enabled through
+    /// [`Self::with_session_journal`], in the order they happened.
+    #[must_use]
+    pub fn session_changes(&self) -> Vec<SessionChange> {
+        self.journal.changes()
+    }
+
+    /// The clock, that is used by the time-based utilities of the client.
+    #[must_use]
+    pub fn clock(&self) -> &dyn Clock {
+        &*self.clock
+    }
+
     /// Access API functions related to OBS configuration.
     pub fn config(&self) -> Config<'_> {
         Config { client: self }
@@ -589,26 +1539,92 @@ impl Drop for Client {
     }
 }
 
+/// Turn a receiver of events into a stream, that ends once the connection is closed. Events
+/// that were dropped, because the listener lagged behind, are skipped.
+#[cfg(feature = "events")]
+fn event_stream(receiver: EventReceiver) -> impl Stream<Item = Event> {
+    // Lagging listeners are already reported by the router, according to the growth policy.
+    lag_aware_event_stream(receiver).filter_map(|item| {
+        futures_util::future::ready(match item {
+            EventOrLag::Event(event) => Some(event),
+            EventOrLag::Lagged(_) => None,
+        })
+    })
+}
+
+/// Turn a receiver of events into a stream of shared events, that ends once the connection is
+/// closed.
+#[cfg(feature = "events")]
+fn shared_event_stream(mut receiver: EventReceiver) -> impl Stream<Item = Arc<Event>> {
+    async_stream::stream! {
+        while let Some(item) = receiver.recv_shared().await {
+            // Lagging listeners are already reported by the router, according to the growth
+            // policy.
+            if let Ok(event) = item {
+                yield event;
+            }
+        }
+    }
+}
+
+/// Turn a receiver of events into a stream, that ends once the connection is closed, and
+/// reports the number of dropped events whenever the listener lagged behind.
+#[cfg(feature = "events")]
+fn lag_aware_event_stream(mut receiver: EventReceiver) -> impl Stream<Item = EventOrLag> {
+    async_stream::stream! {
+        while let Some(item) = receiver.recv().await {
+            yield item;
+        }
+    }
+}
+
+/// Settings for the receiving side of the WebSocket connection and its supervision.
+struct Watchdog {
+    /// Channel to report the reason of why the connection ended.
+    close_tx: watch::Sender<Option<CloseReason>>,
+    /// Continue with the next message, if handling a message panicked.
+    restart_on_panic: bool,
+    /// Encoding of the received messages.
+    codec: Codec,
+}
+
 /// Run the receiving side of the WebSocket connection.
 async fn recv_loop(
     mut read: impl Stream<Item = tungstenite::Result<Message>> + Unpin,
-    #[cfg(feature = "events")] events_tx: Arc<broadcast::Sender<Event>>,
+    #[cfg(feature = "events")] events_tx: Arc<EventRouter>,
     receivers: Arc<ReceiverList>,
     reidentify_receivers: Arc<ReidentifyReceiverList>,
+    #[cfg(feature = "events")] cache: Arc<ResponseCache>,
+    watchdog: Watchdog,
+    #[cfg(feature = "testing")] recorder: Option<Recorder>,
 ) {
+    let mut close_reason = None;
+
     while let Some(Ok(msg)) = read.next().await {
         if let Message::Close(info) = &msg {
             if let Some(CloseFrame { reason, .. }) = info {
                 info!(%reason, "connection closed with reason");
             }
 
+            close_reason = Some(CloseReason::Server(info.as_ref().map(|info| {
+                CloseDetails {
+                    code: info.code,
+                    reason: info.reason.as_str().to_owned(),
+                }
+            })));
+
             #[cfg(feature = "events")]
-            events_tx.send(Event::ServerStopping).ok();
+            events_tx.send(Event::ServerStopping);
             continue;
         }
 
-        let res: Result<(), InnerError> = async {
-            let text = msg.into_text().map_err(InnerError::IntoText)?;
+        let res = AssertUnwindSafe(async {
+            let text = watchdog.codec.decode(msg)?;
+
+            #[cfg(feature = "testing")]
+            if let Some(recorder) = &recorder {
+                recorder.record(&text);
+            }
 
             let message = serde_json::from_str::<ServerMessage>(&text)
                 .map_err(InnerError::DeserializeMessage)?;
@@ -623,10 +1639,19 @@ async fn recv_loop(
                     );
                     receivers.notify(response).await?;
                 }
+                ServerMessage::RequestBatchResponse(response) => {
+                    trace!(
+                        id = %response.id,
+                        results = response.results.len(),
+                        "got request-batch-response message",
+                    );
+                    receivers.notify_batch(response).await?;
+                }
                 #[cfg(feature = "events")]
                 ServerMessage::Event(event) => {
                     trace!(?event, "got OBS event");
-                    events_tx.send(event).ok();
+                    cache.invalidate(&event);
+                    events_tx.send(event);
                 }
                 #[cfg(not(feature = "events"))]
                 ServerMessage::Event => {
@@ -642,20 +1667,45 @@ async fn recv_loop(
                 }
             }
 
-            Ok(())
-        }
+            Ok::<_, InnerError>(())
+        })
+        .catch_unwind()
         .await;
 
-        if let Err(error) = res {
-            error!(?error, "failed handling message");
+        match res {
+            Ok(Ok(())) => {}
+            Ok(Err(InnerError::UnexpectedResponse(kind, id))) => {
+                error!(?kind, id, "received unexpected response, shutting down");
+                close_reason = Some(CloseReason::UnexpectedResponse(kind));
+                break;
+            }
+            Ok(Err(error)) => error!(?error, "failed handling message"),
+            Err(payload) => {
+                let reason = CloseReason::from_panic(&*payload);
+
+                if watchdog.restart_on_panic {
+                    error!(
+                        ?reason,
+                        "handling message panicked, continuing with next message"
+                    );
+                } else {
+                    error!(?reason, "handling message panicked, shutting down");
+                    close_reason = Some(reason);
+                    break;
+                }
+            }
         }
     }
 
+    watchdog
+        .close_tx
+        .send_replace(Some(close_reason.unwrap_or(CloseReason::Disconnected)));
+
     #[cfg(feature = "events")]
-    events_tx.send(Event::ServerStopped).ok();
+    events_tx.send(Event::ServerStopped);
 
     // clear all outstanding receivers to stop them from waiting forever on responses
     // they'll never receive.
-    receivers.reset().await;
+    receivers.reset();
     reidentify_receivers.reset().await;
 }
@@ -4,11 +4,22 @@ use super::Client;
 use crate::{error::Result, requests::outputs::Request, responses::outputs as responses};
 
 /// API functions related to outputs.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("ToggleOutput", json!({"outputActive": true}));
+/// assert!(client.outputs().toggle("virtualcam_output").await?);
+/// # assert_eq!(
+/// #     Some(&("ToggleOutput".to_owned(), json!({"outputName": "virtualcam_output"}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Outputs<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Outputs<'a> {
+impl Outputs<'_> {
     /// Gets the list of available outputs.
     #[doc(alias = "GetOutputList")]
     pub async fn list(&self) -> Result<Vec<responses::Output>> {
@@ -19,6 +30,31 @@ impl<'a> Outputs<'a> {
     }
 
     /// Gets the status of an output.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetOutputStatus",
+    /// #     json!({
+    /// #         "outputActive": true,
+    /// #         "outputReconnecting": false,
+    /// #         "outputTimecode": "00:01:30.000",
+    /// #         "outputDuration": 90000,
+    /// #         "outputCongestion": 0.0,
+    /// #         "outputBytes": 1024,
+    /// #         "outputSkippedFrames": 3,
+    /// #         "outputTotalFrames": 5400,
+    /// #     }),
+    /// # );
+    /// let status = client.outputs().status("adv_file_output").await?;
+    /// if status.active {
+    ///     println!(
+    ///         "skipped {} of {} frames",
+    ///         status.skipped_frames, status.total_frames
+    ///     );
+    /// }
+    /// # });
+    /// ```
     #[doc(alias = "GetOutputStatus")]
     pub async fn status(&self, name: &str) -> Result<responses::OutputStatus> {
         self.client.send_message(Request::Status { name }).await
@@ -0,0 +1,150 @@
+use std::{
+    future::{self, Future},
+    task::{Context, Poll},
+};
+
+use futures_util::SinkExt;
+use tokio::sync::{mpsc, oneshot};
+use tokio_tungstenite::tungstenite::{self, Message};
+
+use super::MessageWriter;
+use crate::error::{Error, Result};
+
+tokio::task_local! {
+    static PRIORITY: Priority;
+}
+
+/// Priority of requests, that decides which ones are sent first, if many of them are waiting to
+/// be sent at the same time.
+///
+/// Requests are sent with [`Self::Background`] priority by default. Wrap calls in
+/// [`Self::scope`] to send them with another priority:
+///
+/// ```no_run
+/// use obws::{client::Priority, Client};
+///
+/// # async fn switch(client: &Client) -> obws::error::Result<()> {
+/// Priority::Interactive
+///     .scope(client.scenes().set_current_program_scene("Live"))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Priority {
+    /// Regular requests, like bulk syncing of settings.
+    #[default]
+    Background,
+    /// Requests initiated by a user, like switching scenes, that should be sent as soon as
+    /// possible.
+    ///
+    /// While any interactive request is waiting to be sent, background requests are held back.
+    Interactive,
+}
+
+impl Priority {
+    /// Run the future, sending all requests within it with this priority.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        PRIORITY.scope(self, future).await
+    }
+
+    /// The priority of the current task, as set by [`Self::scope`].
+    #[must_use]
+    pub fn current() -> Self {
+        PRIORITY.try_with(|priority| *priority).unwrap_or_default()
+    }
+}
+
+/// Queue of outgoing messages, that are written to the connection by a dedicated task.
+///
+/// Messages are queued without waiting on each other, and the task always sends the next
+/// [`Priority::Interactive`] message first. Background messages are only sent while no interactive
+/// ones are waiting.
+pub(super) struct SendQueue {
+    interactive: mpsc::UnboundedSender<Outgoing>,
+    background: mpsc::UnboundedSender<Outgoing>,
+}
+
+/// Entry of the [`SendQueue`], with a channel to report back the result of writing it.
+struct Outgoing {
+    command: Command,
+    done: oneshot::Sender<tungstenite::Result<()>>,
+}
+
+enum Command {
+    /// Send the message to obs-websocket.
+    Send(Message),
+    /// Close the connection and stop the writer task.
+    Close,
+}
+
+impl SendQueue {
+    /// Create a new queue, spawning the writer task that owns the writing side of the connection.
+    ///
+    /// The task ends once the queue is dropped, or the connection was closed through
+    /// [`Self::close`].
+    pub fn new(write: MessageWriter) -> Self {
+        let (interactive, interactive_rx) = mpsc::unbounded_channel();
+        let (background, background_rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(write_loop(write, interactive_rx, background_rx));
+
+        Self {
+            interactive,
+            background,
+        }
+    }
+
+    /// Queue a message with the priority of the current task, and wait until it was sent.
+    pub async fn send(&self, message: Message) -> Result<()> {
+        let queue = match Priority::current() {
+            Priority::Interactive => &self.interactive,
+            Priority::Background => &self.background,
+        };
+
+        Self::submit(queue, Command::Send(message)).await
+    }
+
+    /// Close the connection, after all messages that were queued before were sent.
+    pub async fn close(&self) -> Result<()> {
+        Self::submit(&self.background, Command::Close).await
+    }
+
+    async fn submit(queue: &mpsc::UnboundedSender<Outgoing>, command: Command) -> Result<()> {
+        let (done, rx) = oneshot::channel();
+        queue
+            .send(Outgoing { command, done })
+            .map_err(|_| Error::Disconnected)?;
+
+        rx.await
+            .map_err(|_| Error::Disconnected)?
+            .map_err(|e| crate::error::SendError(Box::new(e)))
+            .map_err(Into::into)
+    }
+}
+
+/// Write all queued messages to the connection, preferring interactive ones.
+async fn write_loop(
+    mut write: MessageWriter,
+    mut interactive: mpsc::UnboundedReceiver<Outgoing>,
+    mut background: mpsc::UnboundedReceiver<Outgoing>,
+) {
+    let mut next = |cx: &mut Context<'_>| match interactive.poll_recv(cx) {
+        Poll::Ready(Some(outgoing)) => Poll::Ready(Some(outgoing)),
+        // Both senders are owned by the same queue, so closing one means closing both.
+        Poll::Ready(None) | Poll::Pending => background.poll_recv(cx),
+    };
+
+    while let Some(Outgoing { command, done }) = future::poll_fn(&mut next).await {
+        match command {
+            Command::Send(message) => {
+                done.send(write.send(message).await).ok();
+            }
+            Command::Close => {
+                done.send(write.close().await).ok();
+                break;
+            }
+        }
+    }
+}
@@ -1,17 +1,51 @@
+use std::future::Future;
+
 use super::Client;
 use crate::{
-    error::Result,
-    requests::profiles::{Request, SetParameter},
+    error::{Error, Result},
+    requests::{
+        custom::profile_parameters::{self, Parameter, ParameterValue},
+        profiles::{Request, SetParameter},
+    },
     responses::profiles as responses,
 };
 
 /// API functions related to profiles.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond(
+/// #     "GetProfileList",
+/// #     json!({"currentProfileName": "Main", "profiles": ["Main", "Recording"]}),
+/// # );
+/// # stub.respond("SetCurrentProfile", json!(null));
+/// assert_eq!("Main", client.profiles().current().await?);
+///
+/// client.profiles().set_current("Recording").await?;
+/// # assert_eq!(
+/// #     Some(&("SetCurrentProfile".to_owned(), json!({"profileName": "Recording"}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Profiles<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Profiles<'a> {
+impl Profiles<'_> {
     /// Gets an array of all profiles.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetProfileList",
+    /// #     json!({"currentProfileName": "Main", "profiles": ["Main", "Recording"]}),
+    /// # );
+    /// let profiles = client.profiles().list().await?;
+    /// assert_eq!("Main", profiles.current);
+    /// assert_eq!(vec!["Main", "Recording"], profiles.profiles);
+    /// # });
+    /// ```
     #[doc(alias = "GetProfileList")]
     pub async fn list(&self) -> Result<responses::Profiles> {
         self.client.send_message(Request::List).await
@@ -45,6 +79,81 @@ impl<'a> Profiles<'a> {
         self.client.send_message(Request::Remove { name }).await
     }
 
+    /// Creates a new profile as copy of the `source` profile, and switches back to the profile
+    /// that was active before.
+    ///
+    /// obs-websocket can't list all parameters of a profile, so only the
+    /// [well-known parameters](profile_parameters::well_known) are copied, that are set in the
+    /// source profile. Other settings of the new profile keep their defaults. The previous
+    /// profile is restored, even if copying fails.
+    #[doc(alias = "CreateProfile")]
+    pub async fn duplicate(&self, source: &str, new_name: &str) -> Result<()> {
+        let previous = self.current().await?;
+        let result = self.copy_profile(source, new_name).await;
+        let restored = self.set_current(&previous).await;
+
+        result.and(restored)
+    }
+
+    async fn copy_profile(&self, source: &str, new_name: &str) -> Result<()> {
+        self.set_current(source).await?;
+
+        let mut values = Vec::new();
+        for (category, name) in profile_parameters::well_known() {
+            if let Some(value) = self.parameter(category.as_str(), name).await?.value {
+                values.push((category, name, value));
+            }
+        }
+
+        self.create(new_name).await?;
+
+        for (category, name, value) in &values {
+            self.set_parameter(SetParameter {
+                category: category.as_str(),
+                name,
+                value: Some(value),
+            })
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Switches to a profile, runs the given function, and switches back to the profile that was
+    /// active before.
+    ///
+    /// The previous profile is restored, even if the function fails. Its error takes precedence
+    /// over an error while restoring the profile. Nothing is switched, if the profile is already
+    /// active.
+    ///
+    /// ```no_run
+    /// # async fn run(client: &obws::Client) -> obws::error::Result<()> {
+    /// client
+    ///     .profiles()
+    ///     .with_temporary("High Quality", || async {
+    ///         client.recording().start().await
+    ///     })
+    ///     .await
+    /// # }
+    /// ```
+    #[doc(alias = "SetCurrentProfile")]
+    pub async fn with_temporary<F, Fut, T>(&self, profile: &str, f: F) -> Result<T>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let previous = self.current().await?;
+        if previous == profile {
+            return f().await;
+        }
+
+        self.set_current(profile).await?;
+        let result = f().await;
+        let restored = self.set_current(&previous).await;
+
+        result.and_then(|value| restored.map(|()| value))
+    }
+
     /// Gets a parameter from the current profile's configuration.
     #[doc(alias = "GetProfileParameter")]
     pub async fn parameter(
@@ -64,4 +173,52 @@ impl<'a> Profiles<'a> {
             .send_message(Request::SetParameter(parameter))
             .await
     }
+
+    /// Gets a well-known parameter from the current profile's configuration, parsed into its
+    /// value type.
+    ///
+    /// The default value is returned if the parameter isn't set, and [`None`] if neither a value
+    /// nor a default value exist.
+    #[doc(alias = "GetProfileParameter")]
+    pub async fn typed_parameter<T>(&self, parameter: Parameter<T>) -> Result<Option<T>>
+    where
+        T: ParameterValue,
+    {
+        let value = self
+            .parameter(parameter.category.as_str(), parameter.name)
+            .await?;
+
+        value
+            .value
+            .or(value.default_value)
+            .map(|value| {
+                T::parse(&value).ok_or_else(|| Error::InvalidProfileParameter {
+                    category: parameter.category.as_str(),
+                    name: parameter.name,
+                    value,
+                })
+            })
+            .transpose()
+    }
+
+    /// Sets a well-known parameter in the current profile's configuration. Use [`None`] to
+    /// delete.
+    #[doc(alias = "SetProfileParameter")]
+    pub async fn set_typed_parameter<T>(
+        &self,
+        parameter: Parameter<T>,
+        value: Option<&T>,
+    ) -> Result<()>
+    where
+        T: ParameterValue,
+    {
+        let value = value.map(ParameterValue::to_value);
+
+        self.set_parameter(SetParameter {
+            category: parameter.category.as_str(),
+            name: parameter.name,
+            value: value.as_deref(),
+        })
+        .await
+    }
 }
@@ -1,13 +1,90 @@
+use std::time::{Duration, Instant};
+
 use super::Client;
-use crate::{error::Result, requests::recording::Request, responses::recording as responses};
+use crate::{
+    error::Result,
+    requests::{
+        custom::profile_parameters::{
+            self, SplitFile, SplitFileType, ADV_SPLIT_FILE, ADV_SPLIT_FILE_SIZE,
+            ADV_SPLIT_FILE_TIME, ADV_SPLIT_FILE_TYPE, FILENAME_FORMATTING,
+        },
+        recording::Request,
+    },
+    responses::recording as responses,
+};
+
+/// Maximum age of a timecode sample, before the recording status is queried again.
+const TIMECODE_RESYNC: Duration = Duration::from_secs(5);
+
+/// Recording timecode, together with the local point in time it was valid at.
+#[derive(Clone, Copy)]
+pub(super) struct TimecodeSample {
+    at: Instant,
+    timecode: time::Duration,
+    paused: bool,
+}
+
+impl TimecodeSample {
+    /// Extrapolate the recording timecode for another local point in time.
+    fn timecode_of(self, instant: Instant) -> Option<time::Duration> {
+        if self.paused {
+            return Some(self.timecode);
+        }
+
+        let timecode = if instant >= self.at {
+            self.timecode
+                .checked_add(time::Duration::try_from(instant - self.at).ok()?)?
+        } else {
+            self.timecode
+                .checked_sub(time::Duration::try_from(self.at - instant).ok()?)?
+        };
+
+        (!timecode.is_negative()).then_some(timecode)
+    }
+}
 
 /// API functions related to recording.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("StartRecord", json!(null));
+/// # stub.respond("StopRecord", json!({"outputPath": "/home/obs/Videos/recording.mkv"}));
+/// client.recording().start().await?;
+///
+/// let path = client.recording().stop().await?;
+/// assert_eq!("/home/obs/Videos/recording.mkv", path);
+/// # assert_eq!(
+/// #     Some(&("StopRecord".to_owned(), json!(null))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Recording<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Recording<'a> {
+impl Recording<'_> {
     /// Gets the status of the record output.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetRecordStatus",
+    /// #     json!({
+    /// #         "outputActive": true,
+    /// #         "outputPaused": false,
+    /// #         "outputTimecode": "00:01:30.000",
+    /// #         "outputDuration": 90000,
+    /// #         "outputBytes": 1024,
+    /// #     }),
+    /// # );
+    /// use std::time::Duration;
+    ///
+    /// let status = client.recording().status().await?;
+    /// assert!(status.active && !status.paused);
+    /// assert_eq!(Duration::from_secs(90), status.duration);
+    /// # });
+    /// ```
     #[doc(alias = "GetRecordStatus")]
     pub async fn status(&self) -> Result<responses::RecordStatus> {
         self.client.send_message(Request::Status).await
@@ -38,6 +115,14 @@ impl<'a> Recording<'a> {
     }
 
     /// Toggles pause on the record output.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond("ToggleRecordPause", json!({"outputPaused": true}));
+    /// let paused = client.recording().toggle_pause().await?;
+    /// assert!(paused);
+    /// # });
+    /// ```
     #[doc(alias = "ToggleRecordPause")]
     pub async fn toggle_pause(&self) -> Result<bool> {
         self.client
@@ -64,6 +149,137 @@ impl<'a> Recording<'a> {
         self.client.send_message(Request::SplitFile).await
     }
 
+    /// Gets the template for the file names of recordings, like `%CCYY-%MM-%DD %hh-%mm-%ss`.
+    ///
+    /// The template is read from the current profile's parameters.
+    pub async fn filename_format(&self) -> Result<Option<String>> {
+        self.client
+            .profiles()
+            .typed_parameter(FILENAME_FORMATTING)
+            .await
+    }
+
+    /// Sets the template for the file names of recordings. It's written to the current profile's
+    /// parameters, and used for the next recording.
+    ///
+    /// The template is checked with [`profile_parameters::validate_filename_format`] first,
+    /// failing with an [`Error::InvalidFilenameFormat`] for unknown specifiers.
+    ///
+    /// [`Error::InvalidFilenameFormat`]: crate::error::Error::InvalidFilenameFormat
+    pub async fn set_filename_format(&self, format: &str) -> Result<()> {
+        profile_parameters::validate_filename_format(format)?;
+
+        self.client
+            .profiles()
+            .set_typed_parameter(FILENAME_FORMATTING, Some(&format.to_owned()))
+            .await
+    }
+
+    /// Gets whether and when recordings are split into multiple files.
+    ///
+    /// The settings are read from the current profile's parameters, and only apply to the
+    /// advanced output mode.
+    pub async fn split_file_settings(&self) -> Result<SplitFile> {
+        let profiles = self.client.profiles();
+
+        if !profiles
+            .typed_parameter(ADV_SPLIT_FILE)
+            .await?
+            .unwrap_or_default()
+        {
+            return Ok(SplitFile::Disabled);
+        }
+
+        Ok(
+            match profiles
+                .typed_parameter(ADV_SPLIT_FILE_TYPE)
+                .await?
+                .unwrap_or(SplitFileType::Time)
+            {
+                SplitFileType::Time => SplitFile::Time {
+                    minutes: profiles
+                        .typed_parameter(ADV_SPLIT_FILE_TIME)
+                        .await?
+                        .unwrap_or_default(),
+                },
+                SplitFileType::Size => SplitFile::Size {
+                    megabytes: profiles
+                        .typed_parameter(ADV_SPLIT_FILE_SIZE)
+                        .await?
+                        .unwrap_or_default(),
+                },
+                SplitFileType::Manual => SplitFile::Manual,
+            },
+        )
+    }
+
+    /// Sets whether and when recordings are split into multiple files.
+    ///
+    /// The settings are written to the current profile's parameters, and only apply to the
+    /// advanced output mode. OBS picks them up the next time a recording starts.
+    pub async fn set_split_file_settings(&self, split: SplitFile) -> Result<()> {
+        let profiles = self.client.profiles();
+
+        let split_type = match split {
+            SplitFile::Disabled => None,
+            SplitFile::Time { minutes } => {
+                profiles
+                    .set_typed_parameter(ADV_SPLIT_FILE_TIME, Some(&minutes))
+                    .await?;
+                Some(SplitFileType::Time)
+            }
+            SplitFile::Size { megabytes } => {
+                profiles
+                    .set_typed_parameter(ADV_SPLIT_FILE_SIZE, Some(&megabytes))
+                    .await?;
+                Some(SplitFileType::Size)
+            }
+            SplitFile::Manual => Some(SplitFileType::Manual),
+        };
+
+        if let Some(split_type) = &split_type {
+            profiles
+                .set_typed_parameter(ADV_SPLIT_FILE_TYPE, Some(split_type))
+                .await?;
+        }
+
+        profiles
+            .set_typed_parameter(ADV_SPLIT_FILE, Some(&split_type.is_some()))
+            .await
+    }
+
+    /// Map a local point in time, like the moment an event was received, to the timecode of the
+    /// current recording. This allows to express events as time into the recording, for example
+    /// to place clip markers.
+    ///
+    /// The recording status is queried at most every few seconds, and the local time of each
+    /// sample is estimated as the middle of the request's round trip, to even out network delays.
+    ///
+    /// Returns [`None`] if no recording is active or the point in time lies before the start of
+    /// the recording. While the recording is paused, the paused timecode is returned for any
+    /// point in time.
+    pub async fn timecode_of(&self, instant: Instant) -> Result<Option<time::Duration>> {
+        let mut sample = self.client.record_timecode.lock().await;
+
+        let fresh = sample.is_some_and(|sample| {
+            !sample.paused && self.client.clock.now().duration_since(sample.at) < TIMECODE_RESYNC
+        });
+
+        if !fresh {
+            let sent = self.client.clock.now();
+            let status = self.status().await?;
+            let received = self.client.clock.now();
+
+            *sample = status.active.then(|| TimecodeSample {
+                at: sent + (received - sent) / 2,
+                timecode: status.timecode,
+                paused: status.paused,
+            });
+        }
+
+        Ok(sample.and_then(|sample| sample.timecode_of(instant)))
+    }
+
     /// Adds a new chapter marker to the file currently being recorded.
     ///
     /// **Note:** As of OBS 30.2.0, the only file format supporting this feature is Hybrid MP4.
@@ -1,14 +1,36 @@
+#[cfg(feature = "events")]
+use std::{path::PathBuf, time::Duration};
+
+#[cfg(feature = "events")]
+use futures_util::{future, StreamExt};
+
 use super::Client;
+#[cfg(feature = "events")]
+use crate::{error::Error, events::Event, requests::EventSubscription};
 use crate::{
     error::Result, requests::replay_buffer::Request, responses::replay_buffer as responses,
 };
 
 /// API functions related to the replay buffer.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("GetReplayBufferStatus", json!({"outputActive": true}));
+/// # stub.respond("SaveReplayBuffer", json!(null));
+/// if client.replay_buffer().status().await? {
+///     client.replay_buffer().save().await?;
+/// }
+/// # assert_eq!(
+/// #     Some(&("SaveReplayBuffer".to_owned(), json!(null))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct ReplayBuffer<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> ReplayBuffer<'a> {
+impl ReplayBuffer<'_> {
     /// Gets the status of the replay buffer output.
     #[doc(alias = "GetReplayBufferStatus")]
     pub async fn status(&self) -> Result<bool> {
@@ -45,7 +67,47 @@ impl<'a> ReplayBuffer<'a> {
         self.client.send_message(Request::Save).await
     }
 
+    /// Saves the contents of the replay buffer output, and waits until the file is written.
+    ///
+    /// In contrast to [`Self::save`], which returns as soon as OBS started saving, this waits for
+    /// the [`Event::ReplayBufferSaved`] event and returns the path of the saved file. If the event
+    /// doesn't arrive within the given timeout, [`Error::Timeout`] is returned.
+    ///
+    /// The events of the client must include [`EventSubscription::OUTPUTS`], which is the case by
+    /// default.
+    #[cfg(feature = "events")]
+    pub async fn save_and_wait(&self, timeout: Duration) -> Result<PathBuf> {
+        // Subscribe before saving, to not miss the event if it arrives quickly.
+        let events = self.client.events_for(EventSubscription::OUTPUTS)?;
+
+        self.save().await?;
+
+        let saved = Box::pin(events.filter_map(|event| async move {
+            match event {
+                Event::ReplayBufferSaved { path } => Some(path),
+                _ => None,
+            }
+        }))
+        .into_future();
+
+        match future::select(saved, self.client.clock().sleep(timeout)).await {
+            future::Either::Left(((path, _), _)) => path.ok_or(Error::Disconnected),
+            future::Either::Right(((), _)) => Err(Error::Timeout),
+        }
+    }
+
     /// Gets the file name of the last replay buffer save file.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetLastReplayBufferReplay",
+    /// #     json!({"savedReplayPath": "/home/obs/Videos/Replay.mkv"}),
+    /// # );
+    /// let path = client.replay_buffer().last_replay().await?;
+    /// assert_eq!("/home/obs/Videos/Replay.mkv", path);
+    /// # });
+    /// ```
     #[doc(alias = "GetLastReplayBufferReplay")]
     pub async fn last_replay(&self) -> Result<String> {
         self.client
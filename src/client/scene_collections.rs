@@ -1,14 +1,39 @@
+use serde_json::Value;
+
 use super::Client;
 use crate::{
-    error::Result, requests::scene_collections::Request, responses::scene_collections as responses,
+    error::Result,
+    requests::{
+        filters::Request as FilterRequest, scene_collections::Request, sources::SourceId,
+        ExecutionType, RequestType,
+    },
+    responses::{filters::Filters, scene_collections as responses},
 };
 
 /// API functions related to scene collections.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond(
+/// #     "GetSceneCollectionList",
+/// #     json!({"currentSceneCollectionName": "Main", "sceneCollections": ["Main", "Gaming"]}),
+/// # );
+/// # stub.respond("SetCurrentSceneCollection", json!(null));
+/// let collections = client.scene_collections().list().await?;
+/// assert_eq!("Main", collections.current);
+///
+/// client.scene_collections().set_current("Gaming").await?;
+/// # assert_eq!(
+/// #     Some(&("SetCurrentSceneCollection".to_owned(), json!({"sceneCollectionName": "Gaming"}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct SceneCollections<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> SceneCollections<'a> {
+impl SceneCollections<'_> {
     /// Gets an array of all scene collections.
     #[doc(alias = "GetSceneCollectionList")]
     pub async fn list(&self) -> Result<responses::SceneCollections> {
@@ -35,8 +60,141 @@ impl<'a> SceneCollections<'a> {
     /// Creates a new scene collection, switching to it in the process.
     ///
     /// **Note:** This will block until the collection has finished changing.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond("CreateSceneCollection", json!(null));
+    /// client.scene_collections().create("Podcast").await?;
+    /// # assert_eq!(
+    /// #     Some(&(
+    /// #         "CreateSceneCollection".to_owned(),
+    /// #         json!({"sceneCollectionName": "Podcast"}),
+    /// #     )),
+    /// #     stub.received().last(),
+    /// # );
+    /// # });
+    /// ```
     #[doc(alias = "CreateSceneCollection")]
     pub async fn create(&self, name: &str) -> Result<()> {
         self.client.send_message(Request::Create { name }).await
     }
+
+    /// Summarizes the size of the current scene collection, and estimates which sources are
+    /// expensive to render. This is helpful to diagnose performance issues of a remote OBS
+    /// instance.
+    ///
+    /// All scenes, inputs and filters are queried to create the report, so this can take a moment
+    /// for large scene collections. The filter lists are requested in a single request batch.
+    pub async fn stats(&self) -> Result<responses::SceneCollectionStats> {
+        let scenes = self.client.scenes().list().await?.scenes;
+        let inputs = self.client.inputs().list(None).await?;
+
+        let mut stats = responses::SceneCollectionStats::default();
+
+        for scene in &scenes {
+            let items = self.client.scene_items().list(&scene.id).await?;
+            stats
+                .items_per_scene
+                .insert(scene.id.name.clone(), items.len());
+        }
+
+        for input in &inputs {
+            *stats.inputs_by_kind.entry(input.kind.clone()).or_default() += 1;
+
+            if !HEAVY_KINDS.contains(&input.unversioned_kind.as_str()) {
+                continue;
+            }
+
+            let settings = self
+                .client
+                .inputs()
+                .settings::<Value>(&input.id)
+                .await?
+                .settings;
+
+            stats
+                .heavy_sources
+                .extend(
+                    heavy_reasons(&input.unversioned_kind, &settings).map(|reason| {
+                        responses::HeavySource {
+                            name: input.id.name.clone(),
+                            reason,
+                        }
+                    }),
+                );
+        }
+
+        let (names, requests): (Vec<_>, Vec<RequestType<'_>>) = scenes
+            .iter()
+            .map(|scene| (&scene.id.name, SourceId::Uuid(scene.id.uuid)))
+            .chain(
+                inputs
+                    .iter()
+                    .map(|input| (&input.id.name, SourceId::Uuid(input.id.uuid))),
+            )
+            .map(|(name, source)| (name, FilterRequest::List { source }.into()))
+            .unzip();
+
+        if !requests.is_empty() {
+            let results = self
+                .client
+                .send_batch(&requests, false, ExecutionType::SerialRealtime)
+                .await?;
+            let filters = super::batch_responses::<Filters>(results)?;
+
+            stats.filters_per_source.extend(
+                names
+                    .into_iter()
+                    .cloned()
+                    .zip(filters.into_iter().map(|list| list.filters.len())),
+            );
+        }
+
+        Ok(stats)
+    }
+}
+
+/// Input kinds, that are checked for expensive settings.
+const HEAVY_KINDS: &[&str] = &["browser_source", "dshow_input"];
+
+/// Frame rate, above which captures are considered expensive.
+const HIGH_FPS: f64 = 30.0;
+
+/// Reasons, why an input is likely expensive to render, estimated from its kind and settings.
+fn heavy_reasons(kind: &str, settings: &Value) -> impl Iterator<Item = responses::HeavyReason> {
+    let mut reasons = Vec::new();
+
+    match kind {
+        "browser_source" => {
+            if !settings
+                .get("shutdown")
+                .and_then(Value::as_bool)
+                .unwrap_or_default()
+            {
+                reasons.push(responses::HeavyReason::BrowserWithoutShutdown);
+            }
+
+            let custom_fps = settings
+                .get("fps_custom")
+                .and_then(Value::as_bool)
+                .unwrap_or_default();
+            if let Some(fps) = settings.get("fps").and_then(Value::as_f64) {
+                if custom_fps && fps > HIGH_FPS {
+                    reasons.push(responses::HeavyReason::HighFps(fps));
+                }
+            }
+        }
+        "dshow_input" => {
+            // The frame interval is given in units of 100 nanoseconds.
+            if let Some(interval) = settings.get("frame_interval").and_then(Value::as_f64) {
+                let fps = 10_000_000.0 / interval;
+                if interval > 0.0 && fps > HIGH_FPS {
+                    reasons.push(responses::HeavyReason::HighFps(fps));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    reasons.into_iter()
 }
@@ -2,19 +2,57 @@ use serde::{de::DeserializeOwned, Serialize};
 
 use super::Client;
 use crate::{
+    animate::{self, Animation},
     common::BlendMode,
     error::Result,
     requests::{
+        general::{Request as GeneralRequest, Sleep},
         scene_items::{
-            CreateSceneItem, Duplicate, Id, Request, SetBlendMode, SetEnabled, SetIndex, SetLocked,
-            SetPrivateSettings, SetPrivateSettingsInternal, SetTransform, Source,
+            CreateSceneItem, Duplicate, Id, ItemUpdate, Position, Request, Scale,
+            SceneItemTransform, SetBlendMode, SetEnabled, SetIndex, SetLocked, SetPrivateSettings,
+            SetPrivateSettingsInternal, SetTransform, Source,
         },
         scenes::SceneId,
+        ExecutionType, RequestType,
     },
-    responses::{scene_items as responses, sources as source_responses},
+    responses::{lenient::LenientList, scene_items as responses, sources as source_responses},
 };
 
 /// API functions related to scene items.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("GetSceneItemId", json!({"sceneItemId": 3}));
+/// # stub.respond("SetSceneItemEnabled", json!(null));
+/// use obws::requests::scene_items::{Id, SetEnabled};
+///
+/// let item_id = client
+///     .scene_items()
+///     .id(Id {
+///         scene: "Gameplay".into(),
+///         source: "Webcam",
+///         ..Id::default()
+///     })
+///     .await?;
+/// assert_eq!(3, item_id);
+///
+/// client
+///     .scene_items()
+///     .set_enabled(SetEnabled {
+///         scene: "Gameplay".into(),
+///         item_id,
+///         enabled: false,
+///     })
+///     .await?;
+/// # assert_eq!(
+/// #     Some(&(
+/// #         "SetSceneItemEnabled".to_owned(),
+/// #         json!({"sceneName": "Gameplay", "sceneItemId": 3, "sceneItemEnabled": false}),
+/// #     )),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct SceneItems<'a> {
     pub(super) client: &'a Client,
 }
@@ -22,18 +60,37 @@ pub struct SceneItems<'a> {
 impl<'a> SceneItems<'a> {
     /// Gets a list of all scene items in a scene.
     #[doc(alias = "GetSceneItemList")]
-    pub async fn list(&self, scene: SceneId<'_>) -> Result<Vec<responses::SceneItem>> {
+    pub async fn list(&self, scene: impl Into<SceneId<'_>>) -> Result<Vec<responses::SceneItem>> {
+        let scene = scene.into();
         self.client
             .send_message::<_, responses::SceneItemList>(Request::List { scene })
             .await
             .map(|sil| sil.scene_items)
     }
 
+    /// Gets a list of all scene items in a scene, like [`Self::list`], but skips any items that
+    /// failed to deserialize instead of failing the whole request.
+    #[doc(alias = "GetSceneItemList")]
+    pub async fn list_lenient(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+    ) -> Result<LenientList<responses::SceneItem>> {
+        let scene = scene.into();
+        self.client
+            .send_message::<_, responses::SceneItemList<_>>(Request::List { scene })
+            .await
+            .map(|sil| sil.scene_items)
+    }
+
     /// Basically [`Self::list`], but for groups.
     ///
     /// Using groups at all in OBS is discouraged, as they are very broken under the hood.
     #[doc(alias = "GetGroupSceneItemList")]
-    pub async fn list_group(&self, scene: SceneId<'_>) -> Result<Vec<responses::SceneItem>> {
+    pub async fn list_group(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+    ) -> Result<Vec<responses::SceneItem>> {
+        let scene = scene.into();
         self.client
             .send_message::<_, responses::SceneItemList>(Request::ListGroup { scene })
             .await
@@ -49,6 +106,46 @@ impl<'a> SceneItems<'a> {
             .map(|sii| sii.id)
     }
 
+    /// Searches a scene for a source, including all groups within it, and returns the item's ID
+    /// together with the group it lives in.
+    ///
+    /// In contrast to [`Self::id`], which only looks at the items directly within a scene, this
+    /// walks into groups as well. Items directly within the scene are preferred over items in
+    /// groups. Returns [`None`] if no item of the source exists.
+    pub async fn find_recursive(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        source: &str,
+    ) -> Result<Option<responses::SceneItemLocation>> {
+        let scene = scene.into();
+        let mut pending = vec![(None, self.list(scene).await?)];
+
+        while !pending.is_empty() {
+            let mut groups = Vec::new();
+
+            for (group, items) in pending {
+                if let Some(item) = items.iter().find(|item| item.source_name == source) {
+                    return Ok(Some(responses::SceneItemLocation { group, id: item.id }));
+                }
+
+                groups.extend(
+                    items
+                        .into_iter()
+                        .filter(|item| item.is_group == Some(true))
+                        .map(|item| item.source_name),
+                );
+            }
+
+            pending = Vec::with_capacity(groups.len());
+            for group in groups {
+                let items = self.list_group(SceneId::Name(&group)).await?;
+                pending.push((Some(group), items));
+            }
+        }
+
+        Ok(None)
+    }
+
     /// Gets the source associated with a scene item.
     #[doc(alias = "GetSceneItemSource")]
     pub async fn source(&self, get: Source<'_>) -> Result<source_responses::SourceId> {
@@ -66,7 +163,8 @@ impl<'a> SceneItems<'a> {
 
     /// Removes a scene item from a scene.
     #[doc(alias = "RemoveSceneItem")]
-    pub async fn remove(&self, scene: SceneId<'_>, item_id: i64) -> Result<()> {
+    pub async fn remove(&self, scene: impl Into<SceneId<'_>>, item_id: i64) -> Result<()> {
+        let scene = scene.into();
         self.client
             .send_message(Request::Remove { scene, item_id })
             .await
@@ -85,9 +183,10 @@ impl<'a> SceneItems<'a> {
     #[doc(alias = "GetSceneItemTransform")]
     pub async fn transform(
         &self,
-        scene: SceneId<'_>,
+        scene: impl Into<SceneId<'_>>,
         item_id: i64,
     ) -> Result<responses::SceneItemTransform> {
+        let scene = scene.into();
         self.client
             .send_message::<_, responses::GetSceneItemTransform>(Request::Transform {
                 scene,
@@ -97,6 +196,63 @@ impl<'a> SceneItems<'a> {
             .map(|gsit| gsit.transform)
     }
 
+    /// Gets the base size of a scene item's source, without scaling and cropping applied.
+    ///
+    /// This uses the same request as [`Self::transform`], but only reads the source dimensions
+    /// from the response.
+    #[doc(alias = "GetSceneItemTransform")]
+    pub async fn source_dimensions(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        item_id: i64,
+    ) -> Result<responses::SourceDimensions> {
+        let scene = scene.into();
+        self.client
+            .send_message::<_, responses::GetSourceDimensions>(Request::Transform {
+                scene,
+                item_id,
+            })
+            .await
+            .map(|gsd| gsd.dimensions)
+    }
+
+    /// Gets a list of all scene items in a scene, together with their transform and crop info.
+    ///
+    /// The transforms of all items are queried in a single request batch, which is much faster
+    /// than querying them one by one for scenes with many items.
+    pub async fn list_with_transforms(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+    ) -> Result<Vec<(responses::SceneItem, responses::SceneItemTransform)>> {
+        let scene = scene.into();
+        let items = self.list(scene).await?;
+        if items.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let requests = items
+            .iter()
+            .map(|item| {
+                Request::Transform {
+                    scene,
+                    item_id: item.id,
+                }
+                .into()
+            })
+            .collect::<Vec<RequestType<'_>>>();
+
+        let results = self
+            .client
+            .send_batch(&requests, false, ExecutionType::SerialRealtime)
+            .await?;
+        let transforms = super::batch_responses::<responses::GetSceneItemTransform>(results)?;
+
+        Ok(items
+            .into_iter()
+            .zip(transforms.into_iter().map(|gsit| gsit.transform))
+            .collect())
+    }
+
     /// Sets the transform and crop info of a scene item.
     #[doc(alias = "SetSceneItemTransform")]
     pub async fn set_transform(&self, transform: SetTransform<'_>) -> Result<()> {
@@ -105,9 +261,135 @@ impl<'a> SceneItems<'a> {
             .await
     }
 
+    /// Moves a scene item relative to its current position.
+    ///
+    /// This fetches the current transform, applies the offset and writes the new position back.
+    pub async fn nudge(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        item_id: i64,
+        dx: f32,
+        dy: f32,
+    ) -> Result<()> {
+        let scene = scene.into();
+        let current = self.transform(scene, item_id).await?;
+
+        self.set_transform(SetTransform {
+            scene,
+            item_id,
+            transform: SceneItemTransform {
+                position: Some(Position {
+                    x: Some(current.position_x + dx),
+                    y: Some(current.position_y + dy),
+                }),
+                ..SceneItemTransform::default()
+            },
+        })
+        .await
+    }
+
+    /// Scales a scene item relative to its current scale, on both axis.
+    ///
+    /// This fetches the current transform, multiplies the scale by the factor and writes the new
+    /// scale back.
+    pub async fn scale_by(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        item_id: i64,
+        factor: f32,
+    ) -> Result<()> {
+        let scene = scene.into();
+        let current = self.transform(scene, item_id).await?;
+
+        self.set_transform(SetTransform {
+            scene,
+            item_id,
+            transform: SceneItemTransform {
+                scale: Some(Scale {
+                    x: Some(current.scale_x * factor),
+                    y: Some(current.scale_y * factor),
+                }),
+                ..SceneItemTransform::default()
+            },
+        })
+        .await
+    }
+
+    /// Rotates a scene item clockwise, relative to its current rotation.
+    ///
+    /// This fetches the current transform, adds the degrees and writes the new rotation back. The
+    /// resulting rotation is normalized into the range of `0..360` degrees.
+    pub async fn rotate_by(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        item_id: i64,
+        degrees: f32,
+    ) -> Result<()> {
+        let scene = scene.into();
+        let current = self.transform(scene, item_id).await?;
+
+        self.set_transform(SetTransform {
+            scene,
+            item_id,
+            transform: SceneItemTransform {
+                rotation: Some((current.rotation + degrees).rem_euclid(360.0)),
+                ..SceneItemTransform::default()
+            },
+        })
+        .await
+    }
+
+    /// Plays an animation of a scene item, from its current transform to the animation's target.
+    ///
+    /// All frames of the animation are calculated upfront, based on the current video frame rate,
+    /// and sent as a single request batch that obs-websocket executes in sync with the rendered
+    /// frames. The call returns once the animation finished playing.
+    pub async fn animate(&self, animation: Animation<'_>) -> Result<()> {
+        let video = self.client.config().video_settings().await?;
+        let current = self.transform(animation.scene, animation.item_id).await?;
+
+        let count = animate::frame_count(
+            animation.duration,
+            video.fps_numerator,
+            video.fps_denominator,
+        );
+        let frames = animate::frames(&current, animation.target, animation.easing, count);
+
+        let mut requests = Vec::with_capacity(frames.len() * 2);
+        for transform in frames {
+            if !requests.is_empty() {
+                requests.push(RequestType::from(GeneralRequest::Sleep(Sleep {
+                    frames: Some(1),
+                    ..Sleep::default()
+                })));
+            }
+
+            requests.push(RequestType::from(Request::SetTransform(SetTransform {
+                scene: animation.scene,
+                item_id: animation.item_id,
+                transform,
+            })));
+        }
+
+        self.client
+            .send_batch(&requests, true, ExecutionType::SerialFrame)
+            .await?
+            .into_iter()
+            .try_for_each(|result| super::check_status(result.status))
+    }
+
     /// Gets the enable state of a scene item.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond("GetSceneItemEnabled", json!({"sceneItemEnabled": true}));
+    /// let visible = client.scene_items().enabled("Gameplay", 3).await?;
+    /// assert!(visible);
+    /// # });
+    /// ```
     #[doc(alias = "GetSceneItemEnabled")]
-    pub async fn enabled(&self, scene: SceneId<'_>, item_id: i64) -> Result<bool> {
+    pub async fn enabled(&self, scene: impl Into<SceneId<'_>>, item_id: i64) -> Result<bool> {
+        let scene = scene.into();
         self.client
             .send_message::<_, responses::SceneItemEnabled>(Request::Enabled { scene, item_id })
             .await
@@ -122,7 +404,8 @@ impl<'a> SceneItems<'a> {
 
     /// Gets the lock state of a scene item.
     #[doc(alias = "GetSceneItemLocked")]
-    pub async fn locked(&self, scene: SceneId<'_>, item_id: i64) -> Result<bool> {
+    pub async fn locked(&self, scene: impl Into<SceneId<'_>>, item_id: i64) -> Result<bool> {
+        let scene = scene.into();
         self.client
             .send_message::<_, responses::SceneItemLocked>(Request::Locked { scene, item_id })
             .await
@@ -139,13 +422,132 @@ impl<'a> SceneItems<'a> {
     ///
     /// An index of 0 is at the bottom of the source list in the UI.
     #[doc(alias = "GetSceneItemIndex")]
-    pub async fn index(&self, scene: SceneId<'_>, item_id: i64) -> Result<u32> {
+    pub async fn index(&self, scene: impl Into<SceneId<'_>>, item_id: i64) -> Result<u32> {
+        let scene = scene.into();
         self.client
             .send_message::<_, responses::SceneItemIndex>(Request::Index { scene, item_id })
             .await
             .map(|sii| sii.index)
     }
 
+    /// Sets the lock state of all scene items in a scene at once, including the items within
+    /// any of its groups.
+    ///
+    /// The changes are sent as a single request batch, so the items are locked or unlocked as a
+    /// whole, for example before going live.
+    pub async fn set_locked_all(&self, scene: impl Into<SceneId<'_>>, locked: bool) -> Result<()> {
+        let scene = scene.into();
+        let nested = self.list_nested(scene).await?;
+        let requests = nested
+            .iter()
+            .flat_map(|(group, items)| {
+                let scene = group.as_deref().map_or(scene, SceneId::Name);
+                items.iter().map(move |item| {
+                    Request::SetLocked(SetLocked {
+                        scene,
+                        item_id: item.id,
+                        locked,
+                    })
+                    .into()
+                })
+            })
+            .collect::<Vec<RequestType<'_>>>();
+
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        let results = self
+            .client
+            .send_batch(&requests, false, ExecutionType::SerialRealtime)
+            .await?;
+
+        super::batch_responses::<()>(results).map(drop)
+    }
+
+    /// Gets a list of all currently locked scene items, across all scenes and their groups.
+    ///
+    /// The lock states of each scene's items are queried in a single request batch per scene.
+    pub async fn locked_all(&self) -> Result<Vec<responses::LockedSceneItem>> {
+        let mut locked_items = Vec::new();
+
+        for scene in self.client.scenes().list().await?.scenes {
+            let id = SceneId::from(&scene.id);
+            let nested = self.list_nested(id).await?;
+            let requests = nested
+                .iter()
+                .flat_map(|(group, items)| {
+                    let scene = group.as_deref().map_or(id, SceneId::Name);
+                    items.iter().map(move |item| {
+                        Request::Locked {
+                            scene,
+                            item_id: item.id,
+                        }
+                        .into()
+                    })
+                })
+                .collect::<Vec<RequestType<'_>>>();
+
+            if requests.is_empty() {
+                continue;
+            }
+
+            let results = self
+                .client
+                .send_batch(&requests, false, ExecutionType::SerialRealtime)
+                .await?;
+            let states = super::batch_responses::<responses::SceneItemLocked>(results)?;
+
+            let items = nested.into_iter().flat_map(|(group, items)| {
+                items.into_iter().map(move |item| (group.clone(), item))
+            });
+
+            locked_items.extend(items.zip(states).filter_map(|((group, item), state)| {
+                state.locked.then(|| responses::LockedSceneItem {
+                    scene: scene.id.clone(),
+                    group,
+                    item_id: item.id,
+                    source_name: item.source_name,
+                })
+            }));
+        }
+
+        Ok(locked_items)
+    }
+
+    /// List the items of a scene, and the items of all groups within it, walking into groups the
+    /// same way as [`Self::find_recursive`]. Each list is paired with the name of its group, or
+    /// [`None`] for the items directly within the scene.
+    async fn list_nested(
+        &self,
+        scene: SceneId<'_>,
+    ) -> Result<Vec<(Option<String>, Vec<responses::SceneItem>)>> {
+        let items = self.list(scene).await?;
+        let mut groups = items
+            .iter()
+            .filter(|item| item.is_group == Some(true))
+            .map(|item| item.source_name.clone())
+            .collect::<Vec<_>>();
+        let mut nested = vec![(None, items)];
+
+        while let Some(group) = groups.pop() {
+            if nested.iter().any(|(name, _)| name.as_ref() == Some(&group)) {
+                continue;
+            }
+
+            let items = self.list_group(SceneId::Name(&group)).await?;
+            groups.extend(
+                items
+                    .iter()
+                    .filter(|item| item.is_group == Some(true))
+                    .map(|item| item.source_name.clone()),
+            );
+            nested.push((Some(group), items));
+        }
+
+        Ok(nested)
+    }
+
     /// Sets the index position of a scene item in a scene.
     #[doc(alias = "SetSceneItemIndex")]
     pub async fn set_index(&self, index: SetIndex<'_>) -> Result<()> {
@@ -154,7 +556,12 @@ impl<'a> SceneItems<'a> {
 
     /// Gets the blend mode of a scene item.
     #[doc(alias = "GetSceneItemBlendMode")]
-    pub async fn blend_mode(&self, scene: SceneId<'_>, item_id: i64) -> Result<BlendMode> {
+    pub async fn blend_mode(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        item_id: i64,
+    ) -> Result<BlendMode> {
+        let scene = scene.into();
         self.client
             .send_message::<_, responses::SceneItemBlendMode>(Request::BlendMode { scene, item_id })
             .await
@@ -167,12 +574,80 @@ impl<'a> SceneItems<'a> {
         self.client.send_message(Request::SetBlendMode(mode)).await
     }
 
+    /// Changes several properties of a scene item at once.
+    ///
+    /// Only the requests for the properties set in the update are sent, together in a single
+    /// request batch. The batch stops at the first failing request, so later properties are left
+    /// unchanged in that case.
+    pub async fn update(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        item_id: i64,
+        update: ItemUpdate,
+    ) -> Result<()> {
+        let scene = scene.into();
+        let mut requests = Vec::new();
+
+        if let Some(enabled) = update.enabled {
+            requests.push(Request::SetEnabled(SetEnabled {
+                scene,
+                item_id,
+                enabled,
+            }));
+        }
+        if let Some(locked) = update.locked {
+            requests.push(Request::SetLocked(SetLocked {
+                scene,
+                item_id,
+                locked,
+            }));
+        }
+        if let Some(index) = update.index {
+            requests.push(Request::SetIndex(SetIndex {
+                scene,
+                item_id,
+                index,
+            }));
+        }
+        if let Some(mode) = update.blend_mode {
+            requests.push(Request::SetBlendMode(SetBlendMode {
+                scene,
+                item_id,
+                mode,
+            }));
+        }
+        if let Some(transform) = update.transform {
+            requests.push(Request::SetTransform(SetTransform {
+                scene,
+                item_id,
+                transform,
+            }));
+        }
+
+        if requests.is_empty() {
+            return Ok(());
+        }
+
+        let requests = requests.into_iter().map(Into::into).collect::<Vec<_>>();
+
+        self.client
+            .send_batch(&requests, true, ExecutionType::SerialRealtime)
+            .await?
+            .into_iter()
+            .try_for_each(|result| super::check_status(result.status))
+    }
+
     /// Gets private scene item settings.
     #[doc(alias = "GetSceneItemPrivateSettings")]
-    pub async fn private_settings<T>(&self, scene: SceneId<'_>, item_id: i64) -> Result<T>
+    pub async fn private_settings<T>(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        item_id: i64,
+    ) -> Result<T>
     where
         T: DeserializeOwned,
     {
+        let scene = scene.into();
         self.client
             .send_message::<_, responses::SceneItemSettings<T>>(Request::PrivateSettings {
                 scene,
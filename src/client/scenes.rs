@@ -8,12 +8,61 @@ use crate::{
 };
 
 /// API functions related to scenes.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("GetGroupList", json!({"groups": ["Overlay"]}));
+/// # stub.respond("SetCurrentProgramScene", json!(null));
+/// let groups = client.scenes().list_groups().await?;
+/// assert_eq!(vec!["Overlay"], groups);
+///
+/// client
+///     .scenes()
+///     .set_current_program_scene("Gameplay")
+///     .await?;
+/// # assert_eq!(
+/// #     Some(&("SetCurrentProgramScene".to_owned(), json!({"sceneName": "Gameplay"}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Scenes<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Scenes<'a> {
+impl Scenes<'_> {
     /// Gets an array of all scenes in OBS.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetSceneList",
+    /// #     json!({
+    /// #         "currentProgramSceneName": "Gameplay",
+    /// #         "currentProgramSceneUuid": "00000000-0000-0000-0000-000000000001",
+    /// #         "scenes": [
+    /// #             {
+    /// #                 "sceneName": "Intro",
+    /// #                 "sceneUuid": "00000000-0000-0000-0000-000000000002",
+    /// #                 "sceneIndex": 1,
+    /// #             },
+    /// #             {
+    /// #                 "sceneName": "Gameplay",
+    /// #                 "sceneUuid": "00000000-0000-0000-0000-000000000001",
+    /// #                 "sceneIndex": 0,
+    /// #             },
+    /// #         ],
+    /// #     }),
+    /// # );
+    /// let scenes = client.scenes().list().await?;
+    /// let names = scenes
+    ///     .scenes
+    ///     .iter()
+    ///     .map(|scene| scene.id.name.as_str())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(vec!["Intro", "Gameplay"], names);
+    /// # });
+    /// ```
     #[doc(alias = "GetSceneList")]
     pub async fn list(&self) -> Result<responses::Scenes> {
         self.client.send_message(Request::List).await
@@ -69,13 +118,26 @@ impl<'a> Scenes<'a> {
 
     /// Sets the name of a scene (rename).
     #[doc(alias = "SetSceneName")]
-    pub async fn set_name(&self, scene: SceneId<'_>, new_name: &str) -> Result<()> {
+    pub async fn set_name(&self, scene: impl Into<SceneId<'_>>, new_name: &str) -> Result<()> {
+        let scene = scene.into();
         self.client
             .send_message(Request::SetName { scene, new_name })
             .await
     }
 
     /// Creates a new scene in OBS.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond("CreateScene", json!({"sceneUuid": "00000000-0000-0000-0000-000000000001"}));
+    /// let uuid = client.scenes().create("Outro").await?;
+    /// println!("created scene with UUID {uuid}");
+    /// # assert_eq!(
+    /// #     Some(&("CreateScene".to_owned(), json!({"sceneName": "Outro"}))),
+    /// #     stub.received().last(),
+    /// # );
+    /// # });
+    /// ```
     #[doc(alias = "CreateScene")]
     pub async fn create(&self, name: &str) -> Result<Uuid> {
         self.client
@@ -86,7 +148,8 @@ impl<'a> Scenes<'a> {
 
     /// Removes a scene from OBS.
     #[doc(alias = "RemoveScene")]
-    pub async fn remove(&self, scene: SceneId<'_>) -> Result<()> {
+    pub async fn remove(&self, scene: impl Into<SceneId<'_>>) -> Result<()> {
+        let scene = scene.into();
         self.client.send_message(Request::Remove { scene }).await
     }
 
@@ -94,8 +157,9 @@ impl<'a> Scenes<'a> {
     #[doc(alias = "GetSceneSceneTransitionOverride")]
     pub async fn transition_override(
         &self,
-        scene: SceneId<'_>,
+        scene: impl Into<SceneId<'_>>,
     ) -> Result<responses::SceneTransitionOverride> {
+        let scene = scene.into();
         self.client
             .send_message(Request::TransitionOverride { scene })
             .await
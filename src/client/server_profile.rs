@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+
+use crate::responses::general::Version;
+
+/// Kind of server on the other side of the connection, that decides which quirks of the server
+/// are worked around.
+///
+/// Besides the obs-websocket plugin of OBS Studio, there are hosted services and tools that
+/// emulate the obs-websocket protocol, but only implement parts of it.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ServerProfile {
+    /// The obs-websocket plugin of OBS Studio, with all version checks in place.
+    #[default]
+    Standard,
+    /// A server that only emulates obs-websocket, like hosted cloud OBS services.
+    ///
+    /// The OBS Studio version isn't checked, as emulators report versions of their own choice.
+    /// Requests that aren't part of the server's list of available requests fail right away with
+    /// an [`Error::UnsupportedRequest`](crate::error::Error::UnsupportedRequest), instead of
+    /// being sent to the server.
+    Emulator,
+}
+
+impl ServerProfile {
+    /// Requests that are known to the server, if sending any other request should be refused.
+    pub(super) fn available_requests(self, version: &Version) -> Option<HashSet<String>> {
+        (self == Self::Emulator && !version.available_requests.is_empty())
+            .then(|| version.available_requests.iter().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(available_requests: &[&str]) -> Version {
+        Version {
+            obs_version: semver::Version::new(31, 0, 0),
+            obs_web_socket_version: semver::Version::new(5, 5, 0),
+            rpc_version: 1,
+            available_requests: available_requests.iter().map(|&r| r.to_owned()).collect(),
+            supported_image_formats: Vec::new(),
+            platform: "mock".to_owned(),
+            platform_description: String::new(),
+        }
+    }
+
+    #[test]
+    fn available_requests() {
+        let emulator = version(&["GetStats"]);
+
+        assert_eq!(None, ServerProfile::Standard.available_requests(&emulator));
+        assert_eq!(
+            None,
+            ServerProfile::Emulator.available_requests(&version(&[]))
+        );
+        assert_eq!(
+            Some(HashSet::from(["GetStats".to_owned()])),
+            ServerProfile::Emulator.available_requests(&emulator)
+        );
+    }
+}
@@ -1,19 +1,43 @@
+use std::{collections::HashMap, path::Path};
+
+use base64::engine::{general_purpose, Engine};
+
 use super::Client;
 use crate::{
     error::Result,
-    requests::sources::{Request, SaveScreenshot, SourceId, TakeScreenshot},
+    requests::{
+        scenes::SceneId,
+        sources::{
+            Request, SaveScreenshot, Screenshot, ScreenshotOptions, SourceId, TakeScreenshot,
+        },
+        ExecutionType, RequestType,
+    },
     responses::sources as responses,
 };
 
 /// API functions related to sources.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("GetSourceActive", json!({"videoActive": true, "videoShowing": false}));
+/// let state = client.sources().active("Webcam").await?;
+/// assert!(state.active);
+/// assert!(!state.showing);
+/// # assert_eq!(
+/// #     Some(&("GetSourceActive".to_owned(), json!({"sourceName": "Webcam"}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Sources<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Sources<'a> {
+impl Sources<'_> {
     /// Gets the active and show state of a source.
     #[doc(alias = "GetSourceActive")]
-    pub async fn active(&self, source: SourceId<'_>) -> Result<responses::SourceActive> {
+    pub async fn active(&self, source: impl Into<SourceId<'_>>) -> Result<responses::SourceActive> {
+        let source = source.into();
         self.client.send_message(Request::Active { source }).await
     }
 
@@ -23,6 +47,28 @@ impl<'a> Sources<'a> {
     /// "scale to inner", meaning the smallest ratio will be used and the aspect ratio of the
     /// original resolution is kept. If [`TakeScreenshot::width`] and [`TakeScreenshot::height`] are
     /// not specified, the compressed image will use the full resolution of the source.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetSourceScreenshot",
+    /// #     json!({"imageData": "data:image/png;base64,iVBORw0KGgo="}),
+    /// # );
+    /// use obws::requests::sources::TakeScreenshot;
+    ///
+    /// let image = client
+    ///     .sources()
+    ///     .take_screenshot(TakeScreenshot {
+    ///         source: "Webcam".into(),
+    ///         format: "png",
+    ///         width: Some(320),
+    ///         height: None,
+    ///         compression_quality: None,
+    ///     })
+    ///     .await?;
+    /// assert!(image.starts_with("data:image/png;base64,"));
+    /// # });
+    /// ```
     #[doc(alias = "GetSourceScreenshot")]
     pub async fn take_screenshot(&self, settings: TakeScreenshot<'_>) -> Result<String> {
         self.client
@@ -43,4 +89,123 @@ impl<'a> Sources<'a> {
             .send_message(Request::SaveScreenshot(settings))
             .await
     }
+
+    /// Takes a screenshot of a source with typed settings, and returns the decoded image.
+    ///
+    /// In contrast to [`Self::take_screenshot`], the settings are validated before sending the
+    /// request, failing with an [`Error::UnsupportedImageFormat`] or
+    /// [`Error::InvalidCompressionQuality`].
+    ///
+    /// [`Error::UnsupportedImageFormat`]: crate::error::Error::UnsupportedImageFormat
+    /// [`Error::InvalidCompressionQuality`]: crate::error::Error::InvalidCompressionQuality
+    #[doc(alias = "GetSourceScreenshot")]
+    pub async fn screenshot(&self, settings: Screenshot<'_>) -> Result<Vec<u8>> {
+        settings.validate(self.supported_image_formats())?;
+
+        let image = self.take_screenshot(settings.take()).await?;
+        decode_image(&image)
+    }
+
+    /// Takes a screenshot of a source with typed settings, and puts the background behind its
+    /// transparent areas.
+    ///
+    /// This gives an accurate preview of how a transparent source, like an overlay, looks on top
+    /// of other content. Transparency is only kept by formats that
+    /// [support alpha](crate::requests::sources::ImageFormat::supports_alpha), so use
+    /// [`Screenshot::transparent`] to create the settings. With other formats, the background
+    /// stays hidden behind the opaque image.
+    ///
+    /// Fails with an [`Error::ReadImage`] if the image data can't be decoded.
+    ///
+    /// [`Error::ReadImage`]: crate::error::Error::ReadImage
+    #[cfg(feature = "image")]
+    #[doc(alias = "GetSourceScreenshot")]
+    pub async fn screenshot_composited(
+        &self,
+        settings: Screenshot<'_>,
+        background: crate::requests::sources::Background,
+    ) -> Result<image::RgbImage> {
+        let data = self.screenshot(settings).await?;
+        let image = image::load_from_memory(&data)
+            .map_err(crate::error::ReadImageError)?
+            .into_rgba8();
+
+        Ok(background.composite(&image))
+    }
+
+    /// Saves a screenshot of a source with typed settings to the file system.
+    ///
+    /// The settings are validated before sending the request, the same way as for
+    /// [`Self::screenshot`].
+    #[doc(alias = "SaveSourceScreenshot")]
+    pub async fn save_screenshot_as(&self, settings: Screenshot<'_>, path: &Path) -> Result<()> {
+        settings.validate(self.supported_image_formats())?;
+        self.save_screenshot(settings.save(path)).await
+    }
+
+    fn supported_image_formats(&self) -> &[String] {
+        self.client
+            .supported_image_formats
+            .get()
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Takes screenshots of all sources in a scene at once, and returns the decoded images keyed
+    /// by source name.
+    ///
+    /// The screenshots are requested in a single request batch, that obs-websocket processes in
+    /// parallel, which is much faster than taking them one by one. Sources that can't be
+    /// screenshotted, like audio-only inputs, are left out.
+    pub async fn screenshot_scene(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        format: &str,
+        options: ScreenshotOptions,
+    ) -> Result<HashMap<String, Vec<u8>>> {
+        let scene = scene.into();
+        let items = self.client.scene_items().list(scene).await?;
+        if items.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let requests = items
+            .iter()
+            .map(|item| {
+                Request::TakeScreenshot(TakeScreenshot {
+                    source: SourceId::Name(&item.source_name),
+                    format,
+                    width: options.width,
+                    height: options.height,
+                    compression_quality: options.compression_quality,
+                })
+                .into()
+            })
+            .collect::<Vec<RequestType<'_>>>();
+
+        let results = self
+            .client
+            .send_batch(&requests, false, ExecutionType::Parallel)
+            .await?;
+
+        let mut screenshots = HashMap::with_capacity(items.len());
+        for (item, result) in items.into_iter().zip(results) {
+            let Ok(image) = super::batch_response::<responses::ImageData>(result) else {
+                continue;
+            };
+
+            screenshots.insert(item.source_name, decode_image(&image.image_data)?);
+        }
+
+        Ok(screenshots)
+    }
+}
+
+/// Decode image data, that is either plain Base64 or a `data:` URI with Base64 content.
+fn decode_image(data: &str) -> Result<Vec<u8>> {
+    let data = data.split_once(',').map_or(data, |(_, data)| data);
+
+    general_purpose::STANDARD
+        .decode(data)
+        .map_err(crate::error::DecodeImageError)
+        .map_err(Into::into)
 }
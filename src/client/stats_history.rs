@@ -0,0 +1,202 @@
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+use super::Client;
+use crate::{error::Result, responses::general::Stats};
+
+/// Collector of [`Stats`] samples over a sliding time window, as created by
+/// [`crate::client::General::stats_history`].
+///
+/// Samples are only taken while [`Self::run`] is polled, or when calling [`Self::sample`]
+/// manually. The collected samples and their [`StatsSummary`] can be read at any time in between.
+pub struct StatsHistory<'a> {
+    client: &'a Client,
+    interval: Duration,
+    window: Duration,
+    samples: Mutex<VecDeque<StatsSample>>,
+}
+
+/// A single [`Stats`] sample, together with the point in time it was taken.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct StatsSample {
+    /// Point in time when the stats were received.
+    pub at: Instant,
+    /// The stats as reported by obs-websocket.
+    pub stats: Stats,
+}
+
+/// Aggregates over all samples of a [`StatsHistory`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[non_exhaustive]
+pub struct StatsSummary {
+    /// Number of samples the summary is based on.
+    pub samples: usize,
+    /// Time between the oldest and the newest sample.
+    pub span: Duration,
+    /// Average CPU usage in percent.
+    pub average_cpu_usage: f64,
+    /// Average time in milliseconds, that OBS took to render a frame.
+    pub average_frame_render_time: f64,
+    /// Frames skipped by the render thread per minute.
+    pub render_skipped_frames_per_minute: f64,
+    /// Frames skipped by the output thread per minute.
+    pub output_skipped_frames_per_minute: f64,
+    /// Change of the memory usage in MiB per minute. Negative if memory was freed.
+    pub memory_growth_per_minute: f64,
+}
+
+impl<'a> StatsHistory<'a> {
+    pub(super) fn new(client: &'a Client, interval: Duration, window: Duration) -> Self {
+        Self {
+            client,
+            interval,
+            window,
+            samples: Mutex::default(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, VecDeque<StatsSample>> {
+        self.samples.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Take samples in the configured interval, until requesting the stats fails, for example
+    /// because the connection to obs-websocket was closed.
+    ///
+    /// This function doesn't return on its own while the connection is alive, so it's usually
+    /// polled alongside the code that reads the collected stats.
+    pub async fn run(&self) -> Result<()> {
+        loop {
+            self.sample().await?;
+            self.client.clock().sleep(self.interval).await;
+        }
+    }
+
+    /// Take a single sample right away, dropping any samples that fell out of the window.
+    pub async fn sample(&self) -> Result<()> {
+        let stats = self.client.general().stats().await?;
+        let at = self.client.clock().now();
+
+        push(&mut self.lock(), StatsSample { at, stats }, self.window);
+        Ok(())
+    }
+
+    /// All samples within the window, from oldest to newest.
+    #[must_use]
+    pub fn samples(&self) -> Vec<StatsSample> {
+        self.lock().iter().cloned().collect()
+    }
+
+    /// Aggregates over all samples within the window, or [`None`] if no sample was taken yet.
+    #[must_use]
+    pub fn summary(&self) -> Option<StatsSummary> {
+        summarize(&self.lock())
+    }
+}
+
+/// Add a sample to the history, and remove all samples that are older than the window, relative
+/// to the new sample.
+fn push(samples: &mut VecDeque<StatsSample>, sample: StatsSample, window: Duration) {
+    while samples
+        .front()
+        .is_some_and(|oldest| sample.at.saturating_duration_since(oldest.at) > window)
+    {
+        samples.pop_front();
+    }
+
+    samples.push_back(sample);
+}
+
+fn summarize(samples: &VecDeque<StatsSample>) -> Option<StatsSummary> {
+    let (first, last) = (samples.front()?, samples.back()?);
+    let span = last.at.saturating_duration_since(first.at);
+
+    #[allow(clippy::cast_precision_loss)]
+    let count = samples.len() as f64;
+    let average =
+        |value: fn(&Stats) -> f64| samples.iter().map(|s| value(&s.stats)).sum::<f64>() / count;
+
+    let minutes = span.as_secs_f64() / 60.0;
+    let per_minute = |delta: f64| if minutes > 0.0 { delta / minutes } else { 0.0 };
+    // Counters start over if OBS restarted in between, which must not count as negative skips.
+    let skipped = |value: fn(&Stats) -> u32| {
+        f64::from(value(&last.stats).saturating_sub(value(&first.stats)))
+    };
+
+    Some(StatsSummary {
+        samples: samples.len(),
+        span,
+        average_cpu_usage: average(|s| s.cpu_usage),
+        average_frame_render_time: average(|s| {
+            s.average_frame_render_time.as_seconds_f64() * 1000.0
+        }),
+        render_skipped_frames_per_minute: per_minute(skipped(|s| s.render_skipped_frames)),
+        output_skipped_frames_per_minute: per_minute(skipped(|s| s.output_skipped_frames)),
+        memory_growth_per_minute: per_minute(
+            last.stats.memory_usage.as_mebibytes() - first.stats.memory_usage.as_mebibytes(),
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Bytes;
+
+    fn sample(at: Instant, memory_usage: f64, render_skipped_frames: u32) -> StatsSample {
+        StatsSample {
+            at,
+            stats: Stats {
+                cpu_usage: memory_usage / 10.0,
+                memory_usage: Bytes::from_mebibytes(memory_usage),
+                average_frame_render_time: time::Duration::milliseconds(2),
+                render_skipped_frames,
+                ..Stats::default()
+            },
+        }
+    }
+
+    #[test]
+    fn window() {
+        let start = Instant::now();
+        let mut samples = VecDeque::new();
+
+        for secs in 0..5 {
+            push(
+                &mut samples,
+                sample(start + Duration::from_secs(secs * 30), 0.0, 0),
+                Duration::from_secs(60),
+            );
+        }
+
+        assert_eq!(3, samples.len());
+        assert_eq!(start + Duration::from_secs(60), samples[0].at);
+    }
+
+    #[test]
+    fn summary() {
+        let start = Instant::now();
+        assert_eq!(None, summarize(&VecDeque::new()));
+
+        let single = summarize(&VecDeque::from([sample(start, 100.0, 5)])).unwrap();
+        assert_eq!(1, single.samples);
+        assert!(single.render_skipped_frames_per_minute.abs() < f64::EPSILON);
+
+        let summary = summarize(&VecDeque::from([
+            sample(start, 100.0, 10),
+            sample(start + Duration::from_secs(60), 110.0, 40),
+            sample(start + Duration::from_secs(120), 120.0, 70),
+        ]))
+        .unwrap();
+
+        assert_eq!(3, summary.samples);
+        assert_eq!(Duration::from_secs(120), summary.span);
+        assert!((summary.average_cpu_usage - 11.0).abs() < 1e-9);
+        assert!((summary.average_frame_render_time - 2.0).abs() < 1e-9);
+        assert!((summary.render_skipped_frames_per_minute - 30.0).abs() < 1e-9);
+        assert!((summary.memory_growth_per_minute - 10.0).abs() < 1e-9);
+    }
+}
@@ -1,12 +1,42 @@
 use super::Client;
-use crate::{error::Result, requests::streaming::Request, responses::streaming as responses};
+use crate::{
+    error::{Error, Result},
+    requests::streaming::{Request, StartChecked, MAX_CAPTION_LENGTH},
+    responses::streaming as responses,
+};
 
 /// API functions related to streaming.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond(
+/// #     "GetStreamStatus",
+/// #     json!({
+/// #         "outputActive": true,
+/// #         "outputReconnecting": false,
+/// #         "outputTimecode": "00:10:00.000",
+/// #         "outputDuration": 600000,
+/// #         "outputCongestion": 0.0,
+/// #         "outputBytes": 1024,
+/// #         "outputSkippedFrames": 0,
+/// #         "outputTotalFrames": 36000,
+/// #     }),
+/// # );
+/// # stub.respond("SendStreamCaption", json!(null));
+/// if client.streaming().status().await?.active {
+///     client.streaming().send_caption("Hello, chat!").await?;
+/// }
+/// # assert_eq!(
+/// #     Some(&("SendStreamCaption".to_owned(), json!({"captionText": "Hello, chat!"}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Streaming<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Streaming<'a> {
+impl Streaming<'_> {
     /// Gets the status of the stream output.
     #[doc(alias = "GetStreamStatus")]
     pub async fn status(&self) -> Result<responses::StreamStatus> {
@@ -14,6 +44,14 @@ impl<'a> Streaming<'a> {
     }
 
     /// Toggles the status of the stream output.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond("ToggleStream", json!({"outputActive": true}));
+    /// let live = client.streaming().toggle().await?;
+    /// assert!(live);
+    /// # });
+    /// ```
     #[doc(alias = "ToggleStream")]
     pub async fn toggle(&self) -> Result<bool> {
         self.client
@@ -28,15 +66,112 @@ impl<'a> Streaming<'a> {
         self.client.send_message(Request::StartStream).await
     }
 
+    /// Starts the stream output, but only after verifying that all preconditions are met.
+    ///
+    /// Instead of blindly starting the stream, the current state of OBS is checked first, like
+    /// the active profile or whether a recording is running. If any check fails, the stream is not
+    /// started and all failed checks are listed in the report.
+    pub async fn start_checked(&self, checks: StartChecked<'_>) -> Result<responses::StartReport> {
+        let mut failed_checks = Vec::new();
+
+        if self.status().await?.active {
+            failed_checks.push(responses::FailedCheck::AlreadyStreaming);
+        }
+
+        if let Some(expected) = checks.profile {
+            let actual = self.client.profiles().current().await?;
+            if actual != expected {
+                failed_checks.push(responses::FailedCheck::WrongProfile {
+                    expected: expected.to_owned(),
+                    actual,
+                });
+            }
+        }
+
+        if let Some(expected) = checks.scene_collection {
+            let actual = self.client.scene_collections().current().await?;
+            if actual != expected {
+                failed_checks.push(responses::FailedCheck::WrongSceneCollection {
+                    expected: expected.to_owned(),
+                    actual,
+                });
+            }
+        }
+
+        let service = self
+            .client
+            .config()
+            .stream_service_settings::<serde_json::Value>()
+            .await?;
+        let server = service.settings["server"].as_str().unwrap_or_default();
+        if service.r#type.is_empty() || server.trim().is_empty() {
+            failed_checks.push(responses::FailedCheck::StreamServiceNotConfigured);
+        }
+
+        if checks.virtual_cam_off && self.client.virtual_cam().status().await? {
+            failed_checks.push(responses::FailedCheck::VirtualCamActive);
+        }
+
+        if checks.not_recording && self.client.recording().status().await?.active {
+            failed_checks.push(responses::FailedCheck::RecordingActive);
+        }
+
+        let started = failed_checks.is_empty();
+        if started {
+            self.start().await?;
+        }
+
+        Ok(responses::StartReport {
+            started,
+            failed_checks,
+        })
+    }
+
     /// Stops the stream output.
     #[doc(alias = "StopStream")]
     pub async fn stop(&self) -> Result<()> {
         self.client.send_message(Request::StopStream).await
     }
 
+    /// Gets all streaming outputs together with their status, like additional multitrack or WHIP
+    /// outputs, besides the primary stream output.
+    ///
+    /// Streaming outputs are all outputs, that send data to a service. Each of them can be
+    /// controlled individually by name, with [`Outputs::start`](super::Outputs::start),
+    /// [`Outputs::stop`](super::Outputs::stop) and [`Outputs::status`](super::Outputs::status).
+    pub async fn outputs(&self) -> Result<Vec<responses::StreamOutput>> {
+        let mut outputs = Vec::new();
+
+        for output in self.client.outputs().list().await? {
+            if !output.flags.service {
+                continue;
+            }
+
+            let status = self.client.outputs().status(&output.name).await?;
+            outputs.push(responses::StreamOutput {
+                name: output.name,
+                kind: output.kind,
+                status,
+            });
+        }
+
+        Ok(outputs)
+    }
+
     /// Sends CEA-608 caption text over the stream output.
+    ///
+    /// Captions longer than [`MAX_CAPTION_LENGTH`] characters can't be shown at once, and are
+    /// refused with an [`Error::CaptionTooLong`] before sending the request.
     #[doc(alias = "SendStreamCaption")]
     pub async fn send_caption(&self, caption_text: &str) -> Result<()> {
+        let length = caption_text.chars().count();
+        if length > MAX_CAPTION_LENGTH {
+            return Err(Error::CaptionTooLong {
+                length,
+                limit: MAX_CAPTION_LENGTH,
+            });
+        }
+
         self.client
             .send_message(Request::SendStreamCaption { caption_text })
             .await
@@ -0,0 +1,30 @@
+use std::{future::Future, time::Duration};
+
+tokio::task_local! {
+    static TIMEOUT: Duration;
+}
+
+/// Run the future, waiting at most the given duration for the response of each request within
+/// it.
+///
+/// This overrides the global [`ConnectConfig::request_timeout`](super::ConnectConfig), for
+/// example to give up early on requests from a UI, or to wait longer for slow ones:
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use obws::{client, Client};
+///
+/// # async fn list(client: &Client) -> obws::error::Result<()> {
+/// let scenes = client::with_timeout(Duration::from_secs(1), client.scenes().list()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_timeout<F: Future>(timeout: Duration, future: F) -> F::Output {
+    TIMEOUT.scope(timeout, future).await
+}
+
+/// The request timeout of the current task, as set by [`with_timeout`].
+pub(super) fn current() -> Option<Duration> {
+    TIMEOUT.try_with(|timeout| *timeout).ok()
+}
@@ -2,14 +2,41 @@ use serde::Serialize;
 use time::Duration;
 
 use super::Client;
-use crate::{error::Result, requests::transitions::Request, responses::transitions as responses};
+use crate::{
+    common::ObwsDuration,
+    error::{Error, Result},
+    requests::{
+        custom::transitions::{BuiltinTransition, TransitionSettings, TYPE_FADE},
+        transitions::Request,
+        ExecutionType, RequestType,
+    },
+    responses::transitions as responses,
+};
 
 /// API functions related to transitions.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond(
+/// #     "GetTransitionKindList",
+/// #     json!({"transitionKinds": ["cut_transition", "fade_transition"]}),
+/// # );
+/// # stub.respond("SetCurrentSceneTransition", json!(null));
+/// let kinds = client.transitions().list_kinds().await?;
+/// assert_eq!(vec!["cut_transition", "fade_transition"], kinds);
+///
+/// client.transitions().set_current("Fade").await?;
+/// # assert_eq!(
+/// #     Some(&("SetCurrentSceneTransition".to_owned(), json!({"transitionName": "Fade"}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Transitions<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> Transitions<'a> {
+impl Transitions<'_> {
     /// Gets an array of all available transition kinds.
     #[doc(alias = "GetTransitionKindList")]
     pub async fn list_kinds(&self) -> Result<Vec<String>> {
@@ -47,13 +74,51 @@ impl<'a> Transitions<'a> {
     }
 
     /// Sets the duration of the current scene transition, if it is not fixed.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond("SetCurrentSceneTransitionDuration", json!(null));
+    /// use std::time::Duration;
+    ///
+    /// client
+    ///     .transitions()
+    ///     .set_current_duration(Duration::from_millis(500))
+    ///     .await?;
+    /// # assert_eq!(
+    /// #     Some(&(
+    /// #         "SetCurrentSceneTransitionDuration".to_owned(),
+    /// #         json!({"transitionDuration": 500}),
+    /// #     )),
+    /// #     stub.received().last(),
+    /// # );
+    /// # });
+    /// ```
     #[doc(alias = "SetCurrentSceneTransitionDuration")]
-    pub async fn set_current_duration(&self, duration: Duration) -> Result<()> {
+    pub async fn set_current_duration(&self, duration: impl Into<ObwsDuration>) -> Result<()> {
+        let duration = Duration::from(duration.into());
         self.client
             .send_message(Request::SetCurrentSceneTransitionDuration { duration })
             .await
     }
 
+    /// Makes the fade transition the current scene transition, and sets its duration.
+    ///
+    /// The transition is looked up by its kind, so it's found regardless of the language OBS runs
+    /// in. OBS always blends linearly and has no setting for the easing curve of a fade, so only
+    /// the duration can be configured.
+    pub async fn configure_fade(&self, duration: impl Into<ObwsDuration>) -> Result<()> {
+        let fade = self
+            .list()
+            .await?
+            .transitions
+            .into_iter()
+            .find(|transition| transition.kind == TYPE_FADE)
+            .ok_or_else(|| Error::UnknownTransitionKind(TYPE_FADE.to_owned()))?;
+
+        self.set_current(&fade.id.name).await?;
+        self.set_current_duration(duration).await
+    }
+
     /// Sets the settings of the current scene transition.
     #[doc(alias = "SetCurrentSceneTransitionSettings")]
     pub async fn set_current_settings<T>(&self, settings: T, overlay: Option<bool>) -> Result<()>
@@ -69,6 +134,38 @@ impl<'a> Transitions<'a> {
             .await
     }
 
+    /// Gets the settings of the current scene transition, parsed into their typed form if the
+    /// transition is built into OBS, or [`None`] for any other transition kind.
+    #[doc(alias = "GetCurrentSceneTransition")]
+    pub async fn current_settings_typed(&self) -> Result<Option<TransitionSettings>> {
+        let current = self.current().await?;
+
+        TransitionSettings::parse(&current.kind, current.settings)
+            .map_err(|e| crate::error::DeserializeResponseError(e).into())
+    }
+
+    /// Sets the settings of the current scene transition, like [`Self::set_current_settings`],
+    /// with the typed settings of one of the transitions built into OBS.
+    ///
+    /// The settings are only applied, if the current transition is of the matching kind.
+    /// Otherwise, an [`Error::TransitionKindMismatch`] is returned. The settings are merged into
+    /// the existing ones, so any settings that aren't covered by the typed form are kept.
+    #[doc(alias = "SetCurrentSceneTransitionSettings")]
+    pub async fn set_current_settings_typed<T>(&self, settings: T) -> Result<()>
+    where
+        T: BuiltinTransition,
+    {
+        let current = self.current().await?;
+        if current.kind != T::KIND {
+            return Err(Error::TransitionKindMismatch {
+                expected: T::KIND,
+                actual: current.kind,
+            });
+        }
+
+        self.set_current_settings(settings, None).await
+    }
+
     /// Gets the cursor position of the current scene transition.
     ///
     /// **Note:** `transitionCursor` will return `1.0` when the transition is inactive.
@@ -91,6 +188,32 @@ impl<'a> Transitions<'a> {
             .await
     }
 
+    /// Triggers the studio mode transition with the given scene transition, optionally with a
+    /// different duration.
+    ///
+    /// The transition stays the current scene transition afterwards. All changes are sent as a
+    /// single request batch, so the transition is only triggered if it could be set up.
+    pub async fn trigger_studio_transition_with(
+        &self,
+        transition: &str,
+        duration: Option<ObwsDuration>,
+    ) -> Result<()> {
+        let mut requests = vec![RequestType::from(Request::SetCurrentSceneTransition {
+            name: transition,
+        })];
+        if let Some(duration) = duration.map(Duration::from) {
+            requests.push(Request::SetCurrentSceneTransitionDuration { duration }.into());
+        }
+        requests.push(Request::TriggerStudioModeTransition.into());
+
+        let results = self
+            .client
+            .send_batch(&requests, true, ExecutionType::SerialRealtime)
+            .await?;
+
+        super::batch_responses::<()>(results).map(drop)
+    }
+
     /// Sets the position of the T-Bar.
     ///
     /// **Very important note:** This will be deprecated and replaced in a future version of
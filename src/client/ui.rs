@@ -1,17 +1,34 @@
 use super::Client;
 use crate::{
-    error::Result,
+    error::{Error, Result},
     requests::{
         inputs::InputId,
+        scenes::Request as ScenesRequest,
+        sources::SourceId,
         ui::{
-            OpenSourceProjector, OpenSourceProjectorInternal, OpenVideoMixProjector,
+            Location, OpenSourceProjector, OpenSourceProjectorInternal, OpenVideoMixProjector,
             OpenVideoMixProjectorInternal, Request,
         },
+        ExecutionType,
     },
-    responses::ui as responses,
+    responses::{scenes as scenes_responses, ui as responses, RequestBatchResult},
 };
 
 /// API functions related to the user interface.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("GetStudioModeEnabled", json!({"studioModeEnabled": false}));
+/// # stub.respond("SetStudioModeEnabled", json!(null));
+/// if !client.ui().studio_mode_enabled().await? {
+///     client.ui().set_studio_mode_enabled(true).await?;
+/// }
+/// # assert_eq!(
+/// #     Some(&("SetStudioModeEnabled".to_owned(), json!({"studioModeEnabled": true}))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct Ui<'a> {
     pub(super) client: &'a Client,
 }
@@ -36,9 +53,49 @@ impl<'a> Ui<'a> {
             .await
     }
 
+    /// Toggles the studio mode, returning the new state.
+    pub async fn toggle_studio_mode(&self) -> Result<bool> {
+        let enabled = !self.studio_mode_enabled().await?;
+        self.set_studio_mode_enabled(enabled).await?;
+        Ok(enabled)
+    }
+
+    /// Gets whether studio mode is enabled, together with the current program and preview scene.
+    ///
+    /// All values are queried in a single request batch, so they are consistent with each other.
+    pub async fn studio_state(&self) -> Result<responses::StudioState> {
+        let requests = [
+            Request::GetStudioModeEnabled.into(),
+            ScenesRequest::CurrentProgramScene.into(),
+            ScenesRequest::CurrentPreviewScene.into(),
+        ];
+
+        let results = self
+            .client
+            .send_batch(&requests, false, ExecutionType::SerialRealtime)
+            .await?;
+        let Ok([enabled, program, preview]) = <[RequestBatchResult; 3]>::try_from(results) else {
+            return Err(Error::UnexpectedResponse);
+        };
+
+        let enabled = super::batch_response::<responses::StudioModeEnabled>(enabled)?.enabled;
+
+        Ok(responses::StudioState {
+            enabled,
+            program_scene: super::batch_response::<scenes_responses::CurrentProgramScene>(program)?
+                .id,
+            preview_scene: if enabled {
+                Some(super::batch_response::<scenes_responses::CurrentPreviewScene>(preview)?.id)
+            } else {
+                None
+            },
+        })
+    }
+
     /// Opens the properties dialog of an input.
     #[doc(alias = "OpenInputPropertiesDialog")]
-    pub async fn open_properties_dialog(&self, input: InputId<'_>) -> Result<()> {
+    pub async fn open_properties_dialog(&self, input: impl Into<InputId<'_>>) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::OpenInputPropertiesDialog { input })
             .await
@@ -46,7 +103,8 @@ impl<'a> Ui<'a> {
 
     /// Opens the filters dialog of an input.
     #[doc(alias = "OpenInputFiltersDialog")]
-    pub async fn open_filters_dialog(&self, input: InputId<'_>) -> Result<()> {
+    pub async fn open_filters_dialog(&self, input: impl Into<InputId<'_>>) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::OpenInputFiltersDialog { input })
             .await
@@ -54,13 +112,35 @@ impl<'a> Ui<'a> {
 
     /// Opens the interact dialog of an input.
     #[doc(alias = "OpenInputInteractDialog")]
-    pub async fn open_interact_dialog(&self, input: InputId<'_>) -> Result<()> {
+    pub async fn open_interact_dialog(&self, input: impl Into<InputId<'_>>) -> Result<()> {
+        let input = input.into();
         self.client
             .send_message(Request::OpenInputInteractDialog { input })
             .await
     }
 
     /// Gets a list of connected monitors and information about them.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond(
+    /// #     "GetMonitorList",
+    /// #     json!({
+    /// #         "monitors": [{
+    /// #             "monitorName": "DP-1",
+    /// #             "monitorIndex": 0,
+    /// #             "monitorWidth": 2560,
+    /// #             "monitorHeight": 1440,
+    /// #             "monitorPositionX": 0,
+    /// #             "monitorPositionY": 0,
+    /// #         }],
+    /// #     }),
+    /// # );
+    /// let monitors = client.ui().list_monitors().await?;
+    /// assert_eq!("DP-1", monitors[0].name);
+    /// assert_eq!(2560, monitors[0].size.width);
+    /// # });
+    /// ```
     #[doc(alias = "GetMonitorList")]
     pub async fn list_monitors(&self) -> Result<Vec<responses::Monitor>> {
         self.client
@@ -92,4 +172,55 @@ impl<'a> Ui<'a> {
             }))
             .await
     }
+
+    /// Makes sure a projector for the source is open on the monitor with the given name.
+    ///
+    /// The monitor list is queried on each call and compared to the monitor that the projector
+    /// was last opened on. If the monitor topology changed (for example after docking or
+    /// undocking a device) or no projector was opened through this function yet, a new
+    /// projector is opened on the monitor's current index.
+    ///
+    /// Returns `true` if a projector was (re-)opened, `false` if the last known placement is
+    /// still valid.
+    ///
+    /// **Note**: obs-websocket provides no way of querying or closing projectors. Therefore, the
+    /// placement is only tracked for the lifetime of this client and projectors closed manually
+    /// in the OBS UI are not detected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownMonitor`] if no monitor with the given name is connected.
+    pub async fn ensure_projector(
+        &self,
+        source: impl Into<SourceId<'_>>,
+        monitor_name: &str,
+    ) -> Result<bool> {
+        let source = source.into();
+        let monitor = self
+            .list_monitors()
+            .await?
+            .into_iter()
+            .find(|m| m.name == monitor_name)
+            .ok_or_else(|| Error::UnknownMonitor(monitor_name.to_owned()))?;
+
+        let mut projectors = self.client.projectors.lock().await;
+        let key = source.to_string();
+
+        if projectors.get(&key) == Some(&monitor) {
+            return Ok(false);
+        }
+
+        self.client
+            .send_message::<_, ()>(Request::OpenSourceProjector(OpenSourceProjectorInternal {
+                source,
+                location: Some(
+                    Location::MonitorIndex(i32::try_from(monitor.index).unwrap_or(i32::MAX)).into(),
+                ),
+            }))
+            .await?;
+
+        projectors.insert(key, monitor);
+
+        Ok(true)
+    }
 }
@@ -2,12 +2,33 @@ use super::Client;
 use crate::{error::Result, requests::virtual_cam::Request, responses::virtual_cam as responses};
 
 /// API functions related to the virtual camera.
+///
+/// ```
+/// # obws::doctest!(|stub, client| {
+/// # stub.respond("ToggleVirtualCam", json!({"outputActive": true}));
+/// assert!(client.virtual_cam().toggle().await?);
+/// # assert_eq!(
+/// #     Some(&("ToggleVirtualCam".to_owned(), json!(null))),
+/// #     stub.received().last(),
+/// # );
+/// # });
+/// ```
 pub struct VirtualCam<'a> {
     pub(super) client: &'a Client,
 }
 
-impl<'a> VirtualCam<'a> {
+impl VirtualCam<'_> {
     /// Gets the status of the virtual cam output.
+    ///
+    /// ```
+    /// # obws::doctest!(|stub, client| {
+    /// # stub.respond("GetVirtualCamStatus", json!({"outputActive": false}));
+    /// # stub.respond("StartVirtualCam", json!(null));
+    /// if !client.virtual_cam().status().await? {
+    ///     client.virtual_cam().start().await?;
+    /// }
+    /// # });
+    /// ```
     #[doc(alias = "GetVirtualCamStatus")]
     pub async fn status(&self) -> Result<bool> {
         self.client
@@ -0,0 +1,35 @@
+//! Abstraction over time, used by the time-based utilities of this crate.
+//!
+//! By default, the client uses the [`TokioClock`], which is backed by the real time. It can be
+//! replaced with [`crate::Client::with_clock`], for example with the manual clock from the
+//! `testing` feature, to control the passing of time in tests.
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+
+/// Source of the current time, and the ability to wait for some time to pass.
+pub trait Clock: Debug + Send + Sync {
+    /// Get the current point in time.
+    fn now(&self) -> Instant;
+
+    /// Wait until the given duration passed.
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+}
+
+/// Clock backed by the real time, through the timer of the [`tokio`] runtime.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioClock;
+
+impl Clock for TokioClock {
+    fn now(&self) -> Instant {
+        tokio::time::Instant::now().into_std()
+    }
+
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + 'static>> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
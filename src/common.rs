@@ -1,14 +1,224 @@
 //! Common data structures shared between requests, responses and events.
 
+use std::cmp::Ordering;
+
 use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
 
 use crate::error::Error;
 
-/// Monitoring type for audio outputs.
+/// Duration as used by the obs-websocket API, which can be negative where the protocol needs it,
+/// like for the audio sync offset of an input.
+///
+/// Functions that take a duration accept anything that converts into it, so both
+/// [`std::time::Duration`] and [`time::Duration`] can be passed directly. Durations in responses
+/// are reported as [`time::Duration`], as they might be negative.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ObwsDuration(time::Duration);
+
+impl ObwsDuration {
+    /// Whether the duration is less than zero.
+    #[must_use]
+    pub fn is_negative(self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+impl From<time::Duration> for ObwsDuration {
+    fn from(value: time::Duration) -> Self {
+        Self(value)
+    }
+}
+
+/// Saturates at [`time::Duration::MAX`] for durations, that exceed it.
+impl From<std::time::Duration> for ObwsDuration {
+    fn from(value: std::time::Duration) -> Self {
+        Self(time::Duration::try_from(value).unwrap_or(time::Duration::MAX))
+    }
+}
+
+impl From<ObwsDuration> for time::Duration {
+    fn from(value: ObwsDuration) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<ObwsDuration> for std::time::Duration {
+    type Error = Error;
+
+    fn try_from(value: ObwsDuration) -> Result<Self, Self::Error> {
+        Self::try_from(value.0).map_err(|_| Error::NegativeDuration(value.0))
+    }
+}
+
+/// Amount of data in bytes, like the memory usage or free disk space reported in
+/// [`crate::responses::general::Stats`].
+///
+/// obs-websocket reports these values in mebibytes, which is available through
+/// [`Self::as_mebibytes`]. On its own, the type (de)serializes as plain number of bytes.
 #[derive(
     Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize,
 )]
+#[serde(transparent)]
+pub struct Bytes(u64);
+
+impl Bytes {
+    /// Create a new value from the given amount of bytes.
+    #[must_use]
+    pub const fn new(bytes: u64) -> Self {
+        Self(bytes)
+    }
+
+    /// Convert a fractional amount of mebibytes into bytes, rounded to the closest byte. Negative
+    /// values saturate at zero.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_mebibytes(mebibytes: f64) -> Self {
+        Self((mebibytes * MEBIBYTE).round() as u64)
+    }
+
+    /// Amount of bytes.
+    #[must_use]
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Amount of data in fractional mebibytes, as reported by obs-websocket.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_mebibytes(self) -> f64 {
+        self.0 as f64 / MEBIBYTE
+    }
+}
+
+const MEBIBYTE: f64 = 1024.0 * 1024.0;
+
+impl From<u64> for Bytes {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Bytes> for u64 {
+    fn from(value: Bytes) -> Self {
+        value.0
+    }
+}
+
+/// Fields of a response, that are unknown to this crate.
+///
+/// These are only kept with the `preserve-unknown` feature. Without it, the map is always empty,
+/// but the type stays the same, so code using it compiles regardless of the enabled features.
+///
+/// Values are ordered by their JSON structure, so the containing responses can keep implementing
+/// [`Ord`].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Extra(serde_json::Map<String, serde_json::Value>);
+
+impl Extra {
+    /// Get the value of an unknown field by its name.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.0.get(key)
+    }
+
+    /// Amount of unknown fields.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether there are no unknown fields.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the names and values of all unknown fields.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &serde_json::Value)> {
+        self.0.iter()
+    }
+}
+
+impl From<Extra> for serde_json::Map<String, serde_json::Value> {
+    fn from(value: Extra) -> Self {
+        value.0
+    }
+}
+
+impl PartialOrd for Extra {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Extra {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_objects(&self.0, &other.0)
+    }
+}
+
+fn cmp_objects(
+    a: &serde_json::Map<String, serde_json::Value>,
+    b: &serde_json::Map<String, serde_json::Value>,
+) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|((ka, va), (kb, vb))| ka.cmp(kb).then_with(|| cmp_values(va, vb)))
+        .find(|ord| ord.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+fn cmp_values(a: &serde_json::Value, b: &serde_json::Value) -> Ordering {
+    use serde_json::Value;
+
+    fn rank(value: &Value) -> u8 {
+        match value {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Number(_) => 2,
+            Value::String(_) => 3,
+            Value::Array(_) => 4,
+            Value::Object(_) => 5,
+        }
+    }
+
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        (Value::Number(a), Value::Number(b)) => cmp_numbers(a, b),
+        (Value::String(a), Value::String(b)) => a.cmp(b),
+        (Value::Array(a), Value::Array(b)) => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| cmp_values(a, b))
+            .find(|ord| ord.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (Value::Object(a), Value::Object(b)) => cmp_objects(a, b),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+fn cmp_numbers(a: &serde_json::Number, b: &serde_json::Number) -> Ordering {
+    if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+        return a.cmp(&b);
+    }
+    if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+        return a.cmp(&b);
+    }
+
+    // JSON numbers are never NaN, so only integers and floats of the same value compare equal
+    // here, which are told apart by their kind to stay consistent with `Eq`.
+    let value = a
+        .as_f64()
+        .zip(b.as_f64())
+        .and_then(|(a, b)| a.partial_cmp(&b))
+        .unwrap_or(Ordering::Equal);
+    value.then_with(|| a.is_f64().cmp(&b.is_f64()))
+}
+
+/// Monitoring type for audio outputs.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize)]
 #[non_exhaustive]
 pub enum MonitorType {
     /// No monitoring.
@@ -21,6 +231,79 @@ pub enum MonitorType {
     /// Monitor the audio and output it at the same time.
     #[serde(rename = "OBS_MONITORING_TYPE_MONITOR_AND_OUTPUT")]
     MonitorAndOutput,
+    /// Any other value, not known to this crate yet, with the original string.
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Color format of the video output, as configured in the advanced video settings.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize,
+)]
+#[non_exhaustive]
+pub enum ColorFormat {
+    /// 8-bit 4:2:0 with interleaved chroma planes. The default.
+    #[default]
+    #[serde(rename = "NV12")]
+    Nv12,
+    /// 8-bit 4:2:0 with separate chroma planes.
+    #[serde(rename = "I420")]
+    I420,
+    /// 8-bit 4:4:4 without chroma subsampling.
+    #[serde(rename = "I444")]
+    I444,
+    /// 10-bit 4:2:0 with interleaved chroma planes, for HDR content.
+    #[serde(rename = "P010")]
+    P010,
+    /// 10-bit 4:2:0 with separate chroma planes, for HDR content.
+    #[serde(rename = "I010")]
+    I010,
+    /// 16-bit 4:2:2 with interleaved chroma planes.
+    #[serde(rename = "P216")]
+    P216,
+    /// 16-bit 4:4:4 with interleaved chroma planes.
+    #[serde(rename = "P416")]
+    P416,
+    /// 8-bit BGRA, which OBS names `RGB`.
+    #[serde(rename = "RGB")]
+    Bgra,
+}
+
+/// Color space of the video output, as configured in the advanced video settings.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize,
+)]
+#[non_exhaustive]
+pub enum ColorSpace {
+    /// Rec. 601 for standard definition content.
+    #[serde(rename = "601")]
+    Rec601,
+    /// Rec. 709 for high definition content. The default.
+    #[default]
+    #[serde(rename = "709")]
+    Rec709,
+    /// sRGB, using the Rec. 709 primaries with the sRGB transfer function.
+    #[serde(rename = "sRGB")]
+    Srgb,
+    /// Rec. 2100 with the perceptual quantizer (PQ) transfer function, for HDR content.
+    #[serde(rename = "2100PQ")]
+    Rec2100Pq,
+    /// Rec. 2100 with the hybrid log-gamma (HLG) transfer function, for HDR content.
+    #[serde(rename = "2100HLG")]
+    Rec2100Hlg,
+}
+
+/// Color range of the video output, as configured in the advanced video settings.
+#[derive(
+    Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Deserialize, Serialize,
+)]
+#[non_exhaustive]
+pub enum ColorRange {
+    /// Limited range, where values use only a part of the available range. The default.
+    #[default]
+    Partial,
+    /// Full range of values.
+    Full,
 }
 
 /// Different flags for font display that can be combined.
@@ -97,11 +380,181 @@ impl From<Alignment> for u8 {
         value.bits()
     }
 }
+/// Enable state of the six audio tracks of an input.
+///
+/// Tracks are addressed by their 1-based number, as shown in OBS. Each track is either enabled,
+/// disabled or not set at all. When setting the tracks of an input, tracks that are not set keep
+/// their current state.
+///
+/// ```
+/// use obws::common::AudioTracks;
+///
+/// // Only change track 2, leaving all others as they are.
+/// let tracks = AudioTracks::new().enable(2);
+/// assert_eq!(None, tracks.get(1));
+///
+/// // Enable tracks 1 and 3, and disable all others.
+/// let tracks = AudioTracks::only(&[1, 3]);
+/// assert!(tracks.is_enabled(3));
+/// assert_eq!(Some(false), tracks.get(2));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct AudioTracks {
+    /// Bits of the enabled tracks.
+    enabled: u8,
+    /// Bits of the tracks that are set, either enabled or disabled.
+    set: u8,
+}
+
+impl AudioTracks {
+    /// Bits of all six tracks.
+    const ALL: u8 = 0b11_1111;
+
+    /// Create a new instance, with none of the tracks set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { enabled: 0, set: 0 }
+    }
+
+    /// Create a new instance, with all tracks enabled.
+    #[must_use]
+    pub const fn all() -> Self {
+        Self {
+            enabled: Self::ALL,
+            set: Self::ALL,
+        }
+    }
+
+    /// Create a new instance, with all tracks disabled.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            enabled: 0,
+            set: Self::ALL,
+        }
+    }
+
+    /// Create a new instance, with only the given tracks enabled and all others disabled.
+    ///
+    /// # Panics
+    ///
+    /// If any of the tracks is not within `1..=6`.
+    #[must_use]
+    pub fn only(tracks: &[u8]) -> Self {
+        tracks
+            .iter()
+            .fold(Self::none(), |this, &track| this.enable(track))
+    }
+
+    /// Enable the given track.
+    ///
+    /// # Panics
+    ///
+    /// If the track is not within `1..=6`.
+    #[must_use]
+    pub const fn enable(self, track: u8) -> Self {
+        let bit = Self::bit(track);
+        Self {
+            enabled: self.enabled | bit,
+            set: self.set | bit,
+        }
+    }
+
+    /// Disable the given track.
+    ///
+    /// # Panics
+    ///
+    /// If the track is not within `1..=6`.
+    #[must_use]
+    pub const fn disable(self, track: u8) -> Self {
+        let bit = Self::bit(track);
+        Self {
+            enabled: self.enabled & !bit,
+            set: self.set | bit,
+        }
+    }
+
+    /// Get the state of the given track, or [`None`] if it's not set.
+    ///
+    /// # Panics
+    ///
+    /// If the track is not within `1..=6`.
+    #[must_use]
+    pub const fn get(self, track: u8) -> Option<bool> {
+        let bit = Self::bit(track);
+        if self.set & bit == 0 {
+            None
+        } else {
+            Some(self.enabled & bit != 0)
+        }
+    }
+
+    /// Whether the given track is enabled. Tracks that are not set count as disabled.
+    ///
+    /// # Panics
+    ///
+    /// If the track is not within `1..=6`.
+    #[must_use]
+    pub const fn is_enabled(self, track: u8) -> bool {
+        self.enabled & Self::bit(track) != 0
+    }
+
+    const fn bit(track: u8) -> u8 {
+        assert!(track >= 1 && track <= 6, "audio track must be within 1..=6");
+        1 << (track - 1)
+    }
+}
+
+impl From<[bool; 6]> for AudioTracks {
+    fn from(value: [bool; 6]) -> Self {
+        value.map(Some).into()
+    }
+}
+
+impl From<[Option<bool>; 6]> for AudioTracks {
+    fn from(value: [Option<bool>; 6]) -> Self {
+        (1..=6)
+            .zip(value)
+            .fold(Self::new(), |this, (track, state)| match state {
+                Some(true) => this.enable(track),
+                Some(false) => this.disable(track),
+                None => this,
+            })
+    }
+}
+
+impl From<AudioTracks> for [Option<bool>; 6] {
+    fn from(value: AudioTracks) -> Self {
+        [1, 2, 3, 4, 5, 6].map(|track| value.get(track))
+    }
+}
+
+impl From<AudioTracks> for [bool; 6] {
+    fn from(value: AudioTracks) -> Self {
+        [1, 2, 3, 4, 5, 6].map(|track| value.is_enabled(track))
+    }
+}
+
+impl Serialize for AudioTracks {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        crate::serde::audio_tracks::option::serialize(&(*self).into(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AudioTracks {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::serde::audio_tracks::deserialize(deserializer).map(Self::from)
+    }
+}
 
 /// Different kinds of bounds that can be applied to different items on the scene.
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[non_exhaustive]
 pub enum BoundsType {
     /// No bounds.
@@ -126,12 +579,13 @@ pub enum BoundsType {
     /// Maximum size only.
     #[serde(rename = "OBS_BOUNDS_MAX_ONLY")]
     MaxOnly,
+    /// Any other value, not known to this crate yet, with the original string.
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Different kinds of media actions that can be performed (or happen in events).
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[non_exhaustive]
 pub enum MediaAction {
     /// No media action.
@@ -156,12 +610,13 @@ pub enum MediaAction {
     /// Play the previous media in the list.
     #[serde(rename = "OBS_WEBSOCKET_MEDIA_INPUT_ACTION_PREVIOUS")]
     Previous,
+    /// Any other value, not known to this crate yet, with the original string.
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Different kinds of scene item blend modes.
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[non_exhaustive]
 pub enum BlendMode {
     /// No blending, overlaying without mixing colors, except for transparency.
@@ -186,4 +641,88 @@ pub enum BlendMode {
     /// Select pixels based on the darkest luminescence value.
     #[serde(rename = "OBS_BLEND_DARKEN")]
     Darken,
+    /// Any other value, not known to this crate yet, with the original string.
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn duration_conversions() {
+        let positive = ObwsDuration::from(std::time::Duration::from_millis(1500));
+        assert_eq!(
+            time::Duration::milliseconds(1500),
+            time::Duration::from(positive)
+        );
+        assert_eq!(
+            std::time::Duration::from_millis(1500),
+            std::time::Duration::try_from(positive).unwrap()
+        );
+
+        let negative = ObwsDuration::from(time::Duration::milliseconds(-200));
+        assert!(negative.is_negative());
+        assert!(matches!(
+            std::time::Duration::try_from(negative),
+            Err(Error::NegativeDuration(d)) if d == time::Duration::milliseconds(-200)
+        ));
+
+        assert_eq!(
+            ObwsDuration::from(time::Duration::MAX),
+            ObwsDuration::from(std::time::Duration::MAX)
+        );
+    }
+
+    #[test]
+    fn unknown_enum_values() {
+        assert_eq!(
+            BoundsType::Stretch,
+            serde_json::from_str::<BoundsType>(r#""OBS_BOUNDS_STRETCH""#).unwrap()
+        );
+
+        let other = serde_json::from_str::<BoundsType>(r#""OBS_BOUNDS_NEW""#).unwrap();
+        assert_eq!(BoundsType::Other("OBS_BOUNDS_NEW".to_owned()), other);
+        assert_eq!(
+            r#""OBS_BOUNDS_NEW""#,
+            serde_json::to_string(&other).unwrap()
+        );
+    }
+
+    #[test]
+    fn extra_ordering() {
+        let extra = |value: serde_json::Value| serde_json::from_value::<Extra>(value).unwrap();
+
+        assert_eq!(
+            Ordering::Equal,
+            extra(json!({"a": 1})).cmp(&extra(json!({"a": 1})))
+        );
+        assert_eq!(
+            Ordering::Less,
+            extra(json!({"a": 1})).cmp(&extra(json!({"a": 2})))
+        );
+        assert_eq!(
+            Ordering::Less,
+            extra(json!({"a": -1})).cmp(&extra(json!({"a": 0.5})))
+        );
+        assert_eq!(
+            Ordering::Less,
+            extra(json!({"a": 1})).cmp(&extra(json!({"a": 1.0})))
+        );
+        assert_eq!(
+            Ordering::Less,
+            extra(json!({"a": 1})).cmp(&extra(json!({"b": 0})))
+        );
+        assert_eq!(
+            Ordering::Less,
+            extra(json!({})).cmp(&extra(json!({"a": null})))
+        );
+        assert_eq!(
+            Ordering::Greater,
+            extra(json!({"a": [1, {"b": "y"}]})).cmp(&extra(json!({"a": [1, {"b": "x"}]})))
+        );
+    }
 }
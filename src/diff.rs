@@ -0,0 +1,992 @@
+//! Diffing of OBS states, for declarative "OBS as code" workflows.
+//!
+//! A [`Snapshot`] describes the scenes and inputs of OBS in a simplified form, that can be stored
+//! in any format supported by [`serde`], or captured from a live OBS instance with
+//! [`Snapshot::capture`]. The [`diff`] between two snapshots is a list of typed [`Operation`]s,
+//! that converge the first state into the second one, and can be sent to OBS with [`apply`].
+//!
+//! Scene items are identified by the name of their source within a scene, so each source should
+//! only appear once per scene. New inputs are created as part of the first scene that uses them,
+//! as obs-websocket can't create inputs outside of a scene. Inputs that aren't used in any scene
+//! are therefore never created, but their settings are still updated if they already exist.
+//!
+//! Groups are captured as part of a snapshot, so it's known which inputs they use, but they're
+//! not touched by a [`diff`].
+//!
+//! The [`Snapshot::input_report`] helps to clean up a scene collection, by listing inputs that
+//! aren't used in any scene or group, and inputs that are duplicates of each other.
+//!
+//! Snapshots also serve as backups. A [`BackupSchedule`], created with
+//! [`Snapshot::schedule_backups`], periodically stores a snapshot of the active scene collection as
+//! timestamped JSON file, and only keeps a limited number of the latest backups around.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use time::OffsetDateTime;
+use tracing::debug;
+
+use crate::{
+    error::Result,
+    requests::{
+        inputs::{Create, InputId, SetSettings},
+        scene_items::{
+            CreateSceneItem, Id, Position, SceneItemTransform, SetEnabled, SetTransform,
+        },
+        scenes::SceneId,
+        sources::SourceId,
+    },
+    Client,
+};
+
+/// Simplified state of the scenes and inputs in OBS.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Snapshot {
+    /// All inputs, keyed by their name.
+    #[serde(default)]
+    pub inputs: BTreeMap<String, InputState>,
+    /// All scenes, keyed by their name.
+    #[serde(default)]
+    pub scenes: BTreeMap<String, SceneState>,
+    /// All groups, that are used in any of the scenes, keyed by their name. These are ignored
+    /// when [`diff`]ing snapshots.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub groups: BTreeMap<String, SceneState>,
+    /// Names of the special inputs, like the global Desktop Audio and Mic/Aux devices. These are
+    /// part of [`Self::inputs`], but usually not used in any scene.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub special_inputs: BTreeSet<String>,
+}
+
+/// State of a single input, as part of a [`Snapshot`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct InputState {
+    /// The kind of input.
+    pub kind: String,
+    /// Settings of the input, that differ from the defaults.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub settings: Map<String, Value>,
+}
+
+/// State of a single scene, as part of a [`Snapshot`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SceneState {
+    /// Items of the scene.
+    #[serde(default)]
+    pub items: Vec<ItemState>,
+}
+
+/// State of a single scene item, as part of a [`SceneState`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ItemState {
+    /// Name of the source, that the item shows.
+    pub source: String,
+    /// Whether the item is visible. Defaults to `true`.
+    #[serde(default = "enabled_default")]
+    pub enabled: bool,
+    /// The x and y position of the item. Left unchanged, if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<(f32, f32)>,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+/// Single change, that is needed to converge one [`Snapshot`] into another.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Operation {
+    /// Remove a scene item.
+    RemoveSceneItem {
+        /// Name of the scene the item is in.
+        scene: String,
+        /// Name of the source, that the item shows.
+        source: String,
+    },
+    /// Remove a scene.
+    RemoveScene {
+        /// Name of the scene.
+        name: String,
+    },
+    /// Remove an input, including all its scene items.
+    RemoveInput {
+        /// Name of the input.
+        name: String,
+    },
+    /// Create a new, empty scene.
+    CreateScene {
+        /// Name of the scene.
+        name: String,
+    },
+    /// Create a new input, as part of a scene.
+    CreateInput {
+        /// Name of the scene, that the first item of the input is created in.
+        scene: String,
+        /// Name of the input.
+        name: String,
+        /// The kind of input.
+        kind: String,
+        /// Settings to initialize the input with.
+        settings: Map<String, Value>,
+        /// Whether the created scene item is visible.
+        enabled: bool,
+    },
+    /// Replace the settings of an input.
+    SetInputSettings {
+        /// Name of the input.
+        name: String,
+        /// The new settings, replacing all previous ones.
+        settings: Map<String, Value>,
+    },
+    /// Create a new scene item for an existing source.
+    CreateSceneItem {
+        /// Name of the scene to create the item in.
+        scene: String,
+        /// Name of the source, that the item shows.
+        source: String,
+        /// Whether the item is visible.
+        enabled: bool,
+    },
+    /// Show or hide a scene item.
+    SetSceneItemEnabled {
+        /// Name of the scene the item is in.
+        scene: String,
+        /// Name of the source, that the item shows.
+        source: String,
+        /// Whether the item is visible.
+        enabled: bool,
+    },
+    /// Move a scene item to a new position.
+    MoveSceneItem {
+        /// Name of the scene the item is in.
+        scene: String,
+        /// Name of the source, that the item shows.
+        source: String,
+        /// The new x and y position.
+        position: (f32, f32),
+    },
+}
+
+impl Snapshot {
+    /// Capture the current state of all scenes and inputs from OBS.
+    pub async fn capture(client: &Client) -> Result<Self> {
+        let mut snapshot = Self::default();
+
+        for input in client.inputs().list(None).await? {
+            let settings = client
+                .inputs()
+                .settings::<Map<String, Value>>(&input.id)
+                .await?;
+
+            snapshot.inputs.insert(
+                input.id.name,
+                InputState {
+                    kind: input.kind,
+                    settings: settings.settings,
+                },
+            );
+        }
+
+        let mut groups = BTreeSet::new();
+
+        for scene in client.scenes().list().await?.scenes {
+            let id = SceneId::from(&scene.id);
+            let mut items = Vec::new();
+
+            for (item, transform) in client.scene_items().list_with_transforms(id).await? {
+                if item.is_group == Some(true) {
+                    groups.insert(item.source_name.clone());
+                }
+
+                items.push(ItemState {
+                    enabled: client.scene_items().enabled(id, item.id).await?,
+                    source: item.source_name,
+                    position: Some((transform.position_x, transform.position_y)),
+                });
+            }
+
+            snapshot.scenes.insert(scene.id.name, SceneState { items });
+        }
+
+        // Walk into groups the same way as `SceneItems::find_recursive`, to know which inputs
+        // are only used within groups.
+        while let Some(group) = groups.pop_first() {
+            if snapshot.groups.contains_key(&group) {
+                continue;
+            }
+
+            let id = SceneId::Name(&group);
+            let mut items = Vec::new();
+
+            for item in client.scene_items().list_group(id).await? {
+                if item.is_group == Some(true) {
+                    groups.insert(item.source_name.clone());
+                }
+
+                items.push(ItemState {
+                    enabled: client.scene_items().enabled(id, item.id).await?,
+                    source: item.source_name,
+                    position: None,
+                });
+            }
+
+            snapshot.groups.insert(group, SceneState { items });
+        }
+
+        let specials = client.inputs().specials().await?;
+        snapshot.special_inputs = [
+            specials.desktop1,
+            specials.desktop2,
+            specials.mic1,
+            specials.mic2,
+            specials.mic3,
+            specials.mic4,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        Ok(snapshot)
+    }
+
+    /// Create a schedule, that stores a backup of the active scene collection in the given
+    /// directory at a fixed interval, and only keeps the latest `keep` backups of each
+    /// collection. See [`BackupSchedule`] for details.
+    #[must_use]
+    pub fn schedule_backups(
+        dir: impl Into<PathBuf>,
+        every: Duration,
+        keep: usize,
+    ) -> BackupSchedule {
+        BackupSchedule {
+            dir: dir.into(),
+            every,
+            keep,
+        }
+    }
+
+    /// Find inputs that aren't used in any scene or group, and groups of inputs that are
+    /// duplicates of each other. See [`InputReport`] for details.
+    #[must_use]
+    pub fn input_report(&self) -> InputReport {
+        let used = self
+            .scenes
+            .values()
+            .chain(self.groups.values())
+            .flat_map(|scene| &scene.items)
+            .map(|item| item.source.as_str())
+            .collect::<BTreeSet<_>>();
+
+        let unused = self
+            .inputs
+            .keys()
+            .filter(|name| !used.contains(name.as_str()) && !self.special_inputs.contains(*name))
+            .cloned()
+            .collect();
+
+        let mut same = BTreeMap::<_, Vec<_>>::new();
+        for (name, input) in &self.inputs {
+            let settings = serde_json::to_string(&input.settings).unwrap_or_default();
+            same.entry((input.kind.as_str(), settings))
+                .or_default()
+                .push(name.clone());
+        }
+
+        let duplicates = same.into_values().filter(|names| names.len() > 1).collect();
+
+        InputReport { unused, duplicates }
+    }
+}
+
+/// Inputs of a [`Snapshot`] that are candidates for cleaning up, as found by
+/// [`Snapshot::input_report`].
+///
+/// Inputs that are only used inside of groups count as used. Special inputs are never reported as
+/// unused either, as they're global audio devices that are used without being part of a scene.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InputReport {
+    /// Names of the inputs that aren't a scene item in any scene or group, excluding the
+    /// [`Snapshot::special_inputs`].
+    pub unused: Vec<String>,
+    /// Names of inputs that share the same kind and settings, grouped together. Each group
+    /// contains at least two inputs.
+    pub duplicates: Vec<Vec<String>>,
+}
+
+impl InputReport {
+    /// Remove all [`Self::unused`] inputs from OBS.
+    pub async fn remove_unused(&self, client: &Client) -> Result<()> {
+        for name in &self.unused {
+            client.inputs().remove(InputId::Name(name)).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Periodic backups of the active scene collection, as created by [`Snapshot::schedule_backups`].
+///
+/// Each backup is a [`Snapshot`], stored as JSON file named after the scene collection and the
+/// current time, like `Streaming-20250101T120000Z.json`. The backups can be restored by
+/// [`diff`]ing the current state against them, and [`apply`]ing the result.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct BackupSchedule {
+    /// Directory to store the backups in. It's created if it doesn't exist yet.
+    #[cfg_attr(feature = "builder", builder(into))]
+    pub dir: PathBuf,
+    /// Time between two backups.
+    pub every: Duration,
+    /// Number of backups to keep for each scene collection. Older backups are removed, after a
+    /// new one was stored.
+    pub keep: usize,
+}
+
+impl BackupSchedule {
+    /// Store backups in the configured interval, until the connection to obs-websocket is closed
+    /// or storing a backup fails.
+    ///
+    /// This function doesn't return on its own while the connection is alive, so it's usually
+    /// run in a separate task.
+    pub async fn run(&self, client: &Client) -> Result<()> {
+        loop {
+            self.backup(client).await?;
+            client.clock().sleep(self.every).await;
+        }
+    }
+
+    /// Store a single backup of the active scene collection right away, and remove old backups
+    /// beyond the retention limit. Returns the path of the new backup.
+    pub async fn backup(&self, client: &Client) -> Result<PathBuf> {
+        let collection = client.scene_collections().current().await?;
+        let snapshot = Snapshot::capture(client).await?;
+        let data =
+            serde_json::to_vec_pretty(&snapshot).map_err(crate::error::SerializeCustomDataError)?;
+
+        let prefix = backup_prefix(&collection);
+        let path = self.dir.join(backup_name(
+            &prefix,
+            OffsetDateTime::from(SystemTime::now()),
+        ));
+        let (dir, keep) = (self.dir.clone(), self.keep);
+
+        tokio::task::spawn_blocking({
+            let path = path.clone();
+            move || store(&dir, &path, &data, &prefix, keep)
+        })
+        .await
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
+        .and_then(|result| result)
+        .map_err(crate::error::WriteBackupError)?;
+
+        debug!(path = %path.display(), "stored scene collection backup");
+        Ok(path)
+    }
+}
+
+/// Write a backup to disk, and remove all but the latest `keep` backups with the same prefix.
+fn store(dir: &Path, path: &Path, data: &[u8], prefix: &str, keep: usize) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    std::fs::write(path, data)?;
+
+    let names = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .collect::<Vec<_>>();
+
+    for name in expired(names, prefix, keep) {
+        std::fs::remove_file(dir.join(name))?;
+    }
+
+    Ok(())
+}
+
+/// File name prefix of all backups of a scene collection, with characters that aren't safe in
+/// file names replaced.
+fn backup_prefix(collection: &str) -> String {
+    let name = collection
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    format!("{name}-")
+}
+
+/// File name of a backup, taken at the given time.
+fn backup_name(prefix: &str, time: OffsetDateTime) -> String {
+    let time = time.to_offset(time::UtcOffset::UTC);
+    format!(
+        "{prefix}{:04}{:02}{:02}T{:02}{:02}{:02}Z.json",
+        time.year(),
+        u8::from(time.month()),
+        time.day(),
+        time.hour(),
+        time.minute(),
+        time.second(),
+    )
+}
+
+/// Names of the backups with the given prefix, that exceed the retention limit. The timestamps
+/// in the names sort chronologically, so the first names are the oldest backups.
+fn expired(names: Vec<String>, prefix: &str, keep: usize) -> Vec<String> {
+    let mut backups = names
+        .into_iter()
+        .filter(|name| {
+            name.strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix("Z.json"))
+                .is_some_and(|stamp| {
+                    stamp.len() == 15
+                        && stamp.chars().enumerate().all(|(i, c)| {
+                            if i == 8 {
+                                c == 'T'
+                            } else {
+                                c.is_ascii_digit()
+                            }
+                        })
+                })
+        })
+        .collect::<Vec<_>>();
+
+    backups.sort_unstable();
+    let excess = backups.len().saturating_sub(keep);
+    backups.truncate(excess);
+    backups
+}
+
+/// Calculate the operations, that are needed to converge the state `from` into the state `to`.
+///
+/// The operations are ordered, so they can be applied one after another. All removals come first,
+/// followed by the creation of new scenes and inputs, and finally the updates of existing ones.
+#[must_use]
+pub fn diff(from: &Snapshot, to: &Snapshot) -> Vec<Operation> {
+    let mut plan = Plan::default();
+    plan.inputs(from, to);
+
+    for name in from.scenes.keys() {
+        if !to.scenes.contains_key(name) {
+            plan.removals
+                .push(Operation::RemoveScene { name: name.clone() });
+        }
+    }
+
+    for (scene, to_state) in &to.scenes {
+        let from_state = from.scenes.get(scene);
+        if from_state.is_none() {
+            plan.creations.insert(
+                0,
+                Operation::CreateScene {
+                    name: scene.clone(),
+                },
+            );
+        }
+
+        let from_items = from_state.map_or(&[][..], |state| &state.items);
+        plan.scene(scene, from_items, &to_state.items);
+    }
+
+    let mut operations = plan.removals;
+    operations.extend(plan.creations);
+    operations.extend(plan.updates);
+    operations
+}
+
+/// Operations of a [`diff`], grouped by the order they need to be applied in.
+#[derive(Default)]
+struct Plan<'a> {
+    removals: Vec<Operation>,
+    creations: Vec<Operation>,
+    updates: Vec<Operation>,
+    /// Inputs that need to be (re-)created, with the scene that they are created in.
+    created_inputs: BTreeMap<&'a str, &'a str>,
+}
+
+impl<'a> Plan<'a> {
+    fn inputs(&mut self, from: &Snapshot, to: &'a Snapshot) {
+        for (name, from_input) in &from.inputs {
+            if to
+                .inputs
+                .get(name)
+                .map_or(true, |to_input| to_input.kind != from_input.kind)
+            {
+                self.removals
+                    .push(Operation::RemoveInput { name: name.clone() });
+            }
+        }
+
+        for (name, to_input) in &to.inputs {
+            match from.inputs.get(name) {
+                Some(from_input) if from_input.kind == to_input.kind => {
+                    if from_input.settings != to_input.settings {
+                        self.updates.push(Operation::SetInputSettings {
+                            name: name.clone(),
+                            settings: to_input.settings.clone(),
+                        });
+                    }
+                }
+                _ => {
+                    let first_use = to.scenes.iter().find_map(|(scene, state)| {
+                        state
+                            .items
+                            .iter()
+                            .find(|item| &item.source == name)
+                            .map(|item| (scene, item))
+                    });
+
+                    if let Some((scene, item)) = first_use {
+                        self.creations.push(Operation::CreateInput {
+                            scene: scene.clone(),
+                            name: name.clone(),
+                            kind: to_input.kind.clone(),
+                            settings: to_input.settings.clone(),
+                            enabled: item.enabled,
+                        });
+                        self.created_inputs.insert(name, scene);
+                    }
+                }
+            }
+        }
+    }
+
+    fn scene(&mut self, scene: &str, from_items: &[ItemState], to_items: &[ItemState]) {
+        for from_item in from_items {
+            let removed = !to_items.iter().any(|item| item.source == from_item.source)
+                || self.created_inputs.contains_key(from_item.source.as_str());
+
+            if removed && !self.is_removed_input(&from_item.source) {
+                self.removals.insert(
+                    0,
+                    Operation::RemoveSceneItem {
+                        scene: scene.to_owned(),
+                        source: from_item.source.clone(),
+                    },
+                );
+            }
+        }
+
+        for to_item in to_items {
+            let created_with = self.created_inputs.get(to_item.source.as_str()).copied();
+            let from_item = from_items
+                .iter()
+                .find(|item| item.source == to_item.source)
+                .filter(|_| created_with.is_none());
+
+            match from_item {
+                Some(from_item) if from_item.enabled != to_item.enabled => {
+                    self.updates.push(Operation::SetSceneItemEnabled {
+                        scene: scene.to_owned(),
+                        source: to_item.source.clone(),
+                        enabled: to_item.enabled,
+                    });
+                }
+                None if created_with != Some(scene) => {
+                    self.updates.push(Operation::CreateSceneItem {
+                        scene: scene.to_owned(),
+                        source: to_item.source.clone(),
+                        enabled: to_item.enabled,
+                    });
+                }
+                _ => {}
+            }
+
+            if let Some(position) = to_item.position {
+                let moved = from_item
+                    .and_then(|item| item.position)
+                    .map_or(true, |current| !same_position(current, position));
+
+                if moved {
+                    self.updates.push(Operation::MoveSceneItem {
+                        scene: scene.to_owned(),
+                        source: to_item.source.clone(),
+                        position,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Whether the input is removed anyway, which implicitly removes all its scene items.
+    fn is_removed_input(&self, source: &str) -> bool {
+        self.removals
+            .iter()
+            .any(|op| matches!(op, Operation::RemoveInput { name } if name == source))
+    }
+}
+
+/// Compare two positions, ignoring tiny differences from floating point rounding.
+fn same_position(a: (f32, f32), b: (f32, f32)) -> bool {
+    (a.0 - b.0).abs() < 0.01 && (a.1 - b.1).abs() < 0.01
+}
+
+/// Send the operations to OBS, one after another.
+///
+/// The operations are usually created with [`diff`]. Applying stops at the first failed
+/// operation, leaving OBS in a partially updated state.
+pub async fn apply(client: &Client, operations: &[Operation]) -> Result<()> {
+    for operation in operations {
+        apply_one(client, operation).await?;
+    }
+
+    Ok(())
+}
+
+async fn apply_one(client: &Client, operation: &Operation) -> Result<()> {
+    match operation {
+        Operation::RemoveSceneItem { scene, source } => {
+            let item_id = item_id(client, scene, source).await?;
+            client
+                .scene_items()
+                .remove(SceneId::Name(scene), item_id)
+                .await?;
+        }
+        Operation::RemoveScene { name } => {
+            client.scenes().remove(SceneId::Name(name)).await?;
+        }
+        Operation::RemoveInput { name } => {
+            client.inputs().remove(InputId::Name(name)).await?;
+        }
+        Operation::CreateScene { name } => {
+            client.scenes().create(name).await?;
+        }
+        Operation::CreateInput {
+            scene,
+            name,
+            kind,
+            settings,
+            enabled,
+        } => {
+            client
+                .inputs()
+                .create(Create {
+                    scene: SceneId::Name(scene),
+                    input: name,
+                    kind,
+                    settings: Some(settings),
+                    enabled: Some(*enabled),
+                })
+                .await?;
+        }
+        Operation::SetInputSettings { name, settings } => {
+            client
+                .inputs()
+                .set_settings(SetSettings {
+                    input: InputId::Name(name),
+                    settings,
+                    overlay: Some(false),
+                })
+                .await?;
+        }
+        Operation::CreateSceneItem {
+            scene,
+            source,
+            enabled,
+        } => {
+            client
+                .scene_items()
+                .create(CreateSceneItem {
+                    scene: SceneId::Name(scene),
+                    source: SourceId::Name(source),
+                    enabled: Some(*enabled),
+                })
+                .await?;
+        }
+        Operation::SetSceneItemEnabled {
+            scene,
+            source,
+            enabled,
+        } => {
+            let item_id = item_id(client, scene, source).await?;
+            client
+                .scene_items()
+                .set_enabled(SetEnabled {
+                    scene: SceneId::Name(scene),
+                    item_id,
+                    enabled: *enabled,
+                })
+                .await?;
+        }
+        Operation::MoveSceneItem {
+            scene,
+            source,
+            position: (x, y),
+        } => {
+            let item_id = item_id(client, scene, source).await?;
+            client
+                .scene_items()
+                .set_transform(SetTransform {
+                    scene: SceneId::Name(scene),
+                    item_id,
+                    transform: SceneItemTransform {
+                        position: Some(Position {
+                            x: Some(*x),
+                            y: Some(*y),
+                        }),
+                        ..SceneItemTransform::default()
+                    },
+                })
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Find the ID of the first scene item in the scene, that shows the given source.
+async fn item_id(client: &Client, scene: &str, source: &str) -> Result<i64> {
+    client
+        .scene_items()
+        .id(Id {
+            scene: SceneId::Name(scene),
+            source,
+            search_offset: None,
+        })
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn item(source: &str, enabled: bool, position: Option<(f32, f32)>) -> ItemState {
+        ItemState {
+            source: source.to_owned(),
+            enabled,
+            position,
+        }
+    }
+
+    fn input(kind: &str, settings: Value) -> InputState {
+        InputState {
+            kind: kind.to_owned(),
+            settings: serde_json::from_value(settings).unwrap(),
+        }
+    }
+
+    /// Two snapshots, that differ in all the ways a diff can detect.
+    fn snapshots() -> (Snapshot, Snapshot) {
+        let from = Snapshot {
+            inputs: [
+                ("camera".to_owned(), input("dshow_input", json!({}))),
+                (
+                    "text".to_owned(),
+                    input("text_gdiplus_v3", json!({"text": "a"})),
+                ),
+                ("old".to_owned(), input("color_source_v3", json!({}))),
+            ]
+            .into(),
+            scenes: [
+                (
+                    "main".to_owned(),
+                    SceneState {
+                        items: vec![
+                            item("camera", true, Some((0.0, 0.0))),
+                            item("text", true, Some((10.0, 10.0))),
+                            item("old", true, None),
+                        ],
+                    },
+                ),
+                ("unused".to_owned(), SceneState::default()),
+            ]
+            .into(),
+            ..Snapshot::default()
+        };
+
+        let to = Snapshot {
+            inputs: [
+                ("camera".to_owned(), input("dshow_input", json!({}))),
+                (
+                    "text".to_owned(),
+                    input("text_gdiplus_v3", json!({"text": "b"})),
+                ),
+                (
+                    "image".to_owned(),
+                    input("image_source", json!({"file": "a.png"})),
+                ),
+            ]
+            .into(),
+            scenes: [
+                (
+                    "main".to_owned(),
+                    SceneState {
+                        items: vec![
+                            item("camera", false, Some((0.0, 0.0))),
+                            item("text", true, Some((20.0, 10.0))),
+                        ],
+                    },
+                ),
+                (
+                    "second".to_owned(),
+                    SceneState {
+                        items: vec![
+                            item("image", true, Some((5.0, 5.0))),
+                            item("camera", true, None),
+                        ],
+                    },
+                ),
+            ]
+            .into(),
+            ..Snapshot::default()
+        };
+
+        (from, to)
+    }
+
+    #[test]
+    fn converge() {
+        let (from, to) = snapshots();
+
+        assert_eq!(
+            vec![
+                Operation::RemoveInput {
+                    name: "old".to_owned()
+                },
+                Operation::RemoveScene {
+                    name: "unused".to_owned()
+                },
+                Operation::CreateScene {
+                    name: "second".to_owned()
+                },
+                Operation::CreateInput {
+                    scene: "second".to_owned(),
+                    name: "image".to_owned(),
+                    kind: "image_source".to_owned(),
+                    settings: serde_json::from_value(json!({"file": "a.png"})).unwrap(),
+                    enabled: true,
+                },
+                Operation::SetInputSettings {
+                    name: "text".to_owned(),
+                    settings: serde_json::from_value(json!({"text": "b"})).unwrap(),
+                },
+                Operation::SetSceneItemEnabled {
+                    scene: "main".to_owned(),
+                    source: "camera".to_owned(),
+                    enabled: false,
+                },
+                Operation::MoveSceneItem {
+                    scene: "main".to_owned(),
+                    source: "text".to_owned(),
+                    position: (20.0, 10.0),
+                },
+                Operation::MoveSceneItem {
+                    scene: "second".to_owned(),
+                    source: "image".to_owned(),
+                    position: (5.0, 5.0),
+                },
+                Operation::CreateSceneItem {
+                    scene: "second".to_owned(),
+                    source: "camera".to_owned(),
+                    enabled: true,
+                },
+            ],
+            diff(&from, &to)
+        );
+
+        assert!(diff(&to, &to).is_empty());
+    }
+
+    #[test]
+    fn backup_names() {
+        let prefix = backup_prefix("Live: Main/Alt");
+        assert_eq!("Live_ Main_Alt-", prefix);
+
+        let time = time::Date::from_calendar_date(2025, time::Month::March, 7)
+            .unwrap()
+            .with_hms(8, 9, 10)
+            .unwrap()
+            .assume_offset(time::UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(
+            "Live_ Main_Alt-20250307T060910Z.json",
+            backup_name(&prefix, time)
+        );
+    }
+
+    #[test]
+    fn expired_backups() {
+        let names = [
+            "Live-20250103T000000Z.json",
+            "Live-20250101T000000Z.json",
+            "Live-Alt-20250101T000000Z.json",
+            "Live-20250102T000000Z.json",
+            "Live-notes.json",
+            "Live-20250104T000000Z.json",
+        ]
+        .map(str::to_owned)
+        .to_vec();
+
+        assert_eq!(
+            vec!["Live-20250101T000000Z.json", "Live-20250102T000000Z.json"],
+            expired(names.clone(), "Live-", 2)
+        );
+        assert!(expired(names.clone(), "Live-", 10).is_empty());
+        assert_eq!(4, expired(names, "Live-", 0).len());
+    }
+
+    #[test]
+    fn input_report() {
+        let from = Snapshot {
+            inputs: BTreeMap::from([
+                (
+                    "Camera".to_owned(),
+                    input("v4l2_input", json!({"device": "0"})),
+                ),
+                (
+                    "Camera 2".to_owned(),
+                    input("v4l2_input", json!({"device": "0"})),
+                ),
+                (
+                    "Desktop Audio".to_owned(),
+                    input("pulse_output_capture", json!({})),
+                ),
+                ("Mic".to_owned(), input("pulse_input_capture", json!({}))),
+                (
+                    "Old Mic".to_owned(),
+                    input("pulse_input_capture", json!({})),
+                ),
+                (
+                    "Title".to_owned(),
+                    input("text_ft2_source_v2", json!({"text": "Hi"})),
+                ),
+            ]),
+            scenes: BTreeMap::from([(
+                "Live".to_owned(),
+                SceneState {
+                    items: vec![
+                        item("Camera", true, None),
+                        item("Mic", true, None),
+                        item("Overlay", true, None),
+                    ],
+                },
+            )]),
+            groups: BTreeMap::from([(
+                "Overlay".to_owned(),
+                SceneState {
+                    items: vec![item("Title", false, None)],
+                },
+            )]),
+            special_inputs: BTreeSet::from(["Desktop Audio".to_owned()]),
+        };
+
+        assert_eq!(
+            InputReport {
+                unused: vec!["Camera 2".to_owned(), "Old Mic".to_owned()],
+                duplicates: vec![
+                    vec!["Mic".to_owned(), "Old Mic".to_owned()],
+                    vec!["Camera".to_owned(), "Camera 2".to_owned()],
+                ],
+            },
+            from.input_report()
+        );
+    }
+}
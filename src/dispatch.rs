@@ -0,0 +1,217 @@
+//! Dispatching of events to registered callbacks, as alternative to event streams.
+//!
+//! Applications with their own event loop, like GUI frameworks, often find it easier to register
+//! handlers for the events they care about, than to own a task that polls an event stream. With
+//! [`Client::on`], an async callback is registered for a single [`EventKind`], like
+//! [`kinds::SceneCreated`]. Each callback runs in its own task and receives the matching events
+//! in order, until its [`Registration`] is unregistered or dropped.
+//!
+//! Callbacks only listen to the [`EventKind::SUBSCRIPTION`] category of their kind, and receive
+//! the events shared with all other listeners, so registering many callbacks doesn't copy each
+//! event many times.
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use obws::{dispatch::kinds::SceneCreated, events::Event, Client};
+//!
+//! # async fn run(client: &Client) -> obws::error::Result<()> {
+//! let registration = client.on::<SceneCreated>(|event: Arc<Event>| async move {
+//!     if let Event::SceneCreated { id, .. } = &*event {
+//!         println!("scene {} created", id.name);
+//!     }
+//! })?;
+//!
+//! // Later, once the events are no longer of interest.
+//! registration.unregister();
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{future::Future, sync::Arc};
+
+use futures_util::{future::BoxFuture, FutureExt, Stream, StreamExt};
+use tokio::task::JoinHandle;
+
+#[cfg(doc)]
+use crate::Client;
+use crate::{events::Event, requests::EventSubscription};
+
+/// A kind of event, that callbacks can be registered for with [`Client::on`].
+///
+/// All event types of obs-websocket have a matching marker type in [`kinds`]. Custom kinds can
+/// be implemented to register a single callback for a group of events.
+pub trait EventKind {
+    /// Event categories, that contain all events of this kind. Defaults to all categories,
+    /// including the high-volume ones.
+    const SUBSCRIPTION: EventSubscription = EventSubscription::all();
+
+    /// Whether the event is of this kind.
+    fn matches(event: &Event) -> bool;
+}
+
+/// Async callback for events, registered with [`Client::on`].
+///
+/// This is implemented for all functions and closures, that take a shared [`Event`] and return
+/// a future.
+pub trait EventHandler: Send + Sync + 'static {
+    /// Handle a single event.
+    fn call(&self, event: Arc<Event>) -> BoxFuture<'static, ()>;
+}
+
+impl<F, Fut> EventHandler for F
+where
+    F: Fn(Arc<Event>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    fn call(&self, event: Arc<Event>) -> BoxFuture<'static, ()> {
+        self(event).boxed()
+    }
+}
+
+/// Handle of a callback, registered with [`Client::on`].
+///
+/// The callback stays registered until [`Self::unregister`] is called or the registration is
+/// dropped, or the connection to obs-websocket is closed.
+#[must_use = "the callback is unregistered when the registration is dropped"]
+#[derive(Debug)]
+pub struct Registration {
+    handle: JoinHandle<()>,
+}
+
+impl Registration {
+    /// Stop calling the callback for further events. A currently running call is cancelled.
+    pub fn unregister(self) {
+        drop(self);
+    }
+
+    /// Whether the callback is still registered. This is no longer the case, once the connection
+    /// to obs-websocket was closed.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        !self.handle.is_finished()
+    }
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Call the handler for all events of the given kind, in a new task.
+pub(crate) fn spawn<K: EventKind>(
+    events: impl Stream<Item = Arc<Event>> + Send + 'static,
+    handler: impl EventHandler,
+) -> Registration {
+    let handle = tokio::spawn(async move {
+        let events = events.filter(|event| std::future::ready(K::matches(event)));
+        tokio::pin!(events);
+
+        while let Some(event) = events.next().await {
+            handler.call(event).await;
+        }
+    });
+
+    Registration { handle }
+}
+
+/// Marker types for all events, to register callbacks with [`Client::on`].
+pub mod kinds {
+    use super::EventKind;
+    use crate::{events::Event, requests::EventSubscription};
+
+    macro_rules! kinds {
+        ($($subscription:ident => [$($kind:ident),+ $(,)?]),+ $(,)?) => {
+            $($(
+                #[doc = concat!("Marker for [`Event::", stringify!($kind), "`].")]
+                #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+                pub struct $kind;
+
+                impl EventKind for $kind {
+                    const SUBSCRIPTION: EventSubscription = EventSubscription::$subscription;
+
+                    fn matches(event: &Event) -> bool {
+                        matches!(event, Event::$kind { .. })
+                    }
+                }
+            )+)+
+        };
+    }
+
+    kinds! {
+        CONFIG => [
+            CurrentSceneCollectionChanging,
+            CurrentSceneCollectionChanged,
+            SceneCollectionListChanged,
+            CurrentProfileChanging,
+            CurrentProfileChanged,
+            ProfileListChanged,
+        ],
+        FILTERS => [
+            SourceFilterCreated,
+            SourceFilterRemoved,
+            SourceFilterListReindexed,
+            SourceFilterEnableStateChanged,
+            SourceFilterNameChanged,
+            SourceFilterSettingsChanged,
+        ],
+        GENERAL => [CustomEvent, ExitStarted],
+        VENDORS => [VendorEvent],
+        INPUTS => [
+            InputCreated,
+            InputRemoved,
+            InputNameChanged,
+            InputSettingsChanged,
+            InputMuteStateChanged,
+            InputVolumeChanged,
+            InputAudioBalanceChanged,
+            InputAudioSyncOffsetChanged,
+            InputAudioTracksChanged,
+            InputAudioMonitorTypeChanged,
+        ],
+        INPUT_ACTIVE_STATE_CHANGED => [InputActiveStateChanged],
+        INPUT_SHOW_STATE_CHANGED => [InputShowStateChanged],
+        INPUT_VOLUME_METERS => [InputVolumeMeters],
+        MEDIA_INPUTS => [
+            MediaInputPlaybackStarted,
+            MediaInputPlaybackEnded,
+            MediaInputActionTriggered,
+        ],
+        OUTPUTS => [
+            StreamStateChanged,
+            RecordStateChanged,
+            RecordFileChanged,
+            ReplayBufferStateChanged,
+            VirtualcamStateChanged,
+            ReplayBufferSaved,
+        ],
+        SCENE_ITEMS => [
+            SceneItemCreated,
+            SceneItemRemoved,
+            SceneItemListReindexed,
+            SceneItemEnableStateChanged,
+            SceneItemLockStateChanged,
+            SceneItemSelected,
+        ],
+        SCENE_ITEM_TRANSFORM_CHANGED => [SceneItemTransformChanged],
+        SCENES => [
+            SceneCreated,
+            SceneRemoved,
+            SceneNameChanged,
+            CurrentProgramSceneChanged,
+            CurrentPreviewSceneChanged,
+            SceneListChanged,
+        ],
+        TRANSITIONS => [
+            CurrentSceneTransitionChanged,
+            CurrentSceneTransitionDurationChanged,
+            SceneTransitionStarted,
+            SceneTransitionEnded,
+            SceneTransitionVideoEnded,
+        ],
+        UI => [StudioModeStateChanged, ScreenshotSaved],
+        // Events without a category are delivered to all listeners.
+        NONE => [ServerStopping, ServerStopped],
+    }
+}
@@ -0,0 +1,36 @@
+//! Shared setup for the examples in the documentation.
+
+/// Run the body of a documentation example against a [`crate::testing::Stub`], with `$stub` and
+/// `$client` bound to the stub and a client connected to it.
+///
+/// Without the `testing` feature, the example compiles to nothing, so it doesn't fail when running
+/// the documentation tests with the default features.
+#[cfg(feature = "testing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! doctest {
+    (|$stub:ident, $client:ident| { $($body:tt)* }) => {
+        ::tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("failed to create runtime")
+            .block_on(async {
+                use ::serde_json::json;
+
+                #[allow(unused_variables)]
+                let $stub = $crate::testing::Stub::new();
+                let $client = $stub.connect().await?;
+                $($body)*
+                ::std::result::Result::<(), $crate::error::Error>::Ok(())
+            })
+            .unwrap();
+    };
+}
+
+/// Run the body of a documentation example, which is skipped without the `testing` feature.
+#[cfg(not(feature = "testing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! doctest {
+    ($($tokens:tt)*) => {};
+}
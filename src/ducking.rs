@@ -0,0 +1,285 @@
+//! Automatic ducking of audio inputs, while another input is loud.
+//!
+//! Ducking lowers the volume of background audio, like music, while someone speaks into a
+//! microphone, similar to a sidechain compressor. [`Ducking`] watches the volume meters of the
+//! trigger input, and lowers the volume of all target inputs once the trigger stayed above the
+//! threshold for the attack time. The original volumes are restored, after the trigger stayed
+//! below the threshold for the release time.
+//!
+//! The volume meters are high-volume events, that must be enabled through
+//! [`EventSubscription::INPUT_VOLUME_METERS`] when connecting, or later with
+//! [`Client::reidentify`].
+//!
+//! Ducking with [`Ducking::run_until`] restores the original volumes once it stops, so the
+//! targets aren't left at their lowered volume.
+//!
+//! ```no_run
+//! use obws::{ducking::Ducking, requests::EventSubscription, Client};
+//!
+//! # async fn run(client: &Client) -> obws::error::Result<()> {
+//! client
+//!     .reidentify(EventSubscription::ALL | EventSubscription::INPUT_VOLUME_METERS)
+//!     .await?;
+//!
+//! // Send on `stop_tx` from elsewhere, to stop ducking and restore the volumes.
+//! let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+//!
+//! Ducking::new("Mic/Aux", vec!["Music".to_owned()])
+//!     .run_until(client, async {
+//!         stop_rx.await.ok();
+//!     })
+//!     .await
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use futures_util::{
+    future::{self, Either},
+    StreamExt,
+};
+use tracing::debug;
+
+use crate::{
+    error::Result,
+    events::{Event, InputVolumeMeter},
+    requests::{
+        inputs::{InputId, Volume},
+        EventSubscription,
+    },
+    Client,
+};
+
+/// Configuration of the ducking of target inputs, based on the volume of a trigger input.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct Ducking {
+    /// Name of the input, that causes the targets to be lowered while it's loud.
+    #[cfg_attr(feature = "builder", builder(into))]
+    pub trigger: String,
+    /// Names of the inputs, that are lowered.
+    pub targets: Vec<String>,
+    /// Peak volume of the trigger in dB, above which it's considered loud.
+    #[cfg_attr(feature = "builder", builder(default = -30.0))]
+    pub threshold_db: f32,
+    /// Amount in dB, that the targets are lowered by.
+    #[cfg_attr(feature = "builder", builder(default = 12.0))]
+    pub reduction_db: f32,
+    /// Time that the trigger has to be loud, before the targets are lowered.
+    #[cfg_attr(feature = "builder", builder(default = Duration::from_millis(100)))]
+    pub attack: Duration,
+    /// Time that the trigger has to be quiet, before the targets are restored.
+    #[cfg_attr(feature = "builder", builder(default = Duration::from_millis(800)))]
+    pub release: Duration,
+}
+
+impl Ducking {
+    /// Create a new configuration for the given trigger and targets, using default values for
+    /// all other settings.
+    #[must_use]
+    pub fn new(trigger: impl Into<String>, targets: Vec<String>) -> Self {
+        Self {
+            trigger: trigger.into(),
+            targets,
+            threshold_db: -30.0,
+            reduction_db: 12.0,
+            attack: Duration::from_millis(100),
+            release: Duration::from_millis(800),
+        }
+    }
+
+    /// Duck the targets based on the volume of the trigger, until the connection to
+    /// obs-websocket is closed.
+    ///
+    /// Volume changes of the targets, that are made while they're not ducked, are picked up as
+    /// their new original volume. See [`Self::run_until`] for details about restoring the
+    /// original volumes.
+    pub async fn run(&self, client: &Client) -> Result<()> {
+        self.run_until(client, future::pending()).await
+    }
+
+    /// Duck the targets like [`Self::run`], until the `stop` future completes or the connection
+    /// to obs-websocket is closed.
+    ///
+    /// If the targets are currently ducked, their original volumes are restored before
+    /// returning, also when ducking fails with an error. This can't happen when the returned
+    /// future is dropped before it completes, for example by aborting its task, which leaves the
+    /// targets at their lowered volume. Ducking should therefore be stopped through `stop`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the volumes of the targets can't be read or changed, including when restoring
+    /// the original volumes. An error during ducking takes precedence over one of restoring.
+    pub async fn run_until(&self, client: &Client, stop: impl Future<Output = ()>) -> Result<()> {
+        // Subscribe before loading the volumes, to not miss any changes in between.
+        let mut events = Box::pin(
+            client
+                .events_for(EventSubscription::INPUT_VOLUME_METERS | EventSubscription::INPUTS)?,
+        );
+
+        let mut volumes = HashMap::with_capacity(self.targets.len());
+        for target in &self.targets {
+            let volume = client.inputs().volume(InputId::Name(target)).await?;
+            volumes.insert(target.clone(), volume.db);
+        }
+
+        let mut envelope = Envelope::new(self.attack, self.release);
+        let mut stop = std::pin::pin!(stop);
+
+        let result = async {
+            loop {
+                let event = match future::select(events.next(), stop.as_mut()).await {
+                    Either::Left((Some(event), _)) => event,
+                    Either::Left((None, _)) | Either::Right(((), _)) => return Ok(()),
+                };
+
+                match event {
+                    Event::InputVolumeMeters { inputs } => {
+                        let loud = inputs
+                            .iter()
+                            .find(|meter| meter.name == self.trigger)
+                            .is_some_and(|meter| peak_db(meter) > self.threshold_db);
+
+                        if let Some(ducked) = envelope.update(client.clock().now(), loud) {
+                            self.set_volumes(client, &volumes, ducked).await?;
+                        }
+                    }
+                    Event::InputVolumeChanged { id, db, .. } if !envelope.ducked => {
+                        if let Some(volume) = volumes.get_mut(&id.name) {
+                            #[allow(clippy::cast_possible_truncation)]
+                            let db = db as f32;
+                            *volume = db;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        .await;
+
+        if envelope.ducked {
+            let restored = self.set_volumes(client, &volumes, false).await;
+            return result.and(restored);
+        }
+
+        result
+    }
+
+    /// Set the targets to their ducked or original volume.
+    async fn set_volumes(
+        &self,
+        client: &Client,
+        volumes: &HashMap<String, f32>,
+        ducked: bool,
+    ) -> Result<()> {
+        debug!(trigger = %self.trigger, ducked, "changing volume of ducked inputs");
+
+        for (target, db) in volumes {
+            let db = if ducked { db - self.reduction_db } else { *db };
+            client
+                .inputs()
+                .set_volume(InputId::Name(target), Volume::Db(db))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Highest peak volume over all channels of an input in dB.
+fn peak_db(meter: &InputVolumeMeter) -> f32 {
+    let peak = meter
+        .levels
+        .iter()
+        .map(|[_, peak, _]| *peak)
+        .fold(0.0, f32::max);
+
+    20.0 * peak.log10()
+}
+
+/// Decides when to duck or restore the targets, based on how long the trigger is loud or quiet.
+struct Envelope {
+    attack: Duration,
+    release: Duration,
+    ducked: bool,
+    /// Since when the trigger is in the opposite state of what [`Self::ducked`] suggests.
+    since: Option<Instant>,
+}
+
+impl Envelope {
+    fn new(attack: Duration, release: Duration) -> Self {
+        Self {
+            attack,
+            release,
+            ducked: false,
+            since: None,
+        }
+    }
+
+    /// Update with the latest state of the trigger, returning the new ducking state if it
+    /// changed.
+    fn update(&mut self, now: Instant, loud: bool) -> Option<bool> {
+        if loud == self.ducked {
+            self.since = None;
+            return None;
+        }
+
+        let since = *self.since.get_or_insert(now);
+        let hold = if loud { self.attack } else { self.release };
+
+        if now.saturating_duration_since(since) < hold {
+            return None;
+        }
+
+        self.ducked = loud;
+        self.since = None;
+        Some(loud)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn envelope() {
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut envelope = Envelope::new(Duration::from_millis(100), Duration::from_millis(500));
+
+        assert_eq!(None, envelope.update(at(0), false));
+
+        // short noise isn't enough to duck
+        assert_eq!(None, envelope.update(at(50), true));
+        assert_eq!(None, envelope.update(at(100), false));
+        assert_eq!(None, envelope.update(at(150), true));
+
+        assert_eq!(None, envelope.update(at(200), true));
+        assert_eq!(Some(true), envelope.update(at(250), true));
+        assert_eq!(None, envelope.update(at(300), true));
+
+        // short pauses keep the targets ducked
+        assert_eq!(None, envelope.update(at(400), false));
+        assert_eq!(None, envelope.update(at(800), true));
+        assert_eq!(None, envelope.update(at(900), false));
+        assert_eq!(None, envelope.update(at(1300), false));
+        assert_eq!(Some(false), envelope.update(at(1400), false));
+        assert_eq!(None, envelope.update(at(1500), false));
+    }
+
+    #[test]
+    fn peak() {
+        let meter = |levels| InputVolumeMeter {
+            name: "Mic".to_owned(),
+            levels,
+        };
+
+        assert!((peak_db(&meter(vec![[0.1, 1.0, 1.0], [0.1, 0.5, 0.5]]))).abs() < 1e-6);
+        assert!((peak_db(&meter(vec![[0.0, 0.1, 0.1]])) + 20.0).abs() < 1e-4);
+        assert!(peak_db(&meter(vec![])) < -1000.0);
+    }
+}
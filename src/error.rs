@@ -12,12 +12,26 @@ pub enum Error {
     /// An error occurred while trying to connect to the web-socket.
     #[error("failed to connect to the obs-websocket plugin")]
     Connect(#[from] ConnectError),
-    /// The set connection timeout was reached before the connection could be created.
-    #[error("timeout happened before the connection could be established")]
+    /// The connection attempt of a [`LazyClient`](crate::client::LazyClient) failed. The same
+    /// error is shared by all callers, that waited for the attempt.
+    #[error("failed to lazily connect to the obs-websocket plugin")]
+    LazyConnect(#[source] std::sync::Arc<Error>),
+    /// The set connection timeout was reached before the connection could be created, or the
+    /// request timeout was reached before a response arrived.
+    #[error("timeout happened before the connection could be established or a response arrived")]
     Timeout,
     /// The initial handshake with `obs-websocket` didn't succeed.
     #[error("failed to execute the handshake with obs-websocket")]
     Handshake(#[from] crate::client::HandshakeError),
+    /// obs-websocket rejected the authentication during the initial handshake and closed the
+    /// connection.
+    #[error("failed to authenticate against obs-websocket: {kind}")]
+    AuthenticationFailed {
+        /// Whether no password was given, or the given password was wrong.
+        kind: AuthenticationFailure,
+        /// Close code and reason, as sent by obs-websocket.
+        details: crate::client::CloseDetails,
+    },
     /// Failed to serialize the message to be send to the web-socket.
     #[error("failed to serialize message")]
     SerializeMessage(#[from] SerializeMessageError),
@@ -33,10 +47,74 @@ pub enum Error {
     /// Failed to serialize custom user defined data for a message.
     #[error("failed to serialize custom data")]
     SerializeCustomData(#[from] SerializeCustomDataError),
+    /// Failed to decode the Base64-encoded image data of a screenshot.
+    #[error("failed to decode image data")]
+    DecodeImage(#[from] DecodeImageError),
+    /// Failed to write a scene collection backup to disk, or to remove an old one.
+    #[error("failed to write backup")]
+    WriteBackup(#[from] WriteBackupError),
+    /// Failed to read the image data of a screenshot as image.
+    #[cfg(feature = "image")]
+    #[error("failed to read image")]
+    ReadImage(#[from] ReadImageError),
+    /// Failed to deserialize a filter preset from TOML.
+    #[cfg(feature = "presets")]
+    #[error("failed to deserialize filter preset")]
+    DeserializePreset(#[from] DeserializePresetError),
+    /// Failed to serialize a filter preset into TOML.
+    #[cfg(feature = "presets")]
+    #[error("failed to serialize filter preset")]
+    SerializePreset(#[from] SerializePresetError),
+    /// Failed to read a session recording.
+    #[cfg(feature = "testing")]
+    #[error("failed to read recording")]
+    ReadRecording(#[from] ReadRecordingError),
+    /// A session recording contained an invalid message.
+    #[cfg(feature = "testing")]
+    #[error("failed to deserialize recorded message")]
+    DeserializeRecording(#[from] DeserializeRecordingError),
+    /// A profile parameter had a value that couldn't be parsed into the expected type.
+    #[error("invalid value {value:?} for profile parameter {category}.{name}")]
+    InvalidProfileParameter {
+        /// Category of the parameter.
+        category: &'static str,
+        /// Name of the parameter.
+        name: &'static str,
+        /// The value that failed to parse.
+        value: String,
+    },
+    /// A sleep request of a batch didn't match the execution type of the batch. Sleeping in
+    /// milliseconds requires realtime execution, and sleeping in frames requires frame execution.
+    #[error("sleep request isn't valid in a batch with {0:?} execution")]
+    InvalidBatchSleep(crate::requests::batch::BatchExecution),
+    /// A template for recording file names contained a specifier, that OBS doesn't know.
+    #[error("unknown specifier `{0}` in file name format")]
+    InvalidFilenameFormat(String),
+    /// A request was larger than the configured [`crate::client::RequestSizeLimit`].
+    #[error("request size of {size} bytes exceeds the limit of {limit} bytes")]
+    RequestTooLarge {
+        /// Size of the serialized request in bytes.
+        size: usize,
+        /// The configured maximum size in bytes.
+        limit: usize,
+    },
+    /// A stream caption was longer than
+    /// [`MAX_CAPTION_LENGTH`](crate::requests::streaming::MAX_CAPTION_LENGTH).
+    #[error("caption of {length} characters exceeds the limit of {limit} characters")]
+    CaptionTooLong {
+        /// Number of characters in the caption.
+        length: usize,
+        /// The maximum number of characters.
+        limit: usize,
+    },
+    /// obs-websocket responded with a batch response to a single request or vice versa.
+    #[error("received a response of unexpected kind")]
+    UnexpectedResponse,
     /// Custom data didn't serialize into a JSON object.
     #[error("custom data must serialize into a JSON object")]
     InvalidCustomData,
-    /// An error returned from the obs-websocket API.
+    /// An error returned from the obs-websocket API, that isn't covered by one of the more
+    /// specific variants, like [`Self::ResourceNotFound`].
     #[error("API error: {code:?}")]
     Api {
         /// Status code that describes the kind of error.
@@ -44,9 +122,73 @@ pub enum Error {
         /// Optional message to provide additional details about the error.
         message: Option<String>,
     },
+    /// A requested resource, like an input, scene or profile, doesn't exist. Contains the
+    /// message of obs-websocket with details about the resource.
+    #[error("resource not found: {0}")]
+    ResourceNotFound(String),
+    /// A resource couldn't be created, because it already exists. Contains the message of
+    /// obs-websocket with details about the resource.
+    #[error("resource already exists: {0}")]
+    ResourceAlreadyExists(String),
+    /// An output is already running, for example when trying to start a stream that is already
+    /// live. Contains the message of obs-websocket with details about the output.
+    #[error("output is already running: {0}")]
+    OutputRunning(String),
+    /// An output is not running, for example when trying to stop a recording that was never
+    /// started. Contains the message of obs-websocket with details about the output.
+    #[error("output is not running: {0}")]
+    OutputNotRunning(String),
+    /// obs-websocket is temporarily not ready, like during a scene collection change. Contains
+    /// the message of obs-websocket with details about the reason.
+    #[error("obs-websocket is not ready: {0}")]
+    NotReady(String),
+    /// A negative duration was converted into a [`std::time::Duration`], which can't represent
+    /// it.
+    #[error("duration {0} is negative")]
+    NegativeDuration(time::Duration),
     /// Unknown flags were found while trying to parse bitflags.
     #[error("value {0} contains unknown flags")]
     UnknownFlags(u8),
+    /// No monitor with the given name is currently connected to the OBS host.
+    #[error("no monitor named `{0}` is connected")]
+    UnknownMonitor(String),
+    /// No scene transition of the given kind exists in OBS.
+    #[error("no transition of kind `{0}` exists")]
+    UnknownTransitionKind(String),
+    /// Typed transition settings were applied to a transition of another kind.
+    #[error("settings for `{expected}` don't apply to the current transition of kind `{actual}`")]
+    TransitionKindMismatch {
+        /// Kind of transition, that the settings belong to.
+        expected: &'static str,
+        /// Kind of the current scene transition.
+        actual: String,
+    },
+    /// A [`crate::restricted::RestrictedClient`] was asked to perform an operation, that it
+    /// isn't allowed to.
+    #[error("operation requires the {0:?} permission")]
+    PermissionDenied(crate::restricted::Permissions),
+    /// The connected server doesn't support the request, as it's missing from its list of
+    /// available requests. Only checked for some
+    /// [`ServerProfile`](crate::client::ServerProfile)s.
+    #[error("the request `{0}` is not supported by the server")]
+    UnsupportedRequest(String),
+    /// No way to send interaction events to a source is configured.
+    #[error("sending interaction events requires a vendor request")]
+    InteractionUnavailable,
+    /// obs-websocket doesn't support the image format for screenshots.
+    #[error("image format `{0}` is not supported by obs-websocket")]
+    UnsupportedImageFormat(&'static str),
+    /// The compression quality for a screenshot is outside the range of -1 to 100.
+    #[error("compression quality {0} is outside the range of -1 to 100")]
+    InvalidCompressionQuality(i32),
+    /// A video resolution isn't a multiple of two or outside the supported range.
+    #[error("invalid resolution {width}x{height}")]
+    InvalidResolution {
+        /// Width of the resolution.
+        width: u32,
+        /// Height of the resolution.
+        height: u32,
+    },
     /// Tried to interact with obs-websocket while not connected (for example trying to get a new
     /// event stream).
     #[error("currently not connected to obs-websocket")]
@@ -68,10 +210,92 @@ pub enum Error {
     },
 }
 
+/// Cause of an [`Error::AuthenticationFailed`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
+pub enum AuthenticationFailure {
+    /// obs-websocket requires a password, but none was configured.
+    #[error("a password is required, but none was given")]
+    MissingPassword,
+    /// The configured password is wrong.
+    #[error("the password was rejected")]
+    PasswordRejected,
+}
+
+impl Error {
+    /// Create the error for a failed request, with the status code and optional message returned
+    /// from obs-websocket.
+    pub(crate) fn from_status(code: StatusCode, message: Option<String>) -> Self {
+        match code {
+            StatusCode::ResourceNotFound => Self::ResourceNotFound(message.unwrap_or_default()),
+            StatusCode::ResourceAlreadyExists => {
+                Self::ResourceAlreadyExists(message.unwrap_or_default())
+            }
+            StatusCode::OutputRunning => Self::OutputRunning(message.unwrap_or_default()),
+            StatusCode::OutputNotRunning => Self::OutputNotRunning(message.unwrap_or_default()),
+            StatusCode::NotReady => Self::NotReady(message.unwrap_or_default()),
+            code => Self::Api { code, message },
+        }
+    }
+
+    /// The status code, if this is an error returned from obs-websocket, like [`Error::Api`] or
+    /// [`Error::ResourceNotFound`].
+    #[must_use]
+    pub fn status_code(&self) -> Option<StatusCode> {
+        match self {
+            Self::Api { code, .. } => Some(*code),
+            Self::ResourceNotFound(_) => Some(StatusCode::ResourceNotFound),
+            Self::ResourceAlreadyExists(_) => Some(StatusCode::ResourceAlreadyExists),
+            Self::OutputRunning(_) => Some(StatusCode::OutputRunning),
+            Self::OutputNotRunning(_) => Some(StatusCode::OutputNotRunning),
+            Self::NotReady(_) => Some(StatusCode::NotReady),
+            _ => None,
+        }
+    }
+
+    /// Whether a requested resource, like an input, scene or profile, doesn't exist.
+    #[must_use]
+    pub fn is_not_found(&self) -> bool {
+        self.status_code() == Some(StatusCode::ResourceNotFound)
+    }
+
+    /// Whether a resource couldn't be created, because it already exists.
+    #[must_use]
+    pub fn is_already_exists(&self) -> bool {
+        self.status_code() == Some(StatusCode::ResourceAlreadyExists)
+    }
+
+    /// Whether the request failed, because an output is already running, for example when trying
+    /// to start a stream that is already live.
+    #[must_use]
+    pub fn is_output_running(&self) -> bool {
+        self.status_code() == Some(StatusCode::OutputRunning)
+    }
+
+    /// Whether the request failed, because an output is not running, for example when trying to
+    /// stop a recording that was never started.
+    #[must_use]
+    pub fn is_output_not_running(&self) -> bool {
+        self.status_code() == Some(StatusCode::OutputNotRunning)
+    }
+
+    /// Whether the same request may succeed when tried again after a short delay.
+    ///
+    /// This is the case when obs-websocket is temporarily not ready, like during a scene
+    /// collection change, or when a connection attempt timed out.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::LazyConnect(error) => error.is_retryable(),
+            _ => matches!(self, Self::Timeout | Self::NotReady(_)),
+        }
+    }
+}
+
 /// An error occurred while trying to connect to the web-socket.
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct ConnectError(pub(crate) tokio_tungstenite::tungstenite::Error);
+pub struct ConnectError(pub(crate) Box<tokio_tungstenite::tungstenite::Error>);
 
 /// Failed to serialize the message to be send to the web-socket.
 #[derive(Debug, thiserror::Error)]
@@ -81,7 +305,7 @@ pub struct SerializeMessageError(pub(crate) serde_json::Error);
 /// A message could not be send through the web-socket.
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct SendError(pub(crate) tokio_tungstenite::tungstenite::Error);
+pub struct SendError(pub(crate) Box<tokio_tungstenite::tungstenite::Error>);
 
 /// Tried to receive data while the send side was already closed.
 #[derive(Debug, thiserror::Error)]
@@ -97,3 +321,80 @@ pub struct DeserializeResponseError(pub(crate) serde_json::Error);
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
 pub struct SerializeCustomDataError(pub(crate) serde_json::Error);
+
+/// Failed to decode the Base64-encoded image data of a screenshot.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DecodeImageError(pub(crate) base64::DecodeError);
+
+/// Failed to write a scene collection backup to disk, or to remove an old one.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct WriteBackupError(pub(crate) std::io::Error);
+
+/// Failed to read the image data of a screenshot as image.
+#[cfg(feature = "image")]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct ReadImageError(pub(crate) image::ImageError);
+
+/// Failed to deserialize a filter preset from TOML.
+#[cfg(feature = "presets")]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DeserializePresetError(pub(crate) toml::de::Error);
+
+/// Failed to serialize a filter preset into TOML.
+#[cfg(feature = "presets")]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct SerializePresetError(pub(crate) toml::ser::Error);
+
+/// Failed to read a session recording.
+#[cfg(feature = "testing")]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct ReadRecordingError(pub(crate) std::io::Error);
+
+/// A session recording contained an invalid message.
+#[cfg(feature = "testing")]
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DeserializeRecordingError(pub(crate) serde_json::Error);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_helpers() {
+        let api = |code| Error::from_status(code, None);
+
+        assert!(api(StatusCode::ResourceNotFound).is_not_found());
+        assert!(api(StatusCode::ResourceAlreadyExists).is_already_exists());
+        assert!(api(StatusCode::OutputRunning).is_output_running());
+        assert!(api(StatusCode::OutputNotRunning).is_output_not_running());
+        assert!(api(StatusCode::NotReady).is_retryable());
+        assert!(Error::Timeout.is_retryable());
+
+        assert!(matches!(
+            Error::from_status(StatusCode::ResourceNotFound, Some("no scene".to_owned())),
+            Error::ResourceNotFound(message) if message == "no scene"
+        ));
+        assert_eq!(
+            Some(StatusCode::NotReady),
+            api(StatusCode::NotReady).status_code()
+        );
+        assert!(matches!(
+            api(StatusCode::GenericError),
+            Error::Api {
+                code: StatusCode::GenericError,
+                message: None,
+            }
+        ));
+
+        assert!(!api(StatusCode::OutputRunning).is_not_found());
+        assert!(!Error::Disconnected.is_retryable());
+        assert_eq!(None, Error::Timeout.status_code());
+    }
+}
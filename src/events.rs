@@ -8,6 +8,7 @@ use uuid::Uuid;
 
 use crate::{
     common::{MediaAction, MonitorType},
+    requests::EventSubscription,
     responses::{
         filters::SourceFilter,
         ids::{SceneId, TransitionId},
@@ -599,6 +600,90 @@ pub enum Event {
     Unknown,
 }
 
+impl Event {
+    /// The event subscription, that this event belongs to.
+    ///
+    /// Events that are created by this crate instead of obs-websocket, like
+    /// [`Self::ServerStopping`], as well as [`Self::Unknown`] events don't belong to any
+    /// subscription and return [`EventSubscription::NONE`].
+    #[must_use]
+    pub fn subscription(&self) -> EventSubscription {
+        match self {
+            Self::CurrentSceneCollectionChanging { .. }
+            | Self::CurrentSceneCollectionChanged { .. }
+            | Self::SceneCollectionListChanged { .. }
+            | Self::CurrentProfileChanging { .. }
+            | Self::CurrentProfileChanged { .. }
+            | Self::ProfileListChanged { .. } => EventSubscription::CONFIG,
+            Self::SourceFilterCreated { .. }
+            | Self::SourceFilterRemoved { .. }
+            | Self::SourceFilterListReindexed { .. }
+            | Self::SourceFilterEnableStateChanged { .. }
+            | Self::SourceFilterNameChanged { .. }
+            | Self::SourceFilterSettingsChanged { .. } => EventSubscription::FILTERS,
+            Self::CustomEvent(_) | Self::ExitStarted => EventSubscription::GENERAL,
+            Self::VendorEvent { .. } => EventSubscription::VENDORS,
+            Self::InputActiveStateChanged { .. } => EventSubscription::INPUT_ACTIVE_STATE_CHANGED,
+            Self::InputShowStateChanged { .. } => EventSubscription::INPUT_SHOW_STATE_CHANGED,
+            Self::InputVolumeMeters { .. } => EventSubscription::INPUT_VOLUME_METERS,
+            Self::InputCreated { .. }
+            | Self::InputRemoved { .. }
+            | Self::InputNameChanged { .. }
+            | Self::InputSettingsChanged { .. }
+            | Self::InputMuteStateChanged { .. }
+            | Self::InputVolumeChanged { .. }
+            | Self::InputAudioBalanceChanged { .. }
+            | Self::InputAudioSyncOffsetChanged { .. }
+            | Self::InputAudioTracksChanged { .. }
+            | Self::InputAudioMonitorTypeChanged { .. } => EventSubscription::INPUTS,
+            Self::MediaInputPlaybackStarted { .. }
+            | Self::MediaInputPlaybackEnded { .. }
+            | Self::MediaInputActionTriggered { .. } => EventSubscription::MEDIA_INPUTS,
+            Self::StreamStateChanged { .. }
+            | Self::RecordStateChanged { .. }
+            | Self::RecordFileChanged { .. }
+            | Self::ReplayBufferStateChanged { .. }
+            | Self::VirtualcamStateChanged { .. }
+            | Self::ReplayBufferSaved { .. } => EventSubscription::OUTPUTS,
+            Self::SceneItemTransformChanged { .. } => {
+                EventSubscription::SCENE_ITEM_TRANSFORM_CHANGED
+            }
+            Self::SceneItemCreated { .. }
+            | Self::SceneItemRemoved { .. }
+            | Self::SceneItemListReindexed { .. }
+            | Self::SceneItemEnableStateChanged { .. }
+            | Self::SceneItemLockStateChanged { .. }
+            | Self::SceneItemSelected { .. } => EventSubscription::SCENE_ITEMS,
+            Self::SceneCreated { .. }
+            | Self::SceneRemoved { .. }
+            | Self::SceneNameChanged { .. }
+            | Self::CurrentProgramSceneChanged { .. }
+            | Self::CurrentPreviewSceneChanged { .. }
+            | Self::SceneListChanged { .. } => EventSubscription::SCENES,
+            Self::CurrentSceneTransitionChanged { .. }
+            | Self::CurrentSceneTransitionDurationChanged { .. }
+            | Self::SceneTransitionStarted { .. }
+            | Self::SceneTransitionEnded { .. }
+            | Self::SceneTransitionVideoEnded { .. } => EventSubscription::TRANSITIONS,
+            Self::StudioModeStateChanged { .. } | Self::ScreenshotSaved { .. } => {
+                EventSubscription::UI
+            }
+            Self::ServerStopping | Self::ServerStopped | Self::Unknown => EventSubscription::NONE,
+        }
+    }
+}
+
+/// Item of a lag-aware event stream, created with [`crate::Client::events_with_lag`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventOrLag {
+    /// An event was received.
+    Event(Event),
+    /// The listener fell behind and the given number of events were dropped, because the
+    /// broadcast channel was full. State derived from events should be resynchronized, for
+    /// example by listing the scenes again.
+    Lagged(u64),
+}
+
 /// Volume meter information for a single input, describing the current volume level.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
 pub struct InputVolumeMeter {
@@ -611,7 +696,7 @@ pub struct InputVolumeMeter {
 }
 
 /// The output state describes the current status of any output (like recording, virtual-cam, ...).
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[non_exhaustive]
 pub enum OutputState {
     /// A request to start the output has been issued.
@@ -638,9 +723,9 @@ pub enum OutputState {
     /// Current output resumed.
     #[serde(rename = "OBS_WEBSOCKET_OUTPUT_RESUMED")]
     Resumed,
-    /// Fallback for any unknown event type.
-    #[serde(other)]
-    Unknown,
+    /// Any other value, not known to this crate yet, with the original string.
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// A basic scene item, only describing identifier and position.
@@ -0,0 +1,329 @@
+//! Automatic switching to a backup scene, when a critical source fails.
+//!
+//! A [`Failover`] watches a single source, like a capture card, by taking small screenshots of it
+//! in a fixed interval. The source is considered failed if the screenshot can't be taken or if it
+//! is almost completely black. After a number of consecutive failed checks, the program switches
+//! to the backup scene. Once the source delivered a picture again for a number of consecutive
+//! checks, the program switches back to the scene that was active before.
+//!
+//! Requiring several checks in a row for both directions avoids rapid switching back and forth,
+//! when the signal is flaky.
+//!
+//! With the `events` feature, the [`Event::InputActiveStateChanged`] and
+//! [`Event::InputShowStateChanged`] events count as additional failed check, whenever the source
+//! becomes inactive or hidden between two screenshots. These are high-volume events, that must be
+//! enabled through [`EventSubscription::INPUT_ACTIVE_STATE_CHANGED`] and
+//! [`EventSubscription::INPUT_SHOW_STATE_CHANGED`] when connecting, or later with
+//! [`Client::reidentify`]. Otherwise, only the screenshots are checked.
+//!
+//! [`Event::InputActiveStateChanged`]: crate::events::Event::InputActiveStateChanged
+//! [`Event::InputShowStateChanged`]: crate::events::Event::InputShowStateChanged
+//! [`EventSubscription::INPUT_ACTIVE_STATE_CHANGED`]: crate::requests::EventSubscription::INPUT_ACTIVE_STATE_CHANGED
+//! [`EventSubscription::INPUT_SHOW_STATE_CHANGED`]: crate::requests::EventSubscription::INPUT_SHOW_STATE_CHANGED
+
+use std::time::Duration;
+
+#[cfg(feature = "events")]
+use futures_util::{
+    future::{self, Either},
+    StreamExt,
+};
+use tracing::{debug, info, warn};
+
+use crate::{
+    bmp,
+    error::Result,
+    requests::{
+        scenes::SceneId,
+        sources::{SourceId, TakeScreenshot},
+    },
+    responses::scenes::SceneId as CurrentSceneId,
+    Client,
+};
+#[cfg(feature = "events")]
+use crate::{events::Event, requests::EventSubscription};
+
+/// Width and height of the screenshots used for the black-frame detection.
+const SCREENSHOT_SIZE: u32 = 16;
+
+/// Configuration of a failover, that switches to a backup scene when a source fails.
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct Failover<'a> {
+    /// The source to watch.
+    pub source: SourceId<'a>,
+    /// Scene to switch to, while the source is failing.
+    pub backup_scene: SceneId<'a>,
+    /// Time between two checks of the source.
+    #[cfg_attr(feature = "builder", builder(default = Duration::from_secs(1)))]
+    pub interval: Duration,
+    /// Average brightness in the range `0.0..=1.0`, below which the source is considered black.
+    #[cfg_attr(feature = "builder", builder(default = 0.02))]
+    pub black_threshold: f32,
+    /// Number of consecutive failed checks, before switching to the backup scene.
+    #[cfg_attr(feature = "builder", builder(default = 3))]
+    pub failure_checks: u32,
+    /// Number of consecutive successful checks, before switching back from the backup scene.
+    #[cfg_attr(feature = "builder", builder(default = 5))]
+    pub recovery_checks: u32,
+}
+
+impl Failover<'_> {
+    /// Watch the source and switch scenes as needed, until the connection to obs-websocket is
+    /// closed or a scene switch fails.
+    ///
+    /// This function doesn't return on its own while the connection is alive, so it's usually
+    /// run in a separate task.
+    pub async fn run(&self, client: &Client) -> Result<()> {
+        let mut hysteresis = Hysteresis::new(self.failure_checks, self.recovery_checks);
+        let mut previous_scene = None::<CurrentSceneId>;
+
+        #[cfg(feature = "events")]
+        let mut states = Box::pin(
+            client
+                .events_for(
+                    EventSubscription::INPUT_ACTIVE_STATE_CHANGED
+                        | EventSubscription::INPUT_SHOW_STATE_CHANGED,
+                )?
+                .fuse(),
+        );
+
+        loop {
+            let healthy = self.check(client).await?;
+            self.switch(client, hysteresis.update(healthy), &mut previous_scene)
+                .await?;
+
+            #[cfg(feature = "events")]
+            {
+                let mut sleep = client.clock().sleep(self.interval);
+
+                loop {
+                    match future::select(&mut sleep, states.next()).await {
+                        Either::Left(((), _)) => break,
+                        Either::Right((Some(event), _)) => {
+                            if hysteresis.failed || !self.lost(&event) {
+                                continue;
+                            }
+
+                            debug!(source = %self.source, "source became inactive or hidden");
+                            self.switch(client, hysteresis.update(false), &mut previous_scene)
+                                .await?;
+                        }
+                        Either::Right((None, _)) => {
+                            sleep.await;
+                            break;
+                        }
+                    }
+                }
+            }
+
+            #[cfg(not(feature = "events"))]
+            client.clock().sleep(self.interval).await;
+        }
+    }
+
+    /// Switch to the backup scene or back to the previous one, depending on the transition.
+    async fn switch(
+        &self,
+        client: &Client,
+        transition: Option<Transition>,
+        previous_scene: &mut Option<CurrentSceneId>,
+    ) -> Result<()> {
+        match transition {
+            Some(Transition::Failed) => {
+                warn!(source = %self.source, "source failed, switching to backup scene");
+                *previous_scene = Some(client.scenes().current_program_scene().await?.id);
+                client
+                    .scenes()
+                    .set_current_program_scene(self.backup_scene)
+                    .await?;
+            }
+            Some(Transition::Recovered) => {
+                info!(source = %self.source, "source recovered, switching back");
+                if let Some(scene) = previous_scene.take() {
+                    client.scenes().set_current_program_scene(&scene).await?;
+                }
+            }
+            None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Whether the event reports, that the watched source became inactive or hidden.
+    #[cfg(feature = "events")]
+    fn lost(&self, event: &Event) -> bool {
+        let (id, state) = match event {
+            Event::InputActiveStateChanged { id, active } => (id, *active),
+            Event::InputShowStateChanged { id, showing } => (id, *showing),
+            _ => return false,
+        };
+
+        !state
+            && match self.source {
+                SourceId::Name(name) => id.name == name,
+                SourceId::Uuid(uuid) => id.uuid == uuid,
+            }
+    }
+
+    /// Check the source once, returning whether it is healthy.
+    async fn check(&self, client: &Client) -> Result<bool> {
+        let screenshot = client
+            .sources()
+            .take_screenshot(TakeScreenshot {
+                source: self.source,
+                format: "bmp",
+                width: Some(SCREENSHOT_SIZE),
+                height: Some(SCREENSHOT_SIZE),
+                compression_quality: None,
+            })
+            .await;
+
+        let image = match screenshot {
+            Ok(image) => image,
+            Err(e) if e.status_code().is_some() => {
+                debug!(source = %self.source, code = ?e.status_code(), "failed taking screenshot");
+                return Ok(false);
+            }
+            Err(e) => return Err(e),
+        };
+
+        Ok(brightness(&image).is_some_and(|brightness| brightness >= self.black_threshold))
+    }
+}
+
+/// Change of the overall health, as reported by [`Hysteresis::update`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Transition {
+    Failed,
+    Recovered,
+}
+
+/// Tracks consecutive check results, and only reports a change of the health after a number of
+/// checks with the same result.
+struct Hysteresis {
+    failure_checks: u32,
+    recovery_checks: u32,
+    failed: bool,
+    streak: u32,
+}
+
+impl Hysteresis {
+    fn new(failure_checks: u32, recovery_checks: u32) -> Self {
+        Self {
+            failure_checks: failure_checks.max(1),
+            recovery_checks: recovery_checks.max(1),
+            failed: false,
+            streak: 0,
+        }
+    }
+
+    fn update(&mut self, healthy: bool) -> Option<Transition> {
+        if healthy != self.failed {
+            self.streak = 0;
+            return None;
+        }
+
+        self.streak += 1;
+
+        if self.failed && self.streak >= self.recovery_checks {
+            self.failed = false;
+            self.streak = 0;
+            Some(Transition::Recovered)
+        } else if !self.failed && self.streak >= self.failure_checks {
+            self.failed = true;
+            self.streak = 0;
+            Some(Transition::Failed)
+        } else {
+            None
+        }
+    }
+}
+
+/// Calculate the average brightness of a Base64-encoded BMP image, as returned from
+/// [`crate::client::Sources::take_screenshot`]. Returns [`None`] if the image can't be decoded.
+fn brightness(image: &str) -> Option<f32> {
+    let pixels = bmp::luma(image, SCREENSHOT_SIZE)?;
+
+    #[allow(clippy::cast_precision_loss)]
+    let count = pixels.len().max(1) as f32;
+
+    Some(pixels.iter().sum::<f32>() / count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn image_brightness() {
+        assert!(brightness(&bmp::encode(3, 2, [0, 0, 0])).unwrap() < f32::EPSILON);
+        assert!((brightness(&bmp::encode(3, 2, [255, 255, 255])).unwrap() - 1.0).abs() < 1e-4);
+        assert!(brightness("data:image/png;base64,AAAA").is_none());
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn lost_source() {
+        use uuid::Uuid;
+
+        use crate::responses::inputs::InputId;
+
+        let uuid = Uuid::new_v8([1; 16]);
+        let camera = || InputId {
+            name: "Camera".to_owned(),
+            uuid,
+        };
+        let failover = |source| Failover {
+            source,
+            backup_scene: SceneId::Name("Backup"),
+            interval: Duration::from_secs(1),
+            black_threshold: 0.02,
+            failure_checks: 3,
+            recovery_checks: 5,
+        };
+
+        for failover in [
+            failover(SourceId::Name("Camera")),
+            failover(SourceId::Uuid(uuid)),
+        ] {
+            assert!(failover.lost(&Event::InputActiveStateChanged {
+                id: camera(),
+                active: false,
+            }));
+            assert!(failover.lost(&Event::InputShowStateChanged {
+                id: camera(),
+                showing: false,
+            }));
+            assert!(!failover.lost(&Event::InputActiveStateChanged {
+                id: camera(),
+                active: true,
+            }));
+            assert!(!failover.lost(&Event::ExitStarted));
+        }
+
+        assert!(
+            !failover(SourceId::Name("Screen")).lost(&Event::InputShowStateChanged {
+                id: camera(),
+                showing: false,
+            })
+        );
+    }
+
+    #[test]
+    fn hysteresis() {
+        let mut hysteresis = Hysteresis::new(2, 3);
+
+        assert_eq!(None, hysteresis.update(false));
+        assert_eq!(None, hysteresis.update(true));
+        assert_eq!(None, hysteresis.update(false));
+        assert_eq!(Some(Transition::Failed), hysteresis.update(false));
+        assert_eq!(None, hysteresis.update(false));
+
+        assert_eq!(None, hysteresis.update(true));
+        assert_eq!(None, hysteresis.update(true));
+        assert_eq!(None, hysteresis.update(false));
+        assert_eq!(None, hysteresis.update(true));
+        assert_eq!(None, hysteresis.update(true));
+        assert_eq!(Some(Transition::Recovered), hysteresis.update(true));
+    }
+}
@@ -0,0 +1,316 @@
+//! Button state feedback for hardware controllers, like stream decks or MIDI pads.
+//!
+//! Controllers usually only light up their buttons in a few colors, so [`Feedback`] boils the
+//! state of OBS down to a single [`ButtonState`] per configured [`Target`]. The states are kept up
+//! to date through events and published as a [`watch`] channel, so an integration only needs to
+//! translate the state vector into the protocol of its device.
+//!
+//! ```no_run
+//! use obws::{
+//!     feedback::{Feedback, Target},
+//!     Client,
+//! };
+//!
+//! # async fn run(client: &Client) -> obws::error::Result<()> {
+//! let feedback = Feedback::new(vec![
+//!     Target::SceneActive("Gaming".to_owned()),
+//!     Target::InputMuted("Mic/Aux".to_owned()),
+//!     Target::Recording,
+//! ]);
+//!
+//! let mut states = feedback.subscribe();
+//! tokio::spawn(async move {
+//!     while states.changed().await.is_ok() {
+//!         println!("buttons: {:?}", *states.borrow());
+//!     }
+//! });
+//!
+//! feedback.run(client).await
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use futures_util::StreamExt;
+use tokio::sync::watch;
+
+use crate::{
+    error::Result,
+    events::{Event, OutputState},
+    requests::inputs::InputId,
+    Client,
+};
+
+/// Part of OBS, that a single button reflects.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Target {
+    /// A scene is shown in the program output.
+    ///
+    /// Reported as [`ButtonState::Warning`], while the scene is only shown in the preview of the
+    /// studio mode, similar to a tally light.
+    SceneActive(String),
+    /// An input is muted.
+    InputMuted(String),
+    /// A recording is running.
+    ///
+    /// Reported as [`ButtonState::Warning`], while the recording is paused, starting or stopping.
+    Recording,
+    /// The stream is running.
+    ///
+    /// Reported as [`ButtonState::Warning`], while the stream is reconnecting, starting or
+    /// stopping.
+    Streaming,
+    /// The virtual camera is running.
+    VirtualCam,
+}
+
+/// Simplified state of a single button.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ButtonState {
+    /// The target is inactive.
+    #[default]
+    Off,
+    /// The target is active.
+    On,
+    /// The target is in a transitional or degraded state, that needs attention.
+    Warning,
+}
+
+/// Tracker of the button states for a list of targets.
+pub struct Feedback {
+    targets: Vec<Target>,
+    states: watch::Sender<Vec<ButtonState>>,
+}
+
+impl Feedback {
+    /// Create a new tracker for the given targets. The button states are reported in the same
+    /// order as the targets.
+    #[must_use]
+    pub fn new(targets: Vec<Target>) -> Self {
+        let (states, _) = watch::channel(vec![ButtonState::Off; targets.len()]);
+        Self { targets, states }
+    }
+
+    /// Subscribe to the button states, that are updated whenever any of them changes.
+    #[must_use]
+    pub fn subscribe(&self) -> watch::Receiver<Vec<ButtonState>> {
+        self.states.subscribe()
+    }
+
+    /// Load the current state of all targets and keep it up to date through events, until the
+    /// connection to obs-websocket is closed.
+    pub async fn run(&self, client: &Client) -> Result<()> {
+        // Subscribe before loading the initial state, to not miss any changes in between.
+        let mut events = Box::pin(client.events()?);
+
+        let mut status = Status::load(client, &self.targets).await?;
+        self.publish(&status);
+
+        while let Some(event) = events.next().await {
+            if status.update(&event) {
+                self.publish(&status);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn publish(&self, status: &Status) {
+        let latest = status.states(&self.targets);
+        self.states.send_if_modified(|current| {
+            let modified = *current != latest;
+            *current = latest;
+            modified
+        });
+    }
+}
+
+/// Last known state of OBS, limited to the parts that targets can reflect.
+#[derive(Debug, Default)]
+struct Status {
+    program_scene: Option<String>,
+    preview_scene: Option<String>,
+    muted: HashMap<String, bool>,
+    recording: ButtonState,
+    streaming: ButtonState,
+    virtual_cam: ButtonState,
+}
+
+impl Status {
+    async fn load(client: &Client, targets: &[Target]) -> Result<Self> {
+        let recording = client.recording().status().await?;
+        let streaming = client.streaming().status().await?;
+
+        let mut status = Self {
+            program_scene: Some(client.scenes().current_program_scene().await?.id.name),
+            preview_scene: None,
+            muted: HashMap::new(),
+            recording: match (recording.active, recording.paused) {
+                (true, true) => ButtonState::Warning,
+                (true, false) => ButtonState::On,
+                (false, _) => ButtonState::Off,
+            },
+            streaming: match (streaming.active, streaming.reconnecting) {
+                (true, true) => ButtonState::Warning,
+                (true, false) => ButtonState::On,
+                (false, _) => ButtonState::Off,
+            },
+            virtual_cam: on_off(client.virtual_cam().status().await?),
+        };
+
+        if client.ui().studio_mode_enabled().await? {
+            status.preview_scene = Some(client.scenes().current_preview_scene().await?.id.name);
+        }
+
+        for target in targets {
+            if let Target::InputMuted(name) = target {
+                let muted = client.inputs().muted(InputId::Name(name)).await?;
+                status.muted.insert(name.clone(), muted);
+            }
+        }
+
+        Ok(status)
+    }
+
+    /// Apply the changes of an event, returning whether anything changed.
+    fn update(&mut self, event: &Event) -> bool {
+        match event {
+            Event::CurrentProgramSceneChanged { id } => {
+                self.program_scene = Some(id.name.clone());
+            }
+            Event::CurrentPreviewSceneChanged { id } => {
+                self.preview_scene = Some(id.name.clone());
+            }
+            Event::StudioModeStateChanged { enabled: false } => self.preview_scene = None,
+            Event::InputMuteStateChanged { id, muted } => {
+                self.muted.insert(id.name.clone(), *muted);
+            }
+            Event::RecordStateChanged { active, state, .. } => {
+                self.recording = output_state(*active, state);
+            }
+            Event::StreamStateChanged { active, state } => {
+                self.streaming = output_state(*active, state);
+            }
+            Event::VirtualcamStateChanged { active, .. } => self.virtual_cam = on_off(*active),
+            _ => return false,
+        }
+
+        true
+    }
+
+    fn states(&self, targets: &[Target]) -> Vec<ButtonState> {
+        targets
+            .iter()
+            .map(|target| match target {
+                Target::SceneActive(name) => {
+                    if self.program_scene.as_ref() == Some(name) {
+                        ButtonState::On
+                    } else if self.preview_scene.as_ref() == Some(name) {
+                        ButtonState::Warning
+                    } else {
+                        ButtonState::Off
+                    }
+                }
+                Target::InputMuted(name) => {
+                    on_off(self.muted.get(name).copied().unwrap_or_default())
+                }
+                Target::Recording => self.recording,
+                Target::Streaming => self.streaming,
+                Target::VirtualCam => self.virtual_cam,
+            })
+            .collect()
+    }
+}
+
+fn on_off(active: bool) -> ButtonState {
+    if active {
+        ButtonState::On
+    } else {
+        ButtonState::Off
+    }
+}
+
+fn output_state(active: bool, state: &OutputState) -> ButtonState {
+    match state {
+        OutputState::Started | OutputState::Resumed | OutputState::Reconnected => ButtonState::On,
+        OutputState::Starting
+        | OutputState::Stopping
+        | OutputState::Paused
+        | OutputState::Reconnecting => ButtonState::Warning,
+        OutputState::Stopped => ButtonState::Off,
+        _ => on_off(active),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::responses::{inputs::InputId, scenes::SceneId};
+
+    #[test]
+    fn states() {
+        let targets = [
+            Target::SceneActive("live".to_owned()),
+            Target::SceneActive("next".to_owned()),
+            Target::InputMuted("mic".to_owned()),
+            Target::Recording,
+            Target::Streaming,
+        ];
+
+        let mut status = Status {
+            program_scene: Some("live".to_owned()),
+            preview_scene: Some("next".to_owned()),
+            ..Status::default()
+        };
+
+        assert_eq!(
+            vec![
+                ButtonState::On,
+                ButtonState::Warning,
+                ButtonState::Off,
+                ButtonState::Off,
+                ButtonState::Off,
+            ],
+            status.states(&targets)
+        );
+
+        assert!(status.update(&Event::CurrentProgramSceneChanged {
+            id: SceneId {
+                name: "next".to_owned(),
+                uuid: Uuid::nil(),
+            },
+        }));
+        assert!(status.update(&Event::StudioModeStateChanged { enabled: false }));
+        assert!(status.update(&Event::InputMuteStateChanged {
+            id: InputId {
+                name: "mic".to_owned(),
+                uuid: Uuid::nil(),
+            },
+            muted: true,
+        }));
+        assert!(status.update(&Event::RecordStateChanged {
+            active: true,
+            state: OutputState::Paused,
+            path: None,
+        }));
+        assert!(status.update(&Event::StreamStateChanged {
+            active: true,
+            state: OutputState::Started,
+        }));
+        assert!(!status.update(&Event::ExitStarted));
+
+        assert_eq!(
+            vec![
+                ButtonState::Off,
+                ButtonState::On,
+                ButtonState::On,
+                ButtonState::Warning,
+                ButtonState::On,
+            ],
+            status.states(&targets)
+        );
+    }
+}
@@ -0,0 +1,54 @@
+//! Entry points for the fuzz targets in the `fuzz` directory of the repository. These are not
+//! part of the public API and may change at any time.
+
+use crate::responses::ServerMessage;
+
+/// Parse a raw message from obs-websocket, going through the same steps as the receive loop of
+/// the client. Any input must either parse or be rejected, but never panic.
+pub fn server_message(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(message) = serde_json::from_str::<ServerMessage>(text) else {
+        return;
+    };
+
+    match &message {
+        ServerMessage::RequestResponse(response) => {
+            response.id.parse::<u64>().ok();
+        }
+        ServerMessage::RequestBatchResponse(response) => {
+            response.id.parse::<u64>().ok();
+        }
+        _ => {}
+    }
+
+    // Messages are formatted for trace logs.
+    std::hint::black_box(format!("{message:?}"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corpus_seeds() {
+        let seeds = [
+            include_str!("../fuzz/corpus/server_message/hello.json"),
+            include_str!("../fuzz/corpus/server_message/identified.json"),
+            include_str!("../fuzz/corpus/server_message/event_scene_changed.json"),
+            include_str!("../fuzz/corpus/server_message/event_stream_state.json"),
+            include_str!("../fuzz/corpus/server_message/event_transform_changed.json"),
+            include_str!("../fuzz/corpus/server_message/response_stream_status.json"),
+            include_str!("../fuzz/corpus/server_message/response_failure.json"),
+            include_str!("../fuzz/corpus/server_message/batch_response.json"),
+        ];
+
+        for seed in seeds {
+            if let Err(e) = serde_json::from_str::<ServerMessage>(seed) {
+                panic!("seed failed to parse: {e}\n{seed}");
+            }
+            server_message(seed.as_bytes());
+        }
+    }
+}
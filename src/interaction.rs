@@ -0,0 +1,280 @@
+//! Interaction with sources, like clicking into a browser source.
+//!
+//! Sources that support interaction in OBS, mainly browser sources, accept mouse and keyboard
+//! events. obs-websocket currently doesn't offer a request to send these events, and only allows
+//! to open the interact dialog in the OBS user interface, where a user can interact with the
+//! source manually.
+//!
+//! To still allow automated interaction, an [`Interactor`] can be configured with an
+//! [`InteractionVendor`], a plugin or script that registers a vendor request for sending
+//! [`InteractionEvent`]s. [`Interactor::support`] reports which of these alternatives are
+//! available for an input, so callers can fall back to the dialog, or skip the interaction.
+//!
+//! ```no_run
+//! use obws::{
+//!     interaction::{InteractionEvent, InteractionVendor, Interactor, MouseButton},
+//!     Client,
+//! };
+//!
+//! # async fn run(client: &Client) -> obws::error::Result<()> {
+//! let interactor = Interactor {
+//!     vendor: Some(InteractionVendor {
+//!         vendor_name: "interaction-bridge",
+//!         request_type: "SendInteraction",
+//!     }),
+//! };
+//!
+//! let support = interactor.support(client, "Overlay").await?;
+//! if support.events {
+//!     interactor
+//!         .send(
+//!             client,
+//!             "Overlay",
+//!             &InteractionEvent::MouseClick {
+//!                 x: 100,
+//!                 y: 50,
+//!                 button: MouseButton::Left,
+//!                 up: false,
+//!                 clicks: 1,
+//!             },
+//!         )
+//!         .await?;
+//! } else if support.dialog {
+//!     client.ui().open_interact_dialog("Overlay").await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    error::{Error, Result},
+    requests::{general::CallVendorRequest, inputs::InputId},
+    Client,
+};
+
+/// Input kinds that are known to support interaction.
+pub const INTERACTIVE_KINDS: &[&str] = &["browser_source"];
+
+/// Name of the request, that opens the interact dialog of an input.
+const INTERACT_DIALOG_REQUEST: &str = "OpenInputInteractDialog";
+
+/// Single mouse or keyboard event, sent to an interactive source.
+///
+/// The events mirror the interaction functions of the OBS source API. Coordinates are in pixels,
+/// relative to the top left corner of the source.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(tag = "eventType", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum InteractionEvent {
+    /// Move the mouse cursor over the source.
+    #[serde(rename_all = "camelCase")]
+    MouseMove {
+        /// Horizontal position of the cursor.
+        x: i32,
+        /// Vertical position of the cursor.
+        y: i32,
+        /// Whether the cursor leaves the source.
+        leave: bool,
+    },
+    /// Press or release a mouse button.
+    #[serde(rename_all = "camelCase")]
+    MouseClick {
+        /// Horizontal position of the cursor.
+        x: i32,
+        /// Vertical position of the cursor.
+        y: i32,
+        /// The button that is pressed or released.
+        button: MouseButton,
+        /// Whether the button is released, instead of pressed.
+        up: bool,
+        /// Number of consecutive clicks, like `2` for a double click.
+        clicks: u32,
+    },
+    /// Scroll the mouse wheel.
+    #[serde(rename_all = "camelCase")]
+    MouseWheel {
+        /// Horizontal position of the cursor.
+        x: i32,
+        /// Vertical position of the cursor.
+        y: i32,
+        /// Horizontal scroll distance.
+        delta_x: i32,
+        /// Vertical scroll distance.
+        delta_y: i32,
+    },
+    /// Press or release a key.
+    #[serde(rename_all = "camelCase")]
+    Key {
+        /// Text that the key produces, like `a` or `A`. Empty for keys that don't produce text.
+        text: String,
+        /// Platform specific virtual key code.
+        native_vkey: u32,
+        /// Whether the key is released, instead of pressed.
+        up: bool,
+    },
+    /// Give the source the input focus, or take it away.
+    #[serde(rename_all = "camelCase")]
+    Focus {
+        /// Whether the source is focused.
+        focused: bool,
+    },
+}
+
+/// Mouse button of an [`InteractionEvent::MouseClick`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum MouseButton {
+    /// The primary button.
+    Left,
+    /// The middle button, usually the mouse wheel.
+    Middle,
+    /// The secondary button.
+    Right,
+}
+
+/// Vendor request, that sends [`InteractionEvent`]s to a source.
+///
+/// The request receives the input identifier (`inputName` or `inputUuid`) and the event as
+/// `event` field in its request data. It's up to the plugin or script, that registers the vendor,
+/// to forward the event to the source.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InteractionVendor<'a> {
+    /// Name of the vendor.
+    pub vendor_name: &'a str,
+    /// Type of the vendor request, that accepts interaction events.
+    pub request_type: &'a str,
+}
+
+/// Configuration of the ways to interact with sources.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct Interactor<'a> {
+    /// Vendor request to send interaction events with. Without a vendor, events can't be sent.
+    pub vendor: Option<InteractionVendor<'a>>,
+}
+
+/// The ways to interact with an input, as reported by [`Interactor::support`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct InteractionSupport {
+    /// The kind of the input.
+    pub kind: String,
+    /// Whether the input kind is known to support interaction, as listed in
+    /// [`INTERACTIVE_KINDS`].
+    pub interactive: bool,
+    /// Whether the interact dialog can be opened with
+    /// [`crate::client::Ui::open_interact_dialog`].
+    pub dialog: bool,
+    /// Whether events can be sent with [`Interactor::send`].
+    pub events: bool,
+}
+
+impl Interactor<'_> {
+    /// Find out which ways to interact with the given input are available.
+    ///
+    /// Whether the vendor request is actually registered can't be detected upfront. An
+    /// unregistered vendor only shows up as error, when [`Self::send`]ing the first event.
+    pub async fn support(
+        &self,
+        client: &Client,
+        input: impl Into<InputId<'_>>,
+    ) -> Result<InteractionSupport> {
+        let kind = client.inputs().settings::<Value>(input).await?.kind;
+        let interactive = INTERACTIVE_KINDS.contains(&kind.as_str());
+        let available = client.general().version().await?.available_requests;
+
+        Ok(InteractionSupport {
+            dialog: interactive && available.iter().any(|r| r == INTERACT_DIALOG_REQUEST),
+            events: interactive && self.vendor.is_some(),
+            interactive,
+            kind,
+        })
+    }
+
+    /// Send a single event to the given input, through the configured
+    /// [`InteractionVendor`].
+    ///
+    /// Fails with [`Error::InteractionUnavailable`] if no vendor is configured.
+    pub async fn send(
+        &self,
+        client: &Client,
+        input: impl Into<InputId<'_>>,
+        event: &InteractionEvent,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct Data<'a> {
+            #[serde(flatten)]
+            input: InputId<'a>,
+            event: &'a InteractionEvent,
+        }
+
+        let vendor = self.vendor.ok_or(Error::InteractionUnavailable)?;
+
+        client
+            .general()
+            .call_vendor_request::<_, Value>(CallVendorRequest {
+                vendor_name: vendor.vendor_name,
+                request_type: vendor.request_type,
+                request_data: &Data {
+                    input: input.into(),
+                    event,
+                },
+            })
+            .await
+            .map(drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn serialize_events() {
+        assert_eq!(
+            json!({
+                "eventType": "mouseClick",
+                "x": 10,
+                "y": 20,
+                "button": "right",
+                "up": true,
+                "clicks": 2,
+            }),
+            serde_json::to_value(InteractionEvent::MouseClick {
+                x: 10,
+                y: 20,
+                button: MouseButton::Right,
+                up: true,
+                clicks: 2,
+            })
+            .unwrap()
+        );
+
+        assert_eq!(
+            json!({"eventType": "mouseWheel", "x": 0, "y": 0, "deltaX": 0, "deltaY": -120}),
+            serde_json::to_value(InteractionEvent::MouseWheel {
+                x: 0,
+                y: 0,
+                delta_x: 0,
+                delta_y: -120,
+            })
+            .unwrap()
+        );
+
+        assert_eq!(
+            json!({"eventType": "key", "text": "a", "nativeVkey": 65, "up": false}),
+            serde_json::to_value(InteractionEvent::Key {
+                text: "a".to_owned(),
+                native_vkey: 65,
+                up: false,
+            })
+            .unwrap()
+        );
+    }
+}
@@ -42,16 +42,43 @@
     clippy::struct_excessive_bools
 )]
 
-pub use self::client::Client;
+pub use self::client::{probe, Client};
 
+pub mod animate;
+#[cfg(feature = "events")]
+pub mod attach;
+pub mod audio;
+#[cfg(feature = "capi")]
+pub mod capi;
 pub mod client;
+pub mod clock;
 pub mod common;
+pub mod diff;
+#[cfg(feature = "events")]
+pub mod dispatch;
 #[cfg(doc)]
 pub mod docs;
+#[cfg(feature = "events")]
+pub mod ducking;
 pub mod error;
 #[cfg(feature = "events")]
 pub mod events;
+pub mod failover;
+#[cfg(feature = "events")]
+pub mod feedback;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
+pub mod interaction;
 pub mod requests;
 pub mod responses;
+pub mod restricted;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod watchdog;
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
 
+mod bmp;
+mod doctest;
 mod serde;
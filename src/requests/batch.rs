@@ -0,0 +1,210 @@
+//! Requests for custom request batches, that pass results from one request to the next.
+//!
+//! In a serial request batch, obs-websocket can store fields of a response in a variable, and use
+//! the variable as a field of a later request. This allows chaining requests, that depend on each
+//! other, in a single round trip. For example, looking up the ID of a scene item and enabling it:
+//!
+//! ```no_run
+//! use obws::{
+//!     requests::batch::{BatchExecution, BatchRequest},
+//!     Client,
+//! };
+//! use serde_json::json;
+//!
+//! # async fn run(client: &Client) -> obws::error::Result<()> {
+//! client
+//!     .batch(
+//!         [
+//!             BatchRequest::new("GetSceneItemId")
+//!                 .data(json!({"sceneName": "Scene", "sourceName": "Camera"}))
+//!                 .output("cameraId", "sceneItemId"),
+//!             BatchRequest::new("SetSceneItemEnabled")
+//!                 .data(json!({"sceneName": "Scene", "sceneItemEnabled": true}))
+//!                 .input("sceneItemId", "cameraId"),
+//!         ],
+//!         BatchExecution::SerialRealtime,
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Batches can also pause between requests with [`BatchRequest::sleep_frames`] or
+//! [`BatchRequest::sleep_millis`]. As the pause happens within obs-websocket, timed sequences are
+//! executed with frame accuracy, which isn't possible with timers on the client side. For
+//! example, switching the scene and enabling a filter exactly 30 frames later:
+//!
+//! ```no_run
+//! use obws::{
+//!     requests::batch::{BatchExecution, BatchRequest},
+//!     Client,
+//! };
+//! use serde_json::json;
+//!
+//! # async fn run(client: &Client) -> obws::error::Result<()> {
+//! client
+//!     .batch(
+//!         [
+//!             BatchRequest::new("SetCurrentProgramScene").data(json!({"sceneName": "Outro"})),
+//!             BatchRequest::sleep_frames(30),
+//!             BatchRequest::new("SetSourceFilterEnabled").data(json!({
+//!                 "sourceName": "Outro",
+//!                 "filterName": "Fade",
+//!                 "filterEnabled": true,
+//!             })),
+//!         ],
+//!         BatchExecution::SerialFrame,
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{ser::SerializeMap, Serialize};
+use serde_json::Value;
+
+use super::{general::Sleep, ExecutionType};
+
+/// Name of the request, that pauses a batch.
+const SLEEP: &str = "Sleep";
+
+/// A single request of a batch, sent with [`crate::Client::batch`].
+///
+/// In contrast to the typed functions of the client, the request is described by its raw name
+/// and data, as listed in the obs-websocket protocol documentation.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BatchRequest<'a> {
+    request_type: &'a str,
+    data: Option<Value>,
+    input_variables: BTreeMap<&'a str, &'a str>,
+    output_variables: BTreeMap<&'a str, &'a str>,
+}
+
+impl<'a> BatchRequest<'a> {
+    /// Create a new request of the given type, like `GetSceneItemId`, without any data.
+    #[must_use]
+    pub fn new(request_type: &'a str) -> Self {
+        Self {
+            request_type,
+            ..Self::default()
+        }
+    }
+
+    /// Create a request, that pauses the batch for the given number of milliseconds. Only valid
+    /// in [`BatchExecution::SerialRealtime`] batches.
+    #[must_use]
+    pub fn sleep_millis(millis: u32) -> Self {
+        Self::sleep(Sleep {
+            millis: Some(millis),
+            frames: None,
+        })
+    }
+
+    /// Create a request, that pauses the batch for the given number of rendered frames. Only
+    /// valid in [`BatchExecution::SerialFrame`] batches.
+    #[must_use]
+    pub fn sleep_frames(frames: u32) -> Self {
+        Self::sleep(Sleep {
+            millis: None,
+            frames: Some(frames),
+        })
+    }
+
+    /// Create a sleep request from the typed request data.
+    fn sleep(sleep: Sleep) -> Self {
+        // Serializing a struct of plain numbers can't fail.
+        Self::new(SLEEP).data(serde_json::to_value(sleep).unwrap_or_default())
+    }
+
+    /// The raw `requestType` of this request.
+    pub(crate) fn request_type(&self) -> &'a str {
+        self.request_type
+    }
+
+    /// Whether this is a sleep request, that isn't valid for the given execution type.
+    pub(crate) fn invalid_sleep(&self, execution: BatchExecution) -> bool {
+        let field = match execution {
+            BatchExecution::SerialRealtime => "sleepMillis",
+            BatchExecution::SerialFrame => "sleepFrames",
+        };
+
+        self.request_type == SLEEP
+            && !self
+                .data
+                .as_ref()
+                .is_some_and(|data| data.get(field).is_some())
+    }
+
+    /// Set the request data, usually a JSON object.
+    #[must_use]
+    pub fn data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Set a field of the request data to the value of a variable, that was stored by an earlier
+    /// request of the batch.
+    #[must_use]
+    pub fn input(mut self, field: &'a str, variable: &'a str) -> Self {
+        self.input_variables.insert(field, variable);
+        self
+    }
+
+    /// Store a field of the response data in a variable, so later requests of the batch can use
+    /// it as input.
+    #[must_use]
+    pub fn output(mut self, variable: &'a str, field: &'a str) -> Self {
+        self.output_variables.insert(variable, field);
+        self
+    }
+}
+
+impl Serialize for BatchRequest<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("requestType", self.request_type)?;
+        if let Some(data) = &self.data {
+            map.serialize_entry("requestData", data)?;
+        }
+        if !self.input_variables.is_empty() {
+            map.serialize_entry("inputVariables", &self.input_variables)?;
+        }
+        if !self.output_variables.is_empty() {
+            map.serialize_entry("outputVariables", &self.output_variables)?;
+        }
+        map.end()
+    }
+}
+
+impl<'a> From<BatchRequest<'a>> for super::RequestType<'a> {
+    fn from(value: BatchRequest<'a>) -> Self {
+        super::RequestType::Raw(value)
+    }
+}
+
+/// How obs-websocket executes the requests of a batch. Variables are only supported by serial
+/// execution, so parallel execution isn't available here.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BatchExecution {
+    /// Process all requests one after another, as fast as possible.
+    #[default]
+    SerialRealtime,
+    /// Process all requests one after another, in sync with the graphics thread. Each request
+    /// takes at least one frame, which is helpful for animations.
+    SerialFrame,
+}
+
+impl From<BatchExecution> for ExecutionType {
+    fn from(value: BatchExecution) -> Self {
+        match value {
+            BatchExecution::SerialRealtime => Self::SerialRealtime,
+            BatchExecution::SerialFrame => Self::SerialFrame,
+        }
+    }
+}
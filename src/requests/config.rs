@@ -3,6 +3,8 @@
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 
+use crate::common::{ColorFormat, ColorRange, ColorSpace};
+
 #[derive(Serialize)]
 #[serde(tag = "requestType", content = "requestData")]
 pub(crate) enum Request<'a> {
@@ -102,6 +104,146 @@ pub struct SetVideoSettings {
     pub output_height: Option<u32>,
 }
 
+/// Request information for [`crate::client::Config::set_video_color_settings`].
+///
+/// Only the values that are set are changed.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct SetVideoColorSettings {
+    /// Color format of the video output.
+    pub format: Option<ColorFormat>,
+    /// Color space of the video output.
+    pub space: Option<ColorSpace>,
+    /// Color range of the video output.
+    pub range: Option<ColorRange>,
+}
+
+/// Frame rate of the video output, as fraction of a numerator and denominator.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Fps {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Fps {
+    /// Create a frame rate from a fraction. Returns [`None`] if either value is zero.
+    #[must_use]
+    pub fn new(numerator: u32, denominator: u32) -> Option<Self> {
+        (numerator > 0 && denominator > 0).then_some(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Exact amount of frames per second, like `60`. A value of zero is raised to one.
+    #[must_use]
+    pub fn exact(fps: u32) -> Self {
+        Self {
+            numerator: fps.max(1),
+            denominator: 1,
+        }
+    }
+
+    /// NTSC frame rate, that is slightly slower than the given rate, like `30000/1001` (29.97)
+    /// for `30`. A value of zero is raised to one.
+    #[must_use]
+    pub fn ntsc(fps: u32) -> Self {
+        Self {
+            numerator: fps.max(1).saturating_mul(1000),
+            denominator: 1001,
+        }
+    }
+
+    /// Numerator of the fraction.
+    #[must_use]
+    pub fn numerator(self) -> u32 {
+        self.numerator
+    }
+
+    /// Denominator of the fraction.
+    #[must_use]
+    pub fn denominator(self) -> u32 {
+        self.denominator
+    }
+
+    /// Frames per second as floating point value, like `29.97` for NTSC `30`.
+    #[must_use]
+    pub fn as_f64(self) -> f64 {
+        f64::from(self.numerator) / f64::from(self.denominator)
+    }
+}
+
+/// Resolution of the canvas or output of OBS, in pixels.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Resolution {
+    width: u32,
+    height: u32,
+}
+
+impl Resolution {
+    /// Largest width or height, that obs-websocket accepts.
+    pub const MAX_SIZE: u32 = 4096;
+    /// Smallest width or height, that obs-websocket accepts.
+    pub const MIN_SIZE: u32 = 8;
+
+    /// Create a new resolution.
+    ///
+    /// # Errors
+    ///
+    /// Will return an [`Error::InvalidResolution`](crate::error::Error::InvalidResolution) if
+    /// either side isn't a multiple of two, or lies outside the range of
+    /// [`MIN_SIZE`](Self::MIN_SIZE) to [`MAX_SIZE`](Self::MAX_SIZE).
+    pub fn new(width: u32, height: u32) -> crate::error::Result<Self> {
+        let valid = |size: u32| size % 2 == 0 && (Self::MIN_SIZE..=Self::MAX_SIZE).contains(&size);
+
+        if valid(width) && valid(height) {
+            Ok(Self { width, height })
+        } else {
+            Err(crate::error::Error::InvalidResolution { width, height })
+        }
+    }
+
+    /// Width in pixels.
+    #[must_use]
+    pub fn width(self) -> u32 {
+        self.width
+    }
+
+    /// Height in pixels.
+    #[must_use]
+    pub fn height(self) -> u32 {
+        self.height
+    }
+}
+
+/// Typed video settings, that convert into [`SetVideoSettings`] for use with
+/// [`crate::client::Config::set_video_settings`].
+///
+/// Only the values that are set are changed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct VideoSettings {
+    /// Frame rate of the video output.
+    pub fps: Option<Fps>,
+    /// Base (canvas) resolution.
+    pub base: Option<Resolution>,
+    /// Output (scaled) resolution.
+    pub output: Option<Resolution>,
+}
+
+impl From<VideoSettings> for SetVideoSettings {
+    fn from(v: VideoSettings) -> Self {
+        Self {
+            fps_numerator: v.fps.map(Fps::numerator),
+            fps_denominator: v.fps.map(Fps::denominator),
+            base_width: v.base.map(Resolution::width),
+            base_height: v.base.map(Resolution::height),
+            output_width: v.output.map(Resolution::width),
+            output_height: v.output.map(Resolution::height),
+        }
+    }
+}
+
 impl From<crate::responses::config::VideoSettings> for SetVideoSettings {
     fn from(v: crate::responses::config::VideoSettings) -> Self {
         Self {
@@ -114,3 +256,24 @@ impl From<crate::responses::config::VideoSettings> for SetVideoSettings {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fps() {
+        assert_eq!(Fps::new(60, 1), Some(Fps::exact(60)));
+        assert_eq!(Fps::new(30_000, 1001), Some(Fps::ntsc(30)));
+        assert_eq!(None, Fps::new(60, 0));
+        assert!((Fps::ntsc(30).as_f64() - 29.97).abs() < 0.01);
+    }
+
+    #[test]
+    fn resolution() {
+        assert!(Resolution::new(1920, 1080).is_ok());
+        assert!(Resolution::new(1921, 1080).is_err());
+        assert!(Resolution::new(6, 1080).is_err());
+        assert!(Resolution::new(1920, 8192).is_err());
+    }
+}
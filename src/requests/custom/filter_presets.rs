@@ -0,0 +1,288 @@
+//! Filter chain presets, that describe a list of filters in TOML format and can be applied to any
+//! source with [`crate::client::Filters::apply_preset`].
+//!
+//! A preset looks like the following, where each `[[filter]]` table describes a single filter that
+//! is created on the target source in the given order:
+//!
+//! ```toml
+//! [variables]
+//! color = "4278190335"
+//!
+//! [[filter]]
+//! name = "{{source}} Color"
+//! kind = "color_filter_v2"
+//!
+//! [filter.settings]
+//! color_multiply = "{{color}}"
+//!
+//! [[filter]]
+//! name = "Sharpen"
+//! kind = "sharpness_filter_v2"
+//! enabled = false
+//! ```
+//!
+//! Any string value in the filter name or settings can contain placeholders in the form of
+//! `{{name}}`. They're replaced with the values from the `variables` table, and the special
+//! `{{source}}` placeholder is replaced with the identifier of the source the preset is applied
+//! to. If a placeholder fully covers a settings value (like `"{{color}}"` above) and its
+//! replacement is a valid number or boolean, the value is inserted as that type instead of a
+//! string.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{error::Result, responses::filters::SourceFilter};
+
+/// A chain of filters, that can be stored as TOML and applied to sources.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct FilterPreset {
+    /// Values for the placeholders used in the filter names and settings.
+    ///
+    /// These act as defaults and can be overwritten before applying the preset to individual
+    /// sources.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, String>,
+    /// List of filters, in the order they're applied to a source.
+    #[serde(rename = "filter", default)]
+    pub filters: Vec<PresetFilter>,
+}
+
+/// Single filter of a [`FilterPreset`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PresetFilter {
+    /// Name of the filter. Can contain placeholders.
+    pub name: String,
+    /// The kind of filter to be created.
+    pub kind: String,
+    /// Whether the filter is enabled after creation. Defaults to `true`.
+    #[serde(default = "enabled_default")]
+    pub enabled: bool,
+    /// Settings to initialize the filter with. String values can contain placeholders.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub settings: Map<String, Value>,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+impl FilterPreset {
+    /// Parse a preset from its TOML representation.
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        toml::from_str(toml)
+            .map_err(crate::error::DeserializePresetError)
+            .map_err(Into::into)
+    }
+
+    /// Convert the preset into its TOML representation.
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(self)
+            .map_err(crate::error::SerializePresetError)
+            .map_err(Into::into)
+    }
+
+    /// Create a preset from an existing list of filters, for example as returned by
+    /// [`crate::client::Filters::list`].
+    ///
+    /// Settings with a `null` value are left out, as they can't be represented in TOML.
+    #[must_use]
+    pub fn from_filters(filters: &[SourceFilter]) -> Self {
+        let mut filters = filters.iter().collect::<Vec<_>>();
+        filters.sort_by_key(|f| f.index);
+
+        Self {
+            variables: BTreeMap::new(),
+            filters: filters
+                .into_iter()
+                .map(|filter| PresetFilter {
+                    name: filter.name.clone(),
+                    kind: filter.kind.clone(),
+                    enabled: filter.enabled,
+                    settings: match strip_nulls(filter.settings.clone()) {
+                        Value::Object(map) => map,
+                        _ => Map::new(),
+                    },
+                })
+                .collect(),
+        }
+    }
+
+    /// Set the value for a placeholder, overwriting any previous value.
+    #[must_use]
+    pub fn with_variable(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.variables.insert(name.into(), value.into());
+        self
+    }
+
+    /// Create a copy of the filter list, with all placeholders replaced.
+    ///
+    /// The `source` value is used for the special `{{source}}` placeholder.
+    #[must_use]
+    pub fn render(&self, source: &str) -> Vec<PresetFilter> {
+        self.filters
+            .iter()
+            .map(|filter| PresetFilter {
+                name: self.replace(&filter.name, source),
+                kind: filter.kind.clone(),
+                enabled: filter.enabled,
+                settings: filter
+                    .settings
+                    .iter()
+                    .map(|(key, value)| (key.clone(), self.render_value(value, source)))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    fn render_value(&self, value: &Value, source: &str) -> Value {
+        match value {
+            Value::String(s) => {
+                let replaced = self.replace(s, source);
+                if is_placeholder(s) && replaced != *s {
+                    serde_json::from_str::<Value>(&replaced)
+                        .ok()
+                        .filter(|v| v.is_number() || v.is_boolean())
+                        .unwrap_or(Value::String(replaced))
+                } else {
+                    Value::String(replaced)
+                }
+            }
+            Value::Array(values) => Value::Array(
+                values
+                    .iter()
+                    .map(|v| self.render_value(v, source))
+                    .collect(),
+            ),
+            Value::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), self.render_value(v, source)))
+                    .collect(),
+            ),
+            _ => value.clone(),
+        }
+    }
+
+    fn replace(&self, value: &str, source: &str) -> String {
+        let mut output = String::with_capacity(value.len());
+        let mut rest = value;
+
+        while let Some(start) = rest.find("{{") {
+            let Some(end) = rest[start + 2..].find("}}") else {
+                break;
+            };
+
+            let name = rest[start + 2..start + 2 + end].trim();
+            output.push_str(&rest[..start]);
+
+            match (name, self.variables.get(name)) {
+                (_, Some(replacement)) => output.push_str(replacement),
+                ("source", None) => output.push_str(source),
+                _ => output.push_str(&rest[start..start + 4 + end]),
+            }
+
+            rest = &rest[start + 4 + end..];
+        }
+
+        output.push_str(rest);
+        output
+    }
+}
+
+fn is_placeholder(value: &str) -> bool {
+    value.starts_with("{{") && value.ends_with("}}") && value.matches("{{").count() == 1
+}
+
+fn strip_nulls(value: Value) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .filter(|v| !v.is_null())
+                .map(strip_nulls)
+                .collect(),
+        ),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, strip_nulls(v)))
+                .collect(),
+        ),
+        _ => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const PRESET: &str = r#"
+        [variables]
+        color = "4278190335"
+
+        [[filter]]
+        name = "{{source}} Color"
+        kind = "color_filter_v2"
+
+        [filter.settings]
+        color_multiply = "{{color}}"
+        comment = "for {{source}}, {{unknown}}"
+
+        [[filter]]
+        name = "Sharpen"
+        kind = "sharpness_filter_v2"
+        enabled = false
+    "#;
+
+    #[test]
+    fn render_placeholders() {
+        let preset = FilterPreset::from_toml(PRESET).unwrap();
+        let filters = preset.render("Camera");
+
+        assert_eq!(2, filters.len());
+        assert_eq!("Camera Color", filters[0].name);
+        assert!(filters[0].enabled);
+        assert_eq!(
+            json!({
+                "color_multiply": 4_278_190_335_u64,
+                "comment": "for Camera, {{unknown}}",
+            }),
+            Value::Object(filters[0].settings.clone())
+        );
+        assert_eq!("Sharpen", filters[1].name);
+        assert!(!filters[1].enabled);
+
+        let filters = preset.with_variable("color", "red").render("Camera");
+        assert_eq!(json!("red"), filters[0].settings["color_multiply"]);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let preset = FilterPreset::from_filters(&[
+            SourceFilter {
+                enabled: false,
+                index: 1,
+                kind: "sharpness_filter_v2".to_owned(),
+                name: "Sharpen".to_owned(),
+                settings: json!({"sharpness": 0.2, "unset": null}),
+            },
+            SourceFilter {
+                enabled: true,
+                index: 0,
+                kind: "color_filter_v2".to_owned(),
+                name: "Color".to_owned(),
+                settings: json!({}),
+            },
+        ]);
+
+        assert_eq!("Color", preset.filters[0].name);
+        assert_eq!("Sharpen", preset.filters[1].name);
+        assert!(!preset.filters[1].settings.contains_key("unset"));
+
+        let toml = preset.to_toml().unwrap();
+        assert_eq!(preset, FilterPreset::from_toml(&toml).unwrap());
+    }
+}
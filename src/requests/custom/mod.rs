@@ -3,5 +3,8 @@
 //!
 //! These types are not thoroughly tested currently and may break on OBS Studio updates.
 
+#[cfg(feature = "presets")]
+pub mod filter_presets;
+pub mod profile_parameters;
 pub mod source_settings;
 pub mod transitions;
@@ -0,0 +1,491 @@
+//! Typed access to well-known profile parameters, for use with
+//! [`crate::client::Profiles::typed_parameter`] and
+//! [`crate::client::Profiles::set_typed_parameter`].
+//!
+//! Profile parameters are stored by OBS as plain strings, identified by a category and a name.
+//! The [`Parameter`] type combines both with the expected type of the value, so common settings
+//! can be read and written without magic strings. Parameters not covered here can be defined with
+//! [`Parameter::new`].
+
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    path::PathBuf,
+};
+
+use crate::common::{ColorFormat, ColorRange, ColorSpace};
+
+/// Well-known categories of profile parameters.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Category {
+    /// General output settings, like the output mode.
+    Output,
+    /// Output settings used in the advanced output mode.
+    AdvOut,
+    /// Output settings used in the simple output mode.
+    SimpleOutput,
+    /// Video settings, like resolutions and frame rate.
+    Video,
+    /// Audio settings, like the sample rate and channel setup.
+    Audio,
+    /// Any other category, not known to this crate.
+    Other(&'static str),
+}
+
+impl Category {
+    /// The category name, as used by OBS.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Output => "Output",
+            Self::AdvOut => "AdvOut",
+            Self::SimpleOutput => "SimpleOutput",
+            Self::Video => "Video",
+            Self::Audio => "Audio",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single profile parameter, identified by its category and name, with the type `T` of its
+/// value.
+#[derive(Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Parameter<T> {
+    /// Category of the parameter.
+    pub category: Category,
+    /// Name of the parameter within its category.
+    pub name: &'static str,
+    value_type: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Parameter<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Parameter<T> {}
+
+impl<T> Parameter<T> {
+    /// Define a new parameter.
+    #[must_use]
+    pub const fn new(category: Category, name: &'static str) -> Self {
+        Self {
+            category,
+            name,
+            value_type: PhantomData,
+        }
+    }
+}
+
+/// Conversion of profile parameter values from and to their string representation.
+pub trait ParameterValue: Sized {
+    /// Parse the value from its string representation, returning [`None`] if it's not valid.
+    fn parse(value: &str) -> Option<Self>;
+
+    /// Convert the value into its string representation.
+    fn to_value(&self) -> String;
+}
+
+impl ParameterValue for String {
+    fn parse(value: &str) -> Option<Self> {
+        Some(value.to_owned())
+    }
+
+    fn to_value(&self) -> String {
+        self.clone()
+    }
+}
+
+impl ParameterValue for PathBuf {
+    fn parse(value: &str) -> Option<Self> {
+        Some(value.into())
+    }
+
+    fn to_value(&self) -> String {
+        self.to_string_lossy().into_owned()
+    }
+}
+
+impl ParameterValue for bool {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "true" | "1" => Some(true),
+            "false" | "0" => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_value(&self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! parameter_value_from_str {
+    ($($ty:ty),+) => {
+        $(
+            impl ParameterValue for $ty {
+                fn parse(value: &str) -> Option<Self> {
+                    value.trim().parse().ok()
+                }
+
+                fn to_value(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )+
+    };
+}
+
+parameter_value_from_str!(u32, u64, i32, i64, f64);
+
+macro_rules! parameter_value_serde {
+    ($($ty:ty),+) => {
+        $(
+            impl ParameterValue for $ty {
+                fn parse(value: &str) -> Option<Self> {
+                    serde_json::from_value(serde_json::Value::String(value.trim().to_owned())).ok()
+                }
+
+                fn to_value(&self) -> String {
+                    match serde_json::to_value(self) {
+                        Ok(serde_json::Value::String(value)) => value,
+                        _ => unreachable!("unit enums always serialize into strings"),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+parameter_value_serde!(ColorFormat, ColorSpace, ColorRange);
+
+/// Output mode, either `Simple` or `Advanced`.
+pub const OUTPUT_MODE: Parameter<String> = Parameter::new(Category::Output, "Mode");
+/// Template for the file names of recordings and replays, like `%CCYY-%MM-%DD %hh-%mm-%ss`. See
+/// [`validate_filename_format`] for the supported specifiers.
+pub const FILENAME_FORMATTING: Parameter<String> =
+    Parameter::new(Category::Output, "FilenameFormatting");
+
+/// Recording path in the simple output mode.
+pub const SIMPLE_RECORDING_PATH: Parameter<PathBuf> =
+    Parameter::new(Category::SimpleOutput, "FilePath");
+/// Recording format in the simple output mode, like `mkv` or `mp4`.
+pub const SIMPLE_RECORDING_FORMAT: Parameter<String> =
+    Parameter::new(Category::SimpleOutput, "RecFormat2");
+/// Video bitrate for streaming in the simple output mode, in kbps.
+pub const SIMPLE_VIDEO_BITRATE: Parameter<u32> = Parameter::new(Category::SimpleOutput, "VBitrate");
+/// Audio bitrate for streaming in the simple output mode, in kbps.
+pub const SIMPLE_AUDIO_BITRATE: Parameter<u32> = Parameter::new(Category::SimpleOutput, "ABitrate");
+/// Whether the replay buffer is enabled in the simple output mode.
+pub const SIMPLE_REPLAY_BUFFER: Parameter<bool> = Parameter::new(Category::SimpleOutput, "RecRB");
+
+/// Recording path in the advanced output mode.
+pub const ADV_RECORDING_PATH: Parameter<PathBuf> = Parameter::new(Category::AdvOut, "RecFilePath");
+/// Recording format in the advanced output mode, like `mkv` or `mp4`.
+pub const ADV_RECORDING_FORMAT: Parameter<String> = Parameter::new(Category::AdvOut, "RecFormat2");
+/// Whether the replay buffer is enabled in the advanced output mode.
+pub const ADV_REPLAY_BUFFER: Parameter<bool> = Parameter::new(Category::AdvOut, "RecRB");
+/// Whether recordings are split into multiple files in the advanced output mode.
+pub const ADV_SPLIT_FILE: Parameter<bool> = Parameter::new(Category::AdvOut, "RecSplitFile");
+/// When recordings are split into a new file in the advanced output mode.
+pub const ADV_SPLIT_FILE_TYPE: Parameter<SplitFileType> =
+    Parameter::new(Category::AdvOut, "RecSplitFileType");
+/// Duration in minutes, after which recordings are split in the advanced output mode.
+pub const ADV_SPLIT_FILE_TIME: Parameter<u32> =
+    Parameter::new(Category::AdvOut, "RecSplitFileTime");
+/// Size in megabytes, after which recordings are split in the advanced output mode.
+pub const ADV_SPLIT_FILE_SIZE: Parameter<u32> =
+    Parameter::new(Category::AdvOut, "RecSplitFileSize");
+/// Whether timestamps restart at zero in each split file in the advanced output mode.
+pub const ADV_SPLIT_FILE_RESET_TIMESTAMPS: Parameter<bool> =
+    Parameter::new(Category::AdvOut, "RecSplitFileResetTimestamps");
+
+/// Width of the base (canvas) resolution in pixels.
+pub const BASE_WIDTH: Parameter<u32> = Parameter::new(Category::Video, "BaseCX");
+/// Height of the base (canvas) resolution in pixels.
+pub const BASE_HEIGHT: Parameter<u32> = Parameter::new(Category::Video, "BaseCY");
+/// Width of the output resolution in pixels.
+pub const OUTPUT_WIDTH: Parameter<u32> = Parameter::new(Category::Video, "OutputCX");
+/// Height of the output resolution in pixels.
+pub const OUTPUT_HEIGHT: Parameter<u32> = Parameter::new(Category::Video, "OutputCY");
+
+/// Color format of the video output.
+pub const COLOR_FORMAT: Parameter<ColorFormat> = Parameter::new(Category::Video, "ColorFormat");
+/// Color space of the video output.
+pub const COLOR_SPACE: Parameter<ColorSpace> = Parameter::new(Category::Video, "ColorSpace");
+/// Color range of the video output.
+pub const COLOR_RANGE: Parameter<ColorRange> = Parameter::new(Category::Video, "ColorRange");
+
+/// Audio sample rate in Hz.
+pub const SAMPLE_RATE: Parameter<u32> = Parameter::new(Category::Audio, "SampleRate");
+/// Audio channel setup, like `Mono` or `Stereo`.
+pub const CHANNEL_SETUP: Parameter<String> = Parameter::new(Category::Audio, "ChannelSetup");
+
+/// Condition for splitting recordings into a new file, in the advanced output mode.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SplitFileType {
+    /// Split after a fixed duration.
+    Time,
+    /// Split after a fixed file size.
+    Size,
+    /// Only split when requested, for example with [`crate::client::Recording::split_file`].
+    Manual,
+}
+
+impl ParameterValue for SplitFileType {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim() {
+            "Time" => Some(Self::Time),
+            "Size" => Some(Self::Size),
+            "Manual" => Some(Self::Manual),
+            _ => None,
+        }
+    }
+
+    fn to_value(&self) -> String {
+        match self {
+            Self::Time => "Time",
+            Self::Size => "Size",
+            Self::Manual => "Manual",
+        }
+        .to_owned()
+    }
+}
+
+/// Splitting of recordings into multiple files, combining the split file parameters of the
+/// advanced output mode.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SplitFile {
+    /// Recordings are written to a single file.
+    Disabled,
+    /// Split after the given amount of minutes.
+    Time {
+        /// Duration of each file in minutes.
+        minutes: u32,
+    },
+    /// Split after the given file size.
+    Size {
+        /// Size of each file in megabytes.
+        megabytes: u32,
+    },
+    /// Only split when requested, for example with [`crate::client::Recording::split_file`].
+    Manual,
+}
+
+/// Specifiers, that OBS replaces in the [`FILENAME_FORMATTING`] template. Longer specifiers come
+/// first, as they share their prefix with shorter ones.
+const FILENAME_SPECIFIERS: &[&str] = &[
+    "%CCYY", "%CRES", "%ORES", "%FPS", "%YY", "%MM", "%DD", "%hh", "%mm", "%ss", "%VF", "%%", "%a",
+    "%A", "%b", "%B", "%d", "%H", "%I", "%m", "%M", "%p", "%s", "%S", "%y", "%Y", "%z", "%Z",
+];
+
+/// Check a template for the [`FILENAME_FORMATTING`] parameter, failing with an
+/// [`Error::InvalidFilenameFormat`](crate::error::Error::InvalidFilenameFormat) for the first
+/// specifier that OBS doesn't know.
+///
+/// Supported are the date and time specifiers `%CCYY`, `%YY`, `%MM`, `%DD`, `%hh`, `%mm` and
+/// `%ss`, the `strftime` style specifiers like `%Y` or `%H`, the video specifiers `%FPS`, `%CRES`
+/// (canvas resolution), `%ORES` (output resolution) and `%VF` (video format), and `%%` for a
+/// literal percent sign.
+pub fn validate_filename_format(format: &str) -> crate::error::Result<()> {
+    let mut rest = format;
+
+    while let Some(start) = rest.find('%') {
+        rest = &rest[start..];
+
+        let Some(specifier) = FILENAME_SPECIFIERS
+            .iter()
+            .find(|specifier| rest.starts_with(**specifier))
+        else {
+            let specifier = rest.chars().take(2).collect();
+            return Err(crate::error::Error::InvalidFilenameFormat(specifier));
+        };
+
+        rest = &rest[specifier.len()..];
+    }
+
+    Ok(())
+}
+
+/// Category and name of all well-known parameters of this module, including the name and bitrate
+/// of each audio track.
+#[must_use]
+pub fn well_known() -> Vec<(Category, &'static str)> {
+    macro_rules! erase {
+        ($($param:expr),+ $(,)?) => {
+            [$(($param.category, $param.name)),+]
+        };
+    }
+
+    let mut parameters = erase![
+        OUTPUT_MODE,
+        FILENAME_FORMATTING,
+        SIMPLE_RECORDING_PATH,
+        SIMPLE_RECORDING_FORMAT,
+        SIMPLE_VIDEO_BITRATE,
+        SIMPLE_AUDIO_BITRATE,
+        SIMPLE_REPLAY_BUFFER,
+        ADV_RECORDING_PATH,
+        ADV_RECORDING_FORMAT,
+        ADV_REPLAY_BUFFER,
+        ADV_SPLIT_FILE,
+        ADV_SPLIT_FILE_TYPE,
+        ADV_SPLIT_FILE_TIME,
+        ADV_SPLIT_FILE_SIZE,
+        ADV_SPLIT_FILE_RESET_TIMESTAMPS,
+        BASE_WIDTH,
+        BASE_HEIGHT,
+        OUTPUT_WIDTH,
+        OUTPUT_HEIGHT,
+        COLOR_FORMAT,
+        COLOR_SPACE,
+        COLOR_RANGE,
+        SAMPLE_RATE,
+        CHANNEL_SETUP,
+        ADV_STREAM_TRACK,
+        ADV_VOD_TRACK_ENABLED,
+        ADV_VOD_TRACK,
+    ]
+    .to_vec();
+
+    parameters.extend(
+        AudioTrack::ALL
+            .into_iter()
+            .flat_map(|track| erase![track.name(), track.bitrate()]),
+    );
+    parameters
+}
+
+/// One of the six audio tracks, that outputs can mix audio into.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AudioTrack {
+    /// Audio track 1.
+    Track1,
+    /// Audio track 2.
+    Track2,
+    /// Audio track 3.
+    Track3,
+    /// Audio track 4.
+    Track4,
+    /// Audio track 5.
+    Track5,
+    /// Audio track 6.
+    Track6,
+}
+
+impl AudioTrack {
+    /// All audio tracks, in order.
+    pub const ALL: [Self; 6] = [
+        Self::Track1,
+        Self::Track2,
+        Self::Track3,
+        Self::Track4,
+        Self::Track5,
+        Self::Track6,
+    ];
+
+    /// The 1-based index of the track, as shown in the OBS settings.
+    #[must_use]
+    pub const fn index(self) -> u8 {
+        match self {
+            Self::Track1 => 1,
+            Self::Track2 => 2,
+            Self::Track3 => 3,
+            Self::Track4 => 4,
+            Self::Track5 => 5,
+            Self::Track6 => 6,
+        }
+    }
+
+    /// Get the track for a 1-based index, if it's within range.
+    #[must_use]
+    pub fn from_index(index: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|track| track.index() == index)
+    }
+
+    /// Name of the track in the advanced output mode.
+    #[must_use]
+    pub const fn name(self) -> Parameter<String> {
+        Parameter::new(
+            Category::AdvOut,
+            match self {
+                Self::Track1 => "Track1Name",
+                Self::Track2 => "Track2Name",
+                Self::Track3 => "Track3Name",
+                Self::Track4 => "Track4Name",
+                Self::Track5 => "Track5Name",
+                Self::Track6 => "Track6Name",
+            },
+        )
+    }
+
+    /// Audio bitrate of the track in the advanced output mode, in kbps.
+    #[must_use]
+    pub const fn bitrate(self) -> Parameter<u32> {
+        Parameter::new(
+            Category::AdvOut,
+            match self {
+                Self::Track1 => "Track1Bitrate",
+                Self::Track2 => "Track2Bitrate",
+                Self::Track3 => "Track3Bitrate",
+                Self::Track4 => "Track4Bitrate",
+                Self::Track5 => "Track5Bitrate",
+                Self::Track6 => "Track6Bitrate",
+            },
+        )
+    }
+}
+
+impl Display for AudioTrack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Track {}", self.index())
+    }
+}
+
+impl ParameterValue for AudioTrack {
+    fn parse(value: &str) -> Option<Self> {
+        Self::from_index(value.trim().parse().ok()?)
+    }
+
+    fn to_value(&self) -> String {
+        self.index().to_string()
+    }
+}
+
+/// Audio track used for streaming in the advanced output mode.
+pub const ADV_STREAM_TRACK: Parameter<AudioTrack> = Parameter::new(Category::AdvOut, "TrackIndex");
+/// Whether a separate VOD (video on demand) track is sent with the stream in the advanced output
+/// mode.
+pub const ADV_VOD_TRACK_ENABLED: Parameter<bool> =
+    Parameter::new(Category::AdvOut, "VodTrackEnabled");
+/// Audio track sent as VOD (video on demand) track with the stream in the advanced output mode.
+pub const ADV_VOD_TRACK: Parameter<AudioTrack> = Parameter::new(Category::AdvOut, "VodTrackIndex");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn filename_format() {
+        assert!(validate_filename_format("%CCYY-%MM-%DD %hh-%mm-%ss").is_ok());
+        assert!(validate_filename_format("%Y%m%d_%H%M%S_%CRES@%FPS (100%%)").is_ok());
+        assert!(validate_filename_format("recording").is_ok());
+
+        assert!(matches!(
+            validate_filename_format("%CCYY_%x"),
+            Err(Error::InvalidFilenameFormat(specifier)) if specifier == "%x"
+        ));
+        assert!(matches!(
+            validate_filename_format("100%"),
+            Err(Error::InvalidFilenameFormat(specifier)) if specifier == "%"
+        ));
+    }
+}
@@ -1,11 +1,17 @@
-//! Additional structs for use with [`crate::client::Inputs::set_settings`].
+//! Additional structs for use with [`crate::client::Transitions::set_current_settings_typed`]
+//! and [`crate::client::Transitions::current_settings_typed`].
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use rgb::RGBA8;
-use serde::Serialize;
-use serde_repr::Serialize_repr;
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
+/// Identifier for cut transitions. They switch scenes instantly and have no settings.
+pub const TYPE_CUT: &str = "cut_transition";
+/// Identifier for fade transitions. They blend linearly between two scenes and have no settings
+/// besides their duration.
+pub const TYPE_FADE: &str = "fade_transition";
 /// Identifier for swipe transitions.
 pub const TYPE_SWIPE: &str = "swipe_transition";
 /// Identifier for slide transitions.
@@ -17,9 +23,63 @@ pub const TYPE_FADE_TO_COLOR: &str = "fade_to_color_transition";
 /// Identifier for luma wipe transitions.
 pub const TYPE_WIPE: &str = "wipe_transition";
 
+/// Settings of a built-in transition, that can be applied with
+/// [`crate::client::Transitions::set_current_settings_typed`].
+pub trait BuiltinTransition: Serialize {
+    /// Kind of the transition, that the settings belong to.
+    const KIND: &'static str;
+}
+
+/// Settings of the current scene transition, for all transition kinds built into OBS.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum TransitionSettings {
+    /// Cut transition, that has no settings.
+    Cut,
+    /// Fade transition, that has no settings besides its duration.
+    Fade,
+    /// Settings of a swipe transition.
+    Swipe(Swipe),
+    /// Settings of a slide transition.
+    Slide(Slide),
+    /// Settings of a stinger transition.
+    Stinger(StingerSettings),
+    /// Settings of a fade to color transition.
+    FadeToColor(FadeToColor),
+    /// Settings of a luma wipe transition.
+    Wipe(Wipe),
+}
+
+impl TransitionSettings {
+    /// Parse the raw settings of a transition of the given kind, or return [`None`] if the kind
+    /// isn't built into OBS.
+    ///
+    /// OBS only reports settings that differ from their defaults, so any missing values are
+    /// filled in with the same defaults that OBS uses.
+    pub(crate) fn parse(
+        kind: &str,
+        settings: Option<serde_json::Value>,
+    ) -> serde_json::Result<Option<Self>> {
+        let settings =
+            settings.unwrap_or_else(|| serde_json::Value::Object(serde_json::Map::new()));
+
+        Ok(Some(match kind {
+            TYPE_CUT => Self::Cut,
+            TYPE_FADE => Self::Fade,
+            TYPE_SWIPE => Self::Swipe(serde_json::from_value(settings)?),
+            TYPE_SLIDE => Self::Slide(serde_json::from_value(settings)?),
+            TYPE_STINGER => Self::Stinger(serde_json::from_value(settings)?),
+            TYPE_FADE_TO_COLOR => Self::FadeToColor(serde_json::from_value(settings)?),
+            TYPE_WIPE => Self::Wipe(serde_json::from_value(settings)?),
+            _ => return Ok(None),
+        }))
+    }
+}
+
 /// Options for a swipe transition. A swipe describes one scene hovering over another and making
 /// the other scene visible by moving in/out of the scene.
-#[derive(Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct Swipe {
     /// Direction of the swipe.
@@ -29,17 +89,26 @@ pub struct Swipe {
     pub swipe_in: bool,
 }
 
+impl BuiltinTransition for Swipe {
+    const KIND: &'static str = TYPE_SWIPE;
+}
+
 /// Options for a slide transition. A slide describes two scene directly next to each other making
 /// one visible by "pushing" the other one away.
-#[derive(Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct Slide {
     /// Direction of the slide.
     pub direction: Direction,
 }
 
+impl BuiltinTransition for Slide {
+    const KIND: &'static str = TYPE_SLIDE;
+}
+
 /// The direction for a [`Swipe`] or [`Slide`].
-#[derive(Clone, Copy, Debug, Default, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
 pub enum Direction {
@@ -74,9 +143,45 @@ pub struct Stinger<'a> {
     pub audio_fade_style: AudioFadeStyle,
 }
 
+impl BuiltinTransition for Stinger<'_> {
+    const KIND: &'static str = TYPE_STINGER;
+}
+
+/// Options of a stinger transition, as read back from OBS. This is the owned counterpart of
+/// [`Stinger`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default)]
+#[non_exhaustive]
+pub struct StingerSettings {
+    /// Location of the video file.
+    pub path: PathBuf,
+    /// The type of value that [`Self::transition_point`] stands for.
+    pub tp_type: TransitionPointType,
+    /// Point at which the scene transition triggers.
+    pub transition_point: u32,
+    /// The kind of audio monitoring to apply.
+    pub audio_monitoring: AudioMonitoring,
+    /// The way audio is gradually swapped between two scenes.
+    pub audio_fade_style: AudioFadeStyle,
+}
+
+impl StingerSettings {
+    /// Borrow the settings as [`Stinger`], to write them back, for example after modifying them.
+    #[must_use]
+    pub fn as_stinger(&self) -> Stinger<'_> {
+        Stinger {
+            path: &self.path,
+            tp_type: self.tp_type,
+            transition_point: self.transition_point,
+            audio_monitoring: self.audio_monitoring,
+            audio_fade_style: self.audio_fade_style,
+        }
+    }
+}
+
 /// Different units that are used together with a value to define scene switching point of a video
 /// transition.
-#[derive(Clone, Copy, Debug, Default, Serialize_repr)]
+#[derive(Clone, Copy, Debug, Default, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
 #[repr(u8)]
 #[non_exhaustive]
 pub enum TransitionPointType {
@@ -89,7 +194,7 @@ pub enum TransitionPointType {
 
 /// Setting for the audio monitoring which defines whether audio is send to the stream, played
 /// locally or both at the same time.
-#[derive(Clone, Copy, Debug, Default, Serialize_repr)]
+#[derive(Clone, Copy, Debug, Default, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
 #[repr(u8)]
 #[non_exhaustive]
 pub enum AudioMonitoring {
@@ -106,7 +211,7 @@ pub enum AudioMonitoring {
 }
 
 /// Describes the way in which the audio is faded between two scenes with a [`Stinger`] transition.
-#[derive(Clone, Copy, Debug, Default, Serialize_repr)]
+#[derive(Clone, Copy, Debug, Default, Deserialize_repr, Eq, Hash, PartialEq, Serialize_repr)]
 #[repr(u8)]
 #[non_exhaustive]
 pub enum AudioFadeStyle {
@@ -121,7 +226,8 @@ pub enum AudioFadeStyle {
 /// Options for a fade to color transition. A color fading describes one scene being blended with
 /// a given color until only the color is visible and then blend from the color to the new scene
 /// until the color is fully gone.
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct FadeToColor {
     /// Color to blend in/out.
@@ -131,9 +237,24 @@ pub struct FadeToColor {
     pub switch_point: u8,
 }
 
+impl Default for FadeToColor {
+    /// Fade to opaque black, swapping the scenes halfway through, same as the defaults of OBS.
+    fn default() -> Self {
+        Self {
+            color: RGBA8::new(0, 0, 0, 255),
+            switch_point: 50,
+        }
+    }
+}
+
+impl BuiltinTransition for FadeToColor {
+    const KIND: &'static str = TYPE_FADE_TO_COLOR;
+}
+
 /// Options for a luma wipe transition. A luma wipe describes one scene being gradually displayed
 /// over the other, where the luma image defines a certain animation to do so.
-#[derive(Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct Wipe {
     /// The image to use. This describes the animation that is used.
@@ -144,8 +265,23 @@ pub struct Wipe {
     pub luma_softness: f64,
 }
 
+impl Default for Wipe {
+    /// Horizontal linear wipe with slightly soft edges, same as the defaults of OBS.
+    fn default() -> Self {
+        Self {
+            luma_image: LumaImage::default(),
+            luma_invert: false,
+            luma_softness: 0.03,
+        }
+    }
+}
+
+impl BuiltinTransition for Wipe {
+    const KIND: &'static str = TYPE_WIPE;
+}
+
 /// A luma image that defines the animation of a [`Wipe`].
-#[derive(Default, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 #[non_exhaustive]
 pub enum LumaImage {
     /// Barn door animation diagonal from the bottom left.
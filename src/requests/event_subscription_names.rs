@@ -0,0 +1,151 @@
+//! Serde helper to (de)serialize an [`EventSubscription`] as list of human-readable names, like
+//! `["General", "Scenes", "InputVolumeMeters"]`, instead of its numeric representation.
+//!
+//! This is helpful for configuration files, and can be used with the `#[serde(with = "...")]`
+//! attribute:
+//!
+//! ```
+//! use obws::requests::EventSubscription;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Config {
+//!     #[serde(with = "obws::requests::event_subscription_names")]
+//!     events: EventSubscription,
+//! }
+//! ```
+
+use std::fmt;
+
+use serde::{
+    de::{self, Deserializer, SeqAccess, Visitor},
+    ser::{SerializeSeq, Serializer},
+};
+
+use super::EventSubscription;
+
+/// Serialize the subscription as list of names. See [`EventSubscription::names`].
+pub fn serialize<S>(value: &EventSubscription, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(None)?;
+    for name in value.names() {
+        seq.serialize_element(name)?;
+    }
+    seq.end()
+}
+
+/// Deserialize the subscription from a list of names.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<EventSubscription, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(NamesVisitor)
+}
+
+struct NamesVisitor;
+
+impl<'de> Visitor<'de> for NamesVisitor {
+    type Value = EventSubscription;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("list of event subscription names")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut value = EventSubscription::NONE;
+        while let Some(name) = seq.next_element::<&str>()? {
+            value |= name.parse().map_err(de::Error::custom)?;
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SimpleStruct {
+        #[serde(with = "super")]
+        value: EventSubscription,
+    }
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(
+            &SimpleStruct {
+                value: EventSubscription::SCENES | EventSubscription::INPUT_VOLUME_METERS,
+            },
+            &[
+                Token::Struct {
+                    name: "SimpleStruct",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::Seq { len: None },
+                Token::BorrowedStr("Scenes"),
+                Token::BorrowedStr("InputVolumeMeters"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        assert_tokens(
+            &SimpleStruct {
+                value: EventSubscription::ALL | EventSubscription::SCENE_ITEM_TRANSFORM_CHANGED,
+            },
+            &[
+                Token::Struct {
+                    name: "SimpleStruct",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::Seq { len: None },
+                Token::BorrowedStr("All"),
+                Token::BorrowedStr("SceneItemTransformChanged"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn deser_unknown() {
+        assert_de_tokens_error::<SimpleStruct>(
+            &[
+                Token::Struct {
+                    name: "SimpleStruct",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::Seq { len: None },
+                Token::BorrowedStr("Everything"),
+                Token::SeqEnd,
+            ],
+            "unknown event subscription `Everything`",
+        );
+    }
+
+    #[test]
+    fn display_and_parse() {
+        let value = EventSubscription::GENERAL | EventSubscription::UI;
+        assert_eq!("General, Ui", value.to_string());
+        assert_eq!(value, "General, Ui".parse().unwrap());
+        assert_eq!(value, "Ui|General".parse().unwrap());
+
+        assert_eq!("None", EventSubscription::NONE.to_string());
+        assert_eq!(EventSubscription::NONE, "None".parse().unwrap());
+        assert_eq!(EventSubscription::NONE, "".parse().unwrap());
+        assert_eq!(EventSubscription::ALL, "All".parse().unwrap());
+        assert!("general".parse::<EventSubscription>().is_err());
+    }
+}
@@ -5,6 +5,8 @@ use serde_with::skip_serializing_none;
 
 use super::sources::SourceId;
 
+pub mod kind;
+
 #[derive(Serialize)]
 #[serde(tag = "requestType", content = "requestData")]
 pub(crate) enum Request<'a> {
@@ -0,0 +1,118 @@
+//! Identifiers of the source filter kinds, that come with OBS Studio.
+//!
+//! The constants can be used directly as kind in [`crate::requests::filters::Create`], while
+//! [`FilterKind`] gives a typed view on the kind of existing filters, through
+//! [`SourceFilter::typed_kind`](crate::responses::filters::SourceFilter::typed_kind).
+
+use std::fmt::{self, Display};
+
+macro_rules! filter_kinds {
+    ($($(#[$doc:meta])* $variant:ident => $constant:ident = $id:literal,)+) => {
+        $(
+            $(#[$doc])*
+            pub const $constant: &str = $id;
+        )+
+
+        /// Typed kind of a source filter.
+        #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #[non_exhaustive]
+        pub enum FilterKind {
+            $(
+                $(#[$doc])*
+                $variant,
+            )+
+            /// Any other filter kind, like filters from third-party plugins.
+            Other(String),
+        }
+
+        impl FilterKind {
+            /// The kind identifier, as used by obs-websocket.
+            #[must_use]
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $constant,)+
+                    Self::Other(kind) => kind,
+                }
+            }
+        }
+
+        impl From<&str> for FilterKind {
+            fn from(kind: &str) -> Self {
+                match kind {
+                    $($constant => Self::$variant,)+
+                    _ => Self::Other(kind.to_owned()),
+                }
+            }
+        }
+    };
+}
+
+filter_kinds! {
+    /// Identifier for video delay (async) filters.
+    AsyncDelay => ASYNC_DELAY_FILTER = "async_delay_filter",
+    /// Identifier for 3-band equalizer filters.
+    BasicEq => BASIC_EQ_FILTER = "basic_eq_filter",
+    /// Identifier for chroma key filters.
+    ChromaKey => CHROMA_KEY_FILTER_V2 = "chroma_key_filter_v2",
+    /// Identifier for apply LUT filters.
+    Clut => CLUT_FILTER = "clut_filter",
+    /// Identifier for color correction filters.
+    Color => COLOR_FILTER_V2 = "color_filter_v2",
+    /// Identifier for color key filters.
+    ColorKey => COLOR_KEY_FILTER_V2 = "color_key_filter_v2",
+    /// Identifier for compressor filters.
+    Compressor => COMPRESSOR_FILTER = "compressor_filter",
+    /// Identifier for crop/pad filters.
+    Crop => CROP_FILTER = "crop_filter",
+    /// Identifier for expander filters.
+    Expander => EXPANDER_FILTER = "expander_filter",
+    /// Identifier for gain filters.
+    Gain => GAIN_FILTER = "gain_filter",
+    /// Identifier for render delay filters.
+    GpuDelay => GPU_DELAY = "gpu_delay",
+    /// Identifier for HDR tone mapping filters.
+    HdrTonemap => HDR_TONEMAP_FILTER = "hdr_tonemap_filter",
+    /// Identifier for invert polarity filters.
+    InvertPolarity => INVERT_POLARITY_FILTER = "invert_polarity_filter",
+    /// Identifier for limiter filters.
+    Limiter => LIMITER_FILTER = "limiter_filter",
+    /// Identifier for luma key filters.
+    LumaKey => LUMA_KEY_FILTER_V2 = "luma_key_filter_v2",
+    /// Identifier for image mask/blend filters.
+    Mask => MASK_FILTER_V2 = "mask_filter_v2",
+    /// Identifier for noise gate filters.
+    NoiseGate => NOISE_GATE_FILTER = "noise_gate_filter",
+    /// Identifier for noise suppression filters.
+    NoiseSuppress => NOISE_SUPPRESS_FILTER_V2 = "noise_suppress_filter_v2",
+    /// Identifier for scaling/aspect ratio filters.
+    Scale => SCALE_FILTER = "scale_filter",
+    /// Identifier for scroll filters.
+    Scroll => SCROLL_FILTER = "scroll_filter",
+    /// Identifier for sharpen filters.
+    Sharpness => SHARPNESS_FILTER_V2 = "sharpness_filter_v2",
+    /// Identifier for upward compressor filters.
+    UpwardCompressor => UPWARD_COMPRESSOR_FILTER = "upward_compressor_filter",
+    /// Identifier for VST 2.x plugin filters.
+    Vst => VST_FILTER = "vst_filter",
+}
+
+impl Display for FilterKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        assert_eq!(FilterKind::Color, FilterKind::from(COLOR_FILTER_V2));
+        assert_eq!("color_filter_v2", FilterKind::Color.as_str());
+
+        let other = FilterKind::from("move_value_filter");
+        assert_eq!(FilterKind::Other("move_value_filter".to_owned()), other);
+        assert_eq!("move_value_filter", other.to_string());
+    }
+}
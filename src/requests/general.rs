@@ -1,6 +1,7 @@
 //! General requests, not fitting into any category.
 
 use serde::Serialize;
+use serde_with::skip_serializing_none;
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Serialize)]
@@ -18,7 +19,8 @@ pub(crate) enum Request<'a> {
     },
     #[serde(rename = "CallVendorRequest")]
     CallVendorRequest(CallVendorRequestInternal<'a>),
-    // TODO: Sleep
+    #[serde(rename = "Sleep")]
+    Sleep(Sleep),
 }
 
 impl<'a> From<Request<'a>> for super::RequestType<'a> {
@@ -51,3 +53,15 @@ pub(crate) struct CallVendorRequestInternal<'a> {
     #[serde(rename = "requestData")]
     pub request_data: serde_json::Value,
 }
+
+/// Request information for the `Sleep` request, that is only valid inside of request batches.
+#[skip_serializing_none]
+#[derive(Default, Serialize)]
+pub(crate) struct Sleep {
+    /// Number of milliseconds to sleep for, in serial realtime batches.
+    #[serde(rename = "sleepMillis")]
+    pub millis: Option<u32>,
+    /// Number of frames to sleep for, in serial frame batches.
+    #[serde(rename = "sleepFrames")]
+    pub frames: Option<u32>,
+}
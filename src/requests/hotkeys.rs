@@ -2,6 +2,9 @@
 
 use serde::Serialize;
 
+pub mod keys;
+pub mod sequence;
+
 #[derive(Serialize)]
 #[serde(tag = "requestType", content = "requestData")]
 pub(crate) enum Request<'a> {
@@ -25,6 +28,8 @@ pub(crate) enum Request<'a> {
         #[serde(rename = "keyModifiers")]
         modifiers: KeyModifiers,
     },
+    #[serde(rename = "TriggerHotkeyByKeySequence")]
+    TriggerByKeySequence(&'a sequence::KeySequence),
     // TODO: Sleep
 }
 
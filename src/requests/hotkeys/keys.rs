@@ -0,0 +1,332 @@
+//! Human-readable labels for the OBS key identifiers, as used by
+//! [`crate::client::Hotkeys::trigger_by_sequence`], and back.
+//!
+//! By default, keys are labeled after what their identifier describes, like `"Page Up"` for
+//! `OBS_KEY_PAGEUP` or `"+"` for `OBS_KEY_PLUS`. Several punctuation keys are bound to a position
+//! on the keyboard rather than a character though, so the same identifier stands for different
+//! characters depending on the keyboard layout. A [`Layout`] labels these keys after the
+//! character printed on them instead, like `"Ü"` for `OBS_KEY_SEMICOLON` on a German keyboard.
+//! The layouts follow the mapping of the Windows virtual keys, that OBS uses for these keys.
+
+use super::KeyModifiers;
+
+/// Keyboard layout, that decides the labels of the punctuation keys.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Layout {
+    /// Label keys after the character their identifier describes.
+    #[default]
+    Generic,
+    /// US English QWERTY layout.
+    Us,
+    /// German QWERTZ layout.
+    German,
+}
+
+impl Layout {
+    /// Labels for keys, that differ from the generic ones in this layout.
+    fn overrides(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Generic => &[],
+            Self::Us => &[
+                ("OBS_KEY_PLUS", "="),
+                ("OBS_KEY_ASCIITILDE", "`"),
+                ("OBS_KEY_BACKSLASH_RT102", "\\ (ISO)"),
+            ],
+            Self::German => &[
+                ("OBS_KEY_SEMICOLON", "Ü"),
+                ("OBS_KEY_SLASH", "#"),
+                ("OBS_KEY_ASCIITILDE", "Ö"),
+                ("OBS_KEY_BRACKETLEFT", "ß"),
+                ("OBS_KEY_BACKSLASH", "^"),
+                ("OBS_KEY_BRACKETRIGHT", "´"),
+                ("OBS_KEY_APOSTROPHE", "Ä"),
+                ("OBS_KEY_BACKSLASH_RT102", "<"),
+            ],
+        }
+    }
+}
+
+/// All known key identifiers with their generic label.
+const KEYS: &[(&str, &str)] = &[
+    ("OBS_KEY_RETURN", "Enter"),
+    ("OBS_KEY_ENTER", "Num Enter"),
+    ("OBS_KEY_ESCAPE", "Esc"),
+    ("OBS_KEY_TAB", "Tab"),
+    ("OBS_KEY_BACKTAB", "Backtab"),
+    ("OBS_KEY_BACKSPACE", "Backspace"),
+    ("OBS_KEY_INSERT", "Insert"),
+    ("OBS_KEY_DELETE", "Delete"),
+    ("OBS_KEY_PAUSE", "Pause"),
+    ("OBS_KEY_PRINT", "Print"),
+    ("OBS_KEY_SYSREQ", "SysReq"),
+    ("OBS_KEY_CLEAR", "Clear"),
+    ("OBS_KEY_HOME", "Home"),
+    ("OBS_KEY_END", "End"),
+    ("OBS_KEY_LEFT", "Left"),
+    ("OBS_KEY_UP", "Up"),
+    ("OBS_KEY_RIGHT", "Right"),
+    ("OBS_KEY_DOWN", "Down"),
+    ("OBS_KEY_PAGEUP", "Page Up"),
+    ("OBS_KEY_PAGEDOWN", "Page Down"),
+    ("OBS_KEY_SHIFT", "Shift"),
+    ("OBS_KEY_CONTROL", "Ctrl"),
+    ("OBS_KEY_META", "Meta"),
+    ("OBS_KEY_ALT", "Alt"),
+    ("OBS_KEY_ALTGR", "AltGr"),
+    ("OBS_KEY_CAPSLOCK", "Caps Lock"),
+    ("OBS_KEY_NUMLOCK", "Num Lock"),
+    ("OBS_KEY_SCROLLLOCK", "Scroll Lock"),
+    ("OBS_KEY_MENU", "Menu"),
+    ("OBS_KEY_HELP", "Help"),
+    ("OBS_KEY_SPACE", "Space"),
+    ("OBS_KEY_F1", "F1"),
+    ("OBS_KEY_F2", "F2"),
+    ("OBS_KEY_F3", "F3"),
+    ("OBS_KEY_F4", "F4"),
+    ("OBS_KEY_F5", "F5"),
+    ("OBS_KEY_F6", "F6"),
+    ("OBS_KEY_F7", "F7"),
+    ("OBS_KEY_F8", "F8"),
+    ("OBS_KEY_F9", "F9"),
+    ("OBS_KEY_F10", "F10"),
+    ("OBS_KEY_F11", "F11"),
+    ("OBS_KEY_F12", "F12"),
+    ("OBS_KEY_F13", "F13"),
+    ("OBS_KEY_F14", "F14"),
+    ("OBS_KEY_F15", "F15"),
+    ("OBS_KEY_F16", "F16"),
+    ("OBS_KEY_F17", "F17"),
+    ("OBS_KEY_F18", "F18"),
+    ("OBS_KEY_F19", "F19"),
+    ("OBS_KEY_F20", "F20"),
+    ("OBS_KEY_F21", "F21"),
+    ("OBS_KEY_F22", "F22"),
+    ("OBS_KEY_F23", "F23"),
+    ("OBS_KEY_F24", "F24"),
+    ("OBS_KEY_0", "0"),
+    ("OBS_KEY_1", "1"),
+    ("OBS_KEY_2", "2"),
+    ("OBS_KEY_3", "3"),
+    ("OBS_KEY_4", "4"),
+    ("OBS_KEY_5", "5"),
+    ("OBS_KEY_6", "6"),
+    ("OBS_KEY_7", "7"),
+    ("OBS_KEY_8", "8"),
+    ("OBS_KEY_9", "9"),
+    ("OBS_KEY_A", "A"),
+    ("OBS_KEY_B", "B"),
+    ("OBS_KEY_C", "C"),
+    ("OBS_KEY_D", "D"),
+    ("OBS_KEY_E", "E"),
+    ("OBS_KEY_F", "F"),
+    ("OBS_KEY_G", "G"),
+    ("OBS_KEY_H", "H"),
+    ("OBS_KEY_I", "I"),
+    ("OBS_KEY_J", "J"),
+    ("OBS_KEY_K", "K"),
+    ("OBS_KEY_L", "L"),
+    ("OBS_KEY_M", "M"),
+    ("OBS_KEY_N", "N"),
+    ("OBS_KEY_O", "O"),
+    ("OBS_KEY_P", "P"),
+    ("OBS_KEY_Q", "Q"),
+    ("OBS_KEY_R", "R"),
+    ("OBS_KEY_S", "S"),
+    ("OBS_KEY_T", "T"),
+    ("OBS_KEY_U", "U"),
+    ("OBS_KEY_V", "V"),
+    ("OBS_KEY_W", "W"),
+    ("OBS_KEY_X", "X"),
+    ("OBS_KEY_Y", "Y"),
+    ("OBS_KEY_Z", "Z"),
+    ("OBS_KEY_EXCLAM", "!"),
+    ("OBS_KEY_QUOTEDBL", "\""),
+    ("OBS_KEY_NUMBERSIGN", "#"),
+    ("OBS_KEY_DOLLAR", "$"),
+    ("OBS_KEY_PERCENT", "%"),
+    ("OBS_KEY_AMPERSAND", "&"),
+    ("OBS_KEY_APOSTROPHE", "'"),
+    ("OBS_KEY_PARENLEFT", "("),
+    ("OBS_KEY_PARENRIGHT", ")"),
+    ("OBS_KEY_ASTERISK", "*"),
+    ("OBS_KEY_PLUS", "+"),
+    ("OBS_KEY_COMMA", ","),
+    ("OBS_KEY_MINUS", "-"),
+    ("OBS_KEY_PERIOD", "."),
+    ("OBS_KEY_SLASH", "/"),
+    ("OBS_KEY_COLON", ":"),
+    ("OBS_KEY_SEMICOLON", ";"),
+    ("OBS_KEY_LESS", "<"),
+    ("OBS_KEY_EQUAL", "="),
+    ("OBS_KEY_GREATER", ">"),
+    ("OBS_KEY_QUESTION", "?"),
+    ("OBS_KEY_AT", "@"),
+    ("OBS_KEY_BRACKETLEFT", "["),
+    ("OBS_KEY_BACKSLASH", "\\"),
+    ("OBS_KEY_BRACKETRIGHT", "]"),
+    ("OBS_KEY_ASCIICIRCUM", "^"),
+    ("OBS_KEY_UNDERSCORE", "_"),
+    ("OBS_KEY_QUOTELEFT", "`"),
+    ("OBS_KEY_BRACELEFT", "{"),
+    ("OBS_KEY_BAR", "|"),
+    ("OBS_KEY_BRACERIGHT", "}"),
+    ("OBS_KEY_ASCIITILDE", "~"),
+    ("OBS_KEY_BACKSLASH_RT102", "\\ (102nd key)"),
+    ("OBS_KEY_NUM0", "Num 0"),
+    ("OBS_KEY_NUM1", "Num 1"),
+    ("OBS_KEY_NUM2", "Num 2"),
+    ("OBS_KEY_NUM3", "Num 3"),
+    ("OBS_KEY_NUM4", "Num 4"),
+    ("OBS_KEY_NUM5", "Num 5"),
+    ("OBS_KEY_NUM6", "Num 6"),
+    ("OBS_KEY_NUM7", "Num 7"),
+    ("OBS_KEY_NUM8", "Num 8"),
+    ("OBS_KEY_NUM9", "Num 9"),
+    ("OBS_KEY_NUMASTERISK", "Num *"),
+    ("OBS_KEY_NUMPLUS", "Num +"),
+    ("OBS_KEY_NUMCOMMA", "Num ,"),
+    ("OBS_KEY_NUMMINUS", "Num -"),
+    ("OBS_KEY_NUMPERIOD", "Num ."),
+    ("OBS_KEY_NUMSLASH", "Num /"),
+    ("OBS_KEY_NUMEQUAL", "Num ="),
+    ("OBS_KEY_MOUSE1", "Left Mouse Button"),
+    ("OBS_KEY_MOUSE2", "Right Mouse Button"),
+    ("OBS_KEY_MOUSE3", "Middle Mouse Button"),
+    ("OBS_KEY_MOUSE4", "Mouse Button 4"),
+    ("OBS_KEY_MOUSE5", "Mouse Button 5"),
+    ("OBS_KEY_VK_VOLUME_MUTE", "Volume Mute"),
+    ("OBS_KEY_VK_VOLUME_DOWN", "Volume Down"),
+    ("OBS_KEY_VK_VOLUME_UP", "Volume Up"),
+    ("OBS_KEY_VK_MEDIA_NEXT_TRACK", "Next Track"),
+    ("OBS_KEY_VK_MEDIA_PREV_TRACK", "Previous Track"),
+    ("OBS_KEY_VK_MEDIA_STOP", "Stop Media"),
+    ("OBS_KEY_VK_MEDIA_PLAY_PAUSE", "Play/Pause Media"),
+];
+
+/// Get the static version of a key identifier, or [`None`] if the key is unknown.
+pub(super) fn known(id: &str) -> Option<&'static str> {
+    KEYS.iter().find(|(key, _)| *key == id).map(|(key, _)| *key)
+}
+
+/// Get the generic label of a key identifier, like `"Page Up"` for `OBS_KEY_PAGEUP`, or [`None`]
+/// if the key is unknown.
+#[must_use]
+pub fn label(id: &str) -> Option<&'static str> {
+    label_for(id, Layout::Generic)
+}
+
+/// Get the label of a key identifier for the given keyboard layout, or [`None`] if the key is
+/// unknown.
+#[must_use]
+pub fn label_for(id: &str, layout: Layout) -> Option<&'static str> {
+    layout
+        .overrides()
+        .iter()
+        .chain(KEYS)
+        .find(|(key, _)| *key == id)
+        .map(|(_, label)| *label)
+}
+
+/// Get the key identifier for a generic label, like `OBS_KEY_PAGEUP` for `"Page Up"`, or [`None`]
+/// if no key has this label. Labels are matched regardless of their case.
+#[must_use]
+pub fn id(label: &str) -> Option<&'static str> {
+    id_for(label, Layout::Generic)
+}
+
+/// Get the key identifier for a label of the given keyboard layout, or [`None`] if no key has
+/// this label. Labels are matched regardless of their case.
+#[must_use]
+pub fn id_for(label: &str, layout: Layout) -> Option<&'static str> {
+    let overrides = layout.overrides();
+    let overridden = |id: &str| overrides.iter().any(|(key, _)| *key == id);
+
+    overrides
+        .iter()
+        .chain(KEYS.iter().filter(|(key, _)| !overridden(key)))
+        .find(|(_, name)| name.to_lowercase() == label.to_lowercase())
+        .map(|(key, _)| *key)
+}
+
+/// Format a key sequence, as triggered by
+/// [`crate::client::Hotkeys::trigger_by_sequence`], into a label like `"Ctrl+Shift+F1"`. Returns
+/// [`None`] if the key is unknown.
+#[must_use]
+pub fn sequence_label(id: &str, modifiers: &KeyModifiers, layout: Layout) -> Option<String> {
+    let key = label_for(id, layout)?;
+    let mut label = String::new();
+
+    for (active, name) in [
+        (modifiers.control, "Ctrl"),
+        (modifiers.alt, "Alt"),
+        (modifiers.shift, "Shift"),
+        (modifiers.command, "Cmd"),
+    ] {
+        if active {
+            label.push_str(name);
+            label.push('+');
+        }
+    }
+
+    label.push_str(key);
+    Some(label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for (key, label) in KEYS {
+            assert_eq!(Some(*key), id(label), "label {label:?} is not unique");
+        }
+
+        for layout in [Layout::Us, Layout::German] {
+            for (key, label) in layout.overrides() {
+                assert_eq!(Some(*label), label_for(key, layout));
+                assert_eq!(Some(*key), id_for(label, layout));
+            }
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        assert_eq!(Some("Page Up"), label("OBS_KEY_PAGEUP"));
+        assert_eq!(Some("+"), label("OBS_KEY_PLUS"));
+        assert_eq!(Some("="), label_for("OBS_KEY_PLUS", Layout::Us));
+        assert_eq!(Some("Ü"), label_for("OBS_KEY_SEMICOLON", Layout::German));
+        assert_eq!(Some("A"), label_for("OBS_KEY_A", Layout::German));
+        assert_eq!(None, label("OBS_KEY_UNKNOWN"));
+
+        assert_eq!(Some("OBS_KEY_A"), id("a"));
+        assert_eq!(Some("OBS_KEY_SEMICOLON"), id_for("ü", Layout::German));
+        assert_eq!(Some("OBS_KEY_NUMBERSIGN"), id("#"));
+        assert_eq!(Some("OBS_KEY_SLASH"), id_for("#", Layout::German));
+    }
+
+    #[test]
+    fn sequence() {
+        assert_eq!(
+            Some("Ctrl+Shift+F1".to_owned()),
+            sequence_label(
+                "OBS_KEY_F1",
+                &KeyModifiers {
+                    shift: true,
+                    control: true,
+                    ..KeyModifiers::default()
+                },
+                Layout::Generic
+            )
+        );
+        assert_eq!(
+            Some("Ä".to_owned()),
+            sequence_label(
+                "OBS_KEY_APOSTROPHE",
+                &KeyModifiers::default(),
+                Layout::German
+            )
+        );
+    }
+}
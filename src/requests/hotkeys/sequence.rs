@@ -0,0 +1,457 @@
+//! Typed key sequences for [`crate::client::Hotkeys::trigger_by_key_sequence`], as an alternative
+//! to passing raw OBS key identifiers like `OBS_KEY_F1`.
+//!
+//! Sequences can be built from a [`Key`] and its [`Modifiers`], or parsed from strings like
+//! `"Ctrl+Shift+S"`:
+//!
+//! ```
+//! use obws::requests::hotkeys::sequence::{Key, KeySequence, Modifiers};
+//!
+//! let built = KeySequence::new(Key::Letter('S')).modifiers(Modifiers::CONTROL | Modifiers::SHIFT);
+//! let parsed = "Ctrl+Shift+S".parse::<KeySequence>().unwrap();
+//!
+//! assert_eq!(built, parsed);
+//! assert_eq!("OBS_KEY_S", parsed.key.id());
+//! ```
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use bitflags::bitflags;
+use serde::{ser::SerializeStruct, Serialize};
+
+use super::{keys, KeyModifiers};
+
+/// A single key on the keyboard or mouse, that can be pressed as part of a [`KeySequence`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Key {
+    /// Letter key from `A` to `Z`. The case of the letter is ignored.
+    Letter(char),
+    /// Digit key from `0` to `9` in the main key block.
+    Digit(u8),
+    /// Function key from `F1` to `F24`.
+    F(u8),
+    /// Digit key from `0` to `9` on the numpad.
+    Numpad(u8),
+    /// Mouse button from `1` to `5`.
+    Mouse(u8),
+    /// Enter key in the main key block.
+    Return,
+    /// Escape key.
+    Escape,
+    /// Tab key.
+    Tab,
+    /// Backspace key.
+    Backspace,
+    /// Insert key.
+    Insert,
+    /// Delete key.
+    Delete,
+    /// Home key.
+    Home,
+    /// End key.
+    End,
+    /// Left arrow key.
+    Left,
+    /// Up arrow key.
+    Up,
+    /// Right arrow key.
+    Right,
+    /// Down arrow key.
+    Down,
+    /// Page up key.
+    PageUp,
+    /// Page down key.
+    PageDown,
+    /// Space bar.
+    Space,
+    /// Pause key.
+    Pause,
+    /// Print screen key.
+    Print,
+    /// Caps lock key.
+    CapsLock,
+    /// Num lock key.
+    NumLock,
+    /// Scroll lock key.
+    ScrollLock,
+    /// Context menu key.
+    Menu,
+    /// Any other key, by its OBS key identifier like `OBS_KEY_VK_VOLUME_UP`.
+    Other(&'static str),
+}
+
+/// Keys without any parameter, with the name of their OBS key identifier.
+const NAMED: &[(&str, Key)] = &[
+    ("RETURN", Key::Return),
+    ("ESCAPE", Key::Escape),
+    ("TAB", Key::Tab),
+    ("BACKSPACE", Key::Backspace),
+    ("INSERT", Key::Insert),
+    ("DELETE", Key::Delete),
+    ("HOME", Key::Home),
+    ("END", Key::End),
+    ("LEFT", Key::Left),
+    ("UP", Key::Up),
+    ("RIGHT", Key::Right),
+    ("DOWN", Key::Down),
+    ("PAGEUP", Key::PageUp),
+    ("PAGEDOWN", Key::PageDown),
+    ("SPACE", Key::Space),
+    ("PAUSE", Key::Pause),
+    ("PRINT", Key::Print),
+    ("CAPSLOCK", Key::CapsLock),
+    ("NUMLOCK", Key::NumLock),
+    ("SCROLLLOCK", Key::ScrollLock),
+    ("MENU", Key::Menu),
+];
+
+/// Common alternative names of keys, besides their labels in [`keys`].
+const ALIASES: &[(&str, &str)] = &[
+    ("return", "OBS_KEY_RETURN"),
+    ("escape", "OBS_KEY_ESCAPE"),
+    ("del", "OBS_KEY_DELETE"),
+    ("ins", "OBS_KEY_INSERT"),
+    ("pageup", "OBS_KEY_PAGEUP"),
+    ("pgup", "OBS_KEY_PAGEUP"),
+    ("pagedown", "OBS_KEY_PAGEDOWN"),
+    ("pgdn", "OBS_KEY_PAGEDOWN"),
+    ("plus", "OBS_KEY_PLUS"),
+];
+
+impl Key {
+    /// The OBS key identifier of this key, like `OBS_KEY_F1` for [`Self::F`]`(1)`.
+    ///
+    /// Parameters out of the documented range aren't checked, and result in identifiers that
+    /// OBS doesn't know, like `OBS_KEY_F99`.
+    #[must_use]
+    pub fn id(self) -> Cow<'static, str> {
+        match self {
+            Self::Letter(c) => format!("OBS_KEY_{}", c.to_ascii_uppercase()).into(),
+            Self::Digit(n) => format!("OBS_KEY_{n}").into(),
+            Self::F(n) => format!("OBS_KEY_F{n}").into(),
+            Self::Numpad(n) => format!("OBS_KEY_NUM{n}").into(),
+            Self::Mouse(n) => format!("OBS_KEY_MOUSE{n}").into(),
+            Self::Other(id) => id.into(),
+            key => NAMED
+                .iter()
+                .find(|(_, named)| *named == key)
+                .map_or(Cow::Borrowed("OBS_KEY_NONE"), |(name, _)| {
+                    format!("OBS_KEY_{name}").into()
+                }),
+        }
+    }
+
+    /// Get the typed key for a known OBS key identifier, or [`None`] if the identifier is
+    /// unknown.
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<Self> {
+        let id = keys::known(id)?;
+        let name = id.strip_prefix("OBS_KEY_")?;
+        let number = |prefix: &str| name.strip_prefix(prefix)?.parse::<u8>().ok();
+
+        if let Some((_, key)) = NAMED.iter().find(|(named, _)| *named == name) {
+            return Some(*key);
+        }
+
+        Some(match name.as_bytes() {
+            [c] if c.is_ascii_uppercase() => Self::Letter(char::from(*c)),
+            [c] if c.is_ascii_digit() => Self::Digit(c - b'0'),
+            _ => {
+                if let Some(n) = number("F") {
+                    Self::F(n)
+                } else if let Some(n) = number("NUM") {
+                    Self::Numpad(n)
+                } else if let Some(n) = number("MOUSE") {
+                    Self::Mouse(n)
+                } else {
+                    Self::Other(id)
+                }
+            }
+        })
+    }
+}
+
+/// Formats the key as its generic label from [`keys`], like `Page Up`, falling back to the OBS
+/// key identifier for unknown keys.
+impl Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = self.id();
+        f.write_str(keys::label(&id).unwrap_or(&id))
+    }
+}
+
+/// Parses a key from its OBS key identifier like `OBS_KEY_PAGEUP`, or its label like `Page Up`.
+/// Labels are matched regardless of their case.
+impl FromStr for Key {
+    type Err = ParseKeySequenceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let lower = s.to_lowercase();
+
+        keys::known(s)
+            .or_else(|| keys::id(s))
+            .or_else(|| {
+                ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == lower)
+                    .map(|(_, id)| *id)
+            })
+            .and_then(Self::from_id)
+            .ok_or_else(|| ParseKeySequenceError::UnknownKey(s.to_owned()))
+    }
+}
+
+/// Modifier keys, that are held while pressing the key of a [`KeySequence`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Modifiers(u8);
+
+bitflags! {
+    /// Modifier keys, that are held while pressing the key of a [`KeySequence`].
+    impl Modifiers: u8 {
+        /// Shift key.
+        const SHIFT = 1 << 0;
+        /// Control key.
+        const CONTROL = 1 << 1;
+        /// Alt key.
+        const ALT = 1 << 2;
+        /// Command key (Mac).
+        const COMMAND = 1 << 3;
+    }
+}
+
+impl Modifiers {
+    /// Parse a single modifier name, like `Ctrl` or `Shift`, regardless of its case.
+    fn from_modifier_name(name: &str) -> Result<Self, ParseKeySequenceError> {
+        match name.to_lowercase().as_str() {
+            "shift" => Ok(Self::SHIFT),
+            "ctrl" | "control" => Ok(Self::CONTROL),
+            "alt" | "option" => Ok(Self::ALT),
+            "cmd" | "command" | "meta" | "super" => Ok(Self::COMMAND),
+            _ => Err(ParseKeySequenceError::UnknownModifier(name.to_owned())),
+        }
+    }
+}
+
+impl From<Modifiers> for KeyModifiers {
+    fn from(value: Modifiers) -> Self {
+        Self {
+            shift: value.contains(Modifiers::SHIFT),
+            control: value.contains(Modifiers::CONTROL),
+            alt: value.contains(Modifiers::ALT),
+            command: value.contains(Modifiers::COMMAND),
+        }
+    }
+}
+
+/// A key together with the modifiers held while pressing it, like `Ctrl+Shift+F1`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct KeySequence {
+    /// The key to press.
+    pub key: Key,
+    /// Modifier keys to hold while pressing the key.
+    pub modifiers: Modifiers,
+}
+
+impl KeySequence {
+    /// Create a new sequence of the given key, without any modifiers.
+    #[must_use]
+    pub fn new(key: Key) -> Self {
+        Self {
+            key,
+            modifiers: Modifiers::empty(),
+        }
+    }
+
+    /// Add modifiers to hold while pressing the key.
+    #[must_use]
+    pub fn modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.modifiers |= modifiers;
+        self
+    }
+}
+
+impl From<Key> for KeySequence {
+    fn from(key: Key) -> Self {
+        Self::new(key)
+    }
+}
+
+/// Formats the sequence like `Ctrl+Shift+F1`, which can be parsed back with [`FromStr`].
+impl Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (flag, name) in [
+            (Modifiers::CONTROL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::COMMAND, "Cmd"),
+        ] {
+            if self.modifiers.contains(flag) {
+                write!(f, "{name}+")?;
+            }
+        }
+
+        self.key.fmt(f)
+    }
+}
+
+/// Parses a sequence of modifiers and a key, separated by `+`, like `Ctrl+Shift+S`. The key is
+/// always last, and can be a `+` itself, like in `Ctrl++`.
+impl FromStr for KeySequence {
+    type Err = ParseKeySequenceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (modifiers, key) = match s.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None if s == "+" => ("", s),
+            None => s.rsplit_once('+').unwrap_or(("", s)),
+        };
+
+        if key.trim().is_empty() {
+            return Err(ParseKeySequenceError::Empty);
+        }
+
+        let modifiers = modifiers
+            .split('+')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .try_fold(Modifiers::empty(), |acc, name| {
+                Ok(acc | Modifiers::from_modifier_name(name)?)
+            })?;
+
+        Ok(Self {
+            key: key.parse()?,
+            modifiers,
+        })
+    }
+}
+
+/// Serializes into the request data of `TriggerHotkeyByKeySequence`.
+impl Serialize for KeySequence {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut s = serializer.serialize_struct("KeySequence", 2)?;
+        s.serialize_field("keyId", &self.key.id())?;
+        s.serialize_field("keyModifiers", &KeyModifiers::from(self.modifiers))?;
+        s.end()
+    }
+}
+
+/// Error that occurs when parsing a [`Key`] or [`KeySequence`] from a string.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ParseKeySequenceError {
+    /// The sequence contained no key.
+    #[error("key sequence contains no key")]
+    Empty,
+    /// The key name is neither a known label nor an OBS key identifier.
+    #[error("unknown key `{0}`")]
+    UnknownKey(String),
+    /// The modifier name isn't one of `Shift`, `Ctrl`, `Alt` or `Cmd`.
+    #[error("unknown modifier `{0}`")]
+    UnknownModifier(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids() {
+        for (key, id) in [
+            (Key::Letter('s'), "OBS_KEY_S"),
+            (Key::Digit(7), "OBS_KEY_7"),
+            (Key::F(12), "OBS_KEY_F12"),
+            (Key::Numpad(0), "OBS_KEY_NUM0"),
+            (Key::Mouse(3), "OBS_KEY_MOUSE3"),
+            (Key::PageUp, "OBS_KEY_PAGEUP"),
+            (Key::Other("OBS_KEY_VK_VOLUME_UP"), "OBS_KEY_VK_VOLUME_UP"),
+        ] {
+            assert_eq!(id, key.id());
+            assert_eq!(
+                Some(if key == Key::Letter('s') {
+                    Key::Letter('S')
+                } else {
+                    key
+                }),
+                Key::from_id(id)
+            );
+        }
+
+        assert_eq!(None, Key::from_id("OBS_KEY_UNKNOWN"));
+    }
+
+    #[test]
+    fn parse() {
+        let parse = |s: &str| s.parse::<KeySequence>();
+
+        assert_eq!(
+            KeySequence::new(Key::Letter('S')).modifiers(Modifiers::CONTROL | Modifiers::SHIFT),
+            parse("Ctrl+Shift+S").unwrap()
+        );
+        assert_eq!(
+            KeySequence::new(Key::F(1)).modifiers(Modifiers::SHIFT | Modifiers::CONTROL),
+            parse("shift + ctrl + f1").unwrap()
+        );
+        assert_eq!(
+            KeySequence::new(Key::Other("OBS_KEY_PLUS")).modifiers(Modifiers::CONTROL),
+            parse("Ctrl++").unwrap()
+        );
+        assert_eq!(
+            KeySequence::new(Key::PageDown),
+            parse("OBS_KEY_PAGEDOWN").unwrap()
+        );
+        assert_eq!(KeySequence::new(Key::PageUp), parse("PgUp").unwrap());
+
+        assert!(matches!(parse(""), Err(ParseKeySequenceError::Empty)));
+        assert!(matches!(parse("Ctrl+"), Err(ParseKeySequenceError::Empty)));
+        assert!(matches!(
+            parse("Hyper+A"),
+            Err(ParseKeySequenceError::UnknownModifier(m)) if m == "Hyper"
+        ));
+        assert!(matches!(
+            parse("Ctrl+Foo"),
+            Err(ParseKeySequenceError::UnknownKey(k)) if k == "Foo"
+        ));
+    }
+
+    #[test]
+    fn display() {
+        for s in [
+            "+",
+            "Ctrl+Shift+S",
+            "Alt+Page Up",
+            "Ctrl++",
+            "Cmd+Num 5",
+            "F24",
+        ] {
+            assert_eq!(s, s.parse::<KeySequence>().unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        let sequence = KeySequence::new(Key::F(1)).modifiers(Modifiers::SHIFT);
+
+        assert_eq!(
+            serde_json::json!({
+                "keyId": "OBS_KEY_F1",
+                "keyModifiers": {
+                    "shift": true,
+                    "control": false,
+                    "alt": false,
+                    "command": false,
+                },
+            }),
+            serde_json::to_value(sequence).unwrap()
+        );
+    }
+}
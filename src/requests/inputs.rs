@@ -1,12 +1,21 @@
 //! Requests related to inputs.
 
+use std::path::Path;
+
+use rgb::RGBA8;
 use serde::Serialize;
 use serde_with::skip_serializing_none;
 use time::Duration;
 
 pub use super::ids::InputId;
-use super::scenes::SceneId;
-use crate::common::MonitorType;
+use super::{
+    custom::source_settings::{
+        BrowserSource, ColorSourceV3, ImageSource, SOURCE_BROWSER_SOURCE, SOURCE_COLOR_SOURCE_V3,
+        SOURCE_IMAGE_SOURCE,
+    },
+    scenes::SceneId,
+};
+use crate::common::{AudioTracks, MonitorType};
 
 #[derive(Serialize)]
 #[serde(tag = "requestType", content = "requestData")]
@@ -152,11 +161,8 @@ pub(crate) enum Request<'a> {
         #[serde(flatten)]
         input: InputId<'a>,
         /// Track settings to apply.
-        #[serde(
-            rename = "inputAudioTracks",
-            with = "crate::serde::audio_tracks::option"
-        )]
-        tracks: [Option<bool>; 6],
+        #[serde(rename = "inputAudioTracks")]
+        tracks: AudioTracks,
     },
     #[serde(rename = "GetInputPropertiesListPropertyItems")]
     PropertiesListPropertyItems {
@@ -239,6 +245,60 @@ pub struct Create<'a, T> {
     pub enabled: Option<bool>,
 }
 
+impl<'a> Create<'a, BrowserSource<'a>> {
+    /// Create a browser source, that shows the web page at the given URL.
+    #[must_use]
+    pub fn browser(scene: SceneId<'a>, input: &'a str, url: &'a str) -> Self {
+        Self {
+            scene,
+            input,
+            kind: SOURCE_BROWSER_SOURCE,
+            settings: Some(BrowserSource {
+                url,
+                ..BrowserSource::default()
+            }),
+            enabled: None,
+        }
+    }
+}
+
+impl<'a> Create<'a, ColorSourceV3> {
+    /// Create a color source, that shows the given color.
+    ///
+    /// The size is taken from [`ColorSourceV3::default`], and can be adjusted through
+    /// [`Self::settings`] before sending the request.
+    #[must_use]
+    pub fn color(scene: SceneId<'a>, input: &'a str, color: RGBA8) -> Self {
+        Self {
+            scene,
+            input,
+            kind: SOURCE_COLOR_SOURCE_V3,
+            settings: Some(ColorSourceV3 {
+                color,
+                ..ColorSourceV3::default()
+            }),
+            enabled: None,
+        }
+    }
+}
+
+impl<'a> Create<'a, ImageSource<'a>> {
+    /// Create an image source, that shows the image file at the given location.
+    #[must_use]
+    pub fn image(scene: SceneId<'a>, input: &'a str, file: &'a Path) -> Self {
+        Self {
+            scene,
+            input,
+            kind: SOURCE_IMAGE_SOURCE,
+            settings: Some(ImageSource {
+                file,
+                ..ImageSource::default()
+            }),
+            enabled: None,
+        }
+    }
+}
+
 /// Request information for [`crate::client::Inputs::create`].
 #[skip_serializing_none]
 #[derive(Default, Serialize)]
@@ -2,19 +2,27 @@
 
 #![expect(clippy::ref_option_ref)]
 
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
 use bitflags::bitflags;
 use serde::{ser::SerializeStruct, Serialize};
 use serde_repr::Serialize_repr;
 use serde_with::skip_serializing_none;
 
+pub mod batch;
 pub mod config;
 pub mod custom;
+pub mod event_subscription_names;
 pub mod filters;
 pub mod general;
 pub mod hotkeys;
 pub(crate) mod ids;
 pub mod inputs;
 pub(crate) mod media_inputs;
+mod name;
 pub(crate) mod outputs;
 pub mod profiles;
 pub(crate) mod recording;
@@ -23,7 +31,7 @@ pub(crate) mod scene_collections;
 pub mod scene_items;
 pub mod scenes;
 pub mod sources;
-pub(crate) mod streaming;
+pub mod streaming;
 pub(crate) mod transitions;
 pub mod ui;
 pub(crate) mod virtual_cam;
@@ -39,7 +47,6 @@ pub(crate) enum ClientRequest<'a> {
     Request(Request<'a>),
     /// Client is making a batch of requests for obs-websocket. Requests are processed serially
     /// (in order) by the server.
-    #[allow(dead_code)]
     RequestBatch(RequestBatch<'a>),
 }
 
@@ -210,6 +217,95 @@ bitflags! {
     }
 }
 
+impl EventSubscription {
+    /// Human-readable names of the single subscriptions, as used by [`Display`] and [`FromStr`].
+    const NAMES: &'static [(&'static str, Self)] = &[
+        ("General", Self::GENERAL),
+        ("Config", Self::CONFIG),
+        ("Scenes", Self::SCENES),
+        ("Inputs", Self::INPUTS),
+        ("Transitions", Self::TRANSITIONS),
+        ("Filters", Self::FILTERS),
+        ("Outputs", Self::OUTPUTS),
+        ("SceneItems", Self::SCENE_ITEMS),
+        ("MediaInputs", Self::MEDIA_INPUTS),
+        ("Vendors", Self::VENDORS),
+        ("Ui", Self::UI),
+        ("InputVolumeMeters", Self::INPUT_VOLUME_METERS),
+        ("InputActiveStateChanged", Self::INPUT_ACTIVE_STATE_CHANGED),
+        ("InputShowStateChanged", Self::INPUT_SHOW_STATE_CHANGED),
+        (
+            "SceneItemTransformChanged",
+            Self::SCENE_ITEM_TRANSFORM_CHANGED,
+        ),
+    ];
+
+    /// Human-readable names of all subscriptions contained in this value. The combination
+    /// [`Self::ALL`] is named `All`, instead of listing each of its subscriptions.
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        let all = self.contains(Self::ALL);
+
+        all.then_some("All").into_iter().chain(
+            Self::NAMES
+                .iter()
+                .filter(move |(_, flag)| {
+                    !(all && Self::ALL.contains(*flag)) && self.contains(*flag)
+                })
+                .map(|(name, _)| *name),
+        )
+    }
+
+    /// Parse a single human-readable subscription name, like `Scenes` or `All`.
+    fn from_display_name(name: &str) -> Result<Self, ParseEventSubscriptionError> {
+        match name {
+            "None" => Ok(Self::NONE),
+            "All" => Ok(Self::ALL),
+            _ => Self::NAMES
+                .iter()
+                .find(|(known, _)| *known == name)
+                .map(|(_, flag)| *flag)
+                .ok_or_else(|| ParseEventSubscriptionError(name.to_owned())),
+        }
+    }
+}
+
+/// Formats the subscriptions as comma-separated list of human-readable names, like
+/// `Scenes, InputVolumeMeters`. An empty value is formatted as `None`.
+impl Display for EventSubscription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut names = self.names();
+        match names.next() {
+            Some(first) => f.write_str(first)?,
+            None => return f.write_str("None"),
+        }
+
+        for name in names {
+            write!(f, ", {name}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a list of human-readable names, separated by commas or `|`, like `General, Scenes`.
+impl FromStr for EventSubscription {
+    type Err = ParseEventSubscriptionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split([',', '|'])
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .try_fold(Self::NONE, |acc, name| {
+                Ok(acc | Self::from_display_name(name)?)
+            })
+    }
+}
+
+/// Error that occurs when parsing an [`EventSubscription`] from an unknown name.
+#[derive(Debug, thiserror::Error)]
+#[error("unknown event subscription `{0}`")]
+pub struct ParseEventSubscriptionError(String);
+
 impl From<EventSubscription> for u32 {
     fn from(value: EventSubscription) -> Self {
         value.bits()
@@ -223,6 +319,172 @@ impl From<u32> for EventSubscription {
     }
 }
 
+/// Names of all requests, that are implemented by this crate.
+pub(crate) const REQUEST_TYPES: &[&str] = &[
+    // config
+    "GetPersistentData",
+    "SetPersistentData",
+    "GetVideoSettings",
+    "SetVideoSettings",
+    "GetStreamServiceSettings",
+    "SetStreamServiceSettings",
+    "GetRecordDirectory",
+    "SetRecordDirectory",
+    // filters
+    "GetSourceFilterKindList",
+    "GetSourceFilterList",
+    "GetSourceFilterDefaultSettings",
+    "CreateSourceFilter",
+    "RemoveSourceFilter",
+    "SetSourceFilterName",
+    "GetSourceFilter",
+    "SetSourceFilterIndex",
+    "SetSourceFilterSettings",
+    "SetSourceFilterEnabled",
+    // general
+    "GetVersion",
+    "GetStats",
+    "BroadcastCustomEvent",
+    "CallVendorRequest",
+    "Sleep",
+    // hotkeys
+    "GetHotkeyList",
+    "TriggerHotkeyByName",
+    "TriggerHotkeyByKeySequence",
+    // inputs
+    "GetInputList",
+    "GetInputKindList",
+    "GetSpecialInputs",
+    "GetInputDefaultSettings",
+    "GetInputSettings",
+    "SetInputSettings",
+    "GetInputMute",
+    "SetInputMute",
+    "ToggleInputMute",
+    "GetInputVolume",
+    "SetInputVolume",
+    "SetInputName",
+    "CreateInput",
+    "RemoveInput",
+    "GetInputAudioBalance",
+    "SetInputAudioBalance",
+    "GetInputAudioSyncOffset",
+    "SetInputAudioSyncOffset",
+    "GetInputAudioMonitorType",
+    "SetInputAudioMonitorType",
+    "GetInputAudioTracks",
+    "SetInputAudioTracks",
+    "GetInputPropertiesListPropertyItems",
+    "PressInputPropertiesButton",
+    // media_inputs
+    "GetMediaInputStatus",
+    "SetMediaInputCursor",
+    "OffsetMediaInputCursor",
+    "TriggerMediaInputAction",
+    // outputs
+    "GetOutputList",
+    "GetOutputStatus",
+    "ToggleOutput",
+    "StartOutput",
+    "StopOutput",
+    "GetOutputSettings",
+    "SetOutputSettings",
+    // profiles
+    "GetProfileList",
+    "SetCurrentProfile",
+    "CreateProfile",
+    "RemoveProfile",
+    "GetProfileParameter",
+    "SetProfileParameter",
+    // recording
+    "GetRecordStatus",
+    "ToggleRecord",
+    "StartRecord",
+    "StopRecord",
+    "ToggleRecordPause",
+    "PauseRecord",
+    "ResumeRecord",
+    "SplitRecordFile",
+    "CreateRecordChapter",
+    // replay_buffer
+    "GetReplayBufferStatus",
+    "ToggleReplayBuffer",
+    "StartReplayBuffer",
+    "StopReplayBuffer",
+    "SaveReplayBuffer",
+    "GetLastReplayBufferReplay",
+    // scene_collections
+    "GetSceneCollectionList",
+    "SetCurrentSceneCollection",
+    "CreateSceneCollection",
+    // scene_items
+    "GetSceneItemList",
+    "GetGroupSceneItemList",
+    "GetSceneItemId",
+    "GetSceneItemSource",
+    "CreateSceneItem",
+    "RemoveSceneItem",
+    "DuplicateSceneItem",
+    "GetSceneItemTransform",
+    "SetSceneItemTransform",
+    "GetSceneItemEnabled",
+    "SetSceneItemEnabled",
+    "GetSceneItemLocked",
+    "SetSceneItemLocked",
+    "GetSceneItemIndex",
+    "SetSceneItemIndex",
+    "GetSceneItemBlendMode",
+    "SetSceneItemBlendMode",
+    "GetSceneItemPrivateSettings",
+    "SetSceneItemPrivateSettings",
+    // scenes
+    "GetSceneList",
+    "GetGroupList",
+    "GetCurrentProgramScene",
+    "SetCurrentProgramScene",
+    "GetCurrentPreviewScene",
+    "SetCurrentPreviewScene",
+    "SetSceneName",
+    "CreateScene",
+    "RemoveScene",
+    "GetSceneSceneTransitionOverride",
+    "SetSceneSceneTransitionOverride",
+    // sources
+    "GetSourceActive",
+    "GetSourceScreenshot",
+    "SaveSourceScreenshot",
+    // streaming
+    "GetStreamStatus",
+    "ToggleStream",
+    "StartStream",
+    "StopStream",
+    "SendStreamCaption",
+    // transitions
+    "GetTransitionKindList",
+    "GetSceneTransitionList",
+    "GetCurrentSceneTransition",
+    "SetCurrentSceneTransition",
+    "SetCurrentSceneTransitionDuration",
+    "SetCurrentSceneTransitionSettings",
+    "GetCurrentSceneTransitionCursor",
+    "TriggerStudioModeTransition",
+    "SetTBarPosition",
+    // ui
+    "GetStudioModeEnabled",
+    "SetStudioModeEnabled",
+    "OpenInputPropertiesDialog",
+    "OpenInputFiltersDialog",
+    "OpenInputInteractDialog",
+    "GetMonitorList",
+    "OpenVideoMixProjector",
+    "OpenSourceProjector",
+    // virtual_cam
+    "GetVirtualCamStatus",
+    "ToggleVirtualCam",
+    "StartVirtualCam",
+    "StopVirtualCam",
+];
+
 #[allow(dead_code)]
 #[derive(Serialize_repr)]
 #[repr(i8)]
@@ -240,6 +502,8 @@ pub(crate) enum ExecutionType {
 }
 
 pub(crate) enum RequestType<'a> {
+    /// Request described by its raw name and data, for custom batches and requests.
+    Raw(self::batch::BatchRequest<'a>),
     Config(self::config::Request<'a>),
     Filters(self::filters::Request<'a>),
     General(self::general::Request<'a>),
@@ -266,6 +530,7 @@ impl Serialize for RequestType<'_> {
         S: serde::Serializer,
     {
         match self {
+            Self::Raw(req) => req.serialize(serializer),
             Self::Config(req) => req.serialize(serializer),
             Self::Filters(req) => req.serialize(serializer),
             Self::General(req) => req.serialize(serializer),
@@ -287,3 +552,30 @@ impl Serialize for RequestType<'_> {
         }
     }
 }
+
+impl RequestType<'_> {
+    /// The `requestType` of this request, like `GetVersion`.
+    pub(crate) fn name(&self) -> &str {
+        match self {
+            Self::Raw(req) => req.request_type(),
+            Self::Config(req) => name::request_name(req),
+            Self::Filters(req) => name::request_name(req),
+            Self::General(req) => name::request_name(req),
+            Self::Hotkeys(req) => name::request_name(req),
+            Self::Inputs(req) => name::request_name(req),
+            Self::MediaInputs(req) => name::request_name(req),
+            Self::Outputs(req) => name::request_name(req),
+            Self::Profiles(req) => name::request_name(req),
+            Self::Recording(req) => name::request_name(req),
+            Self::ReplayBuffer(req) => name::request_name(req),
+            Self::SceneCollections(req) => name::request_name(req),
+            Self::SceneItems(req) => name::request_name(req),
+            Self::Scenes(req) => name::request_name(req),
+            Self::Sources(req) => name::request_name(req),
+            Self::Streaming(req) => name::request_name(req),
+            Self::Transitions(req) => name::request_name(req),
+            Self::Ui(req) => name::request_name(req),
+            Self::VirtualCam(req) => name::request_name(req),
+        }
+    }
+}
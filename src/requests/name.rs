@@ -0,0 +1,246 @@
+//! Extraction of the request type from the typed requests, without serializing their data.
+
+use std::fmt;
+
+use serde::{
+    ser::{Impossible, SerializeStruct},
+    Serialize, Serializer,
+};
+
+/// Get the `requestType` of a request, that is serialized as adjacently tagged enum.
+///
+/// Only the tag is looked at, all other fields like the request data are skipped. All typed
+/// requests carry a tag, so the name is only empty if given anything else.
+pub(super) fn request_name<T: Serialize>(request: &T) -> &'static str {
+    request
+        .serialize(RequestSerializer)
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+}
+
+/// Generate the methods of [`Serializer`] for all the types, that are never a request or its tag.
+macro_rules! unsupported {
+    ($($method:ident($($ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method(self, $(_: $ty),*) -> Result<Self::Ok, Self::Error> {
+                Err(fmt::Error)
+            }
+        )*
+    };
+}
+
+/// Generate the methods of [`Serializer`] for all compound types, except structs.
+macro_rules! unsupported_compound {
+    () => {
+        fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<Self::Ok, Self::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            Err(fmt::Error)
+        }
+    };
+}
+
+/// Serializer for the request itself, which only accepts the struct of an adjacently tagged enum.
+struct RequestSerializer;
+
+impl Serializer for RequestSerializer {
+    type Error = fmt::Error;
+    type Ok = Option<&'static str>;
+    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = RequestFields;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+
+    unsupported! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    }
+
+    unsupported_compound!();
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(RequestFields(None))
+    }
+}
+
+/// Fields of a request, where only the tag is kept.
+struct RequestFields(Option<&'static str>);
+
+impl SerializeStruct for RequestFields {
+    type Error = fmt::Error;
+    type Ok = Option<&'static str>;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        if key == "requestType" {
+            self.0 = Some(value.serialize(TagSerializer)?);
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.0)
+    }
+}
+
+/// Serializer for the tag of a request, which is the name of the enum variant.
+struct TagSerializer;
+
+impl Serializer for TagSerializer {
+    type Error = fmt::Error;
+    type Ok = &'static str;
+    type SerializeMap = Impossible<Self::Ok, Self::Error>;
+    type SerializeSeq = Impossible<Self::Ok, Self::Error>;
+    type SerializeStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeStructVariant = Impossible<Self::Ok, Self::Error>;
+    type SerializeTuple = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleStruct = Impossible<Self::Ok, Self::Error>;
+    type SerializeTupleVariant = Impossible<Self::Ok, Self::Error>;
+
+    unsupported! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+    }
+
+    unsupported_compound!();
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(variant)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(fmt::Error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{batch::BatchRequest, general, scenes, RequestType};
+    use crate::requests::scenes::SceneId;
+
+    #[test]
+    fn names() {
+        assert_eq!(
+            "GetVersion",
+            RequestType::General(general::Request::Version).name()
+        );
+        assert_eq!(
+            "SetCurrentProgramScene",
+            RequestType::Scenes(scenes::Request::SetCurrentProgramScene {
+                scene: SceneId::Name("one"),
+            })
+            .name()
+        );
+        assert_eq!(
+            "Sleep",
+            RequestType::Raw(BatchRequest::sleep_millis(10)).name()
+        );
+    }
+}
@@ -373,6 +373,24 @@ pub struct SetBlendMode<'a> {
     pub mode: BlendMode,
 }
 
+/// Request information for [`crate::client::SceneItems::update`].
+///
+/// Only the properties that are set, are changed.
+#[derive(Default)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct ItemUpdate {
+    /// New enable state of the scene item.
+    pub enabled: Option<bool>,
+    /// New lock state of the scene item.
+    pub locked: Option<bool>,
+    /// New index position of the scene item.
+    pub index: Option<u32>,
+    /// New blend mode.
+    pub blend_mode: Option<BlendMode>,
+    /// Scene item transform info to update.
+    pub transform: Option<SceneItemTransform>,
+}
+
 /// Request information for [`crate::client::SceneItems::set_private_settings`].
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct SetPrivateSettings<'a, T> {
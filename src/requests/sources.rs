@@ -52,6 +52,228 @@ pub struct TakeScreenshot<'a> {
     pub compression_quality: Option<i32>,
 }
 
+/// Options for [`crate::client::Sources::screenshot_scene`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct ScreenshotOptions {
+    /// Width to scale each screenshot to.
+    pub width: Option<u32>,
+    /// Height to scale each screenshot to.
+    pub height: Option<u32>,
+    /// Compression quality to use. 0 for high compression, 100 for uncompressed. -1 to use
+    /// "default".
+    pub compression_quality: Option<i32>,
+}
+
+/// Image format of a screenshot, taken with a [`Screenshot`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// Portable Network Graphics, lossless.
+    Png,
+    /// JPEG, lossy and without transparency.
+    Jpeg,
+    /// WebP, lossy or lossless depending on the compression quality.
+    Webp,
+    /// Windows Bitmap, uncompressed.
+    Bmp,
+}
+
+impl ImageFormat {
+    /// Name of the format, as used by obs-websocket.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpeg",
+            Self::Webp => "webp",
+            Self::Bmp => "bmp",
+        }
+    }
+
+    /// Whether the format keeps the alpha channel of a source, so transparent areas stay
+    /// transparent in the screenshot.
+    #[must_use]
+    pub const fn supports_alpha(self) -> bool {
+        matches!(self, Self::Png | Self::Webp)
+    }
+}
+
+/// Typed and validated screenshot settings, for use with [`crate::client::Sources::screenshot`]
+/// and [`crate::client::Sources::save_screenshot_as`].
+///
+/// In contrast to [`TakeScreenshot`] and [`SaveScreenshot`], invalid settings are reported before
+/// the request is sent to obs-websocket. The image format is checked against the formats that
+/// obs-websocket reported as supported.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Screenshot<'a> {
+    pub(crate) source: SourceId<'a>,
+    pub(crate) format: ImageFormat,
+    pub(crate) width: Option<u32>,
+    pub(crate) height: Option<u32>,
+    pub(crate) compression_quality: Option<i32>,
+}
+
+impl<'a> Screenshot<'a> {
+    /// Largest width or height, that obs-websocket accepts.
+    pub const MAX_SIZE: u32 = 4096;
+    /// Smallest width or height, that obs-websocket accepts.
+    pub const MIN_SIZE: u32 = 8;
+
+    /// Create new settings for a screenshot of the source in the given format, in full
+    /// resolution and with the default compression quality.
+    #[must_use]
+    pub fn new(source: SourceId<'a>, format: ImageFormat) -> Self {
+        Self {
+            source,
+            format,
+            width: None,
+            height: None,
+            compression_quality: None,
+        }
+    }
+
+    /// Create new settings for a screenshot of the source, that preserves transparency. This uses
+    /// the lossless PNG format, in full resolution.
+    #[must_use]
+    pub fn transparent(source: SourceId<'a>) -> Self {
+        Self::new(source, ImageFormat::Png)
+    }
+
+    /// Scale the screenshot to the given width, clamped to the range of
+    /// [`MIN_SIZE`](Self::MIN_SIZE) to [`MAX_SIZE`](Self::MAX_SIZE).
+    #[must_use]
+    pub fn width(mut self, width: u32) -> Self {
+        self.width = Some(width.clamp(Self::MIN_SIZE, Self::MAX_SIZE));
+        self
+    }
+
+    /// Scale the screenshot to the given height, clamped to the range of
+    /// [`MIN_SIZE`](Self::MIN_SIZE) to [`MAX_SIZE`](Self::MAX_SIZE).
+    #[must_use]
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = Some(height.clamp(Self::MIN_SIZE, Self::MAX_SIZE));
+        self
+    }
+
+    /// Compression quality to use, from 0 for high compression to 100 for uncompressed. -1 uses
+    /// the default of the image format. Other values are rejected, once the screenshot is taken.
+    #[must_use]
+    pub fn compression_quality(mut self, quality: i32) -> Self {
+        self.compression_quality = Some(quality);
+        self
+    }
+
+    /// Check the settings, against the image formats supported by obs-websocket.
+    pub(crate) fn validate(&self, supported_formats: &[String]) -> crate::error::Result<()> {
+        use crate::error::Error;
+
+        let format = self.format.as_str();
+        if !supported_formats.is_empty() && !supported_formats.iter().any(|f| f == format) {
+            return Err(Error::UnsupportedImageFormat(format));
+        }
+
+        match self.compression_quality {
+            Some(quality) if !(-1..=100).contains(&quality) => {
+                Err(Error::InvalidCompressionQuality(quality))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) fn take(self) -> TakeScreenshot<'a> {
+        TakeScreenshot {
+            source: self.source,
+            format: self.format.as_str(),
+            width: self.width,
+            height: self.height,
+            compression_quality: self.compression_quality,
+        }
+    }
+
+    pub(crate) fn save(self, file_path: &'a Path) -> SaveScreenshot<'a> {
+        SaveScreenshot {
+            source: self.source,
+            format: self.format.as_str(),
+            width: self.width,
+            height: self.height,
+            compression_quality: self.compression_quality,
+            file_path,
+        }
+    }
+}
+
+/// Background to put behind transparent areas of a screenshot, when using
+/// [`crate::client::Sources::screenshot_composited`].
+#[cfg(feature = "image")]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Background {
+    /// Single solid RGB color.
+    Solid([u8; 3]),
+    /// Checkerboard pattern of two alternating RGB colors, like image editors use to show
+    /// transparency.
+    Checkerboard {
+        /// Width and height of a single square in pixels.
+        size: u32,
+        /// Color of the square in the top left corner.
+        light: [u8; 3],
+        /// Color of the squares next to the light ones.
+        dark: [u8; 3],
+    },
+}
+
+#[cfg(feature = "image")]
+impl Default for Background {
+    fn default() -> Self {
+        Self::Checkerboard {
+            size: 8,
+            light: [204, 204, 204],
+            dark: [153, 153, 153],
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl Background {
+    /// Color of the background at the given pixel.
+    fn color(self, x: u32, y: u32) -> [u8; 3] {
+        match self {
+            Self::Solid(color) => color,
+            Self::Checkerboard { size, light, dark } => {
+                let size = size.max(1);
+                if (x / size + y / size) % 2 == 0 {
+                    light
+                } else {
+                    dark
+                }
+            }
+        }
+    }
+
+    /// Blend the image on top of the background, removing its alpha channel.
+    pub(crate) fn composite(self, image: &image::RgbaImage) -> image::RgbImage {
+        image::RgbImage::from_fn(image.width(), image.height(), |x, y| {
+            let image::Rgba([r, g, b, a]) = *image.get_pixel(x, y);
+            let background = self.color(x, y);
+            let blend = |fg: u8, bg: u8| {
+                let value =
+                    (u16::from(fg) * u16::from(a) + u16::from(bg) * (255 - u16::from(a)) + 127)
+                        / 255;
+                #[allow(clippy::cast_possible_truncation)]
+                let value = value as u8;
+                value
+            };
+
+            image::Rgb([
+                blend(r, background[0]),
+                blend(g, background[1]),
+                blend(b, background[2]),
+            ])
+        })
+    }
+}
+
 /// Request information for [`crate::client::Sources::save_screenshot`].
 #[skip_serializing_none]
 #[derive(Serialize)]
@@ -78,3 +300,68 @@ pub struct SaveScreenshot<'a> {
     #[serde(rename = "imageFilePath")]
     pub file_path: &'a Path,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn screenshot() {
+        let settings = Screenshot::new(SourceId::Name("text"), ImageFormat::Webp)
+            .width(10_000)
+            .height(2);
+        assert_eq!(Some(Screenshot::MAX_SIZE), settings.width);
+        assert_eq!(Some(Screenshot::MIN_SIZE), settings.height);
+
+        let supported = ["png".to_owned(), "jpeg".to_owned()];
+        assert!(matches!(
+            settings.validate(&supported),
+            Err(Error::UnsupportedImageFormat("webp"))
+        ));
+        assert!(settings.validate(&[]).is_ok());
+
+        let settings = Screenshot::new(SourceId::Name("text"), ImageFormat::Png);
+        assert!(settings
+            .compression_quality(-1)
+            .validate(&supported)
+            .is_ok());
+        assert!(matches!(
+            settings.compression_quality(101).validate(&supported),
+            Err(Error::InvalidCompressionQuality(101))
+        ));
+
+        assert!(Screenshot::transparent(SourceId::Name("text"))
+            .format
+            .supports_alpha());
+        assert!(!ImageFormat::Jpeg.supports_alpha());
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn composite() {
+        let image = image::RgbaImage::from_fn(4, 1, |x, _| match x {
+            0 => image::Rgba([255, 0, 0, 255]),
+            1 => image::Rgba([255, 0, 0, 0]),
+            2 => image::Rgba([255, 255, 255, 128]),
+            _ => image::Rgba([0, 0, 0, 0]),
+        });
+
+        let solid = Background::Solid([0, 0, 255]).composite(&image);
+        assert_eq!(
+            vec![255, 0, 0, 0, 0, 255, 128, 128, 255, 0, 0, 255],
+            solid.into_raw()
+        );
+
+        let checkerboard = Background::Checkerboard {
+            size: 2,
+            light: [10, 10, 10],
+            dark: [20, 20, 20],
+        }
+        .composite(&image);
+        assert_eq!(
+            vec![255, 0, 0, 10, 10, 10, 138, 138, 138, 20, 20, 20],
+            checkerboard.into_raw()
+        );
+    }
+}
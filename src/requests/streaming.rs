@@ -2,6 +2,10 @@
 
 use serde::Serialize;
 
+/// Maximum number of characters in a single caption, as CEA-608 captions show at most 4 rows of
+/// 32 characters each.
+pub const MAX_CAPTION_LENGTH: usize = 128;
+
 #[derive(Serialize)]
 #[serde(tag = "requestType", content = "requestData")]
 pub(crate) enum Request<'a> {
@@ -26,3 +30,22 @@ impl<'a> From<Request<'a>> for super::RequestType<'a> {
         super::RequestType::Streaming(value)
     }
 }
+
+/// Request information for [`crate::client::Streaming::start_checked`].
+///
+/// By default, only the general checks are done, that the stream isn't running already and the
+/// stream service is configured. Further checks can be enabled by setting the fields.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct StartChecked<'a> {
+    /// Name of the profile, that must be active.
+    pub profile: Option<&'a str>,
+    /// Name of the scene collection, that must be active.
+    pub scene_collection: Option<&'a str>,
+    /// Whether the virtual camera must be turned off.
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub virtual_cam_off: bool,
+    /// Whether no recording must be in progress.
+    #[cfg_attr(feature = "builder", builder(default))]
+    pub not_recording: bool,
+}
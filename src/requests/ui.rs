@@ -6,6 +6,7 @@ use bitflags::bitflags;
 use serde::Serialize;
 
 use super::{inputs::InputId, sources::SourceId};
+use crate::responses::ui::Monitor;
 
 #[derive(Serialize)]
 #[serde(tag = "requestType", content = "requestData")]
@@ -169,6 +170,13 @@ impl QtGeometry {
         }
     }
 
+    /// Start computing a geometry that is placed on the given monitor, as returned from
+    /// [`crate::client::Ui::list_monitors`].
+    #[must_use]
+    pub fn on_monitor(monitor: &Monitor) -> MonitorGeometry<'_> {
+        MonitorGeometry { monitor }
+    }
+
     /// Serialize this instance into a `base64` encoded byte array.
     ///
     /// The exact format can be found in the
@@ -242,6 +250,88 @@ impl Display for QtGeometry {
     }
 }
 
+/// Helper to compute a [`QtGeometry`] relative to a single monitor, created with
+/// [`QtGeometry::on_monitor`].
+///
+/// All positions are calculated from the monitor's position and size, and the screen number is
+/// set to the monitor's index.
+#[derive(Clone, Copy, Debug)]
+pub struct MonitorGeometry<'a> {
+    monitor: &'a Monitor,
+}
+
+impl MonitorGeometry<'_> {
+    /// Cover the left half of the monitor.
+    #[must_use]
+    pub fn left_half(self) -> QtGeometry {
+        let width = i32::from(self.monitor.size.width);
+        self.geometry(0, 0, width / 2, i32::from(self.monitor.size.height))
+    }
+
+    /// Cover the right half of the monitor. For odd widths, the right half is one pixel wider
+    /// than the left half.
+    #[must_use]
+    pub fn right_half(self) -> QtGeometry {
+        let width = i32::from(self.monitor.size.width);
+        self.geometry(
+            width / 2,
+            0,
+            width - width / 2,
+            i32::from(self.monitor.size.height),
+        )
+    }
+
+    /// Place a window of the given size in the center of the monitor. The size is limited to the
+    /// monitor's size.
+    #[must_use]
+    pub fn centered(self, width: u16, height: u16) -> QtGeometry {
+        let width = width.min(self.monitor.size.width);
+        let height = height.min(self.monitor.size.height);
+
+        self.geometry(
+            i32::from(self.monitor.size.width - width) / 2,
+            i32::from(self.monitor.size.height - height) / 2,
+            i32::from(width),
+            i32::from(height),
+        )
+    }
+
+    /// Cover the whole monitor in full-screen mode.
+    #[must_use]
+    pub fn fullscreen(self) -> QtGeometry {
+        QtGeometry {
+            window_state: QtWindowState::FULLSCREEN,
+            ..self.geometry(
+                0,
+                0,
+                i32::from(self.monitor.size.width),
+                i32::from(self.monitor.size.height),
+            )
+        }
+    }
+
+    /// Create the geometry for a rectangle, positioned relative to the monitor's top left corner.
+    ///
+    /// Qt rectangles are inclusive, so the right and bottom sides are the last pixel that is still
+    /// part of the rectangle.
+    fn geometry(self, x: i32, y: i32, width: i32, height: i32) -> QtGeometry {
+        let left = i32::from(self.monitor.position.x) + x;
+        let top = i32::from(self.monitor.position.y) + y;
+
+        QtGeometry {
+            screen_number: i32::try_from(self.monitor.index).unwrap_or(QtGeometry::DEFAULT_SCREEN),
+            window_state: QtWindowState::default(),
+            screen_width: i32::from(self.monitor.size.width),
+            rect: QtRect {
+                left,
+                top,
+                right: left + width.max(1) - 1,
+                bottom: top + height.max(1) - 1,
+            },
+        }
+    }
+}
+
 /// Request information for [`crate::client::Ui::open_video_mix_projector`] and
 /// [`crate::client::Ui::open_source_projector`] as part of [`QtGeometry`].
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -284,7 +374,7 @@ impl QtWindowState {
 /// │
 /// Y
 /// ```
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "builder", derive(bon::Builder))]
 pub struct QtRect {
     /// Left or X/horizontal position of the rectangle.
@@ -298,3 +388,52 @@ pub struct QtRect {
     /// `bottom = 300` the height would be `200`.
     pub bottom: i32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::responses::ui::{MonitorPosition, MonitorSize};
+
+    fn monitor() -> Monitor {
+        Monitor {
+            name: "second".to_owned(),
+            index: 1,
+            size: MonitorSize {
+                width: 1921,
+                height: 1080,
+            },
+            position: MonitorPosition { x: 1920, y: 0 },
+        }
+    }
+
+    fn rect(left: i32, top: i32, right: i32, bottom: i32) -> QtRect {
+        QtRect {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    #[test]
+    fn monitor_geometry() {
+        let monitor = monitor();
+        let geometry = QtGeometry::on_monitor(&monitor);
+
+        let left = geometry.left_half();
+        assert_eq!(1, left.screen_number);
+        assert_eq!(1921, left.screen_width);
+        assert_eq!(rect(1920, 0, 2879, 1079), left.rect);
+
+        assert_eq!(rect(2880, 0, 3840, 1079), geometry.right_half().rect);
+        assert_eq!(rect(2480, 240, 3279, 839), geometry.centered(800, 600).rect);
+        assert_eq!(
+            rect(1920, 0, 3840, 1079),
+            geometry.centered(4000, 4000).rect
+        );
+
+        let fullscreen = geometry.fullscreen();
+        assert_eq!(QtWindowState::FULLSCREEN, fullscreen.window_state);
+        assert_eq!(rect(1920, 0, 3840, 1079), fullscreen.rect);
+    }
+}
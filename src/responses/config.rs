@@ -2,6 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    common::{ColorFormat, ColorRange, ColorSpace},
+    requests::config::Fps,
+};
+
 /// Response value for [`crate::client::Config::video_settings`].
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct VideoSettings {
@@ -25,6 +30,27 @@ pub struct VideoSettings {
     pub output_height: u32,
 }
 
+impl VideoSettings {
+    /// Frame rate of the video output, or [`None`] if OBS reported an invalid fraction.
+    #[must_use]
+    pub fn fps(&self) -> Option<Fps> {
+        Fps::new(self.fps_numerator, self.fps_denominator)
+    }
+}
+
+/// Response value for [`crate::client::Config::video_color_settings`].
+///
+/// Each value is [`None`] if it isn't set in the current profile.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VideoColorSettings {
+    /// Color format of the video output.
+    pub format: Option<ColorFormat>,
+    /// Color space of the video output.
+    pub space: Option<ColorSpace>,
+    /// Color range of the video output.
+    pub range: Option<ColorRange>,
+}
+
 /// Response value for [`crate::client::Config::stream_service_settings`].
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct StreamServiceSettings<T> {
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::requests::filters::kind::FilterKind;
+
 /// Response value for [`crate::client::Filters::list_kinds`].
 #[derive(Debug, Deserialize)]
 pub(crate) struct FilterKinds {
@@ -38,6 +40,14 @@ pub struct SourceFilter {
     pub settings: serde_json::Value,
 }
 
+impl SourceFilter {
+    /// The kind of filter, parsed into a typed [`FilterKind`].
+    #[must_use]
+    pub fn typed_kind(&self) -> FilterKind {
+        self.kind.as_str().into()
+    }
+}
+
 /// Response value for [`crate::client::Filters::default_settings`].
 #[derive(Debug, Deserialize)]
 pub(crate) struct DefaultFilterSettings<T> {
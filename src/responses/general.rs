@@ -1,6 +1,9 @@
 //! General responses, not fitting into any category.
 
 use serde::{Deserialize, Serialize};
+use time::Duration;
+
+use crate::common::Bytes;
 
 /// Response value for [`crate::client::General::version`].
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -29,24 +32,54 @@ pub struct Version {
     pub platform_description: String,
 }
 
+/// Response value for [`crate::client::Client::coverage_report`].
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CoverageReport {
+    /// Requests, that are available in obs-websocket but not implemented by this crate.
+    pub missing_in_obws: Vec<String>,
+    /// Requests, that are implemented by this crate but not available in obs-websocket. Using
+    /// them results in an error response.
+    pub missing_in_server: Vec<&'static str>,
+}
+
+/// Response value for [`crate::client::Client::health`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Health {
+    /// Status of the stream output.
+    pub stream: super::streaming::StreamStatus,
+    /// Status of the record output.
+    pub record: super::recording::RecordStatus,
+    /// Whether the virtual camera is running.
+    pub virtual_cam: bool,
+    /// Whether the replay buffer is running, or [`None`] if it's not available.
+    pub replay_buffer: Option<bool>,
+    /// Identifier of the current program scene.
+    pub program_scene: super::scenes::SceneId,
+    /// General statistics about OBS and the current session.
+    pub stats: Stats,
+}
+
 /// Response value for [`crate::client::General::stats`].
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
 pub struct Stats {
     /// Current CPU usage in percent.
     #[serde(rename = "cpuUsage")]
     pub cpu_usage: f64,
-    /// Amount of memory in MB currently being used by OBS.
-    #[serde(rename = "memoryUsage")]
-    pub memory_usage: f64,
+    /// Amount of memory currently being used by OBS.
+    #[serde(rename = "memoryUsage", with = "crate::serde::mebibytes")]
+    pub memory_usage: Bytes,
     /// Available disk space on the device being used for recording storage.
-    #[serde(rename = "availableDiskSpace")]
-    pub available_disk_space: f64,
+    #[serde(rename = "availableDiskSpace", with = "crate::serde::mebibytes")]
+    pub available_disk_space: Bytes,
     /// Current FPS being rendered.
     #[serde(rename = "activeFps")]
     pub active_fps: f64,
-    /// Average time in milliseconds that OBS is taking to render a frame.
-    #[serde(rename = "averageFrameRenderTime")]
-    pub average_frame_render_time: f64,
+    /// Average time that OBS is taking to render a frame.
+    #[serde(
+        rename = "averageFrameRenderTime",
+        with = "crate::serde::duration_millis_f64"
+    )]
+    pub average_frame_render_time: Duration,
     /// Number of frames skipped by OBS in the render thread.
     #[serde(rename = "renderSkippedFrames")]
     pub render_skipped_frames: u32,
@@ -67,6 +100,41 @@ pub struct Stats {
     pub web_socket_session_outgoing_messages: u64,
 }
 
+impl Stats {
+    /// Highest frame rate, that the render thread could sustain with the current average frame
+    /// render time. This is independent of the configured frame rate, which limits the
+    /// [`Self::active_fps`], and tells how much headroom is left before frames get skipped.
+    ///
+    /// Returns [`None`] if no render time was reported yet.
+    #[must_use]
+    pub fn fps(&self) -> Option<f64> {
+        let seconds = self.average_frame_render_time.as_seconds_f64();
+        (seconds > 0.0).then(|| seconds.recip())
+    }
+
+    /// Ratio of frames skipped by the render thread, in the range of `0.0` to `1.0`. Returns
+    /// `0.0` if no frames were rendered yet.
+    #[must_use]
+    pub fn render_missed_ratio(&self) -> f64 {
+        missed_ratio(self.render_skipped_frames, self.render_total_frames)
+    }
+
+    /// Ratio of frames skipped by the output thread, in the range of `0.0` to `1.0`. Returns
+    /// `0.0` if no frames were output yet.
+    #[must_use]
+    pub fn output_missed_ratio(&self) -> f64 {
+        missed_ratio(self.output_skipped_frames, self.output_total_frames)
+    }
+}
+
+fn missed_ratio(skipped: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        f64::from(skipped) / f64::from(total)
+    }
+}
+
 /// Response value for [`crate::client::General::call_vendor_request`].
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct VendorResponse<T> {
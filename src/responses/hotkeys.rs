@@ -9,3 +9,264 @@ pub(crate) struct Hotkeys {
     #[serde(rename = "hotkeys")]
     pub hotkeys: Vec<String>,
 }
+
+/// Response value for [`crate::client::Hotkeys::list_detailed`].
+///
+/// obs-websocket only reports the names of hotkeys. This information is derived from the name, by
+/// comparing it against the hotkeys that are known to be registered by OBS itself.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HotkeyInfo {
+    /// Name of the hotkey, as used with [`crate::client::Hotkeys::trigger_by_name`].
+    pub name: String,
+    /// Category the hotkey belongs to.
+    pub category: HotkeyCategory,
+    /// Human-readable description of the hotkey's action, if it is a known hotkey.
+    pub description: Option<&'static str>,
+    /// Suffix of a source-specific hotkey, that identifies the source or scene item it applies to.
+    pub source: Option<String>,
+}
+
+/// Categories of hotkeys, as used to group them in [`HotkeyInfo`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum HotkeyCategory {
+    /// Starting and stopping the stream.
+    Streaming,
+    /// Starting, stopping and pausing the recording.
+    Recording,
+    /// Controlling and saving the replay buffer.
+    ReplayBuffer,
+    /// Starting and stopping the virtual camera.
+    VirtualCam,
+    /// Studio mode and transitions between scenes.
+    Transition,
+    /// Switching to a scene.
+    Scene,
+    /// Actions that apply to a single source, like muting.
+    Source,
+    /// Other actions of the OBS frontend, like taking screenshots.
+    General,
+    /// Hotkeys unknown to this crate, usually registered by plugins.
+    Other,
+}
+
+/// Hotkeys registered by the OBS frontend, with their category and description.
+const FRONTEND_HOTKEYS: &[(&str, HotkeyCategory, &str)] = &[
+    (
+        "OBSBasic.StartStreaming",
+        HotkeyCategory::Streaming,
+        "Start streaming",
+    ),
+    (
+        "OBSBasic.StopStreaming",
+        HotkeyCategory::Streaming,
+        "Stop streaming",
+    ),
+    (
+        "OBSBasic.ForceStopStreaming",
+        HotkeyCategory::Streaming,
+        "Stop streaming (discard delay)",
+    ),
+    (
+        "OBSBasic.StartRecording",
+        HotkeyCategory::Recording,
+        "Start recording",
+    ),
+    (
+        "OBSBasic.StopRecording",
+        HotkeyCategory::Recording,
+        "Stop recording",
+    ),
+    (
+        "OBSBasic.PauseRecording",
+        HotkeyCategory::Recording,
+        "Pause recording",
+    ),
+    (
+        "OBSBasic.UnpauseRecording",
+        HotkeyCategory::Recording,
+        "Unpause recording",
+    ),
+    (
+        "OBSBasic.SplitFile",
+        HotkeyCategory::Recording,
+        "Split recording file",
+    ),
+    (
+        "OBSBasic.StartReplayBuffer",
+        HotkeyCategory::ReplayBuffer,
+        "Start replay buffer",
+    ),
+    (
+        "OBSBasic.StopReplayBuffer",
+        HotkeyCategory::ReplayBuffer,
+        "Stop replay buffer",
+    ),
+    (
+        "ReplayBuffer.Save",
+        HotkeyCategory::ReplayBuffer,
+        "Save replay",
+    ),
+    (
+        "OBSBasic.StartVirtualCam",
+        HotkeyCategory::VirtualCam,
+        "Start virtual camera",
+    ),
+    (
+        "OBSBasic.StopVirtualCam",
+        HotkeyCategory::VirtualCam,
+        "Stop virtual camera",
+    ),
+    (
+        "OBSBasic.EnablePreviewProgram",
+        HotkeyCategory::Transition,
+        "Enable studio mode",
+    ),
+    (
+        "OBSBasic.DisablePreviewProgram",
+        HotkeyCategory::Transition,
+        "Disable studio mode",
+    ),
+    (
+        "OBSBasic.Transition",
+        HotkeyCategory::Transition,
+        "Transition",
+    ),
+    (
+        "OBSBasic.SelectScene",
+        HotkeyCategory::Scene,
+        "Switch to scene",
+    ),
+    (
+        "OBSBasic.EnablePreview",
+        HotkeyCategory::General,
+        "Enable preview",
+    ),
+    (
+        "OBSBasic.DisablePreview",
+        HotkeyCategory::General,
+        "Disable preview",
+    ),
+    (
+        "OBSBasic.ShowContextBar",
+        HotkeyCategory::General,
+        "Show source toolbar",
+    ),
+    (
+        "OBSBasic.HideContextBar",
+        HotkeyCategory::General,
+        "Hide source toolbar",
+    ),
+    (
+        "OBSBasic.ResetStats",
+        HotkeyCategory::General,
+        "Reset stats",
+    ),
+    (
+        "OBSBasic.Screenshot",
+        HotkeyCategory::General,
+        "Screenshot output",
+    ),
+    (
+        "OBSBasic.SelectedSourceScreenshot",
+        HotkeyCategory::General,
+        "Screenshot selected source",
+    ),
+];
+
+/// Hotkeys registered for individual sources, with their description. They may be followed by a
+/// suffix, that identifies the source or scene item.
+const SOURCE_HOTKEYS: &[(&str, &str)] = &[
+    ("libobs.mute", "Mute"),
+    ("libobs.unmute", "Unmute"),
+    ("libobs.push-to-mute", "Push-to-mute"),
+    ("libobs.push-to-talk", "Push-to-talk"),
+    ("libobs.show_scene_item", "Show scene item"),
+    ("libobs.hide_scene_item", "Hide scene item"),
+    ("MediaSource.Restart", "Restart media"),
+    ("MediaSource.Play", "Play media"),
+    ("MediaSource.Pause", "Pause media"),
+    ("MediaSource.Stop", "Stop media"),
+    ("MediaSource.Next", "Next media"),
+    ("MediaSource.Previous", "Previous media"),
+    ("ObsBrowser.Refresh", "Refresh browser page"),
+];
+
+impl From<String> for HotkeyInfo {
+    fn from(name: String) -> Self {
+        if let Some(&(_, category, description)) =
+            FRONTEND_HOTKEYS.iter().find(|(known, ..)| *known == name)
+        {
+            return Self {
+                name,
+                category,
+                description: Some(description),
+                source: None,
+            };
+        }
+
+        if name.starts_with("OBSBasic.QuickTransition.") {
+            return Self {
+                name,
+                category: HotkeyCategory::Transition,
+                description: Some("Quick transition"),
+                source: None,
+            };
+        }
+
+        for &(known, description) in SOURCE_HOTKEYS {
+            let Some(rest) = name.strip_prefix(known) else {
+                continue;
+            };
+
+            let source = match rest.strip_prefix('.') {
+                Some(suffix) if !suffix.is_empty() => Some(suffix.to_owned()),
+                _ if rest.is_empty() => None,
+                _ => continue,
+            };
+
+            return Self {
+                name,
+                category: HotkeyCategory::Source,
+                description: Some(description),
+                source,
+            };
+        }
+
+        Self {
+            name,
+            category: HotkeyCategory::Other,
+            description: None,
+            source: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn categorize() {
+        let info = HotkeyInfo::from("OBSBasic.StartRecording".to_owned());
+        assert_eq!(HotkeyCategory::Recording, info.category);
+        assert_eq!(Some("Start recording"), info.description);
+        assert_eq!(None, info.source);
+
+        let info = HotkeyInfo::from("OBSBasic.QuickTransition.2".to_owned());
+        assert_eq!(HotkeyCategory::Transition, info.category);
+
+        let info = HotkeyInfo::from("libobs.mute".to_owned());
+        assert_eq!(HotkeyCategory::Source, info.category);
+        assert_eq!(None, info.source);
+
+        let info = HotkeyInfo::from("libobs.show_scene_item.Camera".to_owned());
+        assert_eq!(HotkeyCategory::Source, info.category);
+        assert_eq!(Some("Show scene item"), info.description);
+        assert_eq!(Some("Camera"), info.source.as_deref());
+
+        let info = HotkeyInfo::from("libobs.muted".to_owned());
+        assert_eq!(HotkeyCategory::Other, info.category);
+        assert_eq!(None, info.description);
+    }
+}
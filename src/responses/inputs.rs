@@ -5,14 +5,14 @@ use time::Duration;
 use uuid::Uuid;
 
 pub use super::ids::InputId;
-use crate::common::MonitorType;
+use crate::common::{Extra, MonitorType};
 
 /// Response value for [`crate::client::Inputs::list`].
 #[derive(Debug, Deserialize)]
-pub(crate) struct Inputs {
+pub(crate) struct Inputs<T = Vec<Input>> {
     /// Array of inputs.
     #[serde(rename = "inputs")]
-    pub inputs: Vec<Input>,
+    pub inputs: T,
 }
 
 /// Response value for [`crate::client::Inputs::list`].
@@ -27,6 +27,29 @@ pub struct Input {
     /// Kind of input, without the version part.
     #[serde(rename = "unversionedInputKind")]
     pub unversioned_kind: String,
+    /// Any other fields, not known to this crate yet. Only filled with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(feature = "preserve-unknown", serde(flatten))]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: Extra,
+}
+
+impl From<Input> for crate::requests::inputs::InputId<'_> {
+    fn from(value: Input) -> Self {
+        value.id.into()
+    }
+}
+
+impl From<&Input> for crate::requests::inputs::InputId<'_> {
+    fn from(value: &Input) -> Self {
+        (&value.id).into()
+    }
+}
+
+impl From<&Input> for crate::requests::sources::SourceId<'_> {
+    fn from(value: &Input) -> Self {
+        crate::requests::inputs::InputId::from(value).into()
+    }
 }
 
 /// Response value for [`crate::client::Inputs::list_kinds`].
@@ -37,6 +60,16 @@ pub(crate) struct InputKinds {
     pub input_kinds: Vec<String>,
 }
 
+/// Response value for [`crate::client::Inputs::list_kinds_detailed`].
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InputKindInfo {
+    /// Input kind without the version suffix, like `text_gdiplus`.
+    pub unversioned: String,
+    /// Concrete input kind as present in OBS, like `text_gdiplus_v2`. It's the same as the
+    /// unversioned kind, if the kind doesn't have any versions.
+    pub versioned: String,
+}
+
 /// Response value for [`crate::client::Inputs::specials`].
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct SpecialInputs {
@@ -128,21 +161,22 @@ pub(crate) struct AudioMonitorType {
 #[derive(Debug, Deserialize)]
 pub(crate) struct AudioTracks {
     /// Object of audio tracks and associated enable states.
-    #[serde(rename = "inputAudioTracks", with = "crate::serde::audio_tracks")]
-    pub audio_tracks: [bool; 6],
+    #[serde(rename = "inputAudioTracks")]
+    pub audio_tracks: crate::common::AudioTracks,
 }
 
 /// Response value for [`crate::client::Inputs::properties_list_property_items`].
 #[derive(Debug, Deserialize)]
-pub(crate) struct ListPropertyItems {
+pub(crate) struct ListPropertyItems<T = serde_json::Value> {
     /// Array of items in the list property.
     #[serde(rename = "propertyItems")]
-    pub property_items: Vec<ListPropertyItem>,
+    pub property_items: Vec<ListPropertyItem<T>>,
 }
 
-/// Response value for [`crate::client::Inputs::properties_list_property_items`].
+/// Response value for [`crate::client::Inputs::properties_list_property_items`] and
+/// [`crate::client::Inputs::properties_list_enum`].
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
-pub struct ListPropertyItem {
+pub struct ListPropertyItem<T = serde_json::Value> {
     /// Name of the item.
     #[serde(rename = "itemName")]
     pub name: String,
@@ -151,7 +185,84 @@ pub struct ListPropertyItem {
     pub enabled: bool,
     /// Content of the item, depending on what it represents.
     #[serde(rename = "itemValue")]
-    pub value: serde_json::Value,
+    pub value: T,
+}
+
+impl ListPropertyItem {
+    /// Content of the item as [`PropertyValue`], or [`None`] if it's not one of the supported
+    /// value types.
+    #[must_use]
+    pub fn typed_value(&self) -> Option<PropertyValue> {
+        PropertyValue::from_json(&self.value)
+    }
+}
+
+/// Content of a [`ListPropertyItem`]. List properties in OBS always hold one of these types.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum PropertyValue {
+    /// Boolean value.
+    Bool(bool),
+    /// Integer value, like the index of a display.
+    Int(i64),
+    /// Floating point value.
+    Float(f64),
+    /// String value, like the identifier of a device.
+    String(String),
+}
+
+impl PropertyValue {
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        use serde_json::Value;
+
+        Some(match value {
+            Value::Bool(value) => Self::Bool(*value),
+            Value::Number(value) => match value.as_i64() {
+                Some(value) => Self::Int(value),
+                None => Self::Float(value.as_f64()?),
+            },
+            Value::String(value) => Self::String(value.clone()),
+            Value::Null | Value::Array(_) | Value::Object(_) => return None,
+        })
+    }
+
+    /// The value if it's a string.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value if it's an integer.
+    #[must_use]
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Self::Int(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value if it's a number, converting integers to floating point numbers.
+    #[must_use]
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            #[allow(clippy::cast_precision_loss)]
+            Self::Int(value) => Some(value as f64),
+            Self::Float(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value if it's a boolean.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Self::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
 }
 
 /// Response value for [`crate::client::Inputs::create`].
@@ -164,3 +275,27 @@ pub struct SceneItemId {
     #[serde(rename = "sceneItemId")]
     pub scene_item_id: i64,
 }
+
+#[cfg(all(test, feature = "preserve-unknown"))]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn preserve_unknown() {
+        let value = json!({
+            "inputName": "Camera",
+            "inputUuid": "2f0ee6a4-a30f-4e7e-8d6d-79bb1a8fb6b1",
+            "inputKind": "v4l2_input",
+            "unversionedInputKind": "v4l2_input",
+            "inputFlags": 7,
+        });
+
+        let input = serde_json::from_value::<Input>(value.clone()).unwrap();
+        assert_eq!("Camera", input.id.name);
+        assert_eq!(Some(&json!(7)), input.extra.get("inputFlags"));
+        assert_eq!(1, input.extra.len());
+        assert_eq!(value, serde_json::to_value(&input).unwrap());
+    }
+}
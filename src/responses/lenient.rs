@@ -0,0 +1,88 @@
+//! Lenient deserialization of lists, that skips invalid elements instead of failing as a whole.
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
+
+/// List of items from a response, where elements that failed to deserialize are skipped instead
+/// of failing the whole request.
+///
+/// This helps to keep working, when OBS reports an unusual element that isn't understood by this
+/// crate, like an exotic input kind from a third-party plugin.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LenientList<T> {
+    /// All elements that were deserialized successfully, in their original order.
+    pub items: Vec<T>,
+    /// All elements that failed to deserialize.
+    pub skipped: Vec<SkippedItem>,
+}
+
+/// Single element of a [`LenientList`], that failed to deserialize.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SkippedItem {
+    /// Position of the element in the original list.
+    pub index: usize,
+    /// The raw value of the element.
+    pub value: serde_json::Value,
+    /// Description of the deserialization error.
+    pub error: String,
+}
+
+impl<'de, T> Deserialize<'de> for LenientList<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let values = Vec::<serde_json::Value>::deserialize(deserializer)?;
+        let mut list = Self {
+            items: Vec::with_capacity(values.len()),
+            skipped: Vec::new(),
+        };
+
+        for (index, value) in values.into_iter().enumerate() {
+            match T::deserialize(&value) {
+                Ok(item) => list.items.push(item),
+                Err(e) => list.skipped.push(SkippedItem {
+                    index,
+                    value,
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        Ok(list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        id: u32,
+    }
+
+    #[test]
+    fn skip_invalid() {
+        let list = serde_json::from_value::<LenientList<Item>>(json!([
+            {"id": 1},
+            {"id": "two"},
+            {"id": 3},
+        ]))
+        .unwrap();
+
+        assert_eq!(vec![Item { id: 1 }, Item { id: 3 }], list.items);
+        assert_eq!(1, list.skipped.len());
+        assert_eq!(1, list.skipped[0].index);
+        assert_eq!(json!({"id": "two"}), list.skipped[0].value);
+    }
+
+    #[test]
+    fn reject_non_list() {
+        assert!(serde_json::from_value::<LenientList<Item>>(json!({"id": 1})).is_err());
+    }
+}
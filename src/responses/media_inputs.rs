@@ -21,9 +21,7 @@ pub struct MediaStatus {
 }
 
 /// Response value for [`crate::client::MediaInputs::status`] as part of [`MediaStatus`].
-#[derive(
-    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
-)]
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[non_exhaustive]
 pub enum MediaState {
     /// No state.
@@ -51,7 +49,7 @@ pub enum MediaState {
     /// Error occurred while trying to play the media.
     #[serde(rename = "OBS_MEDIA_STATE_ERROR")]
     Error,
-    /// Unknown state.
-    #[serde(other)]
-    Unknown,
+    /// Any other value, not known to this crate yet, with the original string.
+    #[serde(untagged)]
+    Other(String),
 }
@@ -3,9 +3,10 @@
 pub mod config;
 pub mod filters;
 pub mod general;
-pub(crate) mod hotkeys;
+pub mod hotkeys;
 pub(crate) mod ids;
 pub mod inputs;
+pub mod lenient;
 pub mod media_inputs;
 pub mod outputs;
 pub mod profiles;
@@ -21,6 +22,7 @@ pub mod ui;
 pub(crate) mod virtual_cam;
 
 use serde::{de, Deserialize, Deserializer};
+use serde_json::value::RawValue;
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
 #[derive(Debug)]
@@ -40,7 +42,6 @@ pub(crate) enum ServerMessage {
     /// `obs-websocket` is responding to a request coming from a client.
     RequestResponse(RequestResponse),
     /// `obs-websocket` is responding to a request batch coming from the client.
-    #[allow(dead_code)]
     RequestBatchResponse(RequestBatchResponse),
 }
 
@@ -54,7 +55,7 @@ impl<'de> Deserialize<'de> for ServerMessage {
             #[serde(rename = "op")]
             op_code: OpCode,
             #[serde(rename = "d")]
-            data: serde_json::Value,
+            data: Box<RawValue>,
         }
 
         #[derive(Deserialize_repr)]
@@ -78,17 +79,17 @@ impl<'de> Deserialize<'de> for ServerMessage {
         let raw = RawServerMessage::deserialize(deserializer)?;
 
         Ok(match raw.op_code {
-            OpCode::Hello => {
-                ServerMessage::Hello(serde_json::from_value(raw.data).map_err(de::Error::custom)?)
-            }
+            OpCode::Hello => ServerMessage::Hello(
+                serde_json::from_str(raw.data.get()).map_err(de::Error::custom)?,
+            ),
             OpCode::Identified => ServerMessage::Identified(
-                serde_json::from_value(raw.data).map_err(de::Error::custom)?,
+                serde_json::from_str(raw.data.get()).map_err(de::Error::custom)?,
             ),
             OpCode::Event => {
                 #[cfg(feature = "events")]
                 {
                     ServerMessage::Event(
-                        serde_json::from_value(raw.data).map_err(de::Error::custom)?,
+                        serde_json::from_str(raw.data.get()).map_err(de::Error::custom)?,
                     )
                 }
                 #[cfg(not(feature = "events"))]
@@ -97,10 +98,10 @@ impl<'de> Deserialize<'de> for ServerMessage {
                 }
             }
             OpCode::RequestResponse => ServerMessage::RequestResponse(
-                serde_json::from_value(raw.data).map_err(de::Error::custom)?,
+                serde_json::from_str(raw.data.get()).map_err(de::Error::custom)?,
             ),
             OpCode::RequestBatchResponse => ServerMessage::RequestBatchResponse(
-                serde_json::from_value(raw.data).map_err(de::Error::custom)?,
+                serde_json::from_str(raw.data.get()).map_err(de::Error::custom)?,
             ),
         })
     }
@@ -110,7 +111,6 @@ impl<'de> Deserialize<'de> for ServerMessage {
 /// information if authentication is required. Also contains RPC version for version negotiation.
 #[derive(Debug, Deserialize)]
 pub(crate) struct Hello {
-    #[allow(dead_code)]
     #[serde(rename = "obsWebSocketVersion")]
     pub obs_web_socket_version: semver::Version,
     /// version number which gets incremented on each **breaking change** to the obs-websocket
@@ -141,17 +141,35 @@ pub(crate) struct RequestResponse {
     pub id: String,
     #[serde(rename = "requestStatus")]
     pub status: Status,
-    #[serde(rename = "responseData", default)]
-    pub data: serde_json::Value,
+    #[serde(rename = "responseData", default = "null_data")]
+    pub data: Box<RawValue>,
 }
 
+/// `obs-websocket` is responding to a request batch coming from the client.
 #[derive(Debug, Deserialize)]
 pub(crate) struct RequestBatchResponse {
-    #[allow(dead_code)]
     #[serde(rename = "requestId")]
     pub id: String,
+    /// Results of the individual requests, in the same order as they were sent.
+    #[serde(rename = "results")]
+    pub results: Vec<RequestBatchResult>,
+}
+
+/// Result of a single request, as part of a [`RequestBatchResponse`].
+#[derive(Debug, Deserialize)]
+pub(crate) struct RequestBatchResult {
     #[allow(dead_code)]
-    pub results: Vec<serde_json::Value>,
+    #[serde(rename = "requestType")]
+    pub r#type: String,
+    #[serde(rename = "requestStatus")]
+    pub status: Status,
+    #[serde(rename = "responseData", default = "null_data")]
+    pub data: Box<RawValue>,
+}
+
+/// Response data for requests, that don't return any data.
+fn null_data() -> Box<RawValue> {
+    RawValue::NULL.to_owned()
 }
 
 #[derive(Debug, Deserialize)]
@@ -1,5 +1,7 @@
 //! Responses related to scene collections.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Response value for [`crate::client::SceneCollections::list`].
@@ -12,3 +14,37 @@ pub struct SceneCollections {
     #[serde(rename = "sceneCollections")]
     pub collections: Vec<String>,
 }
+
+/// Response value for [`crate::client::SceneCollections::stats`].
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SceneCollectionStats {
+    /// Number of items in each scene, keyed by the scene name.
+    pub items_per_scene: BTreeMap<String, usize>,
+    /// Number of inputs of each kind, keyed by the input kind.
+    pub inputs_by_kind: BTreeMap<String, usize>,
+    /// Number of filters on each scene and input, keyed by the source name.
+    pub filters_per_source: BTreeMap<String, usize>,
+    /// Inputs that are likely expensive to render, with the reason why. Inputs can appear
+    /// multiple times, if there are several reasons.
+    pub heavy_sources: Vec<HeavySource>,
+}
+
+/// Input that is likely expensive to render, as part of the [`SceneCollectionStats`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct HeavySource {
+    /// Name of the input.
+    pub name: String,
+    /// Why the input is considered expensive.
+    pub reason: HeavyReason,
+}
+
+/// Reason of why an input is considered expensive to render.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[non_exhaustive]
+pub enum HeavyReason {
+    /// Browser source, that keeps running while it's not visible, because the "Shutdown source
+    /// when not visible" setting is disabled.
+    BrowserWithoutShutdown,
+    /// Browser source or video capture device, that is set to a frame rate above 30 FPS.
+    HighFps(f64),
+}
@@ -2,7 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::common::{Alignment, BlendMode, BoundsType};
+use super::ids::SceneId;
+use crate::common::{Alignment, BlendMode, BoundsType, Extra};
 
 #[derive(Debug, Deserialize)]
 pub(crate) struct SceneItemId {
@@ -14,10 +15,10 @@ pub(crate) struct SceneItemId {
 /// Response value for [`crate::client::SceneItems::list`] and
 /// [`crate::client::SceneItems::list_group`].
 #[derive(Debug, Deserialize)]
-pub(crate) struct SceneItemList {
+pub(crate) struct SceneItemList<T = Vec<SceneItem>> {
     /// Array of scene items in the scene or group.
     #[serde(rename = "sceneItems")]
-    pub scene_items: Vec<SceneItem>,
+    pub scene_items: T,
 }
 
 /// Response value for [`crate::client::SceneItems::list`] and
@@ -42,10 +43,15 @@ pub struct SceneItem {
     /// Whether this item is a group. Only present if this is a [`SourceType::Scene`].
     #[serde(rename = "isGroup")]
     pub is_group: Option<bool>,
+    /// Any other fields, not known to this crate yet. Only filled with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(feature = "preserve-unknown", serde(flatten))]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: Extra,
 }
 
 /// Kind of source that is represented by a [`SceneItem`].
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 #[non_exhaustive]
 pub enum SourceType {
     /// Input source from outside of OBS.
@@ -60,6 +66,9 @@ pub enum SourceType {
     /// Scene in OBS.
     #[serde(rename = "OBS_SOURCE_TYPE_SCENE")]
     Scene,
+    /// Any other value, not known to this crate yet, with the original string.
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Response value for [`crate::client::SceneItems::transform`].
@@ -129,6 +138,29 @@ pub struct SceneItemTransform {
     /// Whether to limit cropping to the bounding box.
     #[serde(rename = "cropToBounds", default)]
     pub crop_to_bounds: bool,
+    /// Any other fields, not known to this crate yet. Only filled with the `preserve-unknown`
+    /// feature.
+    #[cfg_attr(feature = "preserve-unknown", serde(flatten))]
+    #[cfg_attr(not(feature = "preserve-unknown"), serde(skip))]
+    pub extra: Extra,
+}
+
+/// Response value for [`crate::client::SceneItems::source_dimensions`].
+#[derive(Debug, Deserialize)]
+pub(crate) struct GetSourceDimensions {
+    #[serde(rename = "sceneItemTransform")]
+    pub dimensions: SourceDimensions,
+}
+
+/// Response value for [`crate::client::SceneItems::source_dimensions`].
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct SourceDimensions {
+    /// Base width (without scaling) of the source.
+    #[serde(rename = "sourceWidth")]
+    pub source_width: f32,
+    /// Base height (without scaling) of the source.
+    #[serde(rename = "sourceHeight")]
+    pub source_height: f32,
 }
 
 /// Response value for [`crate::client::SceneItems::enabled`].
@@ -147,6 +179,28 @@ pub(crate) struct SceneItemLocked {
     pub locked: bool,
 }
 
+/// Response value for [`crate::client::SceneItems::find_recursive`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct SceneItemLocation {
+    /// Name of the group the item is in, or [`None`] if it's directly within the searched scene.
+    pub group: Option<String>,
+    /// Numeric ID of the scene item, within the scene or group it's in.
+    pub id: i64,
+}
+
+/// Response value for [`crate::client::SceneItems::locked_all`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct LockedSceneItem {
+    /// Identifier of the scene the item is in.
+    pub scene: SceneId,
+    /// Name of the group the item is in, or [`None`] if it's directly within the scene.
+    pub group: Option<String>,
+    /// Numeric ID of the scene item, within the scene or group it's in.
+    pub item_id: i64,
+    /// Name of the item's source.
+    pub source_name: String,
+}
+
 /// Response value for [`crate::client::SceneItems::index`].
 #[derive(Debug, Deserialize)]
 pub(crate) struct SceneItemIndex {
@@ -3,6 +3,8 @@
 use serde::{Deserialize, Serialize};
 use time::Duration;
 
+use super::outputs::OutputStatus;
+
 /// Response value for [`crate::client::Streaming::status`].
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
 pub struct StreamStatus {
@@ -32,6 +34,55 @@ pub struct StreamStatus {
     pub total_frames: u32,
 }
 
+/// Response value for [`crate::client::Streaming::start_checked`].
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StartReport {
+    /// Whether the stream was started. This is only the case, if no check failed.
+    pub started: bool,
+    /// All checks that failed, preventing the stream from being started.
+    pub failed_checks: Vec<FailedCheck>,
+}
+
+/// A single precondition that wasn't met, when trying to start the stream with
+/// [`crate::client::Streaming::start_checked`].
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum FailedCheck {
+    /// The stream is already running.
+    AlreadyStreaming,
+    /// Another profile than the expected one is active.
+    WrongProfile {
+        /// Name of the expected profile.
+        expected: String,
+        /// Name of the currently active profile.
+        actual: String,
+    },
+    /// Another scene collection than the expected one is active.
+    WrongSceneCollection {
+        /// Name of the expected scene collection.
+        expected: String,
+        /// Name of the currently active scene collection.
+        actual: String,
+    },
+    /// The stream service has no server configured.
+    StreamServiceNotConfigured,
+    /// The virtual camera is running.
+    VirtualCamActive,
+    /// A recording is in progress.
+    RecordingActive,
+}
+
+/// Response value for [`crate::client::Streaming::outputs`].
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct StreamOutput {
+    /// Name of the output, that can be used to control it through [`crate::client::Outputs`].
+    pub name: String,
+    /// The kind of output, like `rtmp_output` or `whip_output`.
+    pub kind: String,
+    /// Current status of the output, including its congestion and sent bytes.
+    pub status: OutputStatus,
+}
+
 #[derive(Debug, Deserialize)]
 pub(crate) struct OutputActive {
     /// New state of the stream output.
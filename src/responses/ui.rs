@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::scenes::SceneId;
+
 /// Response value for [`crate::client::Ui::studio_mode_enabled`].
 #[derive(Debug, Deserialize)]
 pub(crate) struct StudioModeEnabled {
@@ -10,6 +12,18 @@ pub(crate) struct StudioModeEnabled {
     pub enabled: bool,
 }
 
+/// Response value for [`crate::client::Ui::studio_state`].
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct StudioState {
+    /// Whether studio mode is enabled.
+    pub enabled: bool,
+    /// Identifier of the scene, that is shown in the program output.
+    pub program_scene: SceneId,
+    /// Identifier of the scene, that is shown in the preview. Only available while studio mode
+    /// is enabled.
+    pub preview_scene: Option<SceneId>,
+}
+
 /// Response value for [`crate::client::Ui::list_monitors`].
 #[derive(Debug, Deserialize)]
 pub(crate) struct MonitorList {
@@ -0,0 +1,225 @@
+//! Limited access to OBS, for services that hand out control to less trusted parties.
+//!
+//! A [`RestrictedClient`] wraps a regular [`Client`] and only offers a small set of operations,
+//! like switching scenes or muting inputs. Each of them is guarded by a [`Permissions`] flag,
+//! so a service can share a single connection to OBS between several users, for example chat
+//! moderators, while only allowing each of them what they need:
+//!
+//! ```no_run
+//! use obws::{
+//!     requests::inputs::InputId,
+//!     restricted::{Permissions, RestrictedClient},
+//!     Client,
+//! };
+//!
+//! # async fn run(client: &Client) -> obws::error::Result<()> {
+//! let moderator = RestrictedClient::new(client, Permissions::SCENES | Permissions::AUDIO);
+//!
+//! moderator.set_current_program_scene("Be right back").await?;
+//! moderator.toggle_mute(InputId::Name("Mic/Aux")).await?;
+//!
+//! // Fails with `Error::PermissionDenied`, without sending anything to OBS.
+//! assert!(moderator.start_streaming().await.is_err());
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Operations that change settings, or manage scene collections and profiles, are deliberately
+//! not part of the restricted API.
+
+use bitflags::bitflags;
+
+use crate::{
+    common::MediaAction,
+    error::{Error, Result},
+    requests::{
+        inputs::{InputId, Volume},
+        scene_items::SetEnabled,
+        scenes::SceneId,
+    },
+    responses::scenes::Scenes,
+    Client,
+};
+
+/// Operations, that a [`RestrictedClient`] is allowed to perform.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Permissions(u16);
+
+bitflags! {
+    impl Permissions: u16 {
+        /// Switch the program and preview scene.
+        const SCENES = 1 << 0;
+        /// Show and hide scene items.
+        const SCENE_ITEMS = 1 << 1;
+        /// Mute inputs and change their volume.
+        const AUDIO = 1 << 2;
+        /// Control media inputs, like playing, pausing or restarting them.
+        const MEDIA = 1 << 3;
+        /// Start and stop the recording.
+        const RECORDING = 1 << 4;
+        /// Start and stop the stream.
+        const STREAMING = 1 << 5;
+        /// Save the replay buffer.
+        const REPLAY_BUFFER = 1 << 6;
+    }
+}
+
+/// Wrapper around a [`Client`], that only allows the operations granted by its [`Permissions`].
+///
+/// Reading the list of scenes and the current program scene is always allowed. Any other
+/// operation fails with [`Error::PermissionDenied`] if its permission is missing, without sending
+/// a request to obs-websocket.
+pub struct RestrictedClient<'a> {
+    client: &'a Client,
+    permissions: Permissions,
+}
+
+impl<'a> RestrictedClient<'a> {
+    /// Create a new restricted client, that allows the given operations.
+    #[must_use]
+    pub fn new(client: &'a Client, permissions: Permissions) -> Self {
+        Self {
+            client,
+            permissions,
+        }
+    }
+
+    /// The operations, that this client is allowed to perform.
+    #[must_use]
+    pub fn permissions(&self) -> Permissions {
+        self.permissions
+    }
+
+    fn check(&self, required: Permissions) -> Result<&'a Client> {
+        if self.permissions.contains(required) {
+            Ok(self.client)
+        } else {
+            Err(Error::PermissionDenied(required))
+        }
+    }
+
+    /// Gets an array of all scenes in OBS.
+    pub async fn scenes(&self) -> Result<Scenes> {
+        self.client.scenes().list().await
+    }
+
+    /// Gets the current program scene.
+    pub async fn current_program_scene(&self) -> Result<crate::responses::scenes::SceneId> {
+        self.client
+            .scenes()
+            .current_program_scene()
+            .await
+            .map(|scene| scene.id)
+    }
+
+    /// Sets the current program scene. Requires [`Permissions::SCENES`].
+    pub async fn set_current_program_scene(&self, scene: impl Into<SceneId<'_>>) -> Result<()> {
+        self.check(Permissions::SCENES)?
+            .scenes()
+            .set_current_program_scene(scene)
+            .await
+    }
+
+    /// Sets the current preview scene, while studio mode is active. Requires
+    /// [`Permissions::SCENES`].
+    pub async fn set_current_preview_scene(&self, scene: impl Into<SceneId<'_>>) -> Result<()> {
+        self.check(Permissions::SCENES)?
+            .scenes()
+            .set_current_preview_scene(scene)
+            .await
+    }
+
+    /// Shows or hides a scene item. Requires [`Permissions::SCENE_ITEMS`].
+    pub async fn set_scene_item_enabled(
+        &self,
+        scene: impl Into<SceneId<'_>>,
+        item_id: i64,
+        enabled: bool,
+    ) -> Result<()> {
+        let scene = scene.into();
+        self.check(Permissions::SCENE_ITEMS)?
+            .scene_items()
+            .set_enabled(SetEnabled {
+                scene,
+                item_id,
+                enabled,
+            })
+            .await
+    }
+
+    /// Sets the audio mute state of an input. Requires [`Permissions::AUDIO`].
+    pub async fn set_muted(&self, input: impl Into<InputId<'_>>, muted: bool) -> Result<()> {
+        let input = input.into();
+        self.check(Permissions::AUDIO)?
+            .inputs()
+            .set_muted(input, muted)
+            .await
+    }
+
+    /// Toggles the audio mute state of an input, returning the new state. Requires
+    /// [`Permissions::AUDIO`].
+    pub async fn toggle_mute(&self, input: impl Into<InputId<'_>>) -> Result<bool> {
+        let input = input.into();
+        self.check(Permissions::AUDIO)?
+            .inputs()
+            .toggle_mute(input)
+            .await
+    }
+
+    /// Sets the volume of an input. Requires [`Permissions::AUDIO`].
+    pub async fn set_volume(&self, input: impl Into<InputId<'_>>, volume: Volume) -> Result<()> {
+        let input = input.into();
+        self.check(Permissions::AUDIO)?
+            .inputs()
+            .set_volume(input, volume)
+            .await
+    }
+
+    /// Triggers an action on a media input. Requires [`Permissions::MEDIA`].
+    pub async fn trigger_media_action(
+        &self,
+        input: impl Into<InputId<'_>>,
+        action: MediaAction,
+    ) -> Result<()> {
+        let input = input.into();
+        self.check(Permissions::MEDIA)?
+            .media_inputs()
+            .trigger_action(input, action)
+            .await
+    }
+
+    /// Starts the record output. Requires [`Permissions::RECORDING`].
+    pub async fn start_recording(&self) -> Result<()> {
+        self.check(Permissions::RECORDING)?
+            .recording()
+            .start()
+            .await
+    }
+
+    /// Stops the record output, returning the path of the recorded file. Requires
+    /// [`Permissions::RECORDING`].
+    pub async fn stop_recording(&self) -> Result<String> {
+        self.check(Permissions::RECORDING)?.recording().stop().await
+    }
+
+    /// Starts the stream output. Requires [`Permissions::STREAMING`].
+    pub async fn start_streaming(&self) -> Result<()> {
+        self.check(Permissions::STREAMING)?
+            .streaming()
+            .start()
+            .await
+    }
+
+    /// Stops the stream output. Requires [`Permissions::STREAMING`].
+    pub async fn stop_streaming(&self) -> Result<()> {
+        self.check(Permissions::STREAMING)?.streaming().stop().await
+    }
+
+    /// Saves the contents of the replay buffer output. Requires [`Permissions::REPLAY_BUFFER`].
+    pub async fn save_replay_buffer(&self) -> Result<()> {
+        self.check(Permissions::REPLAY_BUFFER)?
+            .replay_buffer()
+            .save()
+            .await
+    }
+}
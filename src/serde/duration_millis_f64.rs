@@ -0,0 +1,65 @@
+use serde::{Deserialize, Deserializer, Serializer};
+use time::Duration;
+
+pub fn serialize<S>(value: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_f64(value.as_seconds_f64() * 1000.0)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    f64::deserialize(deserializer).map(|millis| Duration::seconds_f64(millis / 1000.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens, assert_tokens, Token};
+    use time::Duration;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SimpleDuration {
+        #[serde(with = "super")]
+        value: Duration,
+    }
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(
+            &SimpleDuration {
+                value: Duration::microseconds(2500),
+            },
+            &[
+                Token::Struct {
+                    name: "SimpleDuration",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::F64(2.5),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn deser() {
+        assert_de_tokens(
+            &SimpleDuration {
+                value: Duration::milliseconds(5),
+            },
+            &[
+                Token::Struct {
+                    name: "SimpleDuration",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::U64(5),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
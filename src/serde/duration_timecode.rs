@@ -18,6 +18,8 @@ enum Error {
     MillisecondsMissing,
     #[error("invalid integer")]
     InvalidInteger(#[from] std::num::ParseIntError),
+    #[error("duration is too large")]
+    Overflow,
 }
 
 #[allow(dead_code)]
@@ -56,18 +58,22 @@ impl Visitor<'_> for DurationTimecodeVisitor {
     {
         let duration = || -> Result<Duration, Error> {
             let mut hms = v.splitn(3, ':');
-            let hours = hms.next().ok_or(Error::HoursMissing)?.parse()?;
-            let minutes = hms.next().ok_or(Error::MinutesMissing)?.parse()?;
+            let hours = hms.next().ok_or(Error::HoursMissing)?.parse::<i64>()?;
+            let minutes = hms.next().ok_or(Error::MinutesMissing)?.parse::<i64>()?;
             let seconds = hms.next().ok_or(Error::SecondsMissing)?;
 
             let mut sm = seconds.splitn(2, '.');
-            let seconds = sm.next().ok_or(Error::SecondsMissing)?.parse()?;
+            let seconds = sm.next().ok_or(Error::SecondsMissing)?.parse::<i64>()?;
             let millis = sm.next().ok_or(Error::MillisecondsMissing)?.parse()?;
 
-            Ok(Duration::hours(hours)
-                + Duration::minutes(minutes)
-                + Duration::seconds(seconds)
-                + Duration::milliseconds(millis))
+            // Values come straight from the server, so huge numbers must not overflow and panic.
+            hours
+                .checked_mul(3600)
+                .and_then(|secs| secs.checked_add(minutes.checked_mul(60)?))
+                .and_then(|secs| secs.checked_add(seconds))
+                .map(Duration::seconds)
+                .and_then(|duration| duration.checked_add(Duration::milliseconds(millis)))
+                .ok_or(Error::Overflow)
         };
 
         duration().map_err(de::Error::custom)
@@ -77,7 +83,7 @@ impl Visitor<'_> for DurationTimecodeVisitor {
 #[cfg(test)]
 mod tests {
     use serde::{Deserialize, Serialize};
-    use serde_test::{assert_tokens, Token};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
     use time::Duration;
 
     #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -106,4 +112,19 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn overflow() {
+        assert_de_tokens_error::<SimpleDuration>(
+            &[
+                Token::Struct {
+                    name: "SimpleDuration",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::Str("9223372036854775807:00:00.000"),
+            ],
+            "duration is too large",
+        );
+    }
 }
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::common::Bytes;
+
+pub fn serialize<S>(value: &Bytes, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_f64(value.as_mebibytes())
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Bytes, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    f64::deserialize(deserializer).map(Bytes::from_mebibytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+    use crate::common::Bytes;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SimpleBytes {
+        #[serde(with = "super")]
+        value: Bytes,
+    }
+
+    #[test]
+    fn roundtrip() {
+        assert_tokens(
+            &SimpleBytes {
+                value: Bytes::new(1_572_864),
+            },
+            &[
+                Token::Struct {
+                    name: "SimpleBytes",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::F64(1.5),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn deser() {
+        assert_de_tokens(
+            &SimpleBytes {
+                value: Bytes::new(209_715_200),
+            },
+            &[
+                Token::Struct {
+                    name: "SimpleBytes",
+                    len: 1,
+                },
+                Token::Str("value"),
+                Token::U64(200),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -3,6 +3,8 @@
 
 pub mod audio_tracks;
 pub mod duration_millis;
+pub mod duration_millis_f64;
 pub mod duration_timecode;
 pub mod json_string;
+pub mod mebibytes;
 pub mod rgba8_inverse;
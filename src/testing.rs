@@ -0,0 +1,652 @@
+//! Recording and replaying of obs-websocket sessions, to test applications without a running OBS
+//! instance.
+//!
+//! A session is recorded by connecting with [`record`] instead of
+//! [`Client::connect_with_config`]. All messages received from obs-websocket are then written to
+//! the [`Recorder`], one JSON object per line.
+//!
+//! Such a recording can later be loaded with [`replay`], which returns a [`Client`] that is
+//! connected to an in-memory server instead of OBS. The server answers requests with the recorded
+//! responses in their original order, and emits the recorded events right after the response
+//! they followed in the recording. As it is a regular client, all the usual category APIs are
+//! available and the application under test doesn't need to be aware of the replay.
+//!
+//! For utilities that depend on time, the [`ManualClock`] allows to control the passing of time.
+//!
+//! ```no_run
+//! # async fn run() -> obws::error::Result<()> {
+//! let client = obws::testing::replay_file("session.jsonl").await?;
+//! let scenes = client.scenes().list().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::File,
+    future::Future,
+    io::{self, BufRead, BufReader, LineWriter, Write},
+    path::Path,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+use futures_util::{SinkExt, StreamExt};
+use serde::Serialize;
+use serde_json::{json, Value};
+use tokio::{io::DuplexStream, sync::oneshot};
+use tokio_tungstenite::{
+    tungstenite::{self, Message},
+    WebSocketStream,
+};
+use tracing::{error, warn};
+
+use crate::{
+    client::{
+        BroadcastGrowth, Codec, ConnectConfig, ServerProfile, UnexpectedResponsePolicy,
+        DEFAULT_BROADCAST_CAPACITY, DEFAULT_CONNECT_TIMEOUT,
+    },
+    clock::Clock,
+    error::{Error, Result},
+    responses::StatusCode,
+    Client,
+};
+
+/// Writes all messages received from obs-websocket to a target, in the JSON lines format.
+pub struct Recorder {
+    writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl Recorder {
+    /// Create a new recorder that writes to the given target.
+    pub fn new(writer: impl Write + Send + 'static) -> Self {
+        Self {
+            writer: Mutex::new(Box::new(writer)),
+        }
+    }
+
+    /// Create a new recorder that writes to a file at the given path. An existing file is
+    /// truncated.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        File::create(path).map(|file| Self::new(LineWriter::new(file)))
+    }
+
+    pub(crate) fn record(&self, message: &str) {
+        let message = match serde_json::from_str::<Value>(message) {
+            Ok(message) => message,
+            Err(e) => {
+                warn!(error = ?e, "skipping invalid message from recording");
+                return;
+            }
+        };
+
+        let mut writer = match self.writer.lock() {
+            Ok(writer) => writer,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if let Err(e) = writeln!(writer, "{message}") {
+            error!(error = ?e, "failed writing message to the recording");
+        }
+    }
+}
+
+/// Connect to an obs-websocket instance like [`Client::connect_with_config`] does, but record all
+/// received messages with the given recorder.
+pub async fn record<H, P>(config: ConnectConfig<H, P>, recorder: Recorder) -> Result<Client>
+where
+    H: AsRef<str>,
+    P: AsRef<str>,
+{
+    let (socket, _) = tokio::time::timeout(config.connect_timeout, async {
+        tokio_tungstenite::connect_async(config.request()?).await
+    })
+    .await
+    .map_err(|_| Error::Timeout)?
+    .map_err(|e| crate::error::ConnectError(Box::new(e)))?;
+
+    Client::connect_with_socket(socket, &config, Some(recorder)).await
+}
+
+/// Load a recording from a file and replay it. See [`replay`] for details.
+pub async fn replay_file(path: impl AsRef<Path>) -> Result<Client> {
+    let file = File::open(path).map_err(crate::error::ReadRecordingError)?;
+    replay(BufReader::new(file)).await
+}
+
+/// Create a client, that is connected to an in-memory server replaying the given recording.
+///
+/// The recording must be in the JSON lines format, as written by the [`Recorder`].
+pub async fn replay(recording: impl BufRead) -> Result<Client> {
+    let mut messages = VecDeque::new();
+    for line in recording.lines() {
+        let line = line.map_err(crate::error::ReadRecordingError)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        messages.push_back(
+            serde_json::from_str::<Value>(&line)
+                .map_err(crate::error::DeserializeRecordingError)?,
+        );
+    }
+
+    connect_in_memory(|stream| replay_server(stream, messages)).await
+}
+
+/// Create a client, that is connected to an in-memory server running the given server function.
+async fn connect_in_memory<F, Fut>(server: F) -> Result<Client>
+where
+    F: FnOnce(DuplexStream) -> Fut,
+    Fut: Future<Output = tungstenite::Result<()>> + Send + 'static,
+{
+    let (client_io, server_io) = tokio::io::duplex(64 * 1024);
+    let server = server(server_io);
+
+    tokio::spawn(async move {
+        if let Err(e) = server.await {
+            error!(error = ?e, "in-memory server failed");
+        }
+    });
+
+    let (socket, _) = tokio_tungstenite::client_async("ws://localhost/", client_io)
+        .await
+        .map_err(|e| crate::error::ConnectError(Box::new(e)))?;
+
+    Client::connect_with_socket(
+        socket,
+        &ConnectConfig {
+            host: "localhost",
+            port: 0,
+            url: None,
+            dangerous: None,
+            password: None::<&str>,
+            event_subscriptions: None,
+            #[cfg(any(feature = "tls-rustls", feature = "tls-native"))]
+            tls: false,
+            #[cfg(feature = "tls-rustls")]
+            tls_config: None,
+            broadcast_capacity: DEFAULT_BROADCAST_CAPACITY,
+            broadcast_growth: BroadcastGrowth::default(),
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            request_size_limit: None,
+            restart_on_panic: false,
+            request_timeout: None,
+            unexpected_responses: UnexpectedResponsePolicy::default(),
+            server_profile: ServerProfile::default(),
+            codec: Codec::default(),
+        },
+        None,
+    )
+    .await
+}
+
+/// Clock for tests, where time only passes when explicitly advanced with [`Self::advance`].
+///
+/// It can be set as the client's clock with [`Client::with_clock`]. The clock is cheap to clone,
+/// and all clones share the same time.
+#[derive(Clone, Debug)]
+pub struct ManualClock {
+    state: Arc<Mutex<ManualClockState>>,
+}
+
+#[derive(Debug)]
+struct ManualClockState {
+    now: Instant,
+    sleepers: Vec<(Instant, oneshot::Sender<()>)>,
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualClock {
+    /// Create a new clock, starting at the current point in time.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ManualClockState {
+                now: Instant::now(),
+                sleepers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Move the clock forward, waking up all sleeps that have finished by then.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.lock();
+        state.now += duration;
+
+        let now = state.now;
+        let (done, pending) = state
+            .sleepers
+            .drain(..)
+            .partition::<Vec<_>, _>(|(deadline, _)| *deadline <= now);
+        state.sleepers = pending;
+
+        for (_, tx) in done {
+            tx.send(()).ok();
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ManualClockState> {
+        match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.lock().now
+    }
+
+    fn sleep(&self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + 'static>> {
+        if duration.is_zero() {
+            return Box::pin(std::future::ready(()));
+        }
+
+        let (tx, rx) = oneshot::channel();
+        let mut state = self.lock();
+        let deadline = state.now + duration;
+        state.sleepers.push((deadline, tx));
+
+        Box::pin(async move {
+            rx.await.ok();
+        })
+    }
+}
+
+/// Programmable stand-in for obs-websocket, to unit-test applications that drive OBS through a
+/// [`Client`].
+///
+/// Responses are registered per request type with [`Self::respond`] or [`Self::fail`], and
+/// [`Self::connect`] creates a regular client, that is connected to the stub instead of OBS. All
+/// requests that the client sends are recorded and can be inspected with [`Self::received`].
+///
+/// The stub is cheap to clone, and all clones share the same state. That way, responses can be
+/// added and requests inspected while the client is in use.
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> obws::error::Result<()> {
+/// use obws::testing::Stub;
+/// use serde_json::json;
+///
+/// let stub = Stub::new();
+/// stub.respond("GetStudioModeEnabled", json!({"studioModeEnabled": true}));
+///
+/// let client = stub.connect().await?;
+/// assert!(client.ui().studio_mode_enabled().await?);
+/// // The first request is `GetVersion`, sent while connecting.
+/// assert_eq!("GetStudioModeEnabled", stub.received()[1].0);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Stub {
+    state: Arc<Mutex<StubState>>,
+}
+
+#[derive(Debug, Default)]
+struct StubState {
+    responses: HashMap<String, VecDeque<Value>>,
+    received: Vec<(String, Value)>,
+    #[cfg(feature = "events")]
+    events: Option<tokio::sync::mpsc::UnboundedSender<Value>>,
+}
+
+impl StubState {
+    /// Request types with registered responses, including the built-in `GetVersion`.
+    fn available_requests(&self) -> Vec<&str> {
+        let mut available = self
+            .responses
+            .keys()
+            .map(String::as_str)
+            .chain(["GetVersion"])
+            .collect::<Vec<_>>();
+        available.sort_unstable();
+        available.dedup();
+        available
+    }
+}
+
+impl Stub {
+    /// Create a new stub without any registered responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a successful response for the given request type, like `GetSceneList`.
+    ///
+    /// Multiple responses for the same request type are returned in the order they were
+    /// registered. The last one is repeated for all further requests. Requests without any
+    /// response fail with [`StatusCode::GenericError`]. A response for `GetVersion`, as used while
+    /// connecting, is provided by default. It lists all request types with registered responses as
+    /// available.
+    pub fn respond(&self, request_type: &str, data: impl Serialize) {
+        let data = serde_json::to_value(data).unwrap_or(Value::Null);
+        self.push(
+            request_type,
+            json!({
+                "requestStatus": {"result": true, "code": StatusCode::Success},
+                "responseData": data,
+            }),
+        );
+    }
+
+    /// Register a failed response for the given request type. See [`Self::respond`] for details.
+    pub fn fail(&self, request_type: &str, code: StatusCode, comment: Option<&str>) {
+        self.push(
+            request_type,
+            json!({
+                "requestStatus": {"result": false, "code": code, "comment": comment},
+            }),
+        );
+    }
+
+    /// Send an event to the connected client.
+    #[cfg(feature = "events")]
+    pub fn emit(&self, event: &crate::events::Event) {
+        let Ok(event) = serde_json::to_value(event) else {
+            return;
+        };
+
+        if let Some(events) = &self.lock().events {
+            events.send(json!({"op": op::EVENT, "d": event})).ok();
+        }
+    }
+
+    /// All requests received so far, as pairs of the request type and the request data.
+    #[must_use]
+    pub fn received(&self) -> Vec<(String, Value)> {
+        self.lock().received.clone()
+    }
+
+    /// Create a client, that is connected to this stub.
+    pub async fn connect(&self) -> Result<Client> {
+        let stub = self.clone();
+        connect_in_memory(|stream| stub_server(stream, stub)).await
+    }
+
+    fn push(&self, request_type: &str, response: Value) {
+        self.lock()
+            .responses
+            .entry(request_type.to_owned())
+            .or_default()
+            .push_back(response);
+    }
+
+    /// Record a request and build the response for it.
+    fn handle(&self, request: &Value) -> Value {
+        let request_type = request["requestType"].as_str().unwrap_or_default();
+        let mut state = self.lock();
+
+        state.received.push((
+            request_type.to_owned(),
+            request.get("requestData").cloned().unwrap_or(Value::Null),
+        ));
+
+        let response = match state.responses.get_mut(request_type) {
+            Some(responses) if responses.len() > 1 => responses.pop_front(),
+            Some(responses) => responses.front().cloned(),
+            None if request_type == "GetVersion" => Some(json!({
+                "requestStatus": {"result": true, "code": StatusCode::Success},
+                "responseData": {
+                    "obsVersion": "31.0.0",
+                    "obsWebSocketVersion": "5.5.0",
+                    "rpcVersion": 1,
+                    "availableRequests": state.available_requests(),
+                    "supportedImageFormats": [],
+                    "platform": "stub",
+                    "platformDescription": "",
+                },
+            })),
+            None => None,
+        };
+
+        match response {
+            Some(mut response) => {
+                response["requestType"] = request["requestType"].clone();
+                response["requestId"] = request["requestId"].clone();
+                response
+            }
+            None => missing_response(request)["d"].take(),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, StubState> {
+        match self.state.lock() {
+            Ok(state) => state,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+async fn stub_server(stream: DuplexStream, stub: Stub) -> tungstenite::Result<()> {
+    let mut socket = accept(stream).await?;
+
+    #[cfg(feature = "events")]
+    let mut events = {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        stub.lock().events = Some(tx);
+        rx
+    };
+
+    loop {
+        #[cfg(feature = "events")]
+        let message = tokio::select! {
+            message = socket.next() => message,
+            Some(event) = events.recv() => {
+                send(&mut socket, &event).await?;
+                continue;
+            }
+        };
+        #[cfg(not(feature = "events"))]
+        let message = socket.next().await;
+
+        let Some(Ok(message)) = message else {
+            break;
+        };
+        let Ok(text) = message.to_text() else {
+            continue;
+        };
+        let Ok(message) = serde_json::from_str::<Value>(text) else {
+            continue;
+        };
+
+        let data = &message["d"];
+        let reply = match message["op"].as_u64() {
+            Some(op::IDENTIFY | op::REIDENTIFY) => json!({
+                "op": op::IDENTIFIED,
+                "d": {"negotiatedRpcVersion": 1},
+            }),
+            Some(op::REQUEST) => json!({
+                "op": op::REQUEST_RESPONSE,
+                "d": stub.handle(data),
+            }),
+            Some(op::REQUEST_BATCH) => {
+                let results = data["requests"]
+                    .as_array()
+                    .map(|requests| requests.iter().map(|r| stub.handle(r)).collect())
+                    .unwrap_or_default();
+
+                json!({
+                    "op": op::REQUEST_BATCH_RESPONSE,
+                    "d": {"requestId": data["requestId"], "results": Value::Array(results)},
+                })
+            }
+            _ => continue,
+        };
+
+        send(&mut socket, &reply).await?;
+    }
+
+    Ok(())
+}
+
+/// Op codes of the messages, that the in-memory servers handle.
+mod op {
+    pub const HELLO: u64 = 0;
+    pub const IDENTIFY: u64 = 1;
+    pub const IDENTIFIED: u64 = 2;
+    pub const REIDENTIFY: u64 = 3;
+    pub const EVENT: u64 = 5;
+    pub const REQUEST: u64 = 6;
+    pub const REQUEST_RESPONSE: u64 = 7;
+    pub const REQUEST_BATCH: u64 = 8;
+    pub const REQUEST_BATCH_RESPONSE: u64 = 9;
+}
+
+async fn replay_server(
+    stream: DuplexStream,
+    mut messages: VecDeque<Value>,
+) -> tungstenite::Result<()> {
+    let mut socket = accept(stream).await?;
+
+    while let Some(Ok(message)) = socket.next().await {
+        let Ok(text) = message.to_text() else {
+            continue;
+        };
+        let Ok(message) = serde_json::from_str::<Value>(text) else {
+            continue;
+        };
+
+        let data = &message["d"];
+        let reply = match message["op"].as_u64() {
+            Some(op::IDENTIFY) => Some(json!({
+                "op": op::IDENTIFIED,
+                "d": {"negotiatedRpcVersion": 1},
+            })),
+            Some(op::REIDENTIFY) => Some(
+                next_reply(&mut messages, op::IDENTIFIED, |_| true).unwrap_or_else(|| {
+                    json!({
+                        "op": op::IDENTIFIED,
+                        "d": {"negotiatedRpcVersion": 1},
+                    })
+                }),
+            ),
+            Some(op::REQUEST) => Some(
+                next_reply(&mut messages, op::REQUEST_RESPONSE, |reply| {
+                    reply["requestType"] == data["requestType"]
+                })
+                .map_or_else(|| missing_response(data), |reply| with_id(reply, data)),
+            ),
+            Some(op::REQUEST_BATCH) => Some(
+                next_reply(&mut messages, op::REQUEST_BATCH_RESPONSE, |_| true).map_or_else(
+                    || {
+                        json!({
+                            "op": op::REQUEST_BATCH_RESPONSE,
+                            "d": {"requestId": data["requestId"], "results": []},
+                        })
+                    },
+                    |reply| with_id(reply, data),
+                ),
+            ),
+            _ => None,
+        };
+
+        if let Some(reply) = reply {
+            send(&mut socket, &reply).await?;
+        }
+
+        while let Some(event) = messages
+            .front()
+            .filter(|message| message["op"] == op::EVENT)
+        {
+            send(&mut socket, event).await?;
+            messages.pop_front();
+        }
+    }
+
+    Ok(())
+}
+
+/// Take the next recorded message, if it has the expected op code and matches the filter.
+fn next_reply(
+    messages: &mut VecDeque<Value>,
+    op: u64,
+    filter: impl Fn(&Value) -> bool,
+) -> Option<Value> {
+    let next = messages.front()?;
+    if next["op"] != op || !filter(&next["d"]) {
+        warn!(expected = op, recorded = %next, "recording doesn't match the current request");
+        return None;
+    }
+
+    messages.pop_front()
+}
+
+/// Replace the request ID of a recorded response with the ID of the current request.
+fn with_id(mut reply: Value, request: &Value) -> Value {
+    reply["d"]["requestId"] = request["requestId"].clone();
+    reply
+}
+
+/// Create a failed response for requests, that have no matching response in the recording.
+fn missing_response(request: &Value) -> Value {
+    json!({
+        "op": op::REQUEST_RESPONSE,
+        "d": {
+            "requestType": request["requestType"],
+            "requestId": request["requestId"],
+            "requestStatus": {
+                "result": false,
+                "code": StatusCode::GenericError,
+                "comment": "no matching response in the recording",
+            },
+        },
+    })
+}
+
+/// Accept a new WebSocket connection and greet the client with the initial hello message.
+async fn accept(stream: DuplexStream) -> tungstenite::Result<WebSocketStream<DuplexStream>> {
+    let mut socket = tokio_tungstenite::accept_async(stream).await?;
+
+    send(
+        &mut socket,
+        &json!({
+            "op": op::HELLO,
+            "d": {"obsWebSocketVersion": "5.5.0", "rpcVersion": 1},
+        }),
+    )
+    .await?;
+
+    Ok(socket)
+}
+
+async fn send(
+    socket: &mut WebSocketStream<DuplexStream>,
+    message: &Value,
+) -> tungstenite::Result<()> {
+    socket.send(Message::text(message.to_string())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::FutureExt;
+
+    use super::*;
+
+    #[test]
+    fn manual_clock() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+
+        let mut sleep = clock.sleep(Duration::from_secs(5));
+        assert!(clock.sleep(Duration::ZERO).now_or_never().is_some());
+
+        clock.advance(Duration::from_secs(3));
+        assert!((&mut sleep).now_or_never().is_none());
+        assert_eq!(Duration::from_secs(3), clock.now() - start);
+
+        clock.clone().advance(Duration::from_secs(2));
+        assert!(sleep.now_or_never().is_some());
+        assert_eq!(Duration::from_secs(5), clock.now() - start);
+    }
+}
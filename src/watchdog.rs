@@ -0,0 +1,319 @@
+//! Detection of a frozen or black program output, based on the picture itself.
+//!
+//! The stats reported by OBS, like skipped frames, only tell whether OBS itself keeps up with
+//! rendering. They don't notice when a capture card keeps delivering the same frame, or a scene
+//! only shows a black screen. A [`ContentWatchdog`] takes small screenshots of the current program
+//! scene in a fixed interval instead, and compares each one with the previous screenshot.
+//!
+//! The output is considered black, if its average brightness stays below a threshold, and frozen,
+//! if the difference between two screenshots stays below a threshold. Either condition has to
+//! last for a configured duration, before it's reported as [`ContentEvent`], to not raise alarms
+//! for short fades or still images.
+//!
+//! ```no_run
+//! use futures_util::StreamExt;
+//! use obws::{watchdog::ContentWatchdog, Client};
+//!
+//! # async fn run(client: &Client) -> obws::error::Result<()> {
+//! let watchdog = ContentWatchdog::default();
+//! let mut events = std::pin::pin!(watchdog.watch(client));
+//!
+//! while let Some(event) = events.next().await {
+//!     println!("program output: {:?}", event?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::{Duration, Instant};
+
+use futures_util::{stream, Stream};
+use tracing::debug;
+
+use crate::{
+    bmp,
+    error::Result,
+    requests::sources::{SourceId, TakeScreenshot},
+    Client,
+};
+
+/// Width and height of the screenshots, that are compared with each other.
+const SCREENSHOT_SIZE: u32 = 32;
+
+/// Configuration of a watchdog, that reports a frozen or black program output.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "builder", derive(bon::Builder))]
+pub struct ContentWatchdog {
+    /// Time between two screenshots of the program output.
+    #[cfg_attr(feature = "builder", builder(default = Duration::from_secs(1)))]
+    pub interval: Duration,
+    /// Average brightness in the range `0.0..=1.0`, below which the output is considered black.
+    #[cfg_attr(feature = "builder", builder(default = 0.02))]
+    pub black_threshold: f32,
+    /// Average difference of the pixel brightness between two screenshots in the range
+    /// `0.0..=1.0`, below which the output is considered frozen.
+    #[cfg_attr(feature = "builder", builder(default = 0.002))]
+    pub freeze_threshold: f32,
+    /// Time that the output has to be black, before it's reported.
+    #[cfg_attr(feature = "builder", builder(default = Duration::from_secs(5)))]
+    pub black_after: Duration,
+    /// Time that the output has to be frozen, before it's reported.
+    #[cfg_attr(feature = "builder", builder(default = Duration::from_secs(10)))]
+    pub freeze_after: Duration,
+}
+
+impl Default for ContentWatchdog {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            black_threshold: 0.02,
+            freeze_threshold: 0.002,
+            black_after: Duration::from_secs(5),
+            freeze_after: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Change of the program output, as reported by [`ContentWatchdog::watch`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ContentEvent {
+    /// The output was black for at least [`ContentWatchdog::black_after`].
+    Black {
+        /// Name of the program scene, when the condition was reported.
+        scene: String,
+    },
+    /// The output didn't change for at least [`ContentWatchdog::freeze_after`].
+    Frozen {
+        /// Name of the program scene, when the condition was reported.
+        scene: String,
+    },
+    /// The output shows a changing picture again, after it was reported as black or frozen.
+    Recovered {
+        /// Name of the program scene, when the output recovered.
+        scene: String,
+    },
+}
+
+impl ContentWatchdog {
+    /// Take screenshots of the program output and report whenever it turns black, freezes or
+    /// recovers from either.
+    ///
+    /// The stream doesn't end on its own while the connection is alive. It ends after yielding
+    /// the first error, like when the connection to obs-websocket was closed. Screenshots that
+    /// obs-websocket fails to take are skipped.
+    pub fn watch<'a>(
+        &'a self,
+        client: &'a Client,
+    ) -> impl Stream<Item = Result<ContentEvent>> + 'a {
+        stream::unfold(Some(Detector::new(*self)), move |detector| async move {
+            let mut detector = detector?;
+
+            loop {
+                if detector.started {
+                    client.clock().sleep(self.interval).await;
+                }
+                detector.started = true;
+
+                let (scene, frame) = match self.sample(client).await {
+                    Ok(Some(sample)) => sample,
+                    Ok(None) => continue,
+                    Err(e) => return Some((Err(e), None)),
+                };
+
+                if let Some(event) = detector.update(client.clock().now(), scene, frame) {
+                    return Some((Ok(event), Some(detector)));
+                }
+            }
+        })
+    }
+
+    /// Take a screenshot of the current program scene, or [`None`] if it can't be taken.
+    async fn sample(&self, client: &Client) -> Result<Option<(String, Vec<f32>)>> {
+        let scene = client.scenes().current_program_scene().await?.id;
+        let screenshot = client
+            .sources()
+            .take_screenshot(TakeScreenshot {
+                source: SourceId::Uuid(scene.uuid),
+                format: "bmp",
+                width: Some(SCREENSHOT_SIZE),
+                height: Some(SCREENSHOT_SIZE),
+                compression_quality: None,
+            })
+            .await;
+
+        match screenshot {
+            Ok(image) => Ok(bmp::luma(&image, SCREENSHOT_SIZE).map(|frame| (scene.name, frame))),
+            Err(e) if e.status_code().is_some() => {
+                debug!(scene = %scene.name, code = ?e.status_code(), "failed taking screenshot");
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Condition of the output, that is reported once it lasted long enough.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Condition {
+    Black,
+    Frozen,
+}
+
+/// Tracks the conditions of consecutive screenshots, and decides when to report them.
+struct Detector {
+    config: ContentWatchdog,
+    /// Whether a screenshot was attempted already, so the next one has to wait for the interval.
+    started: bool,
+    previous: Option<Vec<f32>>,
+    /// The current condition, and since when it lasts.
+    current: Option<(Condition, Instant)>,
+    reported: Option<Condition>,
+}
+
+impl Detector {
+    fn new(config: ContentWatchdog) -> Self {
+        Self {
+            config,
+            started: false,
+            previous: None,
+            current: None,
+            reported: None,
+        }
+    }
+
+    fn update(&mut self, now: Instant, scene: String, frame: Vec<f32>) -> Option<ContentEvent> {
+        let condition = if average(&frame) < self.config.black_threshold {
+            Some(Condition::Black)
+        } else if self
+            .previous
+            .as_ref()
+            .and_then(|previous| difference(previous, &frame))
+            .is_some_and(|diff| diff < self.config.freeze_threshold)
+        {
+            Some(Condition::Frozen)
+        } else {
+            None
+        };
+
+        self.previous = Some(frame);
+
+        let Some(condition) = condition else {
+            self.current = None;
+            return self
+                .reported
+                .take()
+                .map(|_| ContentEvent::Recovered { scene });
+        };
+
+        let since = match self.current {
+            Some((current, since)) if current == condition => since,
+            _ => {
+                self.current = Some((condition, now));
+                now
+            }
+        };
+
+        let after = match condition {
+            Condition::Black => self.config.black_after,
+            Condition::Frozen => self.config.freeze_after,
+        };
+
+        if self.reported == Some(condition) || now.saturating_duration_since(since) < after {
+            return None;
+        }
+
+        self.reported = Some(condition);
+        Some(match condition {
+            Condition::Black => ContentEvent::Black { scene },
+            Condition::Frozen => ContentEvent::Frozen { scene },
+        })
+    }
+}
+
+/// Average brightness of all pixels.
+fn average(frame: &[f32]) -> f32 {
+    #[allow(clippy::cast_precision_loss)]
+    let count = frame.len().max(1) as f32;
+    frame.iter().sum::<f32>() / count
+}
+
+/// Average difference of the brightness of each pixel between two frames, or [`None`] if the
+/// frames differ in size.
+fn difference(a: &[f32], b: &[f32]) -> Option<f32> {
+    (a.len() == b.len()).then(|| {
+        average(
+            &a.iter()
+                .zip(b)
+                .map(|(a, b)| (a - b).abs())
+                .collect::<Vec<_>>(),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(brightness: f32) -> Vec<f32> {
+        vec![brightness; 16]
+    }
+
+    #[test]
+    fn detector() {
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+        let mut detector = Detector::new(ContentWatchdog {
+            black_after: Duration::from_secs(2),
+            freeze_after: Duration::from_secs(3),
+            ..ContentWatchdog::default()
+        });
+        let mut update =
+            |secs, brightness| detector.update(at(secs), "Live".to_owned(), frame(brightness));
+        let scene = || "Live".to_owned();
+
+        assert_eq!(None, update(0, 0.5));
+        assert_eq!(None, update(1, 0.6));
+
+        // black
+        assert_eq!(None, update(2, 0.0));
+        assert_eq!(None, update(3, 0.0));
+        assert_eq!(Some(ContentEvent::Black { scene: scene() }), update(4, 0.0));
+        assert_eq!(None, update(5, 0.0));
+        assert_eq!(
+            Some(ContentEvent::Recovered { scene: scene() }),
+            update(6, 0.5)
+        );
+
+        // frozen, interrupted once before it's reported
+        assert_eq!(None, update(7, 0.5));
+        assert_eq!(None, update(8, 0.5));
+        assert_eq!(None, update(9, 0.4));
+        assert_eq!(None, update(10, 0.4));
+        assert_eq!(None, update(12, 0.4));
+        assert_eq!(
+            Some(ContentEvent::Frozen { scene: scene() }),
+            update(13, 0.4)
+        );
+        assert_eq!(None, update(14, 0.4));
+
+        // turning black from frozen, is reported as black
+        assert_eq!(None, update(15, 0.0));
+        assert_eq!(
+            Some(ContentEvent::Black { scene: scene() }),
+            update(17, 0.0)
+        );
+        assert_eq!(
+            Some(ContentEvent::Recovered { scene: scene() }),
+            update(18, 0.8)
+        );
+        assert_eq!(None, update(19, 0.3));
+    }
+
+    #[test]
+    fn frame_difference() {
+        assert_eq!(Some(0.0), difference(&frame(0.5), &frame(0.5)));
+        assert!((difference(&[0.0, 1.0], &[1.0, 1.0]).unwrap() - 0.5).abs() < f32::EPSILON);
+        assert_eq!(None, difference(&frame(0.5), &[0.5]));
+    }
+}
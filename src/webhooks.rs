@@ -0,0 +1,282 @@
+//! Forwarding of events to HTTP endpoints, to integrate OBS into external services like Discord
+//! webhooks or automation platforms.
+//!
+//! The forwarding is fully described by a [`WebhookConfig`], which can be stored in any format
+//! supported by [`serde`]. In TOML, it looks like the following, where each `[[webhook]]` table
+//! describes a single endpoint:
+//!
+//! ```toml
+//! [[webhook]]
+//! url = "https://discord.com/api/webhooks/123/abc"
+//! events = ["CurrentProgramSceneChanged"]
+//! body = '{"content": "Switched to scene {{eventData.sceneName}}"}'
+//!
+//! [[webhook]]
+//! url = "http://localhost:8080/obs/{{eventType}}"
+//! retries = 5
+//! retry_delay_ms = 500
+//! ```
+//!
+//! Each event is converted into JSON in the form of `{"eventType": "...", "eventData": {...}}`,
+//! the same format as used by obs-websocket. Without a `body` template, this JSON is sent as
+//! request body as-is.
+//!
+//! The URL and body can contain placeholders in the form of `{{path}}`, where the path points to a
+//! value in the event JSON, with nested values separated by dots. Values inserted into the URL are
+//! percent-encoded, and string values inserted into the body are escaped to be valid within a JSON
+//! string. Placeholders for missing values are replaced with nothing.
+
+use std::time::Duration;
+
+use futures_util::{future, StreamExt};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{serde_as, DurationMilliSeconds};
+use tracing::{debug, warn};
+
+use crate::{clock::Clock, error::Result, events::Event, Client};
+
+/// List of HTTP endpoints, that events are forwarded to.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct WebhookConfig {
+    /// All endpoints, that events are sent to.
+    #[serde(rename = "webhook", default)]
+    pub webhooks: Vec<Webhook>,
+}
+
+/// Single HTTP endpoint of a [`WebhookConfig`].
+#[serde_as]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Webhook {
+    /// URL to send a `POST` request to, for each event. Can contain placeholders.
+    pub url: String,
+    /// Event types to forward, like `CurrentProgramSceneChanged`. All events are forwarded, if
+    /// the list is empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<String>,
+    /// Template for the request body. Can contain placeholders. The whole event is sent as JSON,
+    /// if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Number of additional attempts, if sending an event failed. Defaults to `3`.
+    #[serde(default = "retries_default")]
+    pub retries: u32,
+    /// Time to wait between two attempts. Defaults to 1 second.
+    #[serde(rename = "retry_delay_ms", default = "retry_delay_default")]
+    #[serde_as(as = "DurationMilliSeconds<u64>")]
+    pub retry_delay: Duration,
+}
+
+fn retries_default() -> u32 {
+    3
+}
+
+fn retry_delay_default() -> Duration {
+    Duration::from_secs(1)
+}
+
+impl WebhookConfig {
+    /// Forward all events received by the client to the configured endpoints, until the connection
+    /// to obs-websocket is closed.
+    ///
+    /// Each event is sent to all matching endpoints at the same time, but events are processed in
+    /// the order they arrived. Failed deliveries are retried as configured, and are logged if they
+    /// didn't succeed in the end, without stopping the forwarding.
+    pub async fn forward(&self, client: &Client) -> Result<()> {
+        let http = reqwest::Client::new();
+        let mut events = Box::pin(client.events()?);
+
+        while let Some(event) = events.next().await {
+            let Some((event_type, event)) = to_json(&event) else {
+                continue;
+            };
+
+            future::join_all(
+                self.webhooks
+                    .iter()
+                    .filter(|webhook| webhook.matches(&event_type))
+                    .map(|webhook| webhook.deliver(&http, client.clock(), &event)),
+            )
+            .await;
+        }
+
+        Ok(())
+    }
+}
+
+impl Webhook {
+    /// Whether events of the given type are forwarded to this endpoint.
+    fn matches(&self, event_type: &str) -> bool {
+        self.events.is_empty() || self.events.iter().any(|name| name == event_type)
+    }
+
+    /// Send an event to this endpoint, retrying as configured.
+    async fn deliver(&self, http: &reqwest::Client, clock: &dyn Clock, event: &Value) {
+        let url = render(&self.url, event, Escape::Url);
+        let body = match &self.body {
+            Some(body) => render(body, event, Escape::Json),
+            None => event.to_string(),
+        };
+
+        for attempt in 0..=self.retries {
+            if attempt > 0 {
+                clock.sleep(self.retry_delay).await;
+            }
+
+            let result = http
+                .post(&url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.clone())
+                .send()
+                .await;
+
+            match result {
+                Ok(response) if response.status().is_success() => {
+                    debug!(%url, "forwarded event");
+                    return;
+                }
+                Ok(response) if !is_retryable(response.status()) => {
+                    warn!(%url, status = %response.status(), "endpoint rejected event");
+                    return;
+                }
+                Ok(response) => {
+                    warn!(%url, attempt, status = %response.status(), "failed forwarding event");
+                }
+                Err(error) => {
+                    warn!(%url, attempt, ?error, "failed forwarding event");
+                }
+            }
+        }
+    }
+}
+
+/// Whether a failed request is worth retrying, as the endpoint might accept it later.
+fn is_retryable(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Convert an event into its JSON representation, together with its type name.
+fn to_json(event: &Event) -> Option<(String, Value)> {
+    let value = serde_json::to_value(event).ok()?;
+    let event_type = value.get("eventType")?.as_str()?.to_owned();
+
+    Some((event_type, value))
+}
+
+/// Escaping that is applied to values, inserted into a template.
+#[derive(Clone, Copy)]
+enum Escape {
+    /// Percent-encode the value, to be part of a URL.
+    Url,
+    /// Escape strings to be valid inside a JSON string, and insert any other value as JSON.
+    Json,
+}
+
+impl Escape {
+    fn apply(self, value: &Value) -> String {
+        match (self, value) {
+            (_, Value::Null) => String::new(),
+            (Self::Url, Value::String(value)) => percent_encode(value),
+            (Self::Url, value) => percent_encode(&value.to_string()),
+            (Self::Json, Value::String(value)) => {
+                let quoted = Value::String(value.clone()).to_string();
+                quoted[1..quoted.len() - 1].to_owned()
+            }
+            (Self::Json, value) => value.to_string(),
+        }
+    }
+}
+
+/// Replace all `{{path}}` placeholders in the template with values from the event.
+fn render(template: &str, event: &Value, escape: Escape) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+
+        let path = rest[start + 2..start + 2 + len].trim();
+        let value = path
+            .split('.')
+            .try_fold(event, |value, key| value.get(key))
+            .unwrap_or(&Value::Null);
+
+        output.push_str(&rest[..start]);
+        output.push_str(&escape.apply(value));
+        rest = &rest[start + len + 4..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// Encode all characters, except for unreserved ones, as defined in
+/// [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-2.3).
+fn percent_encode(value: &str) -> String {
+    use std::fmt::Write;
+
+    value.bytes().fold(String::new(), |mut output, b| {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            output.push(char::from(b));
+        } else {
+            write!(output, "%{b:02X}").ok();
+        }
+        output
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn render_template() {
+        let event = json!({
+            "eventType": "CurrentProgramSceneChanged",
+            "eventData": {"sceneName": "Be \"right\" back", "count": 2},
+        });
+
+        assert_eq!(
+            "http://localhost/CurrentProgramSceneChanged/Be%20%22right%22%20back",
+            render(
+                "http://localhost/{{eventType}}/{{ eventData.sceneName }}",
+                &event,
+                Escape::Url
+            )
+        );
+        assert_eq!(
+            r#"{"content": "Be \"right\" back", "count": 2, "missing": ""}"#,
+            render(
+                r#"{"content": "{{eventData.sceneName}}", "count": {{eventData.count}}, "missing": "{{eventData.other}}"}"#,
+                &event,
+                Escape::Json
+            )
+        );
+        assert_eq!("unclosed {{", render("unclosed {{", &event, Escape::Json));
+    }
+
+    #[test]
+    fn config() {
+        let config = serde_json::from_value::<WebhookConfig>(json!({
+            "webhook": [
+                {"url": "http://localhost", "events": ["ExitStarted"], "retry_delay_ms": 250},
+                {"url": "http://localhost"},
+            ],
+        }))
+        .unwrap();
+
+        let [first, second] = &config.webhooks[..] else {
+            panic!("expected two webhooks");
+        };
+        assert!(first.matches("ExitStarted"));
+        assert!(!first.matches("StudioModeStateChanged"));
+        assert_eq!(Duration::from_millis(250), first.retry_delay);
+        assert!(second.matches("StudioModeStateChanged"));
+        assert_eq!(3, second.retries);
+    }
+}
@@ -0,0 +1,183 @@
+use anyhow::Result;
+use obws::{
+    audio::{self, ProgramMixOptions, Silenced},
+    responses::StatusCode,
+};
+use serde_json::json;
+use test_log::test;
+use uuid::Uuid;
+
+use crate::common;
+
+#[test(tokio::test)]
+async fn program_mix() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let mic = Uuid::new_v8([1; 16]);
+    let image = Uuid::new_v8([2; 16]);
+    let music = Uuid::new_v8([3; 16]);
+
+    server.expect(
+        "GetInputList",
+        json!({}),
+        json!({
+            "inputs": [
+                {
+                    "inputName": "mic",
+                    "inputUuid": mic,
+                    "inputKind": "wasapi_input_capture",
+                    "unversionedInputKind": "wasapi_input_capture",
+                },
+                {
+                    "inputName": "image",
+                    "inputUuid": image,
+                    "inputKind": "image_source",
+                    "unversionedInputKind": "image_source",
+                },
+                {
+                    "inputName": "music",
+                    "inputUuid": music,
+                    "inputKind": "ffmpeg_source",
+                    "unversionedInputKind": "ffmpeg_source",
+                },
+            ],
+        }),
+    );
+
+    let expect_audio = |uuid: Uuid, muted: bool, db: f32, track_1: bool, active: bool| {
+        server.expect(
+            "GetInputMute",
+            json!({"inputUuid": uuid}),
+            json!({"inputMuted": muted}),
+        );
+        server.expect(
+            "GetInputVolume",
+            json!({"inputUuid": uuid}),
+            json!({"inputVolumeMul": 1.0, "inputVolumeDb": db}),
+        );
+        server.expect(
+            "GetInputAudioTracks",
+            json!({"inputUuid": uuid}),
+            json!({
+                "inputAudioTracks": {
+                    "1": track_1,
+                    "2": true,
+                    "3": false,
+                    "4": false,
+                    "5": false,
+                    "6": false,
+                },
+            }),
+        );
+        server.expect(
+            "GetSourceActive",
+            json!({"sourceUuid": uuid}),
+            json!({"videoActive": active, "videoShowing": active}),
+        );
+    };
+
+    expect_audio(mic, false, 0.0, true, true);
+    // All requests are part of a single batch, so they're sent for inputs without audio as well.
+    for request in ["GetInputMute", "GetInputVolume", "GetInputAudioTracks"] {
+        server.expect_failure(
+            request,
+            json!({"inputUuid": image}),
+            StatusCode::InvalidResourceState,
+        );
+    }
+    server.expect(
+        "GetSourceActive",
+        json!({"sourceUuid": image}),
+        json!({"videoActive": true, "videoShowing": true}),
+    );
+    expect_audio(music, true, -80.0, false, false);
+
+    let mix = audio::program_mix(&client, ProgramMixOptions::default()).await?;
+
+    assert_eq!(2, mix.inputs.len());
+    assert_eq!(
+        vec!["mic"],
+        mix.audible()
+            .map(|input| input.id.name.as_str())
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![
+            Silenced::Inactive,
+            Silenced::Muted,
+            Silenced::LowVolume,
+            Silenced::NotOnTrack,
+        ],
+        mix.inputs[1].silenced_by
+    );
+
+    server.stop().await
+}
+
+#[cfg(feature = "events")]
+#[test(tokio::test)]
+async fn ducking_restores_volumes() -> Result<()> {
+    use std::time::Duration;
+
+    use obws::{
+        ducking::Ducking,
+        events::{Event, InputVolumeMeter},
+        requests::inputs::InputId,
+    };
+    use tokio::sync::oneshot;
+
+    let (client, server) = common::new_client().await?;
+    let (stop_tx, stop_rx) = oneshot::channel();
+
+    server.expect(
+        "GetInputVolume",
+        json!({"inputName": "Music"}),
+        json!({"inputVolumeMul": 0.5, "inputVolumeDb": -6.0}),
+    );
+    server.expect(
+        "SetInputVolume",
+        json!({"inputName": "Music", "inputVolumeDb": -18.0}),
+        json!(null),
+    );
+    // The original volume is restored when stopping, while still ducked.
+    server.expect(
+        "SetInputVolume",
+        json!({"inputName": "Music", "inputVolumeDb": -6.0}),
+        json!(null),
+    );
+
+    let ducking = Ducking {
+        attack: Duration::ZERO,
+        ..Ducking::new("Mic", vec!["Music".to_owned()])
+    };
+    let control = async {
+        server.send_event(Event::InputVolumeMeters {
+            inputs: vec![InputVolumeMeter {
+                name: "Mic".to_owned(),
+                levels: vec![[1.0, 1.0, 1.0]],
+            }],
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        stop_tx.send(()).ok();
+    };
+
+    let (result, ()) = tokio::join!(
+        ducking.run_until(&client, async {
+            stop_rx.await.ok();
+        }),
+        control,
+    );
+    result?;
+
+    // Fails, if the original volume wasn't restored before, as the expectations are in order.
+    server.expect(
+        "GetInputVolume",
+        json!({"inputName": "Music"}),
+        json!({"inputVolumeMul": 0.5, "inputVolumeDb": -6.0}),
+    );
+    assert_eq!(
+        -6.0,
+        client.inputs().volume(InputId::Name("Music")).await?.db
+    );
+
+    server.stop().await
+}
@@ -1,8 +1,20 @@
+use std::time::Duration;
+
 use anyhow::Result;
-use obws::requests::EventSubscription;
+use obws::{
+    client::{
+        BroadcastGrowth, CacheConfig, CachedRequest, CloseReason, CoalescedRequest, ConnectConfig,
+        Priority, RequestSizeLimit, ServerProfile, UnexpectedResponse, UnexpectedResponsePolicy,
+    },
+    error::Error,
+    requests::EventSubscription,
+    Client,
+};
+use serde_json::json;
 use test_log::test;
+use tokio::net::TcpStream;
 
-use crate::common;
+use crate::common::{self, MockServer};
 
 #[test(tokio::test)]
 async fn client() -> Result<()> {
@@ -12,3 +24,1160 @@ async fn client() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn connect_url() -> Result<()> {
+    let (server, port) = MockServer::start().await?;
+    let client = Client::connect_url(
+        format!("ws://localhost:{port}/"),
+        ConnectConfig {
+            server_profile: ServerProfile::Emulator,
+            ..common::connect_config(0)
+        },
+    )
+    .await?;
+
+    // The rest of the configuration is applied as usual.
+    assert_eq!(ServerProfile::Emulator, client.server_profile());
+
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": true}),
+    );
+    assert!(client.ui().studio_mode_enabled().await?);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn authentication_failed() -> Result<()> {
+    use obws::error::AuthenticationFailure;
+
+    for (password, expected) in [
+        (
+            Some("wrong-password"),
+            AuthenticationFailure::PasswordRejected,
+        ),
+        (None, AuthenticationFailure::MissingPassword),
+    ] {
+        let (server, port) = MockServer::start().await?;
+        let result = Client::connect("localhost", port, password).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::AuthenticationFailed { kind, details })
+                if kind == expected && u16::from(details.code) == 4009
+        ));
+        assert!(server.stop().await.is_err());
+    }
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn connect_with_stream() -> Result<()> {
+    let (server, port) = MockServer::start().await?;
+    let stream = TcpStream::connect(("localhost", port)).await?;
+    let client = Client::connect_with_stream(stream, common::connect_config(port)).await?;
+
+    client.reidentify(EventSubscription::ALL).await?;
+
+    server.stop().await
+}
+
+#[cfg(feature = "msgpack")]
+#[test(tokio::test)]
+async fn msgpack() -> Result<()> {
+    let (server, port) = MockServer::start().await?;
+    let client = Client::connect_with_config(ConnectConfig {
+        codec: obws::client::Codec::MsgPack,
+        ..common::connect_config(port)
+    })
+    .await?;
+
+    server.expect(
+        "GetRecordDirectory",
+        json!(null),
+        json!({"recordDirectory": "/tmp/recordings"}),
+    );
+    server.expect(
+        "SetRecordDirectory",
+        json!({"recordDirectory": "/tmp/other"}),
+        json!(null),
+    );
+
+    let config = client.config();
+    assert_eq!("/tmp/recordings", config.record_directory().await?);
+    config.set_record_directory("/tmp/other").await?;
+
+    client.reidentify(EventSubscription::ALL).await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn request_size_limit() -> Result<()> {
+    let (server, port) = MockServer::start().await?;
+    let client = Client::connect_with_config(ConnectConfig {
+        request_size_limit: Some(RequestSizeLimit::Reject(1024)),
+        ..common::connect_config(port)
+    })
+    .await?;
+
+    let result = client
+        .general()
+        .broadcast_custom_event(&json!({"css": "a".repeat(2048)}))
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::RequestTooLarge { limit: 1024, .. })
+    ));
+
+    server.expect(
+        "BroadcastCustomEvent",
+        json!({"eventData": {"css": "small"}}),
+        json!(null),
+    );
+
+    client
+        .general()
+        .broadcast_custom_event(&json!({"css": "small"}))
+        .await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn shutdown() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let hotkeys = client.hotkeys();
+
+    let (pending, result) = tokio::join!(hotkeys.list(), async {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        let (result, rejected) = tokio::join!(client.shutdown(Duration::from_secs(5)), async {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let rejected = client.scenes().list().await;
+            server.expect("GetHotkeyList", json!(null), json!({"hotkeys": []}));
+            rejected
+        });
+
+        assert!(matches!(rejected, Err(Error::Disconnected)));
+        result
+    });
+
+    assert!(pending?.is_empty());
+    result?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn probe() -> Result<()> {
+    let (_server, port) = MockServer::start().await?;
+    let info = obws::probe(common::connect_config(port)).await?;
+
+    assert_eq!(semver::Version::new(5, 5, 0), info.obs_web_socket_version);
+    assert_eq!(1, info.rpc_version);
+    assert!(info.authentication_required);
+
+    Ok(())
+}
+
+#[cfg(feature = "testing")]
+#[test(tokio::test)]
+async fn record_and_replay() -> Result<()> {
+    use std::{
+        io::{self, Cursor, Write},
+        sync::{Arc, Mutex},
+    };
+
+    use obws::{
+        responses::StatusCode,
+        testing::{self, Recorder},
+    };
+    use uuid::Uuid;
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let (server, port) = MockServer::start().await?;
+    let buf = SharedBuf::default();
+    let client = testing::record(common::connect_config(port), Recorder::new(buf.clone())).await?;
+
+    server.expect(
+        "GetSceneList",
+        json!(null),
+        json!({
+            "currentProgramSceneName": "main",
+            "currentProgramSceneUuid": Uuid::new_v8([1; 16]),
+            "currentPreviewSceneName": null,
+            "currentPreviewSceneUuid": null,
+            "scenes": [{
+                "sceneName": "main",
+                "sceneUuid": Uuid::new_v8([1; 16]),
+                "sceneIndex": 0,
+            }],
+        }),
+    );
+
+    let recorded = client.scenes().list().await?;
+    drop(client);
+    server.stop().await?;
+
+    let recording = buf.0.lock().unwrap().clone();
+    let client = testing::replay(Cursor::new(recording)).await?;
+
+    assert_eq!(recorded, client.scenes().list().await?);
+    assert!(matches!(
+        client.scenes().list().await,
+        Err(Error::Api {
+            code: StatusCode::GenericError,
+            ..
+        })
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "capi")]
+#[test(tokio::test)]
+async fn capi() -> Result<()> {
+    use std::ffi::{CStr, CString};
+
+    use obws::{capi, events::Event};
+
+    let (server, port) = MockServer::start().await?;
+
+    server.expect(
+        "SetCurrentProgramScene",
+        json!({"sceneName": "main"}),
+        json!(null),
+    );
+    server.expect(
+        "SetInputMute",
+        json!({"inputName": "mic", "inputMuted": true}),
+        json!(null),
+    );
+    server.send_event(Event::CustomEvent(json!({"hello": "world!"})));
+
+    // The C API runs its own runtime, so it must be used from outside of the test's runtime.
+    let event = std::thread::spawn(move || unsafe {
+        let host = CString::new("localhost").unwrap();
+        let password = CString::new("mock-password").unwrap();
+        let scene = CString::new("main").unwrap();
+        let input = CString::new("mic").unwrap();
+
+        let client = capi::obws_connect(host.as_ptr(), port, password.as_ptr());
+        assert!(!client.is_null());
+
+        assert_eq!(
+            0,
+            capi::obws_set_current_program_scene(client, scene.as_ptr())
+        );
+        assert_eq!(0, capi::obws_set_input_muted(client, input.as_ptr(), true));
+
+        let event = loop {
+            let event = capi::obws_poll_event(client);
+            if !event.is_null() {
+                break event;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        };
+        let json = CStr::from_ptr(event).to_str().unwrap().to_owned();
+
+        capi::obws_string_free(event);
+        capi::obws_disconnect(client);
+        json
+    });
+
+    let event = tokio::task::spawn_blocking(move || event.join().unwrap()).await?;
+    assert_eq!(
+        json!({"eventType": "CustomEvent", "eventData": {"hello": "world!"}}),
+        serde_json::from_str::<serde_json::Value>(&event)?
+    );
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn coverage_report() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+
+    server.expect(
+        "GetVersion",
+        json!(null),
+        json!({
+            "obsVersion": "31.0.0",
+            "obsWebSocketVersion": "5.5.0",
+            "rpcVersion": 1,
+            "availableRequests": ["GetVersion", "GetStats", "NewRequest", "AnotherRequest"],
+            "supportedImageFormats": [],
+            "platform": "mock",
+            "platformDescription": "",
+        }),
+    );
+
+    let report = client.coverage_report().await?;
+
+    assert_eq!(vec!["AnotherRequest", "NewRequest"], report.missing_in_obws);
+    assert!(report.missing_in_server.contains(&"GetSceneList"));
+    assert!(!report.missing_in_server.contains(&"GetVersion"));
+    assert!(!report.missing_in_server.contains(&"GetStats"));
+
+    server.stop().await
+}
+
+#[cfg(feature = "events")]
+#[test(tokio::test)]
+async fn events_for() -> Result<()> {
+    use futures_util::StreamExt;
+    use obws::events::Event;
+
+    let (client, server) = common::new_client().await?;
+    let events = client.events_for(EventSubscription::UI)?;
+    tokio::pin!(events);
+
+    server.send_event(Event::CustomEvent(json!({"hello": "world!"})));
+    server.send_event(Event::ScreenshotSaved {
+        path: "screenshot.png".to_owned(),
+    });
+
+    assert_eq!(
+        Some(Event::ScreenshotSaved {
+            path: "screenshot.png".to_owned(),
+        }),
+        events.next().await
+    );
+
+    server.stop().await?;
+
+    assert_eq!(Some(Event::ServerStopped), events.next().await);
+
+    Ok(())
+}
+
+#[cfg(feature = "events")]
+#[test(tokio::test)]
+async fn dispatch() -> Result<()> {
+    use obws::{dispatch::kinds::ScreenshotSaved, events::Event};
+    use tokio::sync::mpsc;
+
+    let (client, server) = common::new_client().await?;
+    let (tx, mut rx) = mpsc::unbounded_channel();
+
+    let registration = client.on::<ScreenshotSaved>(move |event| {
+        let tx = tx.clone();
+        async move {
+            tx.send(event).ok();
+        }
+    })?;
+    assert!(registration.is_active());
+
+    server.send_event(Event::CustomEvent(json!({"hello": "world!"})));
+    server.send_event(Event::ScreenshotSaved {
+        path: "screenshot.png".to_owned(),
+    });
+
+    assert_eq!(
+        Some(Event::ScreenshotSaved {
+            path: "screenshot.png".to_owned(),
+        }),
+        rx.recv().await.as_deref().cloned()
+    );
+
+    registration.unregister();
+    server.send_event(Event::ScreenshotSaved {
+        path: "other.png".to_owned(),
+    });
+
+    // The handler, and with it the sender, is dropped once unregistered.
+    assert_eq!(None, rx.recv().await);
+
+    server.stop().await
+}
+
+#[cfg(feature = "events")]
+#[test(tokio::test)]
+async fn events_shared() -> Result<()> {
+    use std::sync::Arc;
+
+    use futures_util::StreamExt;
+    use obws::events::Event;
+
+    let (client, server) = common::new_client().await?;
+    let first = client.events_shared()?;
+    let second = client.events_for_shared(EventSubscription::UI)?;
+    tokio::pin!(first, second);
+
+    server.send_event(Event::CustomEvent(json!({"hello": "world!"})));
+    server.send_event(Event::ScreenshotSaved {
+        path: "screenshot.png".to_owned(),
+    });
+
+    assert_eq!(
+        Some(Event::CustomEvent(json!({"hello": "world!"}))),
+        first.next().await.as_deref().cloned()
+    );
+
+    let (Some(first), Some(second)) = (first.next().await, second.next().await) else {
+        panic!("expected an event on both streams");
+    };
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(
+        Event::ScreenshotSaved {
+            path: "screenshot.png".to_owned(),
+        },
+        *first
+    );
+
+    server.stop().await
+}
+
+#[cfg(feature = "events")]
+#[test(tokio::test)]
+async fn events_with_lag() -> Result<()> {
+    use futures_util::StreamExt;
+    use obws::events::{Event, EventOrLag};
+
+    let (server, port) = MockServer::start().await?;
+    let client = Client::connect_with_config(ConnectConfig {
+        broadcast_capacity: 1,
+        ..common::connect_config(port)
+    })
+    .await?;
+
+    let events = client.events_with_lag()?;
+    tokio::pin!(events);
+    // Separate listener with its own channel, to know when all events were received.
+    let sync = client.events_for(EventSubscription::UI)?;
+    tokio::pin!(sync);
+
+    server.send_event(Event::CustomEvent(json!({"hello": "world!"})));
+    server.send_event(Event::ScreenshotSaved {
+        path: "screenshot.png".to_owned(),
+    });
+    crate::wait_for!(sync, Event::ScreenshotSaved { .. });
+    server.stop().await?;
+    crate::wait_for!(sync, Event::ServerStopped);
+
+    assert!(matches!(events.next().await, Some(EventOrLag::Lagged(count)) if count > 0));
+    assert_eq!(
+        Some(EventOrLag::Event(Event::ServerStopped)),
+        events.next().await
+    );
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn broadcast_growth() -> Result<()> {
+    use futures_util::StreamExt;
+    use obws::events::{Event, EventOrLag};
+
+    let (server, port) = MockServer::start().await?;
+    let client = Client::connect_with_config(ConnectConfig {
+        broadcast_capacity: 1,
+        broadcast_growth: BroadcastGrowth::Grow { max_capacity: 16 },
+        ..common::connect_config(port)
+    })
+    .await?;
+
+    let events = client.events_with_lag()?;
+    tokio::pin!(events);
+    // Separate listener with its own channel, to know when all events were received.
+    let sync = client.events_for(EventSubscription::UI)?;
+    tokio::pin!(sync);
+
+    let screenshot = || Event::ScreenshotSaved {
+        path: "screenshot.png".to_owned(),
+    };
+
+    server.send_event(Event::CustomEvent(json!({"hello": "world!"})));
+    server.send_event(screenshot());
+    crate::wait_for!(sync, Event::ScreenshotSaved { .. });
+
+    assert_eq!(Some(EventOrLag::Lagged(1)), events.next().await);
+    assert_eq!(Some(EventOrLag::Event(screenshot())), events.next().await);
+
+    let stats = client.event_lag_stats()?;
+    assert_eq!(
+        (1, 1, 2),
+        (stats.lagged, stats.dropped_events, stats.capacity)
+    );
+
+    // The grown channel holds both events, and the listener moved over to it.
+    server.send_event(Event::CustomEvent(json!({"hello": "again!"})));
+    server.send_event(screenshot());
+    crate::wait_for!(sync, Event::ScreenshotSaved { .. });
+
+    assert_eq!(
+        Some(EventOrLag::Event(Event::CustomEvent(
+            json!({"hello": "again!"})
+        ))),
+        events.next().await
+    );
+    assert_eq!(Some(EventOrLag::Event(screenshot())), events.next().await);
+
+    server.stop().await?;
+    crate::wait_for!(sync, Event::ServerStopped);
+
+    assert_eq!(
+        Some(EventOrLag::Event(Event::ServerStopped)),
+        events.next().await
+    );
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn closed() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+
+    assert_eq!(None, client.close_reason());
+
+    server.stop().await?;
+
+    assert_eq!(CloseReason::Disconnected, client.closed().await);
+    assert_eq!(Some(CloseReason::Disconnected), client.close_reason());
+
+    Ok(())
+}
+
+#[test(tokio::test)]
+async fn request_timeout() -> Result<()> {
+    use obws::client;
+
+    let (server, port) = MockServer::start().await?;
+    let client = Client::connect_with_config(ConnectConfig {
+        request_timeout: Some(Duration::from_millis(50)),
+        ..common::connect_config(port)
+    })
+    .await?;
+
+    // The mock server only responds to requests, once an expectation was registered.
+    assert!(matches!(
+        client.ui().studio_mode_enabled().await,
+        Err(Error::Timeout)
+    ));
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": true}),
+    );
+
+    assert!(matches!(
+        client::with_timeout(Duration::from_millis(10), client.ui().studio_mode_enabled()).await,
+        Err(Error::Timeout)
+    ));
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": true}),
+    );
+
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": true}),
+    );
+    assert!(client.ui().studio_mode_enabled().await?);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn session_journal() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.with_session_journal();
+
+    server.expect(
+        "SetCurrentProgramScene",
+        json!({"sceneName": "one"}),
+        json!(null),
+    );
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": false}),
+    );
+    server.expect(
+        "SetCurrentProgramScene",
+        json!({"sceneName": "two"}),
+        json!(null),
+    );
+
+    client.scenes().set_current_program_scene("one").await?;
+    client.ui().studio_mode_enabled().await?;
+    client.scenes().set_current_program_scene("two").await?;
+
+    let changes = client.session_changes();
+    assert_eq!(2, changes.len());
+    assert!(changes
+        .iter()
+        .all(|change| change.request_type == "SetCurrentProgramScene"));
+    assert_eq!(
+        json!({"sceneName": "one"}),
+        json!(changes[1].old.as_ref().unwrap())
+    );
+    assert_eq!(json!({"sceneName": "two"}), json!(changes[1].new));
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn server_profile() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    assert_eq!(ServerProfile::Standard, client.server_profile());
+    server.stop().await?;
+
+    let (server, port) = MockServer::start().await?;
+    let client = Client::connect_with_config(ConnectConfig {
+        server_profile: ServerProfile::Emulator,
+        ..common::connect_config(port)
+    })
+    .await?;
+    assert_eq!(ServerProfile::Emulator, client.server_profile());
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn unexpected_responses() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+
+    server.expect_duplicate(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": true}),
+    );
+    assert!(client.ui().studio_mode_enabled().await?);
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": false}),
+    );
+    assert!(!client.ui().studio_mode_enabled().await?);
+
+    let stats = client.connection_stats();
+    assert_eq!(1, stats.duplicate_responses);
+    assert_eq!(0, stats.orphan_responses);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn unexpected_responses_error() -> Result<()> {
+    let (server, port) = MockServer::start().await?;
+    let client = Client::connect_with_config(ConnectConfig {
+        request_timeout: Some(Duration::from_millis(50)),
+        unexpected_responses: UnexpectedResponsePolicy::Error,
+        ..common::connect_config(port)
+    })
+    .await?;
+
+    // The response arrives after the request timed out, so nobody waits for it anymore.
+    assert!(matches!(
+        client.ui().studio_mode_enabled().await,
+        Err(Error::Timeout)
+    ));
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": true}),
+    );
+
+    assert_eq!(
+        CloseReason::UnexpectedResponse(UnexpectedResponse::Orphan),
+        client.closed().await
+    );
+    assert_eq!(1, client.connection_stats().orphan_responses);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn request_cancellation() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+
+    let ui = client.ui();
+    tokio::select! {
+        _ = ui.studio_mode_enabled() => panic!("request must not be answered yet"),
+        () = tokio::time::sleep(Duration::from_millis(20)) => {}
+    }
+    assert_eq!(0, client.connection_stats().pending_requests);
+
+    // The late response of the canceled request doesn't affect the following ones.
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": false}),
+    );
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": true}),
+    );
+
+    assert!(client.ui().studio_mode_enabled().await?);
+    assert_eq!(0, client.connection_stats().pending_requests);
+    assert_eq!(1, client.connection_stats().orphan_responses);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn health() -> Result<()> {
+    use obws::responses::StatusCode;
+    use uuid::Uuid;
+
+    let (client, server) = common::new_client().await?;
+
+    server.expect(
+        "GetStreamStatus",
+        json!(null),
+        json!({
+            "outputActive": true,
+            "outputReconnecting": false,
+            "outputTimecode": "00:10:00.000",
+            "outputDuration": 600_000,
+            "outputCongestion": 0.1,
+            "outputBytes": 4096,
+            "outputSkippedFrames": 0,
+            "outputTotalFrames": 36_000,
+        }),
+    );
+    server.expect(
+        "GetRecordStatus",
+        json!(null),
+        json!({
+            "outputActive": false,
+            "outputPaused": false,
+            "outputTimecode": "00:00:00.000",
+            "outputDuration": 0,
+            "outputBytes": 0,
+        }),
+    );
+    server.expect(
+        "GetVirtualCamStatus",
+        json!(null),
+        json!({"outputActive": true}),
+    );
+    server.expect_failure(
+        "GetReplayBufferStatus",
+        json!(null),
+        StatusCode::InvalidResourceState,
+    );
+    server.expect(
+        "GetCurrentProgramScene",
+        json!(null),
+        json!({"sceneName": "Live", "sceneUuid": Uuid::nil()}),
+    );
+    server.expect(
+        "GetStats",
+        json!(null),
+        json!({
+            "cpuUsage": 0.5,
+            "memoryUsage": 200,
+            "availableDiskSpace": 30_000_000,
+            "activeFps": 59.99,
+            "averageFrameRenderTime": 5,
+            "renderSkippedFrames": 0,
+            "renderTotalFrames": 10_000,
+            "outputSkippedFrames": 0,
+            "outputTotalFrames": 8_000,
+            "webSocketSessionIncomingMessages": 10,
+            "webSocketSessionOutgoingMessages": 10,
+        }),
+    );
+
+    let health = client.health().await?;
+    assert!(health.stream.active);
+    assert!(!health.record.active);
+    assert!(health.virtual_cam);
+    assert_eq!(None, health.replay_buffer);
+    assert_eq!("Live", health.program_scene.name);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn batch() -> Result<()> {
+    use obws::requests::batch::{BatchExecution, BatchRequest};
+
+    let (client, server) = common::new_client().await?;
+
+    server.expect(
+        "GetSceneItemId",
+        json!({"sceneName": "Scene", "sourceName": "Camera"}),
+        json!({"sceneItemId": 7}),
+    );
+    server.expect(
+        "SetSceneItemEnabled",
+        json!({"sceneName": "Scene", "sceneItemId": 7, "sceneItemEnabled": true}),
+        json!(null),
+    );
+
+    let results = client
+        .batch(
+            [
+                BatchRequest::new("GetSceneItemId")
+                    .data(json!({"sceneName": "Scene", "sourceName": "Camera"}))
+                    .output("cameraId", "sceneItemId"),
+                BatchRequest::new("SetSceneItemEnabled")
+                    .data(json!({"sceneName": "Scene", "sceneItemEnabled": true}))
+                    .input("sceneItemId", "cameraId"),
+            ],
+            BatchExecution::SerialRealtime,
+        )
+        .await?;
+
+    assert_eq!(vec![json!({"sceneItemId": 7}), json!(null)], results);
+
+    server.expect(
+        "SetCurrentProgramScene",
+        json!({"sceneName": "Outro"}),
+        json!(null),
+    );
+    server.expect("Sleep", json!({"sleepFrames": 30}), json!(null));
+    server.expect(
+        "SetSourceFilterEnabled",
+        json!({"sourceName": "Outro", "filterName": "Fade", "filterEnabled": true}),
+        json!(null),
+    );
+
+    let sequence = [
+        BatchRequest::new("SetCurrentProgramScene").data(json!({"sceneName": "Outro"})),
+        BatchRequest::sleep_frames(30),
+        BatchRequest::new("SetSourceFilterEnabled").data(json!({
+            "sourceName": "Outro",
+            "filterName": "Fade",
+            "filterEnabled": true,
+        })),
+    ];
+
+    assert!(matches!(
+        client
+            .batch(sequence.clone(), BatchExecution::SerialRealtime)
+            .await,
+        Err(Error::InvalidBatchSleep(BatchExecution::SerialRealtime))
+    ));
+    client.batch(sequence, BatchExecution::SerialFrame).await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn send_custom() -> Result<()> {
+    #[derive(serde::Deserialize)]
+    struct SceneItemId {
+        #[serde(rename = "sceneItemId")]
+        id: i64,
+    }
+
+    let (client, server) = common::new_client().await?;
+
+    server.expect(
+        "GetSceneItemId",
+        json!({"sceneName": "Scene", "sourceName": "Camera"}),
+        json!({"sceneItemId": 7}),
+    );
+
+    let item = client
+        .send_custom::<SceneItemId>(
+            "GetSceneItemId",
+            json!({"sceneName": "Scene", "sourceName": "Camera"}),
+        )
+        .await?;
+    assert_eq!(7, item.id);
+
+    server.expect("StartVirtualCam", json!(null), json!(null));
+
+    client.send_custom::<()>("StartVirtualCam", ()).await?;
+
+    assert!(matches!(
+        client.send_custom::<()>("StartVirtualCam", 5).await,
+        Err(Error::InvalidCustomData)
+    ));
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn wait_until_ready() -> Result<()> {
+    use obws::responses::StatusCode;
+
+    let (client, server) = common::new_client().await?;
+
+    server.expect_failure("GetSceneList", json!(null), StatusCode::NotReady);
+    server.expect_failure("GetSceneList", json!(null), StatusCode::NotReady);
+    server.expect(
+        "GetSceneList",
+        json!(null),
+        json!({
+            "currentProgramSceneName": null,
+            "currentProgramSceneUuid": null,
+            "currentPreviewSceneName": null,
+            "currentPreviewSceneUuid": null,
+            "scenes": [],
+        }),
+    );
+
+    client.wait_until_ready(Duration::from_secs(5)).await?;
+
+    server.expect_failure("GetSceneList", json!(null), StatusCode::NotReady);
+
+    assert!(matches!(
+        client.wait_until_ready(Duration::ZERO).await,
+        Err(Error::Timeout)
+    ));
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn connect_lazy() -> Result<()> {
+    let (server, port) = MockServer::start().await?;
+    let lazy = Client::connect_lazy(common::connect_config(port));
+
+    assert!(lazy.get().is_none());
+
+    // The mock server only accepts a single connection, so both callers must share it.
+    let (first, second) = tokio::join!(lazy.client(), lazy.client());
+    assert!(std::ptr::eq(first?, second?));
+
+    lazy.client()
+        .await?
+        .reidentify(EventSubscription::ALL)
+        .await?;
+    assert!(lazy.get().is_some());
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn connect_lazy_refused() -> Result<()> {
+    use std::sync::Arc;
+
+    // Reserve a free port and release it again, so nothing listens on it.
+    let port = std::net::TcpListener::bind("localhost:0")?
+        .local_addr()?
+        .port();
+    let lazy = Client::connect_lazy(common::connect_config(port));
+
+    // Concurrent callers share the outcome of a single, failed attempt.
+    let (first, second) = tokio::join!(lazy.client(), lazy.client());
+    let (Err(Error::LazyConnect(first)), Err(Error::LazyConnect(second))) = (first, second) else {
+        panic!("expected both callers to fail");
+    };
+    assert!(Arc::ptr_eq(&first, &second));
+
+    // Later calls start a new attempt.
+    let Err(Error::LazyConnect(third)) = lazy.client().await else {
+        panic!("expected the new attempt to fail");
+    };
+    assert!(!Arc::ptr_eq(&first, &third));
+    assert!(lazy.get().is_none());
+
+    Ok(())
+}
+
+#[cfg(feature = "events")]
+#[test(tokio::test)]
+async fn cache() -> Result<()> {
+    use futures_util::StreamExt;
+    use obws::{events::Event, responses::scenes::SceneId};
+    use uuid::Uuid;
+
+    let (client, server) = common::new_client().await?;
+    let client = client
+        .with_cache(CacheConfig::new().ttl(CachedRequest::SceneList, Duration::from_secs(60)));
+    let events = client.events()?;
+    tokio::pin!(events);
+
+    let scene_list = |scenes: &[&str]| {
+        server.expect(
+            "GetSceneList",
+            json!(null),
+            json!({
+                "currentProgramSceneName": null,
+                "currentProgramSceneUuid": null,
+                "currentPreviewSceneName": null,
+                "currentPreviewSceneUuid": null,
+                "scenes": scenes
+                    .iter()
+                    .enumerate()
+                    .map(|(index, name)| json!({
+                        "sceneName": name,
+                        "sceneUuid": Uuid::new_v8([1; 16]),
+                        "sceneIndex": index,
+                    }))
+                    .collect::<Vec<_>>(),
+            }),
+        );
+    };
+
+    scene_list(&[]);
+    assert!(client.scenes().list().await?.scenes.is_empty());
+
+    // The next response is only used once the cached one is invalidated.
+    scene_list(&["new"]);
+    assert!(client.scenes().list().await?.scenes.is_empty());
+
+    let created = Event::SceneCreated {
+        id: SceneId {
+            name: "new".to_owned(),
+            uuid: Uuid::new_v8([1; 16]),
+        },
+        is_group: false,
+    };
+    server.send_event(created.clone());
+    assert_eq!(Some(created), events.next().await);
+
+    assert_eq!(1, client.scenes().list().await?.scenes.len());
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn latest_wins() -> Result<()> {
+    use futures_util::future;
+    use obws::requests::inputs::{InputId, Volume};
+
+    let (client, server) = common::new_client().await?;
+    let client = client.with_latest_wins([CoalescedRequest::InputVolume]);
+
+    for (input, db) in [("mic", -10.0), ("desktop", -20.0), ("mic", -4.0)] {
+        server.expect(
+            "SetInputVolume",
+            json!({"inputName": input, "inputVolumeDb": db}),
+            json!(null),
+        );
+    }
+
+    // Only the first and last update for the microphone are sent, the others are dropped while
+    // the first one is in flight.
+    let updates = [
+        ("mic", -10.0),
+        ("mic", -8.0),
+        ("desktop", -20.0),
+        ("mic", -6.0),
+        ("mic", -4.0),
+    ];
+    let inputs = client.inputs();
+    let results = future::join_all(
+        updates
+            .into_iter()
+            .map(|(input, db)| inputs.set_volume(InputId::Name(input), Volume::Db(db))),
+    )
+    .await;
+
+    assert!(results.into_iter().all(|result| result.is_ok()));
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn priority() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+
+    assert_eq!(Priority::Background, Priority::current());
+
+    server.expect(
+        "SetCurrentProgramScene",
+        json!({"sceneName": "Live"}),
+        json!(null),
+    );
+
+    Priority::Interactive
+        .scope(async {
+            assert_eq!(Priority::Interactive, Priority::current());
+            client.scenes().set_current_program_scene("Live").await
+        })
+        .await?;
+
+    // Both requests are queued before the writer gets to run, and the interactive one skips
+    // ahead of the background one, even though it was queued last.
+    server.expect(
+        "SetCurrentProgramScene",
+        json!({"sceneName": "Interactive"}),
+        json!(null),
+    );
+    server.expect(
+        "SetCurrentPreviewScene",
+        json!({"sceneName": "Background"}),
+        json!(null),
+    );
+
+    let (background, interactive) = futures_util::future::join(
+        client.scenes().set_current_preview_scene("Background"),
+        Priority::Interactive.scope(client.scenes().set_current_program_scene("Interactive")),
+    )
+    .await;
+    background?;
+    interactive?;
+
+    server.stop().await
+}
+
+#[cfg(feature = "testing")]
+#[test(tokio::test)]
+async fn stub() -> Result<()> {
+    use futures_util::StreamExt;
+    use obws::{events::Event, responses::StatusCode, testing::Stub};
+
+    let stub = Stub::new();
+    stub.respond("GetStudioModeEnabled", json!({"studioModeEnabled": true}));
+    stub.respond("GetStudioModeEnabled", json!({"studioModeEnabled": false}));
+    stub.fail("GetSceneList", StatusCode::NotReady, Some("loading"));
+
+    let client = stub.connect().await?;
+    let events = client.events()?;
+    tokio::pin!(events);
+
+    assert!(client.ui().studio_mode_enabled().await?);
+    assert!(!client.ui().studio_mode_enabled().await?);
+    assert!(!client.ui().studio_mode_enabled().await?);
+    assert!(client
+        .scenes()
+        .list()
+        .await
+        .is_err_and(|e| e.is_retryable()));
+    assert!(matches!(
+        client.hotkeys().list().await,
+        Err(Error::Api {
+            code: StatusCode::GenericError,
+            ..
+        })
+    ));
+
+    client.ui().set_studio_mode_enabled(true).await.unwrap_err();
+    stub.respond("SetStudioModeEnabled", json!(null));
+    client.ui().set_studio_mode_enabled(true).await?;
+
+    let received = stub.received();
+    assert_eq!("GetVersion", received[0].0);
+    assert_eq!(
+        (
+            "SetStudioModeEnabled".to_owned(),
+            json!({"studioModeEnabled": true})
+        ),
+        received[received.len() - 1]
+    );
+
+    stub.emit(&Event::ExitStarted);
+    assert_eq!(Some(Event::ExitStarted), events.next().await);
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
-use std::net::Ipv4Addr;
+use std::{collections::HashMap, net::Ipv4Addr};
 
 use anyhow::{bail, ensure, Context, Result};
 use base64::{engine::general_purpose, Engine};
 use futures_util::{SinkExt, StreamExt};
 use obws::{
+    client::{
+        BroadcastGrowth, ConnectConfig, ServerProfile, UnexpectedResponsePolicy,
+        DEFAULT_BROADCAST_CAPACITY, DEFAULT_CONNECT_TIMEOUT,
+    },
     events::Event,
     requests::{inputs::InputId, scenes::SceneId, EventSubscription},
     responses::StatusCode,
@@ -20,7 +24,13 @@ use tokio::{
     task::JoinHandle,
 };
 use tokio_tungstenite::{
-    tungstenite::{self, Message},
+    tungstenite::{
+        self,
+        handshake::server,
+        http::{header::SEC_WEBSOCKET_PROTOCOL, HeaderValue},
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
+    },
     WebSocketStream,
 };
 use tracing::{debug, error, info};
@@ -49,6 +59,32 @@ pub async fn new_client() -> Result<(Client, MockServer)> {
     Ok((client, server))
 }
 
+/// Configuration to connect to the mock server on the given port, with default values for all
+/// other settings. Tests only override the settings they're interested in.
+pub fn connect_config(port: u16) -> ConnectConfig<&'static str, &'static str> {
+    ConnectConfig {
+        host: "localhost",
+        port,
+        url: None,
+        dangerous: None,
+        password: Some("mock-password"),
+        event_subscriptions: None,
+        #[cfg(any(feature = "tls-rustls", feature = "tls-native"))]
+        tls: false,
+        #[cfg(feature = "tls-rustls")]
+        tls_config: None,
+        broadcast_capacity: DEFAULT_BROADCAST_CAPACITY,
+        broadcast_growth: BroadcastGrowth::default(),
+        connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+        request_size_limit: None,
+        restart_on_panic: false,
+        request_timeout: None,
+        unexpected_responses: UnexpectedResponsePolicy::default(),
+        server_profile: ServerProfile::default(),
+        codec: obws::client::Codec::default(),
+    }
+}
+
 #[macro_export]
 macro_rules! wait_for {
     ($expression:expr, $pattern:pat) => {{
@@ -81,22 +117,41 @@ impl MockServer {
 
         let handle = tokio::spawn(async move {
             let (stream, _) = listener.accept().await?;
-            let mut stream = tokio_tungstenite::accept_async(stream).await?;
-            debug!("connected");
+            let mut msgpack = false;
+            #[allow(clippy::result_large_err)]
+            let negotiate = |req: &server::Request, mut rsp: server::Response| {
+                let protocol = req.headers().get(SEC_WEBSOCKET_PROTOCOL);
+                if protocol.is_some_and(|p| p == MSGPACK_PROTOCOL) {
+                    rsp.headers_mut().insert(
+                        SEC_WEBSOCKET_PROTOCOL,
+                        HeaderValue::from_static(MSGPACK_PROTOCOL),
+                    );
+                    msgpack = true;
+                }
+                Ok(rsp)
+            };
+            let mut stream = tokio_tungstenite::accept_hdr_async(stream, negotiate).await?;
+            let codec = Codec { msgpack };
+            debug!(msgpack, "connected");
 
-            handshake(&mut stream).await?;
+            handshake(&mut stream, codec).await?;
             debug!("handshake done");
-            version_check(&mut stream).await?;
+            version_check(&mut stream, codec).await?;
             debug!("version check done");
 
             loop {
                 select! {
                     _ = &mut shutdown_rx => break,
                     Some(msg) = stream.next() => {
-                        handle_ws_message(&mut stream, &mut expect_rx, msg).await?;
+                        if matches!(msg, Ok(Message::Close(_))) {
+                            debug!("connection closed by client");
+                            stream.close(None).await.ok();
+                            break;
+                        }
+                        handle_ws_message(&mut stream, codec, &mut expect_rx, msg).await?;
                     }
                     Some(event) = event_rx.recv() => {
-                        handle_event(&mut stream, event).await?;
+                        handle_event(&mut stream, codec, event).await?;
                     }
                 }
             }
@@ -132,6 +187,39 @@ impl MockServer {
                 name: name.to_owned(),
                 req: serde_json::to_value(req).unwrap(),
                 rsp: serde_json::to_value(rsp).unwrap(),
+                status: Status::ok(),
+                duplicate: false,
+            })
+            .unwrap();
+    }
+
+    pub fn expect_duplicate<Req, Rsp>(&self, name: &str, req: Req, rsp: Rsp)
+    where
+        Req: Serialize,
+        Rsp: Serialize,
+    {
+        self.expectations
+            .send(Expectation {
+                name: name.to_owned(),
+                req: serde_json::to_value(req).unwrap(),
+                rsp: serde_json::to_value(rsp).unwrap(),
+                status: Status::ok(),
+                duplicate: true,
+            })
+            .unwrap();
+    }
+
+    pub fn expect_failure<Req>(&self, name: &str, req: Req, code: StatusCode)
+    where
+        Req: Serialize,
+    {
+        self.expectations
+            .send(Expectation {
+                name: name.to_owned(),
+                req: serde_json::to_value(req).unwrap(),
+                rsp: serde_json::Value::Null,
+                status: Status::failure(code),
+                duplicate: false,
             })
             .unwrap();
     }
@@ -145,9 +233,42 @@ struct Expectation {
     name: String,
     req: serde_json::Value,
     rsp: serde_json::Value,
+    status: Status,
+    duplicate: bool,
 }
 
-async fn handshake(stream: &mut WebSocketStream<TcpStream>) -> Result<()> {
+const MSGPACK_PROTOCOL: &str = "obswebsocket.msgpack";
+
+/// Encoding of the messages, as negotiated with the client.
+#[derive(Clone, Copy)]
+struct Codec {
+    msgpack: bool,
+}
+
+impl Codec {
+    fn encode(self, msg: &ServerMessage) -> Result<Message> {
+        #[cfg(feature = "msgpack")]
+        if self.msgpack {
+            return Ok(Message::binary(rmp_serde::to_vec_named(msg)?));
+        }
+
+        ensure!(!self.msgpack, "msgpack feature not enabled");
+        Ok(Message::text(serde_json::to_string(msg)?))
+    }
+
+    fn decode(self, msg: &Message) -> Result<ClientMessage> {
+        #[cfg(feature = "msgpack")]
+        if self.msgpack {
+            ensure!(msg.is_binary(), "expected binary message");
+            return Ok(rmp_serde::from_slice(&msg.clone().into_data())?);
+        }
+
+        ensure!(!self.msgpack, "msgpack feature not enabled");
+        Ok(serde_json::from_str(msg.to_text()?)?)
+    }
+}
+
+async fn handshake(stream: &mut WebSocketStream<TcpStream>, codec: Codec) -> Result<()> {
     let hello = ServerMessage::Hello(Hello {
         obs_web_socket_version: semver::Version::new(5, 5, 0),
         rpc_version: 1,
@@ -157,28 +278,31 @@ async fn handshake(stream: &mut WebSocketStream<TcpStream>) -> Result<()> {
         }),
     });
 
-    stream
-        .send(Message::text(serde_json::to_string(&hello)?))
-        .await?;
+    stream.send(codec.encode(&hello)?).await?;
 
     let identify = stream.next().await.context("no message from client")??;
-    let ClientMessage::Identify(identify) =
-        serde_json::from_str::<ClientMessage>(identify.to_text()?)?
-    else {
+    let ClientMessage::Identify(identify) = codec.decode(&identify)? else {
         bail!("unexpected client message");
     };
 
     ensure!(identify.rpc_version == 1);
     ensure!(identify.event_subscriptions == None);
-    verify_auth(&identify)?;
+    if let Err(e) = verify_auth(&identify) {
+        stream
+            .close(Some(CloseFrame {
+                code: CloseCode::Library(4009),
+                reason: "Authentication failed.".into(),
+            }))
+            .await
+            .ok();
+        return Err(e);
+    }
 
     let identified = ServerMessage::Identified(Identified {
         negotiated_rpc_version: 1,
     });
 
-    stream
-        .send(Message::text(serde_json::to_string(&identified)?))
-        .await?;
+    stream.send(codec.encode(&identified)?).await?;
 
     Ok(())
 }
@@ -198,9 +322,9 @@ fn verify_auth(identify: &Identify) -> Result<()> {
     Ok(())
 }
 
-async fn version_check(stream: &mut WebSocketStream<TcpStream>) -> Result<()> {
+async fn version_check(stream: &mut WebSocketStream<TcpStream>, codec: Codec) -> Result<()> {
     let request = stream.next().await.context("no message from client")??;
-    let request = serde_json::from_str::<ClientMessage>(request.to_text()?)?;
+    let request = codec.decode(&request)?;
 
     let ClientMessage::Request(request) = request else {
         bail!("unexpected client message");
@@ -223,21 +347,20 @@ async fn version_check(stream: &mut WebSocketStream<TcpStream>) -> Result<()> {
         }},
     });
 
-    stream
-        .send(Message::text(serde_json::to_string(&response)?))
-        .await?;
+    stream.send(codec.encode(&response)?).await?;
 
     Ok(())
 }
 
 async fn handle_ws_message(
     stream: &mut WebSocketStream<TcpStream>,
+    codec: Codec,
     expect_rx: &mut mpsc::UnboundedReceiver<Expectation>,
     msg: tungstenite::Result<Message>,
 ) -> Result<()> {
     match msg {
         Ok(msg) => {
-            let msg = serde_json::from_str::<ClientMessage>(msg.to_text()?)?;
+            let msg = codec.decode(&msg)?;
             info!(message = ?msg);
 
             match msg {
@@ -252,9 +375,7 @@ async fn handle_ws_message(
                         negotiated_rpc_version: 1,
                     });
 
-                    stream
-                        .send(Message::text(serde_json::to_string(&identified)?))
-                        .await?;
+                    stream.send(codec.encode(&identified)?).await?;
                 }
                 ClientMessage::Request(request) => {
                     let expect = expect_rx
@@ -265,15 +386,62 @@ async fn handle_ws_message(
                     ensure!(expect.name == request.request_type);
                     ensure!(expect.req == request.request_data);
 
+                    let response =
+                        codec.encode(&ServerMessage::RequestResponse(RequestResponse {
+                            request_type: request.request_type,
+                            request_id: request.request_id,
+                            request_status: expect.status,
+                            response_data: expect.rsp,
+                        }))?;
+
+                    if expect.duplicate {
+                        stream.send(response.clone()).await?;
+                    }
+                    stream.send(response).await?;
+                }
+                ClientMessage::RequestBatch(batch) => {
+                    let mut results = Vec::with_capacity(batch.requests.len());
+                    let mut variables = HashMap::new();
+
+                    for mut request in batch.requests {
+                        let expect = expect_rx
+                            .recv()
+                            .await
+                            .context("no expectations for batch request")?;
+
+                        for (field, variable) in request.input_variables {
+                            let value = variables.get(&variable).cloned();
+                            let data = request
+                                .request_data
+                                .as_object_mut()
+                                .context("request data is no object")?;
+                            data.insert(field, value.context("unknown variable")?);
+                        }
+
+                        ensure!(expect.name == request.request_type);
+                        ensure!(expect.req == request.request_data);
+
+                        for (variable, field) in request.output_variables {
+                            if let Some(value) = expect.rsp.get(&field) {
+                                variables.insert(variable, value.clone());
+                            }
+                        }
+
+                        results.push(RequestResponse {
+                            request_type: request.request_type,
+                            request_id: String::new(),
+                            request_status: expect.status,
+                            response_data: expect.rsp,
+                        });
+                    }
+
                     stream
-                        .send(Message::text(serde_json::to_string(
-                            &ServerMessage::RequestResponse(RequestResponse {
-                                request_type: request.request_type,
-                                request_id: request.request_id,
-                                request_status: Status::ok(),
-                                response_data: expect.rsp,
-                            }),
-                        )?))
+                        .send(codec.encode(&ServerMessage::RequestBatchResponse(
+                            RequestBatchResponse {
+                                request_id: batch.request_id,
+                                results,
+                            },
+                        ))?)
                         .await?;
                 }
             }
@@ -284,13 +452,14 @@ async fn handle_ws_message(
     Ok(())
 }
 
-async fn handle_event(stream: &mut WebSocketStream<TcpStream>, event: Event) -> Result<()> {
+async fn handle_event(
+    stream: &mut WebSocketStream<TcpStream>,
+    codec: Codec,
+    event: Event,
+) -> Result<()> {
     let msg = ServerMessage::Event(event);
 
-    stream
-        .send(Message::text(serde_json::to_string(&msg)?))
-        .await
-        .map_err(Into::into)
+    stream.send(codec.encode(&msg)?).await.map_err(Into::into)
 }
 
 enum ServerMessage {
@@ -298,6 +467,7 @@ enum ServerMessage {
     Identified(Identified),
     Event(Event),
     RequestResponse(RequestResponse),
+    RequestBatchResponse(RequestBatchResponse),
 }
 
 impl Serialize for ServerMessage {
@@ -318,6 +488,7 @@ impl Serialize for ServerMessage {
             Identified = 2,
             Event = 5,
             RequestResponse = 7,
+            RequestBatchResponse = 9,
         }
 
         match self {
@@ -341,6 +512,11 @@ impl Serialize for ServerMessage {
                 d,
             }
             .serialize(serializer),
+            ServerMessage::RequestBatchResponse(d) => RawMessage {
+                op: OpCode::RequestBatchResponse,
+                d,
+            }
+            .serialize(serializer),
         }
     }
 }
@@ -375,6 +551,13 @@ struct RequestResponse {
     response_data: serde_json::Value,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestBatchResponse {
+    request_id: String,
+    results: Vec<RequestResponse>,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct Status {
@@ -391,6 +574,14 @@ impl Status {
             comment: None,
         }
     }
+
+    const fn failure(code: StatusCode) -> Self {
+        Self {
+            result: false,
+            code,
+            comment: None,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -398,6 +589,7 @@ enum ClientMessage {
     Identify(Identify),
     Reidentify(Reidentify),
     Request(Request),
+    RequestBatch(RequestBatch),
 }
 
 impl<'de> Deserialize<'de> for ClientMessage {
@@ -417,6 +609,7 @@ impl<'de> Deserialize<'de> for ClientMessage {
             Identify = 1,
             Reidentify = 3,
             Request = 6,
+            RequestBatch = 8,
         }
 
         let raw = RawMessage::deserialize(deserializer)?;
@@ -431,6 +624,9 @@ impl<'de> Deserialize<'de> for ClientMessage {
             OpCode::Request => {
                 ClientMessage::Request(serde_json::from_value(raw.d).map_err(de::Error::custom)?)
             }
+            OpCode::RequestBatch => ClientMessage::RequestBatch(
+                serde_json::from_value(raw.d).map_err(de::Error::custom)?,
+            ),
         })
     }
 }
@@ -457,3 +653,22 @@ struct Request {
     #[serde(default)]
     request_data: serde_json::Value,
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestBatch {
+    request_id: String,
+    requests: Vec<BatchRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchRequest {
+    request_type: String,
+    #[serde(default)]
+    request_data: serde_json::Value,
+    #[serde(default)]
+    input_variables: HashMap<String, String>,
+    #[serde(default)]
+    output_variables: HashMap<String, String>,
+}
@@ -1,5 +1,12 @@
 use anyhow::Result;
-use obws::requests::config::{Realm, SetPersistentData};
+use obws::{
+    common::{ColorFormat, ColorRange, ColorSpace},
+    requests::{
+        config::{Fps, Realm, Resolution, SetPersistentData, SetVideoColorSettings, VideoSettings},
+        custom::profile_parameters::AudioTrack,
+    },
+    responses::config::VideoColorSettings,
+};
 use serde_json::json;
 use test_log::test;
 
@@ -115,3 +122,184 @@ async fn config() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn video_settings() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.config();
+
+    server.expect(
+        "SetVideoSettings",
+        json!({
+            "fpsNumerator": 30000,
+            "fpsDenominator": 1001,
+            "outputWidth": 1280,
+            "outputHeight": 720,
+        }),
+        json!(null),
+    );
+
+    client
+        .set_video_settings(
+            VideoSettings {
+                fps: Some(Fps::ntsc(30)),
+                output: Some(Resolution::new(1280, 720)?),
+                ..VideoSettings::default()
+            }
+            .into(),
+        )
+        .await?;
+
+    server.expect(
+        "GetVideoSettings",
+        json!(null),
+        json!({
+            "fpsNumerator": 60,
+            "fpsDenominator": 1,
+            "baseWidth": 1920,
+            "baseHeight": 1080,
+            "outputWidth": 1280,
+            "outputHeight": 720,
+        }),
+    );
+    server.expect(
+        "SetVideoSettings",
+        json!({
+            "fpsNumerator": 60,
+            "fpsDenominator": 1,
+            "baseWidth": 2560,
+            "baseHeight": 1440,
+            "outputWidth": 1280,
+            "outputHeight": 720,
+        }),
+        json!(null),
+    );
+
+    client
+        .set_canvas_resolution(Resolution::new(2560, 1440)?)
+        .await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn video_color_settings() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.config();
+
+    for (name, value) in [
+        ("ColorFormat", json!("P010")),
+        ("ColorSpace", json!("2100PQ")),
+        ("ColorRange", json!(null)),
+    ] {
+        server.expect(
+            "GetProfileParameter",
+            json!({"parameterCategory": "Video", "parameterName": name}),
+            json!({"parameterValue": value, "defaultParameterValue": null}),
+        );
+    }
+
+    assert_eq!(
+        VideoColorSettings {
+            format: Some(ColorFormat::P010),
+            space: Some(ColorSpace::Rec2100Pq),
+            range: None,
+        },
+        client.video_color_settings().await?
+    );
+
+    for (name, value) in [("ColorFormat", "RGB"), ("ColorRange", "Full")] {
+        server.expect(
+            "SetProfileParameter",
+            json!({
+                "parameterCategory": "Video",
+                "parameterName": name,
+                "parameterValue": value,
+            }),
+            json!(null),
+        );
+    }
+
+    client
+        .set_video_color_settings(SetVideoColorSettings {
+            format: Some(ColorFormat::Bgra),
+            space: None,
+            range: Some(ColorRange::Full),
+        })
+        .await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn audio_tracks() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let config = client.config();
+    let client = config.audio_tracks();
+
+    for (name, value) in [("VodTrackEnabled", "true"), ("VodTrackIndex", "2")] {
+        server.expect(
+            "GetProfileParameter",
+            json!({"parameterCategory": "AdvOut", "parameterName": name}),
+            json!({"parameterValue": value, "defaultParameterValue": null}),
+        );
+    }
+
+    assert_eq!(Some(AudioTrack::Track2), client.vod_track().await?);
+
+    server.expect(
+        "SetProfileParameter",
+        json!({
+            "parameterCategory": "AdvOut",
+            "parameterName": "VodTrackEnabled",
+            "parameterValue": "false",
+        }),
+        json!(null),
+    );
+
+    client.set_vod_track(None).await?;
+
+    server.expect(
+        "GetProfileParameter",
+        json!({"parameterCategory": "AdvOut", "parameterName": "Track3Name"}),
+        json!({"parameterValue": "", "defaultParameterValue": null}),
+    );
+
+    assert_eq!(None, client.name(AudioTrack::Track3).await?);
+
+    server.expect(
+        "SetProfileParameter",
+        json!({
+            "parameterCategory": "AdvOut",
+            "parameterName": "Track3Name",
+            "parameterValue": "Commentary",
+        }),
+        json!(null),
+    );
+
+    client
+        .set_name(AudioTrack::Track3, Some("Commentary"))
+        .await?;
+
+    server.expect(
+        "GetProfileParameter",
+        json!({"parameterCategory": "AdvOut", "parameterName": "Track1Bitrate"}),
+        json!({"parameterValue": null, "defaultParameterValue": "160"}),
+    );
+
+    assert_eq!(Some(160), client.bitrate(AudioTrack::Track1).await?);
+
+    server.expect(
+        "SetProfileParameter",
+        json!({
+            "parameterCategory": "AdvOut",
+            "parameterName": "Track1Bitrate",
+            "parameterValue": "320",
+        }),
+        json!(null),
+    );
+
+    client.set_bitrate(AudioTrack::Track1, 320).await?;
+
+    server.stop().await
+}
@@ -161,3 +161,64 @@ async fn filters() -> Result<()> {
 
     server.stop().await
 }
+
+#[cfg(feature = "presets")]
+#[test(tokio::test)]
+async fn filter_presets() -> Result<()> {
+    use obws::requests::custom::filter_presets::FilterPreset;
+
+    let (client, server) = common::new_client().await?;
+    let client = client.filters();
+
+    let preset = FilterPreset::from_toml(
+        r#"
+        [[filter]]
+        name = "{{source}} Color"
+        kind = "color_filter"
+        enabled = false
+
+        [filter.settings]
+        opacity = "{{opacity}}"
+        "#,
+    )?
+    .with_variable("opacity", "0.5");
+
+    server.expect(
+        "CreateSourceFilter",
+        json!({
+            "sourceName": "OBWS-TEST-Text",
+            "filterName": "OBWS-TEST-Text Color",
+            "filterKind": "color_filter",
+            "filterSettings": {"opacity": 0.5},
+        }),
+        json!(null),
+    );
+    server.expect(
+        "SetSourceFilterEnabled",
+        json!({
+            "sourceName": "OBWS-TEST-Text",
+            "filterName": "OBWS-TEST-Text Color",
+            "filterEnabled": false,
+        }),
+        json!(null),
+    );
+
+    client.apply_preset(TEST_TEXT.as_source(), &preset).await?;
+
+    server.expect(
+        "GetSourceFilterList",
+        json!({"sourceName": "OBWS-TEST-Text"}),
+        json!({"filters": [{
+            "filterEnabled": true,
+            "filterIndex": 0,
+            "filterKind": "color_filter",
+            "filterName": "OBWS-TEST-Filter",
+            "filterSettings": {"opacity": 0.5},
+        }]}),
+    );
+
+    let exported = client.export_preset(TEST_TEXT.as_source()).await?;
+    FilterPreset::from_toml(&exported.to_toml()?)?;
+
+    server.stop().await
+}
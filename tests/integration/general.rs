@@ -39,7 +39,7 @@ async fn general() -> Result<()> {
             "availableDiskSpace": 30_000_000,
             "activeFps": 59.99,
             "averageFrameRenderTime": 5,
-            "renderSkippedFrames": 0,
+            "renderSkippedFrames": 100,
             "renderTotalFrames": 10_000,
             "outputSkippedFrames": 0,
             "outputTotalFrames": 8_000,
@@ -48,7 +48,19 @@ async fn general() -> Result<()> {
         }),
     );
 
-    client.stats().await?;
+    let stats = client.stats().await?;
+    assert_eq!(200 * 1024 * 1024, stats.memory_usage.get());
+    assert_eq!(
+        time::Duration::milliseconds(5),
+        stats.average_frame_render_time
+    );
+    assert!((stats.fps().unwrap() - 200.0).abs() < 1e-9);
+    assert!((stats.render_missed_ratio() - 0.01).abs() < 1e-9);
+    assert!(stats.output_missed_ratio().abs() < f64::EPSILON);
+    assert_eq!(
+        stats,
+        serde_json::from_value(serde_json::to_value(&stats)?)?
+    );
 
     server.expect(
         "BroadcastCustomEvent",
@@ -92,6 +104,48 @@ async fn general() -> Result<()> {
     server.stop().await
 }
 
+#[test(tokio::test)]
+async fn stats_history() -> Result<()> {
+    use std::time::Duration;
+
+    let (client, server) = common::new_client().await?;
+    let history = client
+        .general()
+        .stats_history(Duration::from_secs(1), Duration::from_secs(60));
+
+    assert_eq!(None, history.summary());
+
+    for (cpu_usage, skipped) in [(0.5, 0), (1.5, 10)] {
+        server.expect(
+            "GetStats",
+            json!(null),
+            json!({
+                "cpuUsage": cpu_usage,
+                "memoryUsage": 200,
+                "availableDiskSpace": 30_000_000,
+                "activeFps": 59.99,
+                "averageFrameRenderTime": 5,
+                "renderSkippedFrames": skipped,
+                "renderTotalFrames": 10_000,
+                "outputSkippedFrames": 0,
+                "outputTotalFrames": 8_000,
+                "webSocketSessionIncomingMessages": 10,
+                "webSocketSessionOutgoingMessages": 10,
+            }),
+        );
+        history.sample().await?;
+    }
+
+    let summary = history.summary().unwrap();
+    assert_eq!(2, summary.samples);
+    assert_eq!(2, history.samples().len());
+    assert!((summary.average_cpu_usage - 1.0).abs() < f64::EPSILON);
+    assert!((summary.average_frame_render_time - 5.0).abs() < f64::EPSILON);
+    assert!(summary.render_skipped_frames_per_minute > 0.0);
+
+    server.stop().await
+}
+
 #[derive(Serialize)]
 struct CustomEvent<'a> {
     hello: &'a str,
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use obws::requests::hotkeys::KeyModifiers;
+use obws::{requests::hotkeys::KeyModifiers, responses::hotkeys::HotkeyCategory};
 use serde_json::json;
 use test_log::test;
 
@@ -43,5 +43,62 @@ async fn hotkeys() -> Result<()> {
         .trigger_by_sequence("OBS_KEY_P", KeyModifiers::default())
         .await?;
 
+    server.expect(
+        "TriggerHotkeyByKeySequence",
+        json!({
+            "keyId": "OBS_KEY_F1",
+            "keyModifiers": {
+                "shift": true,
+                "control": true,
+                "alt": false,
+                "command": false,
+            },
+        }),
+        json!(null),
+    );
+
+    client
+        .trigger_by_key_sequence(&"Ctrl+Shift+F1".parse()?)
+        .await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn list_detailed() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.hotkeys();
+
+    server.expect(
+        "GetHotkeyList",
+        json!(null),
+        json!({"hotkeys": [
+            "OBSBasic.StartStreaming",
+            "libobs.mute",
+            "OBSBasic.StopStreaming",
+            "libobs.show_scene_item.Camera",
+            "plugin.DoSomething",
+        ]}),
+    );
+
+    let groups = client.list_detailed().await?;
+
+    assert_eq!(
+        vec![
+            HotkeyCategory::Streaming,
+            HotkeyCategory::Source,
+            HotkeyCategory::Other,
+        ],
+        groups.keys().copied().collect::<Vec<_>>()
+    );
+
+    let streaming = &groups[&HotkeyCategory::Streaming];
+    assert_eq!("OBSBasic.StartStreaming", streaming[0].name);
+    assert_eq!("OBSBasic.StopStreaming", streaming[1].name);
+
+    let sources = &groups[&HotkeyCategory::Source];
+    assert_eq!(None, sources[0].source);
+    assert_eq!(Some("Camera"), sources[1].source.as_deref());
+
     server.stop().await
 }
@@ -1,7 +1,13 @@
 use anyhow::Result;
+use futures_util::StreamExt;
 use obws::{
-    common::MonitorType,
-    requests::inputs::{Create, SetSettings, Volume},
+    common::{AudioTracks, MonitorType},
+    events::Event,
+    requests::{
+        inputs::{Create, InputId, SetSettings, Volume},
+        scene_items::{Position, SceneItemTransform},
+    },
+    responses::inputs::{InputId as ResponseInputId, PropertyValue},
 };
 use serde_json::json;
 use test_log::test;
@@ -29,6 +35,30 @@ async fn inputs() -> Result<()> {
 
     client.list_kinds(false).await?;
 
+    server.expect(
+        "GetInputKindList",
+        json!({"unversioned": true}),
+        json!({"inputKinds": ["text_gdiplus", "text_gdiplus_extra", "color_source"]}),
+    );
+    server.expect(
+        "GetInputKindList",
+        json!({"unversioned": false}),
+        json!({"inputKinds": ["text_gdiplus_v3", "text_gdiplus_extra", "color_source_v12"]}),
+    );
+
+    let kinds = client.list_kinds_detailed().await?;
+    assert_eq!(
+        vec![
+            ("text_gdiplus", "text_gdiplus_v3"),
+            ("text_gdiplus_extra", "text_gdiplus_extra"),
+            ("color_source", "color_source_v12"),
+        ],
+        kinds
+            .iter()
+            .map(|kind| (kind.unversioned.as_str(), kind.versioned.as_str()))
+            .collect::<Vec<_>>()
+    );
+
     server.expect(
         "GetSpecialInputs",
         json!(null),
@@ -168,7 +198,7 @@ async fn inputs() -> Result<()> {
         json!(null),
     );
 
-    client.remove(scene_item_id.input_uuid.into()).await?;
+    client.remove(scene_item_id.input_uuid).await?;
 
     server.expect(
         "SetInputName",
@@ -258,6 +288,7 @@ async fn inputs() -> Result<()> {
     );
 
     let tracks = client.audio_tracks(TEST_MEDIA).await?;
+    assert_eq!(AudioTracks::only(&[1]), tracks);
 
     server.expect(
         "SetInputAudioTracks",
@@ -265,13 +296,18 @@ async fn inputs() -> Result<()> {
             "inputName": "OBWS-TEST-Media",
             "inputAudioTracks": {
                 "1": false,
+                "2": true,
+                "5": true,
             },
         }),
         json!(null),
     );
 
     client
-        .set_audio_tracks(TEST_MEDIA, [Some(!tracks[0]), None, None, None, None, None])
+        .set_audio_tracks(
+            TEST_MEDIA,
+            AudioTracks::new().disable(1).enable(2).enable(5),
+        )
         .await?;
 
     server.expect(
@@ -289,9 +325,36 @@ async fn inputs() -> Result<()> {
         }),
     );
 
-    client
+    let items = client
         .properties_list_property_items(TEST_MEDIA, "prop")
         .await?;
+    assert_eq!(
+        Some("hello"),
+        items[0]
+            .typed_value()
+            .as_ref()
+            .and_then(PropertyValue::as_str)
+    );
+
+    server.expect(
+        "GetInputPropertiesListPropertyItems",
+        json!({
+            "inputName": "OBWS-TEST-Media",
+            "propertyName": "prop",
+        }),
+        json!({
+            "propertyItems": [{
+                "itemName": "Display 1",
+                "itemEnabled": true,
+                "itemValue": 1,
+            }],
+        }),
+    );
+
+    let items = client
+        .properties_list_enum::<u32>(TEST_MEDIA, "prop")
+        .await?;
+    assert_eq!(1, items[0].value);
 
     server.expect(
         "PressInputPropertiesButton",
@@ -306,3 +369,224 @@ async fn inputs() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn input_ids_from_list() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.inputs();
+
+    server.expect(
+        "GetInputList",
+        json!({}),
+        json!({"inputs": [{
+            "inputName": "OBWS-TEST-Media",
+            "inputUuid": Uuid::new_v8([1; 16]),
+            "inputKind": "ffmpeg_source",
+            "unversionedInputKind": "ffmpeg_source",
+        }]}),
+    );
+
+    let inputs = client.list(None).await?;
+
+    server.expect(
+        "SetInputMute",
+        json!({
+            "inputUuid": Uuid::new_v8([1; 16]),
+            "inputMuted": true,
+        }),
+        json!(null),
+    );
+
+    client.set_muted(&inputs[0], true).await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn list_lenient() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.inputs();
+
+    server.expect(
+        "GetInputList",
+        json!({}),
+        json!({"inputs": [
+            {
+                "inputName": "OBWS-TEST-Media",
+                "inputUuid": Uuid::new_v8([1; 16]),
+                "inputKind": "ffmpeg_source",
+                "unversionedInputKind": "ffmpeg_source",
+            },
+            {
+                "inputName": "OBWS-TEST-Broken",
+                "inputKind": 5,
+            },
+        ]}),
+    );
+
+    let inputs = client.list_lenient(None).await?;
+    assert_eq!(1, inputs.items.len());
+    assert_eq!(1, inputs.skipped.len());
+    assert_eq!(1, inputs.skipped[0].index);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn watch_muted() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.inputs();
+    let uuid = Uuid::new_v8([1; 16]);
+
+    server.expect(
+        "GetInputMute",
+        json!({"inputUuid": uuid}),
+        json!({"inputMuted": false}),
+    );
+
+    let muted = client.watch_muted(InputId::Uuid(uuid)).await?;
+    futures_util::pin_mut!(muted);
+
+    assert_eq!(Some(false), muted.next().await);
+
+    let id = |name: &str, uuid| ResponseInputId {
+        name: name.to_owned(),
+        uuid,
+    };
+
+    server.send_event(Event::InputMuteStateChanged {
+        id: id("other", Uuid::new_v8([2; 16])),
+        muted: true,
+    });
+    server.send_event(Event::InputNameChanged {
+        uuid,
+        old_name: "OBWS-TEST-Media".to_owned(),
+        new_name: "renamed".to_owned(),
+    });
+    server.send_event(Event::InputMuteStateChanged {
+        id: id("renamed", uuid),
+        muted: true,
+    });
+    server.send_event(Event::InputRemoved {
+        id: id("renamed", uuid),
+    });
+
+    assert_eq!(Some(true), muted.next().await);
+    assert_eq!(None, muted.next().await);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn create_presets() -> Result<()> {
+    use std::path::Path;
+
+    use rgb::RGBA8;
+
+    let (client, server) = common::new_client().await?;
+    let client = client.inputs();
+
+    let created = json!({
+        "inputUuid": Uuid::nil(),
+        "sceneItemId": 1,
+    });
+
+    server.expect(
+        "CreateInput",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "inputName": "background",
+            "inputKind": "color_source_v3",
+            "inputSettings": {
+                "color": 0xff00_00ff_u32,
+                "width": 0,
+                "height": 0,
+            },
+        }),
+        created.clone(),
+    );
+
+    client
+        .create(Create::color(
+            TEST_SCENE,
+            "background",
+            RGBA8::new(255, 0, 0, 255),
+        ))
+        .await?;
+
+    server.expect(
+        "CreateInput",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "inputName": "logo",
+            "inputKind": "image_source",
+            "inputSettings": {
+                "file": "logo.png",
+                "unload": false,
+            },
+        }),
+        created,
+    );
+
+    client
+        .create(Create::image(TEST_SCENE, "logo", Path::new("logo.png")))
+        .await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn create_placed() -> Result<()> {
+    use std::path::Path;
+
+    let (client, server) = common::new_client().await?;
+    let client = client.inputs();
+
+    server.expect(
+        "CreateInput",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "inputName": "logo",
+            "inputKind": "image_source",
+            "inputSettings": {
+                "file": "logo.png",
+                "unload": false,
+            },
+        }),
+        json!({
+            "inputUuid": Uuid::nil(),
+            "sceneItemId": 4,
+        }),
+    );
+    server.expect(
+        "SetSceneItemTransform",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "sceneItemId": 4,
+            "sceneItemTransform": {
+                "positionX": 1700.0,
+                "positionY": 20.0,
+                "rotation": 0.0,
+            },
+        }),
+        json!(null),
+    );
+
+    let created = client
+        .create_placed(
+            Create::image(TEST_SCENE, "logo", Path::new("logo.png")),
+            SceneItemTransform {
+                position: Some(Position {
+                    x: Some(1700.0),
+                    y: Some(20.0),
+                }),
+                rotation: Some(0.0),
+                ..SceneItemTransform::default()
+            },
+        )
+        .await?;
+    assert_eq!(Uuid::nil(), created.input_uuid);
+    assert_eq!(4, created.scene_item_id);
+
+    server.stop().await
+}
@@ -1,3 +1,4 @@
+mod audio;
 mod client;
 mod common;
 mod config;
@@ -10,6 +11,7 @@ mod outputs;
 mod profiles;
 mod recording;
 mod replay_buffer;
+mod restricted;
 mod scene_collections;
 mod scene_items;
 mod scenes;
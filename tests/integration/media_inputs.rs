@@ -1,8 +1,14 @@
 use anyhow::Result;
-use obws::common::MediaAction;
+use futures_util::StreamExt;
+use obws::{
+    common::MediaAction,
+    events::Event,
+    responses::{inputs::InputId, media_inputs::MediaState},
+};
 use serde_json::json;
 use test_log::test;
 use time::Duration;
+use uuid::Uuid;
 
 use crate::common::{self, TEST_MEDIA};
 
@@ -49,6 +55,19 @@ async fn media_inputs() -> Result<()> {
         .offset_cursor(TEST_MEDIA, Duration::seconds(1))
         .await?;
 
+    server.expect(
+        "OffsetMediaInputCursor",
+        json!({
+            "inputName": "OBWS-TEST-Media",
+            "mediaCursorOffset": 500,
+        }),
+        json!(null),
+    );
+
+    client
+        .offset_cursor(TEST_MEDIA, std::time::Duration::from_millis(500))
+        .await?;
+
     server.expect(
         "TriggerMediaInputAction",
         json!({
@@ -62,3 +81,54 @@ async fn media_inputs() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn progress() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.media_inputs();
+
+    let id = InputId {
+        name: "OBWS-TEST-Media".to_owned(),
+        uuid: Uuid::nil(),
+    };
+
+    server.expect(
+        "GetMediaInputStatus",
+        json!({"inputName": "OBWS-TEST-Media"}),
+        json!({
+            "mediaState": "OBS_MEDIA_STATE_STOPPED",
+            "mediaDuration": null,
+            "mediaCursor": null,
+        }),
+    );
+    server.expect("GetInputList", json!({}), json!({"inputs": []}));
+
+    let progress = client
+        .progress(TEST_MEDIA, std::time::Duration::from_secs(3600))
+        .await?;
+    tokio::pin!(progress);
+
+    let status = progress.next().await.unwrap()?;
+    assert_eq!(MediaState::Stopped, status.state);
+
+    server.expect(
+        "GetMediaInputStatus",
+        json!({"inputName": "OBWS-TEST-Media"}),
+        json!({
+            "mediaState": "OBS_MEDIA_STATE_PLAYING",
+            "mediaDuration": 12_500,
+            "mediaCursor": 100,
+        }),
+    );
+    server.send_event(Event::MediaInputPlaybackStarted { id: id.clone() });
+
+    let status = progress.next().await.unwrap()?;
+    assert_eq!(MediaState::Playing, status.state);
+    assert_eq!(Some(Duration::milliseconds(100)), status.cursor);
+
+    server.send_event(Event::InputRemoved { id });
+
+    assert!(progress.next().await.is_none());
+
+    server.stop().await
+}
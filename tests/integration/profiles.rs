@@ -1,5 +1,14 @@
+use std::path::PathBuf;
+
 use anyhow::Result;
-use obws::{requests::profiles::SetParameter, responses::profiles::Profiles};
+use obws::{
+    error::Error,
+    requests::{
+        custom::profile_parameters::{self, ADV_RECORDING_PATH, BASE_WIDTH, SIMPLE_REPLAY_BUFFER},
+        profiles::SetParameter,
+    },
+    responses::{profiles::Profiles, StatusCode},
+};
 use serde_json::json;
 use test_log::test;
 
@@ -91,3 +100,160 @@ async fn profiles() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn typed_parameters() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.profiles();
+
+    server.expect(
+        "GetProfileParameter",
+        json!({
+            "parameterCategory": "AdvOut",
+            "parameterName": "RecFilePath",
+        }),
+        json!({
+            "parameterValue": null,
+            "defaultParameterValue": "/home/obs/Videos",
+        }),
+    );
+
+    let path = client.typed_parameter(ADV_RECORDING_PATH).await?;
+    assert_eq!(Some(PathBuf::from("/home/obs/Videos")), path);
+
+    server.expect(
+        "GetProfileParameter",
+        json!({
+            "parameterCategory": "Video",
+            "parameterName": "BaseCX",
+        }),
+        json!({
+            "parameterValue": "wide",
+            "defaultParameterValue": "1920",
+        }),
+    );
+
+    assert!(matches!(
+        client.typed_parameter(BASE_WIDTH).await,
+        Err(Error::InvalidProfileParameter { name: "BaseCX", .. })
+    ));
+
+    server.expect(
+        "SetProfileParameter",
+        json!({
+            "parameterCategory": "SimpleOutput",
+            "parameterName": "RecRB",
+            "parameterValue": "true",
+        }),
+        json!(null),
+    );
+
+    client
+        .set_typed_parameter(SIMPLE_REPLAY_BUFFER, Some(&true))
+        .await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn duplicate() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.profiles();
+
+    server.expect(
+        "GetProfileList",
+        json!(null),
+        json!({
+            "currentProfileName": "main",
+            "profiles": ["main", "other"],
+        }),
+    );
+    server.expect(
+        "SetCurrentProfile",
+        json!({"profileName": "other"}),
+        json!(null),
+    );
+
+    for (category, name) in profile_parameters::well_known() {
+        server.expect(
+            "GetProfileParameter",
+            json!({
+                "parameterCategory": category.as_str(),
+                "parameterName": name,
+            }),
+            json!({
+                "parameterValue": (name == "BaseCX").then_some("2560"),
+                "defaultParameterValue": "1920",
+            }),
+        );
+    }
+
+    server.expect(
+        "CreateProfile",
+        json!({"profileName": "OBWS-TEST-Copy"}),
+        json!(null),
+    );
+    server.expect(
+        "SetProfileParameter",
+        json!({
+            "parameterCategory": "Video",
+            "parameterName": "BaseCX",
+            "parameterValue": "2560",
+        }),
+        json!(null),
+    );
+    server.expect(
+        "SetCurrentProfile",
+        json!({"profileName": "main"}),
+        json!(null),
+    );
+
+    client.duplicate("other", "OBWS-TEST-Copy").await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn with_temporary() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let profiles = client.profiles();
+
+    server.expect(
+        "GetProfileList",
+        json!(null),
+        json!({
+            "currentProfileName": "main",
+            "profiles": ["main", "other"],
+        }),
+    );
+    server.expect(
+        "SetCurrentProfile",
+        json!({"profileName": "other"}),
+        json!(null),
+    );
+    server.expect_failure("StartRecord", json!(null), StatusCode::OutputRunning);
+    server.expect(
+        "SetCurrentProfile",
+        json!({"profileName": "main"}),
+        json!(null),
+    );
+
+    let result = profiles
+        .with_temporary("other", || async { client.recording().start().await })
+        .await;
+    assert!(matches!(result, Err(Error::OutputRunning(_))));
+
+    server.expect(
+        "GetProfileList",
+        json!(null),
+        json!({
+            "currentProfileName": "main",
+            "profiles": ["main", "other"],
+        }),
+    );
+
+    let value = profiles.with_temporary("main", || async { Ok(5) }).await?;
+    assert_eq!(5, value);
+
+    server.stop().await
+}
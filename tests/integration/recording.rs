@@ -1,5 +1,11 @@
+use std::time::{Duration, Instant};
+
 use anyhow::Result;
-use obws::events::{Event, OutputState};
+use obws::{
+    error::Error,
+    events::{Event, OutputState},
+    requests::custom::profile_parameters::SplitFile,
+};
 use serde_json::json;
 use test_log::test;
 
@@ -141,3 +147,123 @@ async fn recording() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn timecode_of() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.recording();
+
+    let before = Instant::now();
+    server.expect(
+        "GetRecordStatus",
+        json!(null),
+        json!({
+            "outputActive": true,
+            "outputPaused": false,
+            "outputTimecode": "00:01:00.000",
+            "outputDuration": 60_000,
+            "outputBytes": 2048,
+        }),
+    );
+
+    let timecode = client.timecode_of(Instant::now()).await?.unwrap();
+    assert!(timecode >= time::Duration::seconds(59));
+    assert!(timecode <= time::Duration::seconds(61));
+
+    // The previous sample is reused, so no further request is sent.
+    let later = client
+        .timecode_of(Instant::now() + Duration::from_secs(1))
+        .await?
+        .unwrap();
+    assert!(later > timecode);
+    if let Some(instant) = before.checked_sub(Duration::from_secs(120)) {
+        assert_eq!(None, client.timecode_of(instant).await?);
+    }
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn file_settings() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.recording();
+
+    let parameter = |name: &str| json!({"parameterCategory": "AdvOut", "parameterName": name});
+    let value = |value: &str| json!({"parameterValue": value, "defaultParameterValue": null});
+    let set = |name: &str, value: &str| {
+        json!({
+            "parameterCategory": "AdvOut",
+            "parameterName": name,
+            "parameterValue": value,
+        })
+    };
+
+    server.expect(
+        "SetProfileParameter",
+        json!({
+            "parameterCategory": "Output",
+            "parameterName": "FilenameFormatting",
+            "parameterValue": "%CCYY-%MM-%DD_%hh-%mm-%ss",
+        }),
+        json!(null),
+    );
+
+    client
+        .set_filename_format("%CCYY-%MM-%DD_%hh-%mm-%ss")
+        .await?;
+    assert!(matches!(
+        client.set_filename_format("%CCYY_%Q").await,
+        Err(Error::InvalidFilenameFormat(_))
+    ));
+
+    server.expect(
+        "GetProfileParameter",
+        parameter("RecSplitFile"),
+        value("true"),
+    );
+    server.expect(
+        "GetProfileParameter",
+        parameter("RecSplitFileType"),
+        value("Size"),
+    );
+    server.expect(
+        "GetProfileParameter",
+        parameter("RecSplitFileSize"),
+        value("2048"),
+    );
+
+    assert_eq!(
+        SplitFile::Size { megabytes: 2048 },
+        client.split_file_settings().await?
+    );
+
+    server.expect(
+        "SetProfileParameter",
+        set("RecSplitFileTime", "30"),
+        json!(null),
+    );
+    server.expect(
+        "SetProfileParameter",
+        set("RecSplitFileType", "Time"),
+        json!(null),
+    );
+    server.expect(
+        "SetProfileParameter",
+        set("RecSplitFile", "true"),
+        json!(null),
+    );
+
+    client
+        .set_split_file_settings(SplitFile::Time { minutes: 30 })
+        .await?;
+
+    server.expect(
+        "SetProfileParameter",
+        set("RecSplitFile", "false"),
+        json!(null),
+    );
+
+    client.set_split_file_settings(SplitFile::Disabled).await?;
+
+    server.stop().await
+}
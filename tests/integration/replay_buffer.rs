@@ -1,5 +1,10 @@
+use std::{path::PathBuf, time::Duration};
+
 use anyhow::Result;
-use obws::events::{Event, OutputState};
+use obws::{
+    error::Error,
+    events::{Event, OutputState},
+};
 use serde_json::json;
 use test_log::test;
 
@@ -84,3 +89,28 @@ async fn replay_buffer() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn save_and_wait() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.replay_buffer();
+
+    server.expect("SaveReplayBuffer", json!(null), json!(null));
+    server.send_event(Event::ReplayBufferSaved {
+        path: PathBuf::from("/tmp/replay.mkv"),
+    });
+
+    assert_eq!(
+        PathBuf::from("/tmp/replay.mkv"),
+        client.save_and_wait(Duration::from_secs(5)).await?
+    );
+
+    server.expect("SaveReplayBuffer", json!(null), json!(null));
+
+    assert!(matches!(
+        client.save_and_wait(Duration::from_millis(50)).await,
+        Err(Error::Timeout)
+    ));
+
+    server.stop().await
+}
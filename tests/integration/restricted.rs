@@ -0,0 +1,42 @@
+use anyhow::Result;
+use obws::{
+    error::Error,
+    restricted::{Permissions, RestrictedClient},
+};
+use serde_json::json;
+use test_log::test;
+
+use crate::common::{self, TEST_MEDIA};
+
+#[test(tokio::test)]
+async fn restricted() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = RestrictedClient::new(&client, Permissions::SCENES | Permissions::AUDIO);
+
+    server.expect(
+        "SetCurrentProgramScene",
+        json!({"sceneName": "Be right back"}),
+        json!(null),
+    );
+
+    client.set_current_program_scene("Be right back").await?;
+
+    server.expect(
+        "ToggleInputMute",
+        json!({"inputName": "OBWS-TEST-Media"}),
+        json!({"inputMuted": true}),
+    );
+
+    assert!(client.toggle_mute(TEST_MEDIA).await?);
+
+    assert!(matches!(
+        client.start_streaming().await,
+        Err(Error::PermissionDenied(Permissions::STREAMING))
+    ));
+    assert!(matches!(
+        client.save_replay_buffer().await,
+        Err(Error::PermissionDenied(Permissions::REPLAY_BUFFER))
+    ));
+
+    server.stop().await
+}
@@ -1,7 +1,8 @@
 use anyhow::Result;
-use obws::responses::scene_collections::SceneCollections;
+use obws::responses::scene_collections::{HeavyReason, SceneCollections};
 use serde_json::json;
 use test_log::test;
+use uuid::Uuid;
 
 use crate::common;
 
@@ -54,3 +55,109 @@ async fn scene_collections() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn stats() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.scene_collections();
+
+    server.expect(
+        "GetSceneList",
+        json!(null),
+        json!({
+            "currentProgramSceneName": "Live",
+            "currentProgramSceneUuid": Uuid::new_v8([1; 16]),
+            "currentPreviewSceneName": null,
+            "currentPreviewSceneUuid": null,
+            "scenes": [{
+                "sceneName": "Live",
+                "sceneUuid": Uuid::new_v8([1; 16]),
+                "sceneIndex": 0,
+            }],
+        }),
+    );
+    server.expect(
+        "GetInputList",
+        json!({}),
+        json!({
+            "inputs": [
+                {
+                    "inputName": "Alerts",
+                    "inputUuid": Uuid::new_v8([2; 16]),
+                    "inputKind": "browser_source",
+                    "unversionedInputKind": "browser_source",
+                },
+                {
+                    "inputName": "Camera",
+                    "inputUuid": Uuid::new_v8([3; 16]),
+                    "inputKind": "dshow_input",
+                    "unversionedInputKind": "dshow_input",
+                },
+            ],
+        }),
+    );
+    server.expect(
+        "GetSceneItemList",
+        json!({"sceneUuid": Uuid::new_v8([1; 16])}),
+        json!({
+            "sceneItems": [{
+                "sceneItemId": 1,
+                "sceneItemIndex": 0,
+                "sourceName": "Camera",
+                "sourceType": "OBS_SOURCE_TYPE_INPUT",
+                "inputKind": "dshow_input",
+                "isGroup": null,
+            }],
+        }),
+    );
+    server.expect(
+        "GetInputSettings",
+        json!({"inputUuid": Uuid::new_v8([2; 16])}),
+        json!({
+            "inputSettings": {"shutdown": false, "fps_custom": true, "fps": 60},
+            "inputKind": "browser_source",
+        }),
+    );
+    server.expect(
+        "GetInputSettings",
+        json!({"inputUuid": Uuid::new_v8([3; 16])}),
+        json!({
+            "inputSettings": {"frame_interval": 400_000},
+            "inputKind": "dshow_input",
+        }),
+    );
+    for (uuid, filters) in [(1, 0), (2, 0), (3, 2)] {
+        server.expect(
+            "GetSourceFilterList",
+            json!({"sourceUuid": Uuid::new_v8([uuid; 16])}),
+            json!({
+                "filters": (0..filters).map(|index| json!({
+                    "filterEnabled": true,
+                    "filterIndex": index,
+                    "filterKind": "color_filter",
+                    "filterName": format!("Filter {index}"),
+                    "filterSettings": {},
+                })).collect::<Vec<_>>(),
+            }),
+        );
+    }
+
+    let stats = client.stats().await?;
+    assert_eq!(Some(&1), stats.items_per_scene.get("Live"));
+    assert_eq!(Some(&1), stats.inputs_by_kind.get("browser_source"));
+    assert_eq!(Some(&1), stats.inputs_by_kind.get("dshow_input"));
+    assert_eq!(Some(&0), stats.filters_per_source.get("Live"));
+    assert_eq!(Some(&0), stats.filters_per_source.get("Alerts"));
+    assert_eq!(Some(&2), stats.filters_per_source.get("Camera"));
+
+    let heavy = stats
+        .heavy_sources
+        .iter()
+        .map(|source| (source.name.as_str(), source.reason))
+        .collect::<Vec<_>>();
+    assert_eq!(2, heavy.len());
+    assert_eq!(("Alerts", HeavyReason::BrowserWithoutShutdown), heavy[0]);
+    assert!(matches!(heavy[1], ("Alerts", HeavyReason::HighFps(fps)) if fps > 59.0));
+
+    server.stop().await
+}
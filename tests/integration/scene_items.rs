@@ -1,10 +1,14 @@
+use std::time::Duration;
+
 use anyhow::Result;
 use obws::{
+    animate::{Animation, Easing, Target},
     common::{BlendMode, BoundsType},
     requests::scene_items::{
-        Bounds, CreateSceneItem, Duplicate, Id, SceneItemTransform, SetBlendMode, SetEnabled,
-        SetIndex, SetLocked, SetPrivateSettings, SetTransform, Source,
+        Bounds, CreateSceneItem, Duplicate, Id, ItemUpdate, SceneItemTransform, SetBlendMode,
+        SetEnabled, SetIndex, SetLocked, SetPrivateSettings, SetTransform, Source,
     },
+    responses::scene_items::{SceneItemLocation, SourceDimensions},
 };
 use serde_json::json;
 use test_log::test;
@@ -149,6 +153,30 @@ async fn scene_items() -> Result<()> {
 
     client.transform(TEST_SCENE, test_text_id).await?;
 
+    server.expect(
+        "GetSceneItemTransform",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "sceneItemId": 1,
+        }),
+        json!({
+            "sceneItemTransform": {
+                "sourceWidth": 1920,
+                "sourceHeight": 1080,
+                "positionX": 5,
+            },
+        }),
+    );
+
+    let dimensions = client.source_dimensions(TEST_SCENE, test_text_id).await?;
+    assert_eq!(
+        SourceDimensions {
+            source_width: 1920.0,
+            source_height: 1080.0,
+        },
+        dimensions
+    );
+
     server.expect(
         "SetSceneItemTransform",
         json!({
@@ -325,3 +353,530 @@ async fn scene_items() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn relative_transforms() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.scene_items();
+
+    let transform = json!({
+        "sceneItemTransform": {
+            "sourceWidth": 1920,
+            "sourceHeight": 1080,
+            "positionX": 5,
+            "positionY": 10,
+            "rotation": 30.0,
+            "scaleX": 1.0,
+            "scaleY": 0.5,
+            "width": 1920,
+            "height": 540,
+            "alignment": 0,
+            "boundsType": "OBS_BOUNDS_NONE",
+            "boundsAlignment": 0,
+            "boundsWidth": 0,
+            "boundsHeight": 0,
+            "cropLeft": 0,
+            "cropRight": 0,
+            "cropTop": 0,
+            "cropBottom": 0,
+        },
+    });
+    let get = json!({"sceneName": "OBWS-TEST-Scene", "sceneItemId": 1});
+
+    server.expect("GetSceneItemTransform", &get, &transform);
+    server.expect(
+        "SetSceneItemTransform",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "sceneItemId": 1,
+            "sceneItemTransform": {"positionX": 8.0, "positionY": 6.0},
+        }),
+        json!(null),
+    );
+
+    client.nudge(TEST_SCENE, 1, 3.0, -4.0).await?;
+
+    server.expect("GetSceneItemTransform", &get, &transform);
+    server.expect(
+        "SetSceneItemTransform",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "sceneItemId": 1,
+            "sceneItemTransform": {"scaleX": 2.0, "scaleY": 1.0},
+        }),
+        json!(null),
+    );
+
+    client.scale_by(TEST_SCENE, 1, 2.0).await?;
+
+    server.expect("GetSceneItemTransform", &get, &transform);
+    server.expect(
+        "SetSceneItemTransform",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "sceneItemId": 1,
+            "sceneItemTransform": {"rotation": 20.0},
+        }),
+        json!(null),
+    );
+
+    client.rotate_by(TEST_SCENE, 1, 350.0).await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn animate() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.scene_items();
+
+    server.expect(
+        "GetVideoSettings",
+        json!(null),
+        json!({
+            "fpsNumerator": 20,
+            "fpsDenominator": 1,
+            "baseWidth": 1920,
+            "baseHeight": 1080,
+            "outputWidth": 1920,
+            "outputHeight": 1080,
+        }),
+    );
+    server.expect(
+        "GetSceneItemTransform",
+        json!({"sceneName": "OBWS-TEST-Scene", "sceneItemId": 1}),
+        json!({
+            "sceneItemTransform": {
+                "sourceWidth": 1920,
+                "sourceHeight": 1080,
+                "positionX": 0,
+                "positionY": 0,
+                "rotation": 0.0,
+                "scaleX": 1.0,
+                "scaleY": 1.0,
+                "width": 1920,
+                "height": 1080,
+                "alignment": 0,
+                "boundsType": "OBS_BOUNDS_NONE",
+                "boundsAlignment": 0,
+                "boundsWidth": 0,
+                "boundsHeight": 0,
+                "cropLeft": 0,
+                "cropRight": 0,
+                "cropTop": 0,
+                "cropBottom": 0,
+            },
+        }),
+    );
+
+    for (i, x) in [50.0, 100.0].into_iter().enumerate() {
+        if i > 0 {
+            server.expect("Sleep", json!({"sleepFrames": 1}), json!(null));
+        }
+        server.expect(
+            "SetSceneItemTransform",
+            json!({
+                "sceneName": "OBWS-TEST-Scene",
+                "sceneItemId": 1,
+                "sceneItemTransform": {"positionX": x, "positionY": 0.0},
+            }),
+            json!(null),
+        );
+    }
+
+    client
+        .animate(Animation {
+            scene: TEST_SCENE,
+            item_id: 1,
+            target: Target {
+                position: Some((100.0, 0.0)),
+                ..Target::default()
+            },
+            duration: Duration::from_millis(100),
+            easing: Easing::Linear,
+        })
+        .await?;
+
+    server.stop().await
+}
+
+#[cfg(feature = "events")]
+#[test(tokio::test)]
+async fn attach() -> Result<()> {
+    use obws::{
+        attach::Attachment,
+        events::Event,
+        responses::{scene_items::SceneItemTransform as CurrentTransform, scenes::SceneId},
+    };
+
+    let (client, server) = common::new_client().await?;
+
+    let transform = |x: f32, y: f32, scale: f32| CurrentTransform {
+        position_x: x,
+        position_y: y,
+        scale_x: scale,
+        scale_y: scale,
+        ..CurrentTransform::default()
+    };
+
+    server.expect(
+        "GetVideoSettings",
+        json!(null),
+        json!({
+            "fpsNumerator": 20,
+            "fpsDenominator": 1,
+            "baseWidth": 1920,
+            "baseHeight": 1080,
+            "outputWidth": 1920,
+            "outputHeight": 1080,
+        }),
+    );
+    for (item_id, current) in [
+        (1, transform(100.0, 100.0, 1.0)),
+        (2, transform(150.0, 120.0, 0.5)),
+    ] {
+        server.expect(
+            "GetSceneItemTransform",
+            json!({"sceneName": "OBWS-TEST-Scene", "sceneItemId": item_id}),
+            json!({"sceneItemTransform": current}),
+        );
+    }
+    server.expect(
+        "SetSceneItemTransform",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "sceneItemId": 2,
+            "sceneItemTransform": {
+                "positionX": 300.0,
+                "positionY": 140.0,
+                "rotation": 0.0,
+                "scaleX": 1.0,
+                "scaleY": 1.0,
+            },
+        }),
+        json!(null),
+    );
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": false}),
+    );
+
+    let attachment = Attachment {
+        scene: TEST_SCENE,
+        parent: 1,
+        children: vec![2],
+    };
+
+    tokio::select! {
+        biased;
+        result = attachment.run(&client) => result?,
+        result = async {
+            // Only the last change within a frame is applied to the children.
+            for (x, scale) in [(150.0, 1.5), (200.0, 2.0)] {
+                server.send_event(Event::SceneItemTransformChanged {
+                    scene: SceneId {
+                        name: "OBWS-TEST-Scene".to_owned(),
+                        uuid: Uuid::nil(),
+                    },
+                    item_id: 1,
+                    transform: transform(x, 100.0, scale),
+                });
+            }
+
+            // Requests are answered in order, so the children were updated before this request.
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            client.ui().studio_mode_enabled().await
+        } => assert!(!result?),
+    }
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn bulk_locking() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.scene_items();
+
+    let items = json!({"sceneItems": [
+        {
+            "sceneItemId": 1,
+            "sceneItemIndex": 0,
+            "sourceName": "OBWS-TEST-Text",
+            "sourceType": "OBS_SOURCE_TYPE_INPUT",
+        },
+        {
+            "sceneItemId": 2,
+            "sceneItemIndex": 1,
+            "sourceName": "OBWS-TEST-Browser",
+            "sourceType": "OBS_SOURCE_TYPE_INPUT",
+        },
+        {
+            "sceneItemId": 3,
+            "sceneItemIndex": 2,
+            "sourceName": "OBWS-TEST-Group",
+            "sourceType": "OBS_SOURCE_TYPE_SCENE",
+            "isGroup": true,
+        },
+    ]});
+    let group_items = json!({"sceneItems": [
+        {
+            "sceneItemId": 1,
+            "sceneItemIndex": 0,
+            "sourceName": "OBWS-TEST-Media",
+            "sourceType": "OBS_SOURCE_TYPE_INPUT",
+        },
+    ]});
+
+    server.expect(
+        "GetSceneItemList",
+        json!({"sceneName": "OBWS-TEST-Scene"}),
+        &items,
+    );
+    server.expect(
+        "GetGroupSceneItemList",
+        json!({"sceneName": "OBWS-TEST-Group"}),
+        &group_items,
+    );
+    for (scene, id) in [
+        ("OBWS-TEST-Scene", 1),
+        ("OBWS-TEST-Scene", 2),
+        ("OBWS-TEST-Scene", 3),
+        ("OBWS-TEST-Group", 1),
+    ] {
+        server.expect(
+            "SetSceneItemLocked",
+            json!({
+                "sceneName": scene,
+                "sceneItemId": id,
+                "sceneItemLocked": true,
+            }),
+            json!(null),
+        );
+    }
+
+    client.set_locked_all(TEST_SCENE, true).await?;
+
+    server.expect(
+        "GetSceneList",
+        json!(null),
+        json!({
+            "currentProgramSceneName": "OBWS-TEST-Scene",
+            "currentProgramSceneUuid": Uuid::new_v8([1; 16]),
+            "currentPreviewSceneName": null,
+            "currentPreviewSceneUuid": null,
+            "scenes": [{
+                "sceneName": "OBWS-TEST-Scene",
+                "sceneUuid": Uuid::new_v8([1; 16]),
+                "sceneIndex": 0,
+            }],
+        }),
+    );
+    server.expect(
+        "GetSceneItemList",
+        json!({"sceneUuid": Uuid::new_v8([1; 16])}),
+        &items,
+    );
+    server.expect(
+        "GetGroupSceneItemList",
+        json!({"sceneName": "OBWS-TEST-Group"}),
+        &group_items,
+    );
+    for (id, locked) in [(1, true), (2, false), (3, false)] {
+        server.expect(
+            "GetSceneItemLocked",
+            json!({"sceneUuid": Uuid::new_v8([1; 16]), "sceneItemId": id}),
+            json!({"sceneItemLocked": locked}),
+        );
+    }
+    server.expect(
+        "GetSceneItemLocked",
+        json!({"sceneName": "OBWS-TEST-Group", "sceneItemId": 1}),
+        json!({"sceneItemLocked": true}),
+    );
+
+    let locked = client.locked_all().await?;
+    assert_eq!(2, locked.len());
+    assert_eq!(None, locked[0].group);
+    assert_eq!(1, locked[0].item_id);
+    assert_eq!("OBWS-TEST-Text", locked[0].source_name);
+    assert_eq!(Some("OBWS-TEST-Group"), locked[1].group.as_deref());
+    assert_eq!("OBWS-TEST-Media", locked[1].source_name);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn list_with_transforms() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.scene_items();
+
+    server.expect(
+        "GetSceneItemList",
+        json!({"sceneName": "OBWS-TEST-Scene"}),
+        json!({"sceneItems": [
+            {
+                "sceneItemId": 1,
+                "sceneItemIndex": 0,
+                "sourceName": "OBWS-TEST-Text",
+                "sourceType": "OBS_SOURCE_TYPE_INPUT",
+            },
+            {
+                "sceneItemId": 2,
+                "sceneItemIndex": 1,
+                "sourceName": "OBWS-TEST-Browser",
+                "sourceType": "OBS_SOURCE_TYPE_INPUT",
+            },
+        ]}),
+    );
+    for (id, x) in [(1, 5), (2, 50)] {
+        server.expect(
+            "GetSceneItemTransform",
+            json!({"sceneName": "OBWS-TEST-Scene", "sceneItemId": id}),
+            json!({
+                "sceneItemTransform": {
+                    "sourceWidth": 1920,
+                    "sourceHeight": 1080,
+                    "positionX": x,
+                    "positionY": 10,
+                    "rotation": 0.0,
+                    "scaleX": 1.0,
+                    "scaleY": 1.0,
+                    "width": 1920,
+                    "height": 1080,
+                    "alignment": 0b0101,
+                    "boundsType": "OBS_BOUNDS_NONE",
+                    "boundsAlignment": 0,
+                    "boundsWidth": 0,
+                    "boundsHeight": 0,
+                    "cropLeft": 0,
+                    "cropRight": 0,
+                    "cropTop": 0,
+                    "cropBottom": 0,
+                    "cropToBounds": false,
+                },
+            }),
+        );
+    }
+
+    let items = client.list_with_transforms(TEST_SCENE).await?;
+    assert_eq!(2, items.len());
+    assert_eq!("OBWS-TEST-Text", items[0].0.source_name);
+    assert!((items[0].1.position_x - 5.0).abs() < f32::EPSILON);
+    assert_eq!("OBWS-TEST-Browser", items[1].0.source_name);
+    assert!((items[1].1.position_x - 50.0).abs() < f32::EPSILON);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn find_recursive() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.scene_items();
+
+    server.expect(
+        "GetSceneItemList",
+        json!({"sceneName": "OBWS-TEST-Scene"}),
+        json!({"sceneItems": [
+            {
+                "sceneItemId": 1,
+                "sceneItemIndex": 0,
+                "sourceName": "OBWS-TEST-Text",
+                "sourceType": "OBS_SOURCE_TYPE_INPUT",
+            },
+            {
+                "sceneItemId": 2,
+                "sceneItemIndex": 1,
+                "sourceName": "OBWS-TEST-Group",
+                "sourceType": "OBS_SOURCE_TYPE_SCENE",
+                "isGroup": true,
+            },
+        ]}),
+    );
+    server.expect(
+        "GetGroupSceneItemList",
+        json!({"sceneName": "OBWS-TEST-Group"}),
+        json!({"sceneItems": [
+            {
+                "sceneItemId": 7,
+                "sceneItemIndex": 0,
+                "sourceName": "OBWS-TEST-Browser",
+                "sourceType": "OBS_SOURCE_TYPE_INPUT",
+            },
+        ]}),
+    );
+
+    assert_eq!(
+        Some(SceneItemLocation {
+            group: Some("OBWS-TEST-Group".to_owned()),
+            id: 7,
+        }),
+        client
+            .find_recursive(TEST_SCENE, "OBWS-TEST-Browser")
+            .await?
+    );
+
+    server.expect(
+        "GetSceneItemList",
+        json!({"sceneName": "OBWS-TEST-Scene"}),
+        json!({"sceneItems": []}),
+    );
+
+    assert_eq!(None, client.find_recursive(TEST_SCENE, "missing").await?);
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn update() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.scene_items();
+
+    server.expect(
+        "SetSceneItemLocked",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "sceneItemId": 1,
+            "sceneItemLocked": true,
+        }),
+        json!(null),
+    );
+    server.expect(
+        "SetSceneItemBlendMode",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "sceneItemId": 1,
+            "sceneItemBlendMode": "OBS_BLEND_ADDITIVE",
+        }),
+        json!(null),
+    );
+    server.expect(
+        "SetSceneItemTransform",
+        json!({
+            "sceneName": "OBWS-TEST-Scene",
+            "sceneItemId": 1,
+            "sceneItemTransform": {"rotation": 90.0},
+        }),
+        json!(null),
+    );
+
+    client
+        .update(
+            TEST_SCENE,
+            1,
+            ItemUpdate {
+                locked: Some(true),
+                blend_mode: Some(BlendMode::Additive),
+                transform: Some(SceneItemTransform {
+                    rotation: Some(90.0),
+                    ..SceneItemTransform::default()
+                }),
+                ..ItemUpdate::default()
+            },
+        )
+        .await?;
+
+    client.update(TEST_SCENE, 1, ItemUpdate::default()).await?;
+
+    server.stop().await
+}
@@ -1,11 +1,11 @@
 use std::path::Path;
 
 use anyhow::Result;
-use obws::requests::sources::{SaveScreenshot, TakeScreenshot};
+use obws::requests::sources::{ImageFormat, SaveScreenshot, Screenshot, TakeScreenshot};
 use serde_json::json;
 use test_log::test;
 
-use crate::common::{self, TEST_TEXT};
+use crate::common::{self, TEST_SCENE, TEST_TEXT};
 
 #[test(tokio::test)]
 async fn sources() -> Result<()> {
@@ -66,5 +66,104 @@ async fn sources() -> Result<()> {
         })
         .await?;
 
+    server.expect(
+        "GetSourceScreenshot",
+        json!({
+            "sourceName": "OBWS-TEST-Text",
+            "imageFormat": "png",
+            "imageWidth": 4096,
+            "imageCompressionQuality": 80,
+        }),
+        json!({"imageData": "data:image/png;base64,AQID"}),
+    );
+
+    let image = client
+        .screenshot(
+            Screenshot::new(TEST_TEXT.as_source(), ImageFormat::Png)
+                .width(5000)
+                .compression_quality(80),
+        )
+        .await?;
+    assert_eq!(vec![1, 2, 3], image);
+
+    server.expect(
+        "SaveSourceScreenshot",
+        json!({
+            "sourceName": "OBWS-TEST-Text",
+            "imageFormat": "jpeg",
+            "imageHeight": 8,
+            "imageFilePath": "/tmp/file.jpeg",
+        }),
+        json!(null),
+    );
+
+    client
+        .save_screenshot_as(
+            Screenshot::new(TEST_TEXT.as_source(), ImageFormat::Jpeg).height(1),
+            Path::new("/tmp/file.jpeg"),
+        )
+        .await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn screenshot_scene() -> Result<()> {
+    use obws::{requests::sources::ScreenshotOptions, responses::StatusCode};
+
+    let (client, server) = common::new_client().await?;
+    let client = client.sources();
+
+    server.expect(
+        "GetSceneItemList",
+        json!({"sceneName": "OBWS-TEST-Scene"}),
+        json!({"sceneItems": [
+            {
+                "sceneItemId": 1,
+                "sceneItemIndex": 0,
+                "sourceName": "OBWS-TEST-Text",
+                "sourceType": "OBS_SOURCE_TYPE_INPUT",
+            },
+            {
+                "sceneItemId": 2,
+                "sceneItemIndex": 1,
+                "sourceName": "OBWS-TEST-Media",
+                "sourceType": "OBS_SOURCE_TYPE_INPUT",
+            },
+        ]}),
+    );
+    server.expect(
+        "GetSourceScreenshot",
+        json!({
+            "sourceName": "OBWS-TEST-Text",
+            "imageFormat": "png",
+            "imageWidth": 100,
+        }),
+        json!({"imageData": "data:image/png;base64,aGVsbG8="}),
+    );
+    server.expect_failure(
+        "GetSourceScreenshot",
+        json!({
+            "sourceName": "OBWS-TEST-Media",
+            "imageFormat": "png",
+            "imageWidth": 100,
+        }),
+        StatusCode::InvalidResourceState,
+    );
+
+    let screenshots = client
+        .screenshot_scene(
+            TEST_SCENE,
+            "png",
+            ScreenshotOptions {
+                width: Some(100),
+                ..ScreenshotOptions::default()
+            },
+        )
+        .await?;
+
+    assert_eq!(1, screenshots.len());
+    assert_eq!(b"hello", &screenshots["OBWS-TEST-Text"][..]);
+
     server.stop().await
 }
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use obws::{error::Error, requests::streaming::StartChecked, responses::streaming::FailedCheck};
 use serde_json::json;
 use test_log::test;
 
@@ -46,5 +47,164 @@ async fn streaming() -> Result<()> {
 
     client.send_caption("test").await?;
 
+    assert!(matches!(
+        client.send_caption(&"a".repeat(129)).await,
+        Err(Error::CaptionTooLong {
+            length: 129,
+            limit: 128,
+        })
+    ));
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn start_checked() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.streaming();
+
+    let checks = StartChecked {
+        profile: Some("main"),
+        scene_collection: None,
+        virtual_cam_off: false,
+        not_recording: true,
+    };
+    let expect_status = |active: bool| {
+        server.expect(
+            "GetStreamStatus",
+            json!(null),
+            json!({
+                "outputActive": active,
+                "outputReconnecting": false,
+                "outputTimecode": "00:00:00.000",
+                "outputDuration": 0,
+                "outputCongestion": 0,
+                "outputBytes": 0,
+                "outputSkippedFrames": 0,
+                "outputTotalFrames": 0,
+            }),
+        );
+    };
+    let expect_profile = |current: &str| {
+        server.expect(
+            "GetProfileList",
+            json!(null),
+            json!({
+                "currentProfileName": current,
+                "profiles": ["main", "other"],
+            }),
+        );
+    };
+    let expect_service = |server_url: &str| {
+        server.expect(
+            "GetStreamServiceSettings",
+            json!(null),
+            json!({
+                "streamServiceType": "rtmp_custom",
+                "streamServiceSettings": {"server": server_url},
+            }),
+        );
+    };
+    let expect_recording = |active: bool| {
+        server.expect(
+            "GetRecordStatus",
+            json!(null),
+            json!({
+                "outputActive": active,
+                "outputPaused": false,
+                "outputTimecode": "00:00:00.000",
+                "outputDuration": 0,
+                "outputBytes": 0,
+            }),
+        );
+    };
+
+    expect_status(false);
+    expect_profile("other");
+    expect_service("");
+    expect_recording(true);
+
+    let report = client.start_checked(checks).await?;
+    assert!(!report.started);
+    assert_eq!(
+        vec![
+            FailedCheck::WrongProfile {
+                expected: "main".to_owned(),
+                actual: "other".to_owned(),
+            },
+            FailedCheck::StreamServiceNotConfigured,
+            FailedCheck::RecordingActive,
+        ],
+        report.failed_checks
+    );
+
+    expect_status(false);
+    expect_profile("main");
+    expect_service("rtmp://localhost/live");
+    expect_recording(false);
+    server.expect("StartStream", json!(null), json!(null));
+
+    let report = client.start_checked(checks).await?;
+    assert!(report.started);
+    assert!(report.failed_checks.is_empty());
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn outputs() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.streaming();
+
+    let output = |name: &str, kind: &str, service: bool| {
+        json!({
+            "outputName": name,
+            "outputKind": kind,
+            "outputWidth": 1920,
+            "outputHeight": 1080,
+            "outputActive": true,
+            "outputFlags": {
+                "OBS_OUTPUT_AUDIO": true,
+                "OBS_OUTPUT_VIDEO": true,
+                "OBS_OUTPUT_ENCODED": true,
+                "OBS_OUTPUT_MULTI_TRACK": false,
+                "OBS_OUTPUT_SERVICE": service,
+            },
+        })
+    };
+
+    server.expect(
+        "GetOutputList",
+        json!(null),
+        json!({"outputs": [
+            output("simple_stream", "rtmp_output", true),
+            output("virtualcam_output", "virtualcam_output", false),
+            output("whip", "whip_output", true),
+        ]}),
+    );
+
+    for (name, congestion, bytes) in [("simple_stream", 0.0, 1024), ("whip", 0.5, 2048)] {
+        server.expect(
+            "GetOutputStatus",
+            json!({"outputName": name}),
+            json!({
+                "outputActive": true,
+                "outputReconnecting": false,
+                "outputTimecode": "00:01:00.000",
+                "outputDuration": 60_000,
+                "outputCongestion": congestion,
+                "outputBytes": bytes,
+                "outputSkippedFrames": 0,
+                "outputTotalFrames": 3600,
+            }),
+        );
+    }
+
+    let outputs = client.outputs().await?;
+    assert_eq!(2, outputs.len());
+    assert_eq!("whip_output", outputs[1].kind);
+    assert_eq!(0.5, outputs[1].status.congestion);
+    assert_eq!(2048, outputs[1].status.bytes);
+
     server.stop().await
 }
@@ -1,6 +1,8 @@
 use anyhow::Result;
+use obws::error::Error;
 use serde_json::json;
 use test_log::test;
+use time::Duration;
 use uuid::Uuid;
 
 use crate::common::{self, TEST_TRANSITION};
@@ -116,3 +118,179 @@ async fn transitions() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn configure_fade() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.transitions();
+
+    let list = |kind: &str| {
+        json!({
+            "currentSceneTransitionName": null,
+            "currentSceneTransitionUuid": null,
+            "currentSceneTransitionKind": null,
+            "transitions": [
+                {
+                    "transitionName": "Schnitt",
+                    "transitionUuid": Uuid::new_v8([1; 16]),
+                    "transitionKind": "cut_transition",
+                    "transitionFixed": true,
+                    "transitionConfigurable": false,
+                },
+                {
+                    "transitionName": "Überblende",
+                    "transitionUuid": Uuid::new_v8([2; 16]),
+                    "transitionKind": kind,
+                    "transitionFixed": false,
+                    "transitionConfigurable": false,
+                },
+            ],
+        })
+    };
+
+    server.expect(
+        "GetSceneTransitionList",
+        json!(null),
+        list("fade_transition"),
+    );
+    server.expect(
+        "SetCurrentSceneTransition",
+        json!({"transitionName": "Überblende"}),
+        json!(null),
+    );
+    server.expect(
+        "SetCurrentSceneTransitionDuration",
+        json!({"transitionDuration": 750}),
+        json!(null),
+    );
+
+    client.configure_fade(Duration::milliseconds(750)).await?;
+
+    server.expect(
+        "GetSceneTransitionList",
+        json!(null),
+        list("swipe_transition"),
+    );
+
+    assert!(matches!(
+        client.configure_fade(Duration::milliseconds(750)).await,
+        Err(Error::UnknownTransitionKind(kind)) if kind == "fade_transition"
+    ));
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn trigger_studio_transition_with() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.transitions();
+
+    server.expect(
+        "SetCurrentSceneTransition",
+        json!({"transitionName": "OBWS-TEST-Transition"}),
+        json!(null),
+    );
+    server.expect(
+        "SetCurrentSceneTransitionDuration",
+        json!({"transitionDuration": 300}),
+        json!(null),
+    );
+    server.expect("TriggerStudioModeTransition", json!(null), json!(null));
+
+    client
+        .trigger_studio_transition_with(TEST_TRANSITION, Some(Duration::milliseconds(300).into()))
+        .await?;
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn typed_settings() -> Result<()> {
+    use obws::requests::custom::transitions::{Direction, Slide, Swipe, TransitionSettings, Wipe};
+
+    let (client, server) = common::new_client().await?;
+    let client = client.transitions();
+
+    let current = |kind: &str, settings| {
+        json!({
+            "transitionName": "OBWS-TEST-Transition",
+            "transitionUuid": Uuid::new_v8([1; 16]),
+            "transitionKind": kind,
+            "transitionFixed": false,
+            "transitionDuration": 300,
+            "transitionConfigurable": true,
+            "transitionSettings": settings,
+        })
+    };
+
+    server.expect(
+        "GetCurrentSceneTransition",
+        json!(null),
+        current("swipe_transition", json!({"direction": "up"})),
+    );
+
+    assert_eq!(
+        Some(TransitionSettings::Swipe(Swipe {
+            direction: Direction::Up,
+            swipe_in: false,
+        })),
+        client.current_settings_typed().await?
+    );
+
+    server.expect(
+        "GetCurrentSceneTransition",
+        json!(null),
+        current("wipe_transition", json!({})),
+    );
+
+    assert_eq!(
+        Some(TransitionSettings::Wipe(Wipe::default())),
+        client.current_settings_typed().await?
+    );
+
+    server.expect(
+        "GetCurrentSceneTransition",
+        json!(null),
+        current("move_transition", json!({"easing": 2})),
+    );
+
+    assert_eq!(None, client.current_settings_typed().await?);
+
+    server.expect(
+        "GetCurrentSceneTransition",
+        json!(null),
+        current("swipe_transition", json!({})),
+    );
+    server.expect(
+        "SetCurrentSceneTransitionSettings",
+        json!({"transitionSettings": {"direction": "right", "swipe_in": true}}),
+        json!(null),
+    );
+
+    client
+        .set_current_settings_typed(Swipe {
+            direction: Direction::Right,
+            swipe_in: true,
+        })
+        .await?;
+
+    server.expect(
+        "GetCurrentSceneTransition",
+        json!(null),
+        current("swipe_transition", json!({})),
+    );
+
+    assert!(matches!(
+        client
+            .set_current_settings_typed(Slide {
+                direction: Direction::Left,
+            })
+            .await,
+        Err(Error::TransitionKindMismatch {
+            expected: "slide_transition",
+            ..
+        })
+    ));
+
+    server.stop().await
+}
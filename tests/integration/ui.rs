@@ -1,9 +1,13 @@
 use anyhow::Result;
-use obws::requests::ui::{
-    Location, OpenSourceProjector, OpenVideoMixProjector, QtGeometry, QtRect, VideoMixType,
+use obws::{
+    requests::ui::{
+        Location, OpenSourceProjector, OpenVideoMixProjector, QtGeometry, QtRect, VideoMixType,
+    },
+    responses::{scenes::SceneId, StatusCode},
 };
 use serde_json::json;
 use test_log::test;
+use uuid::Uuid;
 
 use crate::common::{self, TEST_TEXT};
 
@@ -113,3 +117,133 @@ async fn ui() -> Result<()> {
 
     server.stop().await
 }
+
+#[test(tokio::test)]
+async fn ensure_projector() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.ui();
+
+    let monitors = |index: u32| {
+        json!({
+            "monitors": [{
+                "monitorName": "sample",
+                "monitorIndex": index,
+                "monitorWidth": 640,
+                "monitorHeight": 480,
+                "monitorPositionX": 5,
+                "monitorPositionY": 10,
+            }],
+        })
+    };
+
+    server.expect("GetMonitorList", json!(null), monitors(0));
+    server.expect(
+        "OpenSourceProjector",
+        json!({"sourceName": "OBWS-TEST-Text", "monitorIndex": 0}),
+        json!(null),
+    );
+
+    assert!(
+        client
+            .ensure_projector(TEST_TEXT.as_source(), "sample")
+            .await?
+    );
+
+    server.expect("GetMonitorList", json!(null), monitors(0));
+
+    assert!(
+        !client
+            .ensure_projector(TEST_TEXT.as_source(), "sample")
+            .await?
+    );
+
+    server.expect("GetMonitorList", json!(null), monitors(1));
+    server.expect(
+        "OpenSourceProjector",
+        json!({"sourceName": "OBWS-TEST-Text", "monitorIndex": 1}),
+        json!(null),
+    );
+
+    assert!(
+        client
+            .ensure_projector(TEST_TEXT.as_source(), "sample")
+            .await?
+    );
+
+    server.expect("GetMonitorList", json!(null), monitors(1));
+
+    assert!(client
+        .ensure_projector(TEST_TEXT.as_source(), "missing")
+        .await
+        .is_err());
+
+    server.stop().await
+}
+
+#[test(tokio::test)]
+async fn studio_mode() -> Result<()> {
+    let (client, server) = common::new_client().await?;
+    let client = client.ui();
+
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": false}),
+    );
+    server.expect(
+        "SetStudioModeEnabled",
+        json!({"studioModeEnabled": true}),
+        json!(null),
+    );
+
+    assert!(client.toggle_studio_mode().await?);
+
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": true}),
+    );
+    server.expect(
+        "GetCurrentProgramScene",
+        json!(null),
+        json!({"sceneName": "Live", "sceneUuid": Uuid::new_v8([1; 16])}),
+    );
+    server.expect(
+        "GetCurrentPreviewScene",
+        json!(null),
+        json!({"sceneName": "Next", "sceneUuid": Uuid::new_v8([2; 16])}),
+    );
+
+    let state = client.studio_state().await?;
+    assert!(state.enabled);
+    assert_eq!("Live", state.program_scene.name);
+    assert_eq!(
+        Some(SceneId {
+            name: "Next".to_owned(),
+            uuid: Uuid::new_v8([2; 16]),
+        }),
+        state.preview_scene
+    );
+
+    server.expect(
+        "GetStudioModeEnabled",
+        json!(null),
+        json!({"studioModeEnabled": false}),
+    );
+    server.expect(
+        "GetCurrentProgramScene",
+        json!(null),
+        json!({"sceneName": "Live", "sceneUuid": Uuid::new_v8([1; 16])}),
+    );
+    server.expect_failure(
+        "GetCurrentPreviewScene",
+        json!(null),
+        StatusCode::StudioModeNotActive,
+    );
+
+    let state = client.studio_state().await?;
+    assert!(!state.enabled);
+    assert_eq!(None, state.preview_scene);
+
+    server.stop().await
+}